@@ -0,0 +1,221 @@
+"""Writes noon-test.ttf, a tiny TrueType font used by Noon's text tests.
+
+The font is generated rather than vendored so its outlines, metrics and
+kerning are small enough to reason about in assertions:
+
+* units per em 1000, ascender 800, descender -200;
+* ``I`` is a 200x700 bar, ``L`` an L-shaped polygon, ``V`` a triangle and
+  ``O`` a ring drawn with quadratic curves;
+* the pair ``L V`` kerns by -100 units in both the legacy ``kern`` table and
+  a GPOS pair adjustment (format 1), while ``V O`` kerns by -50 units only
+  through a GPOS class pair (format 2);
+* space has an advance and no contours.
+
+Run ``python3 make_test_font.py`` from this directory to regenerate it.
+"""
+
+import struct
+
+UNITS_PER_EM = 1000
+ASCENDER = 800
+DESCENDER = -200
+
+# (character, advance, contours); contour points are (x, y, on_curve).
+GLYPHS = [
+    (None, 500, [
+        [(50, 0, True), (450, 0, True), (450, 700, True), (50, 700, True)],
+        [(100, 50, True), (100, 650, True), (400, 650, True), (400, 50, True)],
+    ]),
+    (" ", 300, []),
+    ("I", 400, [[(100, 0, True), (300, 0, True), (300, 700, True), (100, 700, True)]]),
+    ("L", 600, [[
+        (100, 0, True), (550, 0, True), (550, 150, True),
+        (250, 150, True), (250, 700, True), (100, 700, True),
+    ]]),
+    ("O", 800, [
+        [(400, 0, True), (700, 0, False), (700, 350, True), (700, 700, False),
+         (400, 700, True), (100, 700, False), (100, 350, True), (100, 0, False)],
+        [(400, 150, True), (250, 150, False), (250, 350, True), (250, 550, False),
+         (400, 550, True), (550, 550, False), (550, 350, True), (550, 150, False)],
+    ]),
+    ("V", 700, [[(50, 700, True), (350, 0, True), (650, 700, True)]]),
+]
+KERNING = [("L", "V", -100)]
+GPOS_CLASS_KERNING = ("V", "O", -50)
+
+
+def glyph_bytes(contours):
+    if not contours:
+        return b""
+    points = [point for contour in contours for point in contour]
+    xs = [x for x, _, _ in points]
+    ys = [y for _, y, _ in points]
+    data = struct.pack(">hhhhh", len(contours), min(xs), min(ys), max(xs), max(ys))
+    end = -1
+    for contour in contours:
+        end += len(contour)
+        data += struct.pack(">H", end)
+    data += struct.pack(">H", 0)
+    data += bytes(1 if on_curve else 0 for _, _, on_curve in points)
+    previous = 0
+    for x, _, _ in points:
+        data += struct.pack(">h", x - previous)
+        previous = x
+    previous = 0
+    for _, y, _ in points:
+        data += struct.pack(">h", y - previous)
+        previous = y
+    return data + b"\0" * (-len(data) % 4)
+
+
+def cmap_table():
+    mapping = sorted((ord(char), index) for index, (char, _, _) in enumerate(GLYPHS) if char)
+    segments = [(code, code, (index - code) % 0x10000) for code, index in mapping]
+    segments.append((0xFFFF, 0xFFFF, 1))
+    count = len(segments)
+    search_range = 2 * 2 ** (count.bit_length() - 1)
+    subtable = struct.pack(
+        ">HHHHHHH", 4, 16 + 8 * count, 0, 2 * count, search_range,
+        count.bit_length() - 1, 2 * count - search_range,
+    )
+    subtable += b"".join(struct.pack(">H", end) for _, end, _ in segments)
+    subtable += struct.pack(">H", 0)
+    subtable += b"".join(struct.pack(">H", start) for start, _, _ in segments)
+    subtable += b"".join(struct.pack(">H", delta) for _, _, delta in segments)
+    subtable += b"".join(struct.pack(">H", 0) for _ in segments)
+    return struct.pack(">HHHHI", 0, 1, 3, 1, 12) + subtable
+
+
+def kern_table():
+    index = glyph_ids()
+    pairs = sorted((index[left], index[right], value) for left, right, value in KERNING)
+    body = struct.pack(">HHHH", len(pairs), 6, 0, 0)
+    body += b"".join(struct.pack(">HHh", *pair) for pair in pairs)
+    subtable = struct.pack(">HHH", 0, 6 + len(body), 0x0001) + body
+    return struct.pack(">HH", 0, 1) + subtable
+
+
+def glyph_ids():
+    return {char: position for position, (char, _, _) in enumerate(GLYPHS)}
+
+
+def coverage(glyphs):
+    return struct.pack(">HH", 1, len(glyphs)) + b"".join(struct.pack(">H", g) for g in glyphs)
+
+
+def class_def(glyph):
+    # Format 1 starting at the glyph itself: that glyph is class 1, all others 0.
+    return struct.pack(">HHHH", 1, glyph, 1, 1)
+
+
+def pair_pos_format1():
+    index = glyph_ids()
+    pairs = sorted((index[left], index[right], value) for left, right, value in KERNING)
+    firsts = sorted({left for left, _, _ in pairs})
+    sets = [
+        struct.pack(">H", sum(1 for left, _, _ in pairs if left == first))
+        + b"".join(struct.pack(">Hh", right, value) for left, right, value in pairs if left == first)
+        for first in firsts
+    ]
+    # Value format 0x0004 carries only XAdvance on the first glyph.
+    header_len = 10 + 2 * len(sets)
+    offsets = []
+    body = b""
+    for pair_set in sets:
+        offsets.append(header_len + len(body))
+        body += pair_set
+    cover = header_len + len(body)
+    header = struct.pack(">HHHHH", 1, cover, 0x0004, 0, len(sets))
+    header += b"".join(struct.pack(">H", offset) for offset in offsets)
+    return header + body + coverage(firsts)
+
+
+def pair_pos_format2():
+    index = glyph_ids()
+    left, right, value = GPOS_CLASS_KERNING
+    # Two classes on each side; only (class 1, class 1) carries an adjustment.
+    matrix = struct.pack(">hhhh", 0, 0, 0, value)
+    header_len = 16 + len(matrix)
+    cover = coverage([index[left]])
+    first = class_def(index[left])
+    header = struct.pack(
+        ">HHHHHHHH", 2, header_len, 0x0004, 0,
+        header_len + len(cover), header_len + len(cover) + len(first), 2, 2,
+    )
+    return header + matrix + cover + first + class_def(index[right])
+
+
+def gpos_table():
+    subtables = [pair_pos_format1(), pair_pos_format2()]
+    lookup = struct.pack(">HHH", 2, 0, len(subtables))
+    offset = 6 + 2 * len(subtables)
+    for subtable in subtables:
+        lookup += struct.pack(">H", offset)
+        offset += len(subtable)
+    lookup += b"".join(subtables)
+    lookups = struct.pack(">HH", 1, 4) + lookup
+    features = struct.pack(">H4sH", 1, b"kern", 8) + struct.pack(">HHH", 0, 1, 0)
+    lang_sys = struct.pack(">HHHH", 0, 0xFFFF, 1, 0)
+    scripts = struct.pack(">H4sH", 1, b"DFLT", 8) + struct.pack(">HH", 4, 0) + lang_sys
+    script_offset = 10
+    feature_offset = script_offset + len(scripts)
+    lookup_offset = feature_offset + len(features)
+    header = struct.pack(">HHHHH", 1, 0, script_offset, feature_offset, lookup_offset)
+    return header + scripts + features + lookups
+
+
+def checksum(data):
+    data += b"\0" * (-len(data) % 4)
+    return sum(struct.unpack(f">{len(data) // 4}I", data)) & 0xFFFFFFFF
+
+
+def build():
+    glyf = b""
+    offsets = []
+    for _, _, contours in GLYPHS:
+        offsets.append(len(glyf))
+        glyf += glyph_bytes(contours)
+    offsets.append(len(glyf))
+    all_points = [p for _, _, contours in GLYPHS for c in contours for p in c]
+    x_min = min(x for x, _, _ in all_points)
+    x_max = max(x for x, _, _ in all_points)
+    y_max = max(y for _, y, _ in all_points)
+    tables = {
+        b"head": struct.pack(
+            ">IIIIHHqqhhhhHHhhh",
+            0x00010000, 0x00010000, 0, 0x5F0F3CF5, 0, UNITS_PER_EM, 0, 0,
+            x_min, 0, x_max, y_max, 0, 8, 2, 1, 0,
+        ),
+        b"hhea": struct.pack(
+            ">IhhhHhhhhhhhhhhhH",
+            0x00010000, ASCENDER, DESCENDER, 0, max(a for _, a, _ in GLYPHS),
+            0, 0, x_max, 1, 0, 0, 0, 0, 0, 0, 0, len(GLYPHS),
+        ),
+        b"maxp": struct.pack(">IH", 0x00010000, len(GLYPHS)) + b"\0" * 26,
+        b"hmtx": b"".join(struct.pack(">Hh", advance, 0) for _, advance, _ in GLYPHS),
+        b"cmap": cmap_table(),
+        b"loca": b"".join(struct.pack(">I", offset) for offset in offsets),
+        b"glyf": glyf,
+        b"kern": kern_table(),
+        b"GPOS": gpos_table(),
+    }
+    tags = sorted(tables)
+    count = len(tags)
+    search_range = 16 * 2 ** (count.bit_length() - 1)
+    header = struct.pack(
+        ">IHHHH", 0x00010000, count, search_range,
+        count.bit_length() - 1, 16 * count - search_range,
+    )
+    offset = len(header) + 16 * count
+    records = b""
+    body = b""
+    for tag in tags:
+        data = tables[tag]
+        records += struct.pack(">4sIII", tag, checksum(data), offset + len(body), len(data))
+        body += data + b"\0" * (-len(data) % 4)
+    return header + records + body
+
+
+if __name__ == "__main__":
+    with open("noon-test.ttf", "wb") as output:
+        output.write(build())
@@ -5,7 +5,8 @@
 use std::collections::BTreeMap;
 
 use noon_core::{
-    GeometryRef, ObjectId, Property, SceneDefinition, ScenePatch, Style, TimelineError,
+    FontData, FontDefinition, FontId, GeometryRef, ImageData, ImageDefinition, ImageId, Labels,
+    MotionPath, ObjectId, Property, SceneDefinition, ScenePatch, Style, TimelineError,
     TrackDefinition, TrackId, TrackTiming, TrackValues, Transform2D, Vec2, VectorPath,
 };
 
@@ -19,6 +20,7 @@ pub struct DynamicProperties {
     pub appearance: bool,
     pub reveal: bool,
     pub morph: bool,
+    pub value: bool,
 }
 
 impl DynamicProperties {
@@ -32,6 +34,7 @@ impl DynamicProperties {
             Property::Appearance => self.appearance = true,
             Property::Reveal => self.reveal = true,
             Property::Morph => self.morph = true,
+            Property::Value => self.value = true,
         }
     }
 
@@ -44,6 +47,7 @@ impl DynamicProperties {
             || self.appearance
             || self.reveal
             || self.morph
+            || self.value
     }
 }
 
@@ -54,6 +58,8 @@ pub struct CompiledObject {
     pub base_transform: Transform2D,
     pub base_style: Style,
     pub dynamic: DynamicProperties,
+    /// Carried for tooling only; evaluation never reads labels.
+    pub labels: Labels,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -75,6 +81,29 @@ pub enum TransformGeometryPlan {
     },
     /// Fixed source/target topology prepared once for the path renderer.
     PathPair(GeometryRef),
+    /// Quad resize of one registered image; the texture itself never blends.
+    Image {
+        from_size: noon_core::Vec2,
+        to_size: noon_core::Vec2,
+    },
+}
+
+/// Compiler-prepared translation route for a Motion Transform track.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MotionPlan {
+    /// Interpolate the start translation toward `unrotated_end` and rotate
+    /// the result about `center` by `angle` times progress. Plain arcs keep
+    /// the start translation as `unrotated_end`; pivots also carry a scale.
+    Arc {
+        center: Vec2,
+        angle: f32,
+        unrotated_end: Vec2,
+    },
+    /// Displace the start translation by the sampled path offset.
+    Path {
+        sampler: noon_geometry::MotionSampler,
+        follow_tangent: bool,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -87,6 +116,10 @@ pub struct CompiledTrack {
     /// Compiler-selected geometry interpolation strategy for an atomic Transform.
     /// Non-Transform tracks carry `None`.
     pub transform_geometry_plan: Option<TransformGeometryPlan>,
+    /// Curved translation route of a Motion track. Straight-line motion,
+    /// including zero-angle and whole-turn arcs, carries `None`.
+    pub motion_plan: Option<MotionPlan>,
+    pub labels: Labels,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -94,16 +127,21 @@ pub struct CompiledScene {
     objects: Vec<CompiledObject>,
     tracks: Vec<CompiledTrack>,
     object_indices: BTreeMap<ObjectId, u32>,
+    images: Vec<ImageDefinition>,
+    fonts: Vec<FontDefinition>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CompileError {
     TooManyObjects(usize),
     UnknownObject(ObjectId),
+    UnknownImage(ImageId),
+    UnknownFont(FontId),
     DiscontinuousPresence { previous: TrackId, next: TrackId },
     UnsupportedTransformGeometry(TrackId),
     PathTransformRequiresRetessellation(TrackId),
     UnsafeFilledPathTransform(TrackId),
+    InvalidMotionPath(TrackId),
 }
 
 impl std::fmt::Display for CompileError {
@@ -115,6 +153,12 @@ impl std::fmt::Display for CompileError {
             Self::UnknownObject(id) => {
                 write!(formatter, "track references unknown object {}", id.get())
             }
+            Self::UnknownImage(id) => {
+                write!(formatter, "geometry references unknown image {}", id.get())
+            }
+            Self::UnknownFont(id) => {
+                write!(formatter, "geometry references unknown font {}", id.get())
+            }
             Self::DiscontinuousPresence { previous, next } => write!(
                 formatter,
                 "presence track {} does not hand off continuously to track {}",
@@ -136,6 +180,11 @@ impl std::fmt::Display for CompileError {
                 "transform track {} uses filled path geometry without a stable fixed triangulation",
                 id.get()
             ),
+            Self::InvalidMotionPath(id) => write!(
+                formatter,
+                "transform track {} moves along an empty or malformed path",
+                id.get()
+            ),
         }
     }
 }
@@ -149,11 +198,27 @@ pub enum CompilePatchError {
     UnknownObject(ObjectId),
     DuplicateTrack(TrackId),
     UnknownTrack(TrackId),
+    DuplicateImage(ImageId),
+    UnknownImage(ImageId),
+    DuplicateFont(FontId),
+    UnknownFont(FontId),
     InvalidTrack(TimelineError),
-    DiscontinuousPresence { previous: TrackId, next: TrackId },
+    DiscontinuousPresence {
+        previous: TrackId,
+        next: TrackId,
+    },
     UnsupportedTransformGeometry(TrackId),
     PathTransformRequiresRetessellation(TrackId),
     UnsafeFilledPathTransform(TrackId),
+    InvalidMotionPath(TrackId),
+    ObjectIndexOutOfRange {
+        object: ObjectId,
+        index: usize,
+    },
+    /// An image cannot be removed while geometry still draws it.
+    ImageInUse(ImageId),
+    /// A font cannot be removed while a glyph run still uses it.
+    FontInUse(FontId),
 }
 
 impl std::fmt::Display for CompilePatchError {
@@ -166,6 +231,14 @@ impl std::fmt::Display for CompilePatchError {
             Self::UnknownObject(id) => write!(formatter, "unknown object id {}", id.get()),
             Self::DuplicateTrack(id) => write!(formatter, "duplicate track id {}", id.get()),
             Self::UnknownTrack(id) => write!(formatter, "unknown track id {}", id.get()),
+            Self::DuplicateImage(id) => write!(formatter, "duplicate image id {}", id.get()),
+            Self::UnknownImage(id) => {
+                write!(formatter, "geometry references unknown image {}", id.get())
+            }
+            Self::DuplicateFont(id) => write!(formatter, "duplicate font id {}", id.get()),
+            Self::UnknownFont(id) => {
+                write!(formatter, "geometry references unknown font {}", id.get())
+            }
             Self::InvalidTrack(error) => write!(formatter, "invalid track: {error}"),
             Self::DiscontinuousPresence { previous, next } => write!(
                 formatter,
@@ -188,20 +261,60 @@ impl std::fmt::Display for CompilePatchError {
                 "transform track {} uses filled path geometry without a stable fixed triangulation",
                 id.get()
             ),
+            Self::InvalidMotionPath(id) => write!(
+                formatter,
+                "transform track {} moves along an empty or malformed path",
+                id.get()
+            ),
+            Self::ObjectIndexOutOfRange { object, index } => write!(
+                formatter,
+                "cannot move object {} to draw index {index}",
+                object.get()
+            ),
+            Self::ImageInUse(id) => write!(formatter, "image {} is still in use", id.get()),
+            Self::FontInUse(id) => write!(formatter, "font {} is still in use", id.get()),
         }
     }
 }
 
 impl std::error::Error for CompilePatchError {}
 
+/// A registered asset that object geometry references but the scene lacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MissingAsset {
+    Image(ImageId),
+    Font(FontId),
+}
+
+impl From<MissingAsset> for CompileError {
+    fn from(value: MissingAsset) -> Self {
+        match value {
+            MissingAsset::Image(id) => Self::UnknownImage(id),
+            MissingAsset::Font(id) => Self::UnknownFont(id),
+        }
+    }
+}
+
+impl From<MissingAsset> for CompilePatchError {
+    fn from(value: MissingAsset) -> Self {
+        match value {
+            MissingAsset::Image(id) => Self::UnknownImage(id),
+            MissingAsset::Font(id) => Self::UnknownFont(id),
+        }
+    }
+}
+
 impl CompiledScene {
     pub fn compile(scene: &SceneDefinition) -> Result<Self, CompileError> {
         let mut object_indices = BTreeMap::new();
         let mut objects = Vec::with_capacity(scene.objects().len());
+        let images = scene.images().to_vec();
+        let fonts = scene.fonts().to_vec();
 
         for (index, object) in scene.objects().iter().enumerate() {
             let index = u32::try_from(index)
                 .map_err(|_| CompileError::TooManyObjects(scene.objects().len()))?;
+            validate_geometry_assets(&images, &fonts, &object.geometry)?;
             object_indices.insert(object.id, index);
             objects.push(CompiledObject {
                 id: object.id,
@@ -209,6 +322,7 @@ impl CompiledScene {
                 base_transform: object.transform,
                 base_style: object.style,
                 dynamic: DynamicProperties::default(),
+                labels: object.labels.clone(),
             });
         }
 
@@ -217,6 +331,7 @@ impl CompiledScene {
             let object_index = *object_indices
                 .get(&track.object)
                 .ok_or(CompileError::UnknownObject(track.object))?;
+            validate_track_assets(&images, &fonts, track)?;
             objects[object_index as usize].dynamic.mark(track.property);
             tracks.push(
                 compile_track(track, object_index)
@@ -231,6 +346,8 @@ impl CompiledScene {
             objects,
             tracks,
             object_indices,
+            images,
+            fonts,
         })
     }
 
@@ -246,12 +363,43 @@ impl CompiledScene {
         self.object_indices.get(&id).copied()
     }
 
+    /// Index of the first object in draw order named `name`.
+    pub fn find_by_name(&self, name: &str) -> Option<u32> {
+        self.objects
+            .iter()
+            .position(|object| object.labels.name.as_deref() == Some(name))
+            .map(|index| index as u32)
+    }
+
+    pub fn images(&self) -> &[ImageDefinition] {
+        &self.images
+    }
+
+    pub fn image(&self, id: ImageId) -> Option<&ImageData> {
+        self.images
+            .iter()
+            .find(|image| image.id == id)
+            .map(|image| &image.data)
+    }
+
+    pub fn fonts(&self) -> &[FontDefinition] {
+        &self.fonts
+    }
+
+    pub fn font(&self, id: FontId) -> Option<&FontData> {
+        self.fonts
+            .iter()
+            .find(|font| font.id == id)
+            .map(|font| &font.data)
+    }
+
     pub fn apply_patch(&mut self, patch: &ScenePatch) -> Result<(), CompilePatchError> {
         match patch {
             ScenePatch::CreateObject(object) => {
                 if self.object_indices.contains_key(&object.id) {
                     return Err(CompilePatchError::DuplicateObject(object.id));
                 }
+                validate_geometry_assets(&self.images, &self.fonts, &object.geometry)?;
                 let index = u32::try_from(self.objects.len())
                     .map_err(|_| CompilePatchError::TooManyObjects(self.objects.len()))?;
                 self.objects.push(CompiledObject {
@@ -260,6 +408,7 @@ impl CompiledScene {
                     base_transform: object.transform,
                     base_style: object.style,
                     dynamic: DynamicProperties::default(),
+                    labels: object.labels.clone(),
                 });
                 self.object_indices.insert(object.id, index);
             }
@@ -333,10 +482,128 @@ impl CompiledScene {
                 self.tracks = tracks;
                 self.recompute_dynamic();
             }
+            ScenePatch::RegisterImage(image) => {
+                if self.image(image.id).is_some() {
+                    return Err(CompilePatchError::DuplicateImage(image.id));
+                }
+                self.images.push(image.clone());
+            }
+            ScenePatch::RegisterFont(font) => {
+                if self.font(font.id).is_some() {
+                    return Err(CompilePatchError::DuplicateFont(font.id));
+                }
+                self.fonts.push(font.clone());
+            }
+            ScenePatch::SetGeometry { object, geometry } => {
+                let index = self
+                    .object_index(*object)
+                    .ok_or(CompilePatchError::UnknownObject(*object))?;
+                validate_geometry_assets(&self.images, &self.fonts, geometry)?;
+                self.objects[index as usize].geometry = geometry.clone();
+            }
+            ScenePatch::MoveObject { object, index } => self.move_object(*object, *index)?,
+            ScenePatch::ReplaceImage(image) => {
+                let existing = self
+                    .images
+                    .iter_mut()
+                    .find(|existing| existing.id == image.id)
+                    .ok_or(CompilePatchError::UnknownImage(image.id))?;
+                *existing = image.clone();
+            }
+            ScenePatch::RemoveImage(id) => {
+                let position = self
+                    .images
+                    .iter()
+                    .position(|image| image.id == *id)
+                    .ok_or(CompilePatchError::UnknownImage(*id))?;
+                if self.uses_geometry(
+                    |geometry| matches!(geometry, GeometryRef::Image { image, .. } if image == id),
+                ) {
+                    return Err(CompilePatchError::ImageInUse(*id));
+                }
+                self.images.remove(position);
+            }
+            ScenePatch::ReplaceFont(font) => {
+                let existing = self
+                    .fonts
+                    .iter_mut()
+                    .find(|existing| existing.id == font.id)
+                    .ok_or(CompilePatchError::UnknownFont(font.id))?;
+                *existing = font.clone();
+            }
+            ScenePatch::RemoveFont(id) => {
+                let position = self
+                    .fonts
+                    .iter()
+                    .position(|font| font.id == *id)
+                    .ok_or(CompilePatchError::UnknownFont(*id))?;
+                if self.uses_geometry(
+                    |geometry| matches!(geometry, GeometryRef::GlyphRun(run) if run.font == *id),
+                ) {
+                    return Err(CompilePatchError::FontInUse(*id));
+                }
+                self.fonts.remove(position);
+            }
+            ScenePatch::SetLabels { object, labels } => {
+                let index = self
+                    .object_index(*object)
+                    .ok_or(CompilePatchError::UnknownObject(*object))?;
+                self.objects[index as usize].labels = labels.clone();
+            }
+            ScenePatch::SetTrackLabels { track, labels } => {
+                self.tracks
+                    .iter_mut()
+                    .find(|existing| existing.id == *track)
+                    .ok_or(CompilePatchError::UnknownTrack(*track))?
+                    .labels = labels.clone();
+            }
         }
         Ok(())
     }
 
+    fn move_object(&mut self, id: ObjectId, index: usize) -> Result<(), CompilePatchError> {
+        let from = self
+            .object_index(id)
+            .ok_or(CompilePatchError::UnknownObject(id))? as usize;
+        if index >= self.objects.len() {
+            return Err(CompilePatchError::ObjectIndexOutOfRange { object: id, index });
+        }
+        let object = self.objects.remove(from);
+        self.objects.insert(index, object);
+        // Objects between the old and new slot shift by one toward the gap.
+        let remap = |old: usize| {
+            if old == from {
+                index
+            } else if from < index && (from..=index).contains(&old) {
+                old - 1
+            } else if index < from && (index..=from).contains(&old) {
+                old + 1
+            } else {
+                old
+            }
+        };
+        for track in &mut self.tracks {
+            track.object_index = remap(track.object_index as usize) as u32;
+        }
+        sort_tracks(&mut self.tracks);
+        self.rebuild_object_indices();
+        Ok(())
+    }
+
+    /// True when any object or object-valued track draws geometry matching
+    /// `predicate`.
+    fn uses_geometry(&self, predicate: impl Fn(&GeometryRef) -> bool) -> bool {
+        self.objects
+            .iter()
+            .any(|object| predicate(&object.geometry))
+            || self.tracks.iter().any(|track| {
+                track
+                    .values
+                    .snapshots()
+                    .is_some_and(|(from, to)| predicate(&from.geometry) || predicate(&to.geometry))
+            })
+    }
+
     fn compile_patch_track(
         &self,
         track: &TrackDefinition,
@@ -344,6 +611,7 @@ impl CompiledScene {
         let object_index = self
             .object_index(track.object)
             .ok_or(CompilePatchError::UnknownObject(track.object))?;
+        validate_track_assets(&self.images, &self.fonts, track)?;
         if !track.timing.start_time.is_finite() {
             return Err(CompilePatchError::InvalidTrack(
                 TimelineError::InvalidStartTime(track.timing.start_time),
@@ -402,11 +670,21 @@ impl CompiledScene {
     }
 }
 
+/// Builds the interpolation plans [`CompiledScene::compile`] would build for
+/// `track` alone, so tooling can report every unsupported transform at once.
+/// The track must already pass [`TrackDefinition::validate`].
+pub fn check_track(track: &TrackDefinition) -> Result<(), CompileError> {
+    compile_track(track, 0)
+        .map(drop)
+        .map_err(|error| compile_error(track.id, error))
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum TransformCompileFailure {
     UnsupportedGeometry,
     RequiresRetessellation,
     UnsafeFilledPath,
+    InvalidMotionPath,
 }
 
 fn compile_track(
@@ -420,16 +698,73 @@ fn compile_track(
         values: track.values.clone(),
         timing: track.timing,
         transform_geometry_plan: compile_transform_geometry_plan(track)?,
+        motion_plan: compile_motion_plan(track)?,
+        labels: track.labels.clone(),
     })
 }
 
+fn compile_motion_plan(
+    track: &TrackDefinition,
+) -> Result<Option<MotionPlan>, TransformCompileFailure> {
+    let TrackValues::Motion { from, to, path } = &track.values else {
+        return Ok(None);
+    };
+    match path {
+        MotionPath::Arc { angle } if *angle == 0.0 => Ok(None),
+        // Whole turns put the center at infinity, but in f32 `tan(PI)` is a
+        // tiny finite number, so the center would land far away yet finite
+        // and the route would sweep a huge circle. Move straight instead.
+        MotionPath::Arc { angle } if is_whole_turn(*angle) => Ok(None),
+        MotionPath::Arc { angle } => {
+            // Same construction as Manim's path_along_arc: the center sits on
+            // the chord bisector, where the chord subtends `angle`.
+            let chord = to.transform.translation - from.transform.translation;
+            let half_tangent = (angle * 0.5).tan();
+            let center = from.transform.translation
+                + chord * 0.5
+                + Vec2::new(-chord.y, chord.x) * (0.5 / half_tangent);
+            if !center.x.is_finite() || !center.y.is_finite() {
+                return Ok(None);
+            }
+            Ok(Some(MotionPlan::Arc {
+                center,
+                angle: *angle,
+                unrotated_end: from.transform.translation,
+            }))
+        }
+        MotionPath::Pivot { angle, .. } if *angle == 0.0 => Ok(None),
+        MotionPath::Pivot { point, angle } => Ok(Some(MotionPlan::Arc {
+            center: *point,
+            angle: *angle,
+            unrotated_end: *point + (to.transform.translation - *point).rotate(-angle),
+        })),
+        MotionPath::Path {
+            path,
+            follow_tangent,
+        } => Ok(Some(MotionPlan::Path {
+            sampler: noon_geometry::MotionSampler::new(path)
+                .map_err(|_| TransformCompileFailure::InvalidMotionPath)?,
+            follow_tangent: *follow_tangent,
+        })),
+    }
+}
+
+/// Angles within this many radians of a multiple of a full turn count as
+/// whole turns.
+const WHOLE_TURN_TOLERANCE: f32 = 1.0e-4;
+
+fn is_whole_turn(angle: f32) -> bool {
+    let remainder = angle.rem_euclid(std::f32::consts::TAU);
+    remainder.min(std::f32::consts::TAU - remainder) < WHOLE_TURN_TOLERANCE
+}
+
 fn compile_transform_geometry_plan(
     track: &TrackDefinition,
 ) -> Result<Option<TransformGeometryPlan>, TransformCompileFailure> {
     if track.property != Property::Transform {
         return Ok(None);
     }
-    let TrackValues::Object { from, to } = &track.values else {
+    let Some((from, to)) = track.values.snapshots() else {
         unreachable!("validated Transform track must contain object snapshots");
     };
 
@@ -486,11 +821,58 @@ fn compile_transform_geometry_plan(
                 .expect("closed analytic target geometry must convert to a path");
             compile_path_pair(from, to, source, target)?
         }
+        (
+            GeometryRef::Image {
+                image: from_image,
+                size: from_size,
+                sampling: from_sampling,
+            },
+            GeometryRef::Image {
+                image: to_image,
+                size: to_size,
+                sampling: to_sampling,
+            },
+        ) if from_image == to_image && from_sampling == to_sampling => {
+            TransformGeometryPlan::Image {
+                from_size: *from_size,
+                to_size: *to_size,
+            }
+        }
         _ => return Err(TransformCompileFailure::UnsupportedGeometry),
     };
     Ok(Some(plan))
 }
 
+fn validate_geometry_assets(
+    images: &[ImageDefinition],
+    fonts: &[FontDefinition],
+    geometry: &GeometryRef,
+) -> Result<(), MissingAsset> {
+    match geometry {
+        GeometryRef::Image { image, .. } if !images.iter().any(|known| known.id == *image) => {
+            Err(MissingAsset::Image(*image))
+        }
+        GeometryRef::GlyphRun(run) if !fonts.iter().any(|known| known.id == run.font) => {
+            Err(MissingAsset::Font(run.font))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_track_assets(
+    images: &[ImageDefinition],
+    fonts: &[FontDefinition],
+    track: &TrackDefinition,
+) -> Result<(), MissingAsset> {
+    match track.values.snapshots() {
+        Some((from, to)) => {
+            validate_geometry_assets(images, fonts, &from.geometry)?;
+            validate_geometry_assets(images, fonts, &to.geometry)
+        }
+        None => Ok(()),
+    }
+}
+
 fn path_style_requires_retessellation(from: Style, to: Style) -> bool {
     from.stroke_width.to_bits() != to.stroke_width.to_bits()
         || from.stroke_join != to.stroke_join
@@ -582,6 +964,7 @@ fn compile_error(id: TrackId, error: TransformCompileFailure) -> CompileError {
             CompileError::PathTransformRequiresRetessellation(id)
         }
         TransformCompileFailure::UnsafeFilledPath => CompileError::UnsafeFilledPathTransform(id),
+        TransformCompileFailure::InvalidMotionPath => CompileError::InvalidMotionPath(id),
     }
 }
 
@@ -596,6 +979,7 @@ fn compile_patch_error(id: TrackId, error: TransformCompileFailure) -> CompilePa
         TransformCompileFailure::UnsafeFilledPath => {
             CompilePatchError::UnsafeFilledPathTransform(id)
         }
+        TransformCompileFailure::InvalidMotionPath => CompilePatchError::InvalidMotionPath(id),
     }
 }
 
@@ -641,6 +1025,7 @@ const fn property_rank(property: Property) -> u8 {
         Property::Appearance => 5,
         Property::Reveal => 6,
         Property::Morph => 7,
+        Property::Value => 8,
     }
 }
 
@@ -815,6 +1200,7 @@ mod tests {
                 appearance: false,
                 reveal: false,
                 morph: false,
+                value: false,
             }
         );
         assert!(!compiled.objects()[static_index].dynamic.any());
@@ -888,6 +1274,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn whole_turn_arcs_compile_to_straight_motion() {
+        use std::f32::consts::TAU;
+
+        let mut scene = SceneDefinition::new();
+        let angles = [TAU, -TAU, 2.0 * TAU, TAU - 1.0e-6, TAU * 0.5];
+        for angle in angles {
+            let object = scene.add(GeometryRef::circle(1.0));
+            let from = scene.snapshot(object).expect("object exists");
+            let to = from.clone().shift(Vec2::new(2.0, 0.0));
+            // The naive center is finite, which the old check relied on.
+            assert!((angle * 0.5).tan().recip().is_finite());
+            scene
+                .animate_motion(
+                    object,
+                    from,
+                    to,
+                    MotionPath::Arc { angle },
+                    TrackTiming::new(0.0, 1.0, Easing::Linear),
+                )
+                .expect("valid motion track");
+        }
+
+        let compiled = CompiledScene::compile(&scene).expect("scene must compile");
+        let plans: Vec<_> = compiled
+            .tracks()
+            .iter()
+            .map(|track| track.motion_plan.is_some())
+            .collect();
+        assert_eq!(plans, [false, false, false, false, true]);
+    }
+
+    #[test]
+    fn motion_tracks_compile_curved_routes_and_reject_empty_paths() {
+        let mut scene = SceneDefinition::new();
+        let object = scene.add(GeometryRef::circle(1.0));
+        let from = scene.snapshot(object).expect("object exists");
+        let to = from.clone().shift(Vec2::new(2.0, 0.0));
+        let timing = TrackTiming::new(0.0, 1.0, Easing::Linear);
+        for angle in [0.0, std::f32::consts::PI] {
+            scene
+                .animate_motion(
+                    object,
+                    from.clone(),
+                    to.clone(),
+                    MotionPath::Arc { angle },
+                    timing,
+                )
+                .expect("valid motion track");
+        }
+
+        let compiled = CompiledScene::compile(&scene).expect("scene must compile");
+        assert_eq!(compiled.tracks()[0].motion_plan, None);
+        let Some(MotionPlan::Arc { center, angle, .. }) = compiled.tracks()[1].motion_plan else {
+            panic!("half-turn arc must compile to an arc plan");
+        };
+        assert!(
+            (center - Vec2::new(1.0, 0.0)).length() < 1.0e-6,
+            "{center:?}"
+        );
+        assert_eq!(angle, std::f32::consts::PI);
+
+        let empty = scene
+            .animate_motion(
+                object,
+                from.clone(),
+                to,
+                MotionPath::Path {
+                    path: VectorPath::new(),
+                    follow_tangent: false,
+                },
+                timing,
+            )
+            .expect("track values are individually valid");
+        assert_eq!(
+            CompiledScene::compile(&scene),
+            Err(CompileError::InvalidMotionPath(empty))
+        );
+    }
+
     #[test]
     fn patch_rejects_discontinuous_presence_without_mutating_tracks() {
         let mut scene = SceneDefinition::new();
@@ -908,6 +1374,7 @@ mod tests {
                 to: true,
             },
             timing: TrackTiming::new(2.0, 0.0, Easing::Linear),
+            labels: Labels::new(),
         };
         assert_eq!(
             compiled.apply_patch(&ScenePatch::AddTrack(track)),
@@ -966,6 +1433,7 @@ mod tests {
                 appearance: false,
                 reveal: true,
                 morph: false,
+                value: false,
             }
         );
     }
@@ -1033,10 +1501,187 @@ mod tests {
             property: Property::Opacity,
             values: TrackValues::Scalar { from: 1.0, to: 0.0 },
             timing: TrackTiming::new(0.0, 1.0, Easing::Linear),
+            labels: Labels::new(),
         };
         compiled
             .apply_patch(&ScenePatch::AddTrack(track))
             .expect("valid patch");
         assert!(compiled.objects()[1].dynamic.opacity);
     }
+
+    #[test]
+    fn labels_survive_compilation_and_patches() {
+        let mut scene = SceneDefinition::new();
+        let first = scene.add(GeometryRef::circle(1.0));
+        let second = scene.add(GeometryRef::circle(2.0));
+        scene.object_mut(second).expect("object exists").labels =
+            Labels::named("moon").with_tag("sky");
+        let track = scene
+            .animate_position(
+                second,
+                Vec2::ZERO,
+                Vec2::ONE,
+                TrackTiming::new(0.0, 1.0, Easing::Linear),
+            )
+            .expect("valid track");
+        let mut compiled = CompiledScene::compile(&scene).expect("scene must compile");
+        assert_eq!(
+            compiled.objects()[1].labels,
+            Labels::named("moon").with_tag("sky")
+        );
+        assert_eq!(compiled.find_by_name("moon"), Some(1));
+
+        compiled
+            .apply_patch(&ScenePatch::SetLabels {
+                object: first,
+                labels: Labels::named("sun"),
+            })
+            .expect("valid patch");
+        compiled
+            .apply_patch(&ScenePatch::SetTrackLabels {
+                track,
+                labels: Labels::named("orbit"),
+            })
+            .expect("valid patch");
+        assert_eq!(compiled.find_by_name("sun"), Some(0));
+        assert_eq!(compiled.tracks()[0].labels, Labels::named("orbit"));
+        assert_eq!(
+            compiled.apply_patch(&ScenePatch::SetTrackLabels {
+                track: TrackId::new(40),
+                labels: Labels::new(),
+            }),
+            Err(CompilePatchError::UnknownTrack(TrackId::new(40)))
+        );
+    }
+
+    #[test]
+    fn image_geometry_requires_registered_pixels_and_resizes_in_place() {
+        let pixels = noon_core::ImageData::new(1, 1, vec![255; 4]).expect("valid image");
+        let mut scene = SceneDefinition::new();
+        let image = scene.register_image(pixels.clone());
+        let object = scene.add(GeometryRef::image(image, Vec2::new(2.0, 1.0)));
+        scene
+            .add_track(
+                object,
+                Property::Transform,
+                TrackValues::Object {
+                    from: noon_core::ObjectSnapshot::new(GeometryRef::image(
+                        image,
+                        Vec2::new(2.0, 1.0),
+                    )),
+                    to: noon_core::ObjectSnapshot::new(GeometryRef::image(
+                        image,
+                        Vec2::new(4.0, 2.0),
+                    )),
+                },
+                TrackTiming::new(0.0, 1.0, Easing::Linear),
+            )
+            .expect("valid track");
+        let mut compiled = CompiledScene::compile(&scene).expect("image scene must compile");
+        assert_eq!(compiled.image(image), Some(&pixels));
+        assert_eq!(
+            compiled.tracks()[0].transform_geometry_plan,
+            Some(TransformGeometryPlan::Image {
+                from_size: Vec2::new(2.0, 1.0),
+                to_size: Vec2::new(4.0, 2.0),
+            })
+        );
+
+        let missing = noon_core::ImageId::new(9);
+        assert_eq!(
+            compiled.apply_patch(&ScenePatch::CreateObject(ObjectDefinition::new(
+                ObjectId::new(5),
+                GeometryRef::image(missing, Vec2::new(1.0, 1.0)),
+            ))),
+            Err(CompilePatchError::UnknownImage(missing))
+        );
+        compiled
+            .apply_patch(&ScenePatch::RegisterImage(noon_core::ImageDefinition {
+                id: missing,
+                data: pixels,
+            }))
+            .expect("new image");
+        compiled
+            .apply_patch(&ScenePatch::CreateObject(ObjectDefinition::new(
+                ObjectId::new(5),
+                GeometryRef::image(missing, Vec2::new(1.0, 1.0)),
+            )))
+            .expect("image is now registered");
+
+        let mut unregistered = SceneDefinition::new();
+        unregistered.add(GeometryRef::image(missing, Vec2::new(1.0, 1.0)));
+        assert_eq!(
+            CompiledScene::compile(&unregistered),
+            Err(CompileError::UnknownImage(missing))
+        );
+    }
+
+    #[test]
+    fn structural_patches_keep_track_indices_and_asset_references_consistent() {
+        let pixels = noon_core::ImageData::new(1, 1, vec![255; 4]).expect("valid image");
+        let mut scene = SceneDefinition::new();
+        let image = scene.register_image(pixels.clone());
+        let ids: Vec<ObjectId> = (0..3)
+            .map(|_| scene.add(GeometryRef::circle(1.0)))
+            .collect();
+        for id in &ids {
+            scene
+                .animate_appearance(*id, 0.0, 1.0, TrackTiming::new(0.0, 1.0, Easing::Linear))
+                .expect("valid track");
+        }
+        let mut compiled = CompiledScene::compile(&scene).expect("scene must compile");
+
+        compiled
+            .apply_patch(&ScenePatch::MoveObject {
+                object: ids[0],
+                index: 2,
+            })
+            .expect("valid move");
+        assert_eq!(compiled.object_index(ids[0]), Some(2));
+        assert_eq!(compiled.object_index(ids[1]), Some(0));
+        let owners: Vec<_> = compiled
+            .tracks()
+            .iter()
+            .map(|track| {
+                (
+                    track.object_index,
+                    compiled.objects()[track.object_index as usize].id,
+                )
+            })
+            .collect();
+        assert_eq!(owners, vec![(0, ids[1]), (1, ids[2]), (2, ids[0])]);
+        assert_eq!(
+            compiled.apply_patch(&ScenePatch::MoveObject {
+                object: ids[0],
+                index: 3,
+            }),
+            Err(CompilePatchError::ObjectIndexOutOfRange {
+                object: ids[0],
+                index: 3,
+            })
+        );
+
+        let drawn = GeometryRef::image(image, Vec2::ONE);
+        compiled
+            .apply_patch(&ScenePatch::SetGeometry {
+                object: ids[1],
+                geometry: drawn.clone(),
+            })
+            .expect("registered image");
+        assert_eq!(compiled.objects()[0].geometry, drawn);
+        assert_eq!(
+            compiled.apply_patch(&ScenePatch::RemoveImage(image)),
+            Err(CompilePatchError::ImageInUse(image))
+        );
+        compiled
+            .apply_patch(&ScenePatch::SetGeometry {
+                object: ids[1],
+                geometry: GeometryRef::circle(1.0),
+            })
+            .expect("plain geometry");
+        compiled
+            .apply_patch(&ScenePatch::RemoveImage(image))
+            .expect("image no longer drawn");
+        assert!(compiled.images().is_empty());
+    }
 }
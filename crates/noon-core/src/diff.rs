@@ -0,0 +1,602 @@
+//! Structural differences between two scene definitions.
+//!
+//! [`SceneDiff::between`] turns any current scene into any desired one with
+//! patches keyed by stable identity. Every changed field costs exactly one
+//! patch and draw order is restored with the fewest possible moves, so the
+//! same diff doubles as a review report for generated scenes.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    FontId, GeometryRef, ImageId, ObjectDefinition, ObjectId, SceneDefinition, ScenePatch,
+    TrackDefinition, TrackId,
+};
+
+/// Patches from one scene to another, with a report describing them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneDiff {
+    /// Applying these to the current scene in order yields the desired
+    /// objects, tracks, images and fonts. Track order is not reproduced; the
+    /// compiler orders tracks by object, property, start time and id.
+    pub patches: Vec<ScenePatch>,
+    pub report: DiffReport,
+}
+
+/// Reviewable summary of a [`SceneDiff`], one change per entity.
+///
+/// `Display` renders one line per change; `Serialize` gives the same data
+/// as structured JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct DiffReport {
+    pub changes: Vec<DiffChange>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "change")]
+pub enum DiffChange {
+    Added {
+        target: DiffTarget,
+        description: String,
+    },
+    Removed {
+        target: DiffTarget,
+    },
+    /// Names of the fields that differ, such as `geometry` or `timing`.
+    Modified {
+        target: DiffTarget,
+        fields: Vec<&'static str>,
+    },
+    /// An object moved to `index` in draw order.
+    Moved {
+        target: DiffTarget,
+        index: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffTarget {
+    Object(ObjectId),
+    Track(TrackId),
+    Image(ImageId),
+    Font(FontId),
+}
+
+impl SceneDiff {
+    pub fn between(current: &SceneDefinition, desired: &SceneDefinition) -> Self {
+        let mut diff = Self {
+            patches: Vec::new(),
+            report: DiffReport::default(),
+        };
+        diff.register_assets(current, desired);
+        diff.remove_entities(current, desired);
+        diff.update_objects(current, desired);
+        diff.reorder_objects(current, desired);
+        diff.update_tracks(current, desired);
+        diff.unregister_assets(current, desired);
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    fn push(&mut self, patch: ScenePatch) {
+        self.patches.push(patch);
+    }
+
+    fn note(&mut self, change: DiffChange) {
+        self.report.changes.push(change);
+    }
+
+    /// New and changed assets go first so objects created or edited later in
+    /// the batch can already draw them.
+    fn register_assets(&mut self, current: &SceneDefinition, desired: &SceneDefinition) {
+        for image in desired.images() {
+            let target = DiffTarget::Image(image.id);
+            match current.image(image.id) {
+                None => {
+                    self.push(ScenePatch::RegisterImage(image.clone()));
+                    self.note(DiffChange::Added {
+                        target,
+                        description: format!("{}x{}", image.data.width(), image.data.height()),
+                    });
+                }
+                Some(data) if *data != image.data => {
+                    self.push(ScenePatch::ReplaceImage(image.clone()));
+                    self.note(DiffChange::Modified {
+                        target,
+                        fields: vec!["data"],
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        for font in desired.fonts() {
+            let target = DiffTarget::Font(font.id);
+            match current.font(font.id) {
+                None => {
+                    self.push(ScenePatch::RegisterFont(font.clone()));
+                    self.note(DiffChange::Added {
+                        target,
+                        description: format!("{} bytes", font.data.bytes().len()),
+                    });
+                }
+                Some(data) if *data != font.data => {
+                    self.push(ScenePatch::ReplaceFont(font.clone()));
+                    self.note(DiffChange::Modified {
+                        target,
+                        fields: vec!["data"],
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Removing an object drops its tracks too, so only tracks of surviving
+    /// objects are removed explicitly.
+    fn remove_entities(&mut self, current: &SceneDefinition, desired: &SceneDefinition) {
+        let desired_objects: HashSet<ObjectId> =
+            desired.objects().iter().map(|object| object.id).collect();
+        let desired_tracks: HashSet<TrackId> =
+            desired.tracks().iter().map(|track| track.id).collect();
+        for track in current.tracks() {
+            if desired_tracks.contains(&track.id) {
+                continue;
+            }
+            if desired_objects.contains(&track.object) {
+                self.push(ScenePatch::RemoveTrack(track.id));
+            }
+            self.note(DiffChange::Removed {
+                target: DiffTarget::Track(track.id),
+            });
+        }
+        for object in current.objects() {
+            if !desired_objects.contains(&object.id) {
+                self.push(ScenePatch::RemoveObject(object.id));
+                self.note(DiffChange::Removed {
+                    target: DiffTarget::Object(object.id),
+                });
+            }
+        }
+    }
+
+    fn update_objects(&mut self, current: &SceneDefinition, desired: &SceneDefinition) {
+        let current_objects: HashMap<ObjectId, &ObjectDefinition> = current
+            .objects()
+            .iter()
+            .map(|object| (object.id, object))
+            .collect();
+        for object in desired.objects() {
+            let id = object.id;
+            let Some(existing) = current_objects.get(&id) else {
+                self.push(ScenePatch::CreateObject(object.clone()));
+                self.note(DiffChange::Added {
+                    target: DiffTarget::Object(id),
+                    description: geometry_name(&object.geometry).to_owned(),
+                });
+                continue;
+            };
+            let mut fields = Vec::new();
+            if existing.geometry != object.geometry {
+                fields.push("geometry");
+                self.push(ScenePatch::SetGeometry {
+                    object: id,
+                    geometry: object.geometry.clone(),
+                });
+            }
+            if existing.transform != object.transform {
+                fields.push("transform");
+                self.push(ScenePatch::SetTransform {
+                    object: id,
+                    transform: object.transform,
+                });
+            }
+            if existing.style != object.style {
+                fields.push("style");
+                self.push(ScenePatch::SetStyle {
+                    object: id,
+                    style: object.style,
+                });
+            }
+            if existing.labels != object.labels {
+                fields.push("labels");
+                self.push(ScenePatch::SetLabels {
+                    object: id,
+                    labels: object.labels.clone(),
+                });
+            }
+            if !fields.is_empty() {
+                self.note(DiffChange::Modified {
+                    target: DiffTarget::Object(id),
+                    fields,
+                });
+            }
+        }
+    }
+
+    /// After removals and appends the surviving objects keep their current
+    /// relative order. The longest run already in desired order stays put and
+    /// every other object moves directly behind its desired predecessor.
+    fn reorder_objects(&mut self, current: &SceneDefinition, desired: &SceneDefinition) {
+        let target: Vec<ObjectId> = desired.objects().iter().map(|object| object.id).collect();
+        let target_index: HashMap<ObjectId, usize> = target
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (*id, index))
+            .collect();
+        let current_ids: HashSet<ObjectId> =
+            current.objects().iter().map(|object| object.id).collect();
+        let mut order: Vec<ObjectId> = current
+            .objects()
+            .iter()
+            .map(|object| object.id)
+            .filter(|id| target_index.contains_key(id))
+            .chain(
+                target
+                    .iter()
+                    .copied()
+                    .filter(|id| !current_ids.contains(id)),
+            )
+            .collect();
+
+        let positions: Vec<usize> = order.iter().map(|id| target_index[id]).collect();
+        let mut settled = vec![false; target.len()];
+        for position in longest_increasing_run(&positions) {
+            settled[positions[position]] = true;
+        }
+        for (index, id) in target.iter().enumerate() {
+            if settled[index] {
+                continue;
+            }
+            let from = order
+                .iter()
+                .position(|existing| existing == id)
+                .expect("every desired object is in the working order");
+            order.remove(from);
+            let to = match index.checked_sub(1) {
+                Some(previous) => {
+                    order
+                        .iter()
+                        .position(|existing| *existing == target[previous])
+                        .expect("predecessors are placed before their successors")
+                        + 1
+                }
+                None => 0,
+            };
+            order.insert(to, *id);
+            self.push(ScenePatch::MoveObject {
+                object: *id,
+                index: to,
+            });
+            self.note(DiffChange::Moved {
+                target: DiffTarget::Object(*id),
+                index: to,
+            });
+        }
+    }
+
+    fn update_tracks(&mut self, current: &SceneDefinition, desired: &SceneDefinition) {
+        let current_tracks: HashMap<TrackId, &TrackDefinition> = current
+            .tracks()
+            .iter()
+            .map(|track| (track.id, track))
+            .collect();
+        let desired_objects: HashSet<ObjectId> =
+            desired.objects().iter().map(|object| object.id).collect();
+        for track in desired.tracks() {
+            let target = DiffTarget::Track(track.id);
+            match current_tracks.get(&track.id) {
+                // The track went away with its removed object, so it is
+                // added back under the same id.
+                Some(existing) if !desired_objects.contains(&existing.object) => {
+                    self.push(ScenePatch::AddTrack(track.clone()));
+                    self.note(DiffChange::Modified {
+                        target,
+                        fields: track_fields(existing, track),
+                    });
+                }
+                None => {
+                    self.push(ScenePatch::AddTrack(track.clone()));
+                    self.note(DiffChange::Added {
+                        target,
+                        description: format!(
+                            "{:?} of object {}",
+                            track.property,
+                            track.object.get()
+                        ),
+                    });
+                }
+                Some(existing) if **existing != *track => {
+                    let fields = track_fields(existing, track);
+                    // Relabelling alone leaves the compiled track in place.
+                    if fields == ["labels"] {
+                        self.push(ScenePatch::SetTrackLabels {
+                            track: track.id,
+                            labels: track.labels.clone(),
+                        });
+                    } else {
+                        self.push(ScenePatch::ReplaceTrack(track.clone()));
+                    }
+                    self.note(DiffChange::Modified { target, fields });
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Dropped assets go last, once nothing in the scene draws them.
+    fn unregister_assets(&mut self, current: &SceneDefinition, desired: &SceneDefinition) {
+        for image in current.images() {
+            if desired.image(image.id).is_none() {
+                self.push(ScenePatch::RemoveImage(image.id));
+                self.note(DiffChange::Removed {
+                    target: DiffTarget::Image(image.id),
+                });
+            }
+        }
+        for font in current.fonts() {
+            if desired.font(font.id).is_none() {
+                self.push(ScenePatch::RemoveFont(font.id));
+                self.note(DiffChange::Removed {
+                    target: DiffTarget::Font(font.id),
+                });
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DiffReport {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.changes.is_empty() {
+            return formatter.write_str("no changes\n");
+        }
+        for change in &self.changes {
+            match change {
+                DiffChange::Added {
+                    target,
+                    description,
+                } => writeln!(formatter, "+ {target}: {description}")?,
+                DiffChange::Removed { target } => writeln!(formatter, "- {target}")?,
+                DiffChange::Modified { target, fields } => {
+                    writeln!(formatter, "~ {target}: {}", fields.join(", "))?;
+                }
+                DiffChange::Moved { target, index } => {
+                    writeln!(formatter, "> {target}: to draw index {index}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for DiffTarget {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Object(id) => write!(formatter, "object {}", id.get()),
+            Self::Track(id) => write!(formatter, "track {}", id.get()),
+            Self::Image(id) => write!(formatter, "image {}", id.get()),
+            Self::Font(id) => write!(formatter, "font {}", id.get()),
+        }
+    }
+}
+
+const fn geometry_name(geometry: &GeometryRef) -> &'static str {
+    match geometry {
+        GeometryRef::Circle { .. } => "circle",
+        GeometryRef::Rectangle { .. } => "rectangle",
+        GeometryRef::Line { .. } => "line",
+        GeometryRef::VectorPath(_) => "vector path",
+        GeometryRef::External(_) => "external geometry",
+        GeometryRef::Image { .. } => "image",
+        GeometryRef::GlyphRun(_) => "glyph run",
+    }
+}
+
+fn track_fields(current: &TrackDefinition, desired: &TrackDefinition) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if current.object != desired.object {
+        fields.push("object");
+    }
+    if current.property != desired.property {
+        fields.push("property");
+    }
+    if current.values != desired.values {
+        fields.push("values");
+    }
+    if current.timing != desired.timing {
+        fields.push("timing");
+    }
+    if current.labels != desired.labels {
+        fields.push("labels");
+    }
+    fields
+}
+
+/// Positions of one longest strictly increasing subsequence of `values`.
+fn longest_increasing_run(values: &[usize]) -> Vec<usize> {
+    // `tails[k]` is the position ending the best run of length `k + 1`.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; values.len()];
+    for (position, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|tail| values[*tail] < *value);
+        if length > 0 {
+            previous[position] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(position);
+        } else {
+            tails[length] = position;
+        }
+    }
+    let mut run = Vec::with_capacity(tails.len());
+    let mut cursor = tails.last().copied();
+    while let Some(position) = cursor {
+        run.push(position);
+        cursor = previous[position];
+    }
+    run.reverse();
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Easing, TrackTiming, Vec2};
+
+    use super::*;
+
+    fn apply(mut scene: SceneDefinition, patches: &[ScenePatch]) -> SceneDefinition {
+        for patch in patches {
+            scene
+                .apply_patch(patch.clone())
+                .expect("diff patch applies");
+        }
+        scene
+    }
+
+    #[test]
+    fn geometry_and_draw_order_changes_are_patched_in_place() {
+        let mut current = SceneDefinition::new();
+        let ids: Vec<ObjectId> = (0..5)
+            .map(|index| current.add(GeometryRef::circle(index as f32 + 1.0)))
+            .collect();
+        let mut desired = current.clone();
+        desired
+            .apply_patch(ScenePatch::MoveObject {
+                object: ids[0],
+                index: 4,
+            })
+            .unwrap();
+        desired.object_mut(ids[2]).unwrap().geometry = GeometryRef::square(1.0);
+
+        let diff = SceneDiff::between(&current, &desired);
+        assert_eq!(
+            diff.patches,
+            vec![
+                ScenePatch::SetGeometry {
+                    object: ids[2],
+                    geometry: GeometryRef::square(1.0),
+                },
+                ScenePatch::MoveObject {
+                    object: ids[0],
+                    index: 4,
+                },
+            ]
+        );
+        assert_eq!(apply(current, &diff.patches).objects(), desired.objects());
+        assert_eq!(
+            diff.report.to_string(),
+            "~ object 2: geometry\n> object 0: to draw index 4\n"
+        );
+    }
+
+    #[test]
+    fn permutations_take_the_fewest_moves() {
+        let mut current = SceneDefinition::new();
+        for _ in 0..12 {
+            current.add(GeometryRef::circle(1.0));
+        }
+        let mut state = 0x9e37_79b9_u32;
+        for _ in 0..50 {
+            let mut order: Vec<ObjectDefinition> = current.objects().to_vec();
+            for index in (1..order.len()).rev() {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                order.swap(index, state as usize % (index + 1));
+            }
+            let desired = SceneDefinition::from_parts(order, Vec::new()).unwrap();
+            let positions: Vec<usize> = desired
+                .objects()
+                .iter()
+                .map(|object| object.id.get() as usize)
+                .collect();
+            let fixed = longest_increasing_run(&positions).len();
+
+            let diff = SceneDiff::between(&current, &desired);
+            assert_eq!(diff.patches.len(), 12 - fixed);
+            assert_eq!(
+                apply(current.clone(), &diff.patches).objects(),
+                desired.objects()
+            );
+        }
+    }
+
+    #[test]
+    fn tracks_of_removed_objects_are_reported_but_not_patched() {
+        let mut current = SceneDefinition::new();
+        let kept = current.add(GeometryRef::circle(1.0));
+        let dropped = current.add(GeometryRef::circle(1.0));
+        let timing = TrackTiming::new(0.0, 1.0, Easing::Linear);
+        current
+            .animate_position(dropped, Vec2::ZERO, Vec2::ONE, timing)
+            .unwrap();
+        let moved = current
+            .animate_position(kept, Vec2::ZERO, Vec2::ONE, timing)
+            .unwrap();
+
+        let mut desired = SceneDefinition::new();
+        desired.add(GeometryRef::circle(1.0));
+        let mut track = current.tracks()[1].clone();
+        track.timing.duration = 2.0;
+        desired.apply_patch(ScenePatch::AddTrack(track)).unwrap();
+
+        let diff = SceneDiff::between(&current, &desired);
+        assert_eq!(
+            diff.patches[0],
+            ScenePatch::RemoveObject(dropped),
+            "the dropped object's track goes with it"
+        );
+        assert_eq!(diff.patches.len(), 2);
+        assert!(matches!(&diff.patches[1], ScenePatch::ReplaceTrack(track) if track.id == moved));
+        assert_eq!(
+            diff.report.to_string(),
+            "- track 0\n- object 1\n~ track 1: timing\n"
+        );
+        assert!(SceneDiff::between(&desired, &desired).is_empty());
+    }
+
+    #[test]
+    fn relabelling_patches_labels_only() {
+        let mut current = SceneDefinition::new();
+        let object = current.add(GeometryRef::circle(1.0));
+        let track = current
+            .animate_position(
+                object,
+                Vec2::ZERO,
+                Vec2::ONE,
+                TrackTiming::new(0.0, 1.0, Easing::Linear),
+            )
+            .unwrap();
+        let mut desired = current.clone();
+        let labels = crate::Labels::named("sun").with_tag("sky");
+        desired.object_mut(object).unwrap().labels = labels.clone();
+        desired
+            .apply_patch(ScenePatch::SetTrackLabels {
+                track,
+                labels: labels.clone(),
+            })
+            .unwrap();
+
+        let diff = SceneDiff::between(&current, &desired);
+        assert_eq!(
+            diff.patches,
+            vec![
+                ScenePatch::SetLabels {
+                    object,
+                    labels: labels.clone(),
+                },
+                ScenePatch::SetTrackLabels { track, labels },
+            ]
+        );
+        let patched = apply(current, &diff.patches);
+        assert_eq!(patched.objects(), desired.objects());
+        assert_eq!(patched.tracks(), desired.tracks());
+        assert_eq!(
+            diff.report.to_string(),
+            "~ object 0: labels\n~ track 0: labels\n"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::{ImageId, PatchError, SceneDefinition};
+
+/// Texture filtering used when an image is drawn at a different resolution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageSampling {
+    #[default]
+    Linear,
+    Nearest,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImageError {
+    InvalidDimensions { width: u32, height: u32 },
+    PixelLengthMismatch { expected: usize, actual: usize },
+}
+
+impl std::fmt::Display for ImageError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDimensions { width, height } => write!(
+                formatter,
+                "image dimensions must be positive, got {width}x{height}"
+            ),
+            Self::PixelLengthMismatch { expected, actual } => write!(
+                formatter,
+                "image pixel data must contain {expected} RGBA bytes, got {actual}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// Decoded straight-alpha RGBA8 pixels, stored row-major from the top row.
+///
+/// Pixel storage is shared, so cloning scenes, snapshots and compiled data
+/// never copies the raster itself.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedImageData")]
+pub struct ImageData {
+    width: u32,
+    height: u32,
+    pixels: Arc<[u8]>,
+}
+
+#[derive(Deserialize)]
+struct UncheckedImageData {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl TryFrom<UncheckedImageData> for ImageData {
+    type Error = ImageError;
+
+    fn try_from(value: UncheckedImageData) -> Result<Self, Self::Error> {
+        Self::new(value.width, value.height, value.pixels)
+    }
+}
+
+impl ImageData {
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Result<Self, ImageError> {
+        if width == 0 || height == 0 {
+            return Err(ImageError::InvalidDimensions { width, height });
+        }
+        let expected = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|count| count.checked_mul(4))
+            .ok_or(ImageError::InvalidDimensions { width, height })?;
+        if pixels.len() != expected {
+            return Err(ImageError::PixelLengthMismatch {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels: pixels.into(),
+        })
+    }
+
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Width divided by height, used to size image quads without distortion.
+    pub fn aspect_ratio(&self) -> f32 {
+        self.width as f32 / self.height as f32
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Straight-alpha RGBA of one pixel, `None` outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        let mut rgba = [0; 4];
+        rgba.copy_from_slice(&self.pixels[offset..offset + 4]);
+        Some(rgba)
+    }
+
+    /// True when both values share one pixel allocation. Renderers use this as
+    /// a constant-time check before falling back to a full comparison.
+    pub fn shares_pixels(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.pixels, &other.pixels)
+    }
+}
+
+/// Pixel data registered in a scene under a stable identity.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageDefinition {
+    pub id: ImageId,
+    pub data: ImageData,
+}
+
+impl SceneDefinition {
+    pub fn register_image(&mut self, data: ImageData) -> ImageId {
+        let id = ImageId::new(self.next_image_id);
+        self.next_image_id = self
+            .next_image_id
+            .checked_add(1)
+            .expect("Noon image ID space exhausted");
+        self.images.push(ImageDefinition { id, data });
+        id
+    }
+
+    pub fn images(&self) -> &[ImageDefinition] {
+        &self.images
+    }
+
+    pub fn image(&self, id: ImageId) -> Option<&ImageData> {
+        self.images
+            .iter()
+            .find(|image| image.id == id)
+            .map(|image| &image.data)
+    }
+
+    pub(crate) fn insert_image(&mut self, image: ImageDefinition) -> Result<(), PatchError> {
+        if self.image(image.id).is_some() {
+            return Err(PatchError::DuplicateImage(image.id));
+        }
+        let next = image
+            .id
+            .get()
+            .checked_add(1)
+            .ok_or(PatchError::ImageIdExhausted)?;
+        self.next_image_id = self.next_image_id.max(next);
+        self.images.push(image);
+        Ok(())
+    }
+
+    pub(crate) fn replace_image(&mut self, image: ImageDefinition) -> Result<(), PatchError> {
+        let existing = self
+            .images
+            .iter_mut()
+            .find(|existing| existing.id == image.id)
+            .ok_or(PatchError::UnknownImage(image.id))?;
+        *existing = image;
+        Ok(())
+    }
+
+    pub(crate) fn remove_image(&mut self, id: ImageId) -> Result<(), PatchError> {
+        let original_len = self.images.len();
+        self.images.retain(|image| image.id != id);
+        if self.images.len() == original_len {
+            return Err(PatchError::UnknownImage(id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GeometryRef, ScenePatch, Vec2};
+
+    fn checker() -> ImageData {
+        ImageData::new(
+            2,
+            1,
+            vec![255, 0, 0, 255, 0, 0, 255, 128], // red, half-transparent blue
+        )
+        .expect("valid image")
+    }
+
+    #[test]
+    fn image_data_validates_dimensions_and_pixel_length() {
+        assert_eq!(
+            ImageData::new(0, 4, Vec::new()),
+            Err(ImageError::InvalidDimensions {
+                width: 0,
+                height: 4
+            })
+        );
+        assert_eq!(
+            ImageData::new(2, 2, vec![0; 15]),
+            Err(ImageError::PixelLengthMismatch {
+                expected: 16,
+                actual: 15
+            })
+        );
+        let image = checker();
+        assert_eq!(image.pixel(1, 0), Some([0, 0, 255, 128]));
+        assert_eq!(image.pixel(2, 0), None);
+        assert_eq!(image.aspect_ratio(), 2.0);
+        assert!(image.shares_pixels(&image.clone()));
+    }
+
+    #[test]
+    fn registered_images_have_deterministic_ids_and_geometry_bounds() {
+        let mut scene = SceneDefinition::new();
+        let first = scene.register_image(checker());
+        let second = scene.register_image(checker());
+        assert_eq!(first, ImageId::new(0));
+        assert_eq!(second, ImageId::new(1));
+        assert_eq!(scene.image(second), Some(&checker()));
+
+        let geometry = GeometryRef::image(first, Vec2::new(4.0, 2.0));
+        let bounds = geometry.local_bounds().expect("image has bounds");
+        assert_eq!(bounds.min, Vec2::new(-2.0, -1.0));
+        assert_eq!(bounds.max, Vec2::new(2.0, 1.0));
+    }
+
+    #[test]
+    fn register_image_patch_rejects_duplicates_and_advances_ids() {
+        let mut scene = SceneDefinition::new();
+        let patch = ScenePatch::RegisterImage(ImageDefinition {
+            id: ImageId::new(4),
+            data: checker(),
+        });
+        scene.apply_patch(patch.clone()).expect("new image");
+        assert_eq!(
+            scene.apply_patch(patch),
+            Err(PatchError::DuplicateImage(ImageId::new(4)))
+        );
+        assert_eq!(scene.register_image(checker()), ImageId::new(5));
+    }
+
+    #[test]
+    fn deserialized_images_are_validated() {
+        let unchecked = |pixels: Vec<u8>| UncheckedImageData {
+            width: 1,
+            height: 1,
+            pixels,
+        };
+        assert_eq!(
+            ImageData::try_from(unchecked(vec![1, 2, 3])),
+            Err(ImageError::PixelLengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        let image = ImageData::try_from(unchecked(vec![1, 2, 3, 4])).expect("valid image");
+        assert_eq!(image.pixel(0, 0), Some([1, 2, 3, 4]));
+    }
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::{ObjectDefinition, SceneDefinition, TrackDefinition};
+
+/// Authoring names, tags and opaque user metadata attached to objects and
+/// tracks. Labels travel through documents, patches, diffs and compilation
+/// but never affect evaluation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Labels {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl Labels {
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            tags: Vec::new(),
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new()
+        }
+    }
+
+    /// Adds `tag` unless it is already present.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|existing| existing == tag)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.tags.is_empty() && self.metadata.is_empty()
+    }
+}
+
+impl SceneDefinition {
+    /// The first object in draw order named `name`.
+    pub fn find_by_name(&self, name: &str) -> Option<&ObjectDefinition> {
+        self.objects()
+            .iter()
+            .find(|object| object.labels.name.as_deref() == Some(name))
+    }
+
+    /// Objects carrying `tag`, in draw order.
+    pub fn objects_tagged<'a>(
+        &'a self,
+        tag: &'a str,
+    ) -> impl Iterator<Item = &'a ObjectDefinition> + 'a {
+        self.objects()
+            .iter()
+            .filter(move |object| object.labels.has_tag(tag))
+    }
+
+    /// The first track in timeline order named `name`.
+    pub fn find_track_by_name(&self, name: &str) -> Option<&TrackDefinition> {
+        self.tracks()
+            .iter()
+            .find(|track| track.labels.name.as_deref() == Some(name))
+    }
+
+    /// Tracks carrying `tag`, in timeline order.
+    pub fn tracks_tagged<'a>(
+        &'a self,
+        tag: &'a str,
+    ) -> impl Iterator<Item = &'a TrackDefinition> + 'a {
+        self.tracks()
+            .iter()
+            .filter(move |track| track.labels.has_tag(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Easing, GeometryRef, TrackTiming, Vec2};
+
+    use super::*;
+
+    #[test]
+    fn lookups_address_objects_and_tracks_by_labels() {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(1.0));
+        let square = scene.add(GeometryRef::rectangle(1.0, 1.0));
+        scene.object_mut(circle).expect("object exists").labels =
+            Labels::named("sun").with_tag("sky").with_tag("sky");
+        scene.object_mut(square).expect("object exists").labels = Labels::new().with_tag("sky");
+        let track = scene
+            .animate_position(
+                circle,
+                Vec2::ZERO,
+                Vec2::ONE,
+                TrackTiming::new(0.0, 1.0, Easing::Linear),
+            )
+            .expect("valid track");
+        scene
+            .apply_patch(crate::ScenePatch::SetTrackLabels {
+                track,
+                labels: Labels::named("sunrise").with_metadata("beat", "1"),
+            })
+            .expect("known track");
+
+        assert_eq!(
+            scene.find_by_name("sun").map(|object| object.id),
+            Some(circle)
+        );
+        assert!(scene.find_by_name("moon").is_none());
+        assert_eq!(
+            scene
+                .objects_tagged("sky")
+                .map(|object| object.id)
+                .collect::<Vec<_>>(),
+            vec![circle, square]
+        );
+        assert_eq!(
+            scene.object(circle).expect("object exists").labels.tags,
+            vec!["sky".to_owned()]
+        );
+        let sunrise = scene.find_track_by_name("sunrise").expect("named track");
+        assert_eq!(sunrise.id, track);
+        assert_eq!(
+            sunrise.labels.metadata.get("beat").map(String::as_str),
+            Some("1")
+        );
+        assert_eq!(scene.tracks_tagged("sky").count(), 0);
+    }
+}
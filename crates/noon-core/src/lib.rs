@@ -6,10 +6,18 @@
 
 #![forbid(unsafe_code)]
 
+mod diff;
+mod image;
+mod labels;
 mod patch;
+mod text;
 mod timeline;
 
+pub use diff::*;
+pub use image::*;
+pub use labels::*;
 pub use patch::*;
+pub use text::*;
 pub use timeline::*;
 
 use serde::{Deserialize, Serialize};
@@ -38,6 +46,8 @@ define_id!(ObjectId);
 define_id!(GeometryId);
 define_id!(TrackId);
 define_id!(SignalId);
+define_id!(ImageId);
+define_id!(FontId);
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Vec2 {
@@ -559,11 +569,26 @@ pub enum PathCommand {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GeometryRef {
-    Circle { radius: f32 },
-    Rectangle { size: Vec2 },
-    Line { start: Vec2, end: Vec2 },
+    Circle {
+        radius: f32,
+    },
+    Rectangle {
+        size: Vec2,
+    },
+    Line {
+        start: Vec2,
+        end: Vec2,
+    },
     VectorPath(VectorPath),
     External(GeometryId),
+    Image {
+        image: ImageId,
+        size: Vec2,
+        #[serde(default)]
+        sampling: ImageSampling,
+    },
+    // Boxed so text does not widen every geometry value.
+    GlyphRun(Box<GlyphRun>),
 }
 
 impl GeometryRef {
@@ -589,18 +614,33 @@ impl GeometryRef {
         Self::VectorPath(path)
     }
 
+    /// A quad of `size` world units showing a registered image, centered on
+    /// the object origin.
+    pub const fn image(image: ImageId, size: Vec2) -> Self {
+        Self::Image {
+            image,
+            size,
+            sampling: ImageSampling::Linear,
+        }
+    }
+
+    pub fn glyph_run(run: GlyphRun) -> Self {
+        Self::GlyphRun(Box::new(run))
+    }
+
     pub fn local_bounds(&self) -> Option<Rect> {
         match self {
             Self::Circle { radius } => Some(Rect::new(
                 Vec2::new(-radius, -radius),
                 Vec2::new(*radius, *radius),
             )),
-            Self::Rectangle { size } => {
+            Self::Rectangle { size } | Self::Image { size, .. } => {
                 let half = *size * 0.5;
                 Some(Rect::new(-half, half))
             }
             Self::Line { start, end } => Rect::from_points([*start, *end]),
             Self::VectorPath(path) => path.conservative_bounds(),
+            Self::GlyphRun(run) => Some(run.bounds),
             Self::External(_) => None,
         }
     }
@@ -612,6 +652,8 @@ pub struct ObjectDefinition {
     pub geometry: GeometryRef,
     pub transform: Transform2D,
     pub style: Style,
+    #[serde(default, skip_serializing_if = "Labels::is_empty")]
+    pub labels: Labels,
 }
 
 impl ObjectDefinition {
@@ -621,6 +663,7 @@ impl ObjectDefinition {
             geometry,
             transform: Transform2D::default(),
             style: Style::default(),
+            labels: Labels::new(),
         }
     }
 
@@ -681,6 +724,18 @@ impl ObjectSnapshot {
         self
     }
 
+    /// Rotate by `angle` while the local origin orbits `point`.
+    pub fn rotate_about(mut self, angle: f32, point: Vec2) -> Self {
+        self.transform.translation = point + (self.transform.translation - point).rotate(angle);
+        self.rotate_by(angle)
+    }
+
+    /// Scale by `factor` while the local origin moves away from `point`.
+    pub fn scale_about(mut self, factor: f32, point: Vec2) -> Self {
+        self.transform.translation = point + (self.transform.translation - point) * factor;
+        self.scale_by(factor)
+    }
+
     pub fn set_color(mut self, color: Color) -> Self {
         if self.style.fill.is_some() {
             self.style.fill = Some(color);
@@ -763,8 +818,8 @@ impl ObjectSnapshot {
         };
         let target_point = target_bounds.critical_point(direction);
         let point_to_align = self_bounds.critical_point(direction);
-        let mask = Vec2::new(direction.x.signum().abs(), direction.y.signum().abs());
-        self.transform.translation += (target_point - point_to_align).component_mul(mask);
+        self.transform.translation +=
+            (target_point - point_to_align).component_mul(axis_mask(direction));
         self
     }
 
@@ -786,12 +841,20 @@ impl ObjectSnapshot {
         );
         let point_to_align = bounds.critical_point(direction);
         let shift = frame_target - point_to_align - direction * buff;
-        let mask = Vec2::new(direction.x.signum().abs(), direction.y.signum().abs());
-        self.transform.translation += shift.component_mul(mask);
+        self.transform.translation += shift.component_mul(axis_mask(direction));
         self
     }
 }
 
+/// Selects the axes a direction names. `f32::signum` maps zero to one, so it
+/// cannot be used to leave the other axis alone.
+fn axis_mask(direction: Vec2) -> Vec2 {
+    Vec2::new(
+        if direction.x == 0.0 { 0.0 } else { 1.0 },
+        if direction.y == 0.0 { 0.0 } else { 1.0 },
+    )
+}
+
 impl From<&ObjectDefinition> for ObjectSnapshot {
     fn from(value: &ObjectDefinition) -> Self {
         Self {
@@ -808,6 +871,10 @@ pub struct SceneDefinition {
     pub(crate) next_object_id: u64,
     pub(crate) tracks: Vec<TrackDefinition>,
     pub(crate) next_track_id: u64,
+    pub(crate) images: Vec<ImageDefinition>,
+    pub(crate) next_image_id: u64,
+    pub(crate) fonts: Vec<FontDefinition>,
+    pub(crate) next_font_id: u64,
 }
 
 impl SceneDefinition {
@@ -1015,6 +1082,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pivot_operations_keep_the_pivot_fixed() {
+        let snapshot = ObjectSnapshot::new(GeometryRef::square(1.0)).shift(RIGHT * 2.0);
+        let pivot = RIGHT;
+        // The square's top-right corner starts at (2.5, 0.5).
+        let local = Vec2::new(0.5, 0.5);
+
+        let rotated = snapshot.clone().rotate_about(PI * 0.5, pivot);
+        assert!((rotated.transform.translation - Vec2::new(1.0, 1.0)).length() < 1.0e-6);
+        assert!((rotated.transform.transform_point(local) - Vec2::new(0.5, 1.5)).length() < 1.0e-6);
+
+        let scaled = snapshot.scale_about(2.0, pivot);
+        assert_eq!(scaled.transform.translation, Vec2::new(3.0, 0.0));
+        assert_eq!(scaled.transform.transform_point(local), Vec2::new(4.0, 1.0));
+    }
+
+    /// Regression: `to_edge`, `to_corner` and `align_to` built their mask
+    /// from `signum().abs()`, which is one for zero components, so aligning
+    /// to LEFT also snapped the object vertically.
+    #[test]
+    fn axis_mask_ignores_zero_components_unlike_signum() {
+        assert_eq!(0.0_f32.signum().abs(), 1.0);
+        assert_eq!(axis_mask(LEFT), Vec2::new(1.0, 0.0));
+        assert_eq!(axis_mask(DOWN), Vec2::new(0.0, 1.0));
+        assert_eq!(axis_mask(UR), Vec2::ONE);
+        assert_eq!(axis_mask(Vec2::ZERO), Vec2::ZERO);
+    }
+
+    #[test]
+    fn edge_alignment_leaves_the_unnamed_axis_alone() {
+        let snapshot = ObjectSnapshot::new(GeometryRef::square(1.0)).shift(UP);
+        let edged = snapshot.clone().to_edge(LEFT, 0.0);
+        assert_eq!(edged.center().y, 1.0);
+        assert!((edged.world_bounds().unwrap().min.x + DEFAULT_FRAME_WIDTH * 0.5).abs() < 1e-5);
+
+        let target = ObjectSnapshot::new(GeometryRef::square(2.0)).shift(RIGHT * 3.0);
+        let aligned = snapshot.align_to(&target, LEFT);
+        assert_eq!(aligned.center(), Vec2::new(2.5, 1.0));
+    }
+
     #[test]
     fn id_namespaces_are_distinct_types() {
         let object = ObjectId::new(7);
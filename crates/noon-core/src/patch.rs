@@ -1,10 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-use crate::timeline::validate_track_timing;
 use crate::{
-    ObjectDefinition, ObjectId, SceneDefinition, Style, TimelineError, TrackDefinition, TrackId,
-    Transform2D,
+    FontDefinition, FontId, GeometryRef, ImageDefinition, ImageId, Labels, ObjectDefinition,
+    ObjectId, SceneDefinition, Style, TimelineError, TrackDefinition, TrackId, Transform2D,
 };
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -23,6 +22,36 @@ pub enum ScenePatch {
     AddTrack(TrackDefinition),
     ReplaceTrack(TrackDefinition),
     RemoveTrack(TrackId),
+    RegisterImage(ImageDefinition),
+    RegisterFont(FontDefinition),
+    /// Swaps an object's geometry in place, keeping its identity and tracks.
+    SetGeometry {
+        object: ObjectId,
+        geometry: GeometryRef,
+    },
+    /// Moves an object to `index` in draw order, counted after it has been
+    /// taken out of its current position.
+    MoveObject {
+        object: ObjectId,
+        index: usize,
+    },
+    /// Swaps the pixels registered under an existing image id.
+    ReplaceImage(ImageDefinition),
+    RemoveImage(ImageId),
+    /// Swaps the bytes registered under an existing font id.
+    ReplaceFont(FontDefinition),
+    RemoveFont(FontId),
+    /// Replaces an object's name, tags and metadata.
+    SetLabels {
+        object: ObjectId,
+        labels: Labels,
+    },
+    /// Replaces a track's name, tags and metadata without touching its
+    /// values or timing.
+    SetTrackLabels {
+        track: TrackId,
+        labels: Labels,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -34,6 +63,13 @@ pub enum PatchError {
     InvalidTrack(TimelineError),
     ObjectIdExhausted,
     TrackIdExhausted,
+    DuplicateImage(ImageId),
+    UnknownImage(ImageId),
+    ImageIdExhausted,
+    DuplicateFont(FontId),
+    UnknownFont(FontId),
+    FontIdExhausted,
+    ObjectIndexOutOfRange { object: ObjectId, index: usize },
 }
 
 impl std::fmt::Display for PatchError {
@@ -46,6 +82,17 @@ impl std::fmt::Display for PatchError {
             Self::InvalidTrack(error) => write!(formatter, "invalid track: {error}"),
             Self::ObjectIdExhausted => formatter.write_str("Noon object ID space exhausted"),
             Self::TrackIdExhausted => formatter.write_str("Noon track ID space exhausted"),
+            Self::DuplicateImage(id) => write!(formatter, "duplicate image id {}", id.get()),
+            Self::UnknownImage(id) => write!(formatter, "unknown image id {}", id.get()),
+            Self::ImageIdExhausted => formatter.write_str("Noon image ID space exhausted"),
+            Self::DuplicateFont(id) => write!(formatter, "duplicate font id {}", id.get()),
+            Self::UnknownFont(id) => write!(formatter, "unknown font id {}", id.get()),
+            Self::FontIdExhausted => formatter.write_str("Noon font ID space exhausted"),
+            Self::ObjectIndexOutOfRange { object, index } => write!(
+                formatter,
+                "cannot move object {} to draw index {index}",
+                object.get()
+            ),
         }
     }
 }
@@ -96,6 +143,10 @@ impl SceneDefinition {
             next_object_id,
             tracks,
             next_track_id,
+            images: Vec::new(),
+            next_image_id: 0,
+            fonts: Vec::new(),
+            next_font_id: 0,
         })
     }
 
@@ -118,9 +169,50 @@ impl SceneDefinition {
             ScenePatch::AddTrack(track) => self.insert_track(track),
             ScenePatch::ReplaceTrack(track) => self.replace_track(track),
             ScenePatch::RemoveTrack(id) => self.remove_track(id),
+            ScenePatch::RegisterImage(image) => self.insert_image(image),
+            ScenePatch::RegisterFont(font) => self.insert_font(font),
+            ScenePatch::SetGeometry { object, geometry } => {
+                self.object_mut(object)
+                    .ok_or(PatchError::UnknownObject(object))?
+                    .geometry = geometry;
+                Ok(())
+            }
+            ScenePatch::MoveObject { object, index } => self.move_object(object, index),
+            ScenePatch::ReplaceImage(image) => self.replace_image(image),
+            ScenePatch::RemoveImage(id) => self.remove_image(id),
+            ScenePatch::ReplaceFont(font) => self.replace_font(font),
+            ScenePatch::RemoveFont(id) => self.remove_font(id),
+            ScenePatch::SetLabels { object, labels } => {
+                self.object_mut(object)
+                    .ok_or(PatchError::UnknownObject(object))?
+                    .labels = labels;
+                Ok(())
+            }
+            ScenePatch::SetTrackLabels { track, labels } => {
+                self.tracks
+                    .iter_mut()
+                    .find(|existing| existing.id == track)
+                    .ok_or(PatchError::UnknownTrack(track))?
+                    .labels = labels;
+                Ok(())
+            }
         }
     }
 
+    fn move_object(&mut self, id: ObjectId, index: usize) -> Result<(), PatchError> {
+        let position = self
+            .objects
+            .iter()
+            .position(|object| object.id == id)
+            .ok_or(PatchError::UnknownObject(id))?;
+        if index >= self.objects.len() {
+            return Err(PatchError::ObjectIndexOutOfRange { object: id, index });
+        }
+        let object = self.objects.remove(position);
+        self.objects.insert(index, object);
+        Ok(())
+    }
+
     fn insert_object(&mut self, object: ObjectDefinition) -> Result<(), PatchError> {
         if self.object(object.id).is_some() {
             return Err(PatchError::DuplicateObject(object.id));
@@ -188,17 +280,7 @@ impl SceneDefinition {
     }
 
     fn validate_track_fields(track: &TrackDefinition) -> Result<(), PatchError> {
-        validate_track_timing(track.property, track.timing).map_err(PatchError::InvalidTrack)?;
-        let expected = track.property.value_kind();
-        let actual = track.values.value_kind();
-        if expected != actual {
-            return Err(PatchError::InvalidTrack(TimelineError::ValueTypeMismatch {
-                property: track.property,
-                expected,
-                actual,
-            }));
-        }
-        Ok(())
+        track.validate().map_err(PatchError::InvalidTrack)
     }
 }
 
@@ -273,6 +355,7 @@ mod tests {
             property: Property::Opacity,
             values: TrackValues::Scalar { from: 1.0, to: 0.0 },
             timing: TrackTiming::new(0.0, 1.0, Easing::Linear),
+            labels: Labels::new(),
         };
 
         scene
@@ -310,6 +393,7 @@ mod tests {
             property: Property::Opacity,
             values: TrackValues::Scalar { from: 0.0, to: 1.0 },
             timing: TrackTiming::new(0.0, 1.0, Easing::Linear),
+            labels: Labels::new(),
         }];
 
         let mut scene = SceneDefinition::from_parts(objects.clone(), tracks.clone())
@@ -344,6 +428,7 @@ mod tests {
                 to: true,
             },
             timing: TrackTiming::instant(3.0),
+            labels: Labels::new(),
         };
 
         let scene = SceneDefinition::from_parts(vec![object], vec![track.clone()])
@@ -365,6 +450,7 @@ mod tests {
             property: Property::Opacity,
             values: TrackValues::Scalar { from: 0.0, to: 1.0 },
             timing: TrackTiming::new(0.0, 1.0, Easing::Linear),
+            labels: Labels::new(),
         };
         assert!(matches!(
             SceneDefinition::from_parts(Vec::new(), vec![dangling_track]),
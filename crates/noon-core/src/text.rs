@@ -0,0 +1,387 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::{FontId, PatchError, Rect, SceneDefinition, Vec2};
+
+/// Raw TrueType/OpenType font file bytes.
+///
+/// The data model keeps fonts opaque; shaping and rasterization live in
+/// `noon-geometry`. Storage is shared so cloning scenes never copies the file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FontData {
+    bytes: Arc<[u8]>,
+}
+
+impl FontData {
+    pub fn new(bytes: impl Into<Arc<[u8]>>) -> Self {
+        Self {
+            bytes: bytes.into(),
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// True when both values share one allocation; see
+    /// [`crate::ImageData::shares_pixels`].
+    pub fn shares_bytes(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.bytes, &other.bytes)
+    }
+}
+
+/// Font bytes registered in a scene under a stable identity.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FontDefinition {
+    pub id: FontId,
+    pub data: FontData,
+}
+
+/// One shaped glyph: a font glyph index placed at its baseline origin.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PositionedGlyph {
+    pub glyph: u16,
+    /// Baseline origin in local object units.
+    pub origin: Vec2,
+}
+
+/// Text shaped once at authoring time into positioned glyphs.
+///
+/// Renderers draw glyph runs from a cached glyph atlas, so moving, fading or
+/// recoloring a run never reshapes or retessellates it. `text` is kept for
+/// exporters and diagnostics; rendering uses only `glyphs`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GlyphRun {
+    pub font: FontId,
+    /// World units per em.
+    pub size: f32,
+    pub text: String,
+    pub glyphs: Vec<PositionedGlyph>,
+    /// Ink bounds in local object units, computed during shaping.
+    pub bounds: Rect,
+    /// Present on counters. The runtime re-typesets `text`, `glyphs` and
+    /// `bounds` from it whenever the object's Value property changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<NumberLayout>>,
+}
+
+impl GlyphRun {
+    /// Re-typesets a counter for `value`. Returns whether anything changed;
+    /// runs without a number layout are left alone.
+    pub fn set_number_value(&mut self, value: f32) -> bool {
+        let Some(number) = self.number.as_mut() else {
+            return false;
+        };
+        if number.value == value {
+            return false;
+        }
+        number.value = value;
+        let (text, glyphs, bounds) = number.typeset(value);
+        self.text = text;
+        self.glyphs = glyphs;
+        self.bounds = bounds;
+        true
+    }
+}
+
+/// How a counter turns its value into text.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberFormat {
+    pub decimal_places: u8,
+    pub prefix: String,
+    pub suffix: String,
+    /// Inserted between groups of three integer digits, such as `','`.
+    pub group_separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_places: 2,
+            prefix: String::new(),
+            suffix: String::new(),
+            group_separator: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decimal_places(mut self, places: u8) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    pub fn group_separator(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+
+    /// Every character [`format`](Self::format) can produce.
+    pub fn characters(&self) -> impl Iterator<Item = char> + '_ {
+        "0123456789-."
+            .chars()
+            .chain(self.group_separator)
+            .chain(self.prefix.chars())
+            .chain(self.suffix.chars())
+    }
+
+    /// Rounds `value` to the configured places. A value that rounds to zero
+    /// never shows a minus sign.
+    pub fn format(&self, value: f32) -> String {
+        let places = usize::from(self.decimal_places);
+        let digits = format!("{:.places$}", value.abs());
+        let negative = value < 0.0 && digits.chars().any(|digit| ('1'..='9').contains(&digit));
+        let (integer, fraction) = digits.split_at(digits.find('.').unwrap_or(digits.len()));
+
+        let mut text = self.prefix.clone();
+        if negative {
+            text.push('-');
+        }
+        for (index, digit) in integer.chars().enumerate() {
+            let remaining = integer.len() - index;
+            if index > 0 && remaining % 3 == 0 {
+                text.extend(self.group_separator);
+            }
+            text.push(digit);
+        }
+        text.push_str(fraction);
+        text.push_str(&self.suffix);
+        text
+    }
+}
+
+/// One character a counter can display, shaped ahead of time.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NumberGlyph {
+    pub character: char,
+    pub glyph: u16,
+    /// Pen advance in local object units.
+    pub advance: f32,
+    /// Ink bounds relative to the glyph origin in local object units, `None`
+    /// for blank glyphs.
+    pub bounds: Option<Rect>,
+}
+
+/// Everything needed to typeset a counter for any value without the font.
+///
+/// Glyphs are placed by advance alone, without kerning, so digits keep their
+/// tabular spacing while counting.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NumberLayout {
+    pub format: NumberFormat,
+    pub value: f32,
+    /// Baseline origin of the first character in local object units. It
+    /// stays put as the value changes, so counters grow to the right.
+    pub origin: Vec2,
+    pub glyphs: Vec<NumberGlyph>,
+}
+
+impl NumberLayout {
+    /// Text, positioned glyphs and ink bounds for `value`. Characters
+    /// missing from the glyph table are skipped.
+    pub fn typeset(&self, value: f32) -> (String, Vec<PositionedGlyph>, Rect) {
+        let text = self.format.format(value);
+        let mut pen = self.origin;
+        let mut glyphs = Vec::with_capacity(text.len());
+        let mut bounds: Option<Rect> = None;
+        for character in text.chars() {
+            let Some(entry) = self
+                .glyphs
+                .iter()
+                .find(|entry| entry.character == character)
+            else {
+                continue;
+            };
+            glyphs.push(PositionedGlyph {
+                glyph: entry.glyph,
+                origin: pen,
+            });
+            if let Some(ink) = entry.bounds {
+                let ink = Rect::new(pen + ink.min, pen + ink.max);
+                bounds = Some(bounds.map_or(ink, |bounds| bounds.union(ink)));
+            }
+            pen.x += entry.advance;
+        }
+        (
+            text,
+            glyphs,
+            bounds.unwrap_or(Rect::new(self.origin, self.origin)),
+        )
+    }
+}
+
+impl SceneDefinition {
+    pub fn register_font(&mut self, data: FontData) -> FontId {
+        let id = FontId::new(self.next_font_id);
+        self.next_font_id = self
+            .next_font_id
+            .checked_add(1)
+            .expect("Noon font ID space exhausted");
+        self.fonts.push(FontDefinition { id, data });
+        id
+    }
+
+    pub fn fonts(&self) -> &[FontDefinition] {
+        &self.fonts
+    }
+
+    pub fn font(&self, id: FontId) -> Option<&FontData> {
+        self.fonts
+            .iter()
+            .find(|font| font.id == id)
+            .map(|font| &font.data)
+    }
+
+    pub(crate) fn insert_font(&mut self, font: FontDefinition) -> Result<(), PatchError> {
+        if self.font(font.id).is_some() {
+            return Err(PatchError::DuplicateFont(font.id));
+        }
+        let next = font
+            .id
+            .get()
+            .checked_add(1)
+            .ok_or(PatchError::FontIdExhausted)?;
+        self.next_font_id = self.next_font_id.max(next);
+        self.fonts.push(font);
+        Ok(())
+    }
+
+    pub(crate) fn replace_font(&mut self, font: FontDefinition) -> Result<(), PatchError> {
+        let existing = self
+            .fonts
+            .iter_mut()
+            .find(|existing| existing.id == font.id)
+            .ok_or(PatchError::UnknownFont(font.id))?;
+        *existing = font;
+        Ok(())
+    }
+
+    pub(crate) fn remove_font(&mut self, id: FontId) -> Result<(), PatchError> {
+        let original_len = self.fonts.len();
+        self.fonts.retain(|font| font.id != id);
+        if self.fonts.len() == original_len {
+            return Err(PatchError::UnknownFont(id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GeometryRef, ScenePatch};
+
+    #[test]
+    fn register_font_patch_rejects_duplicates_and_advances_ids() {
+        let mut scene = SceneDefinition::new();
+        let data = FontData::new(vec![0, 1, 0, 0]);
+        assert_eq!(scene.register_font(data.clone()), FontId::new(0));
+        let patch = ScenePatch::RegisterFont(FontDefinition {
+            id: FontId::new(3),
+            data: data.clone(),
+        });
+        scene.apply_patch(patch.clone()).expect("new font");
+        assert_eq!(
+            scene.apply_patch(patch),
+            Err(PatchError::DuplicateFont(FontId::new(3)))
+        );
+        assert_eq!(scene.register_font(data.clone()), FontId::new(4));
+        assert!(scene
+            .font(FontId::new(3))
+            .is_some_and(|font| font.shares_bytes(&data)));
+    }
+
+    #[test]
+    fn glyph_run_bounds_come_from_shaping() {
+        let bounds = Rect::new(Vec2::new(-1.0, -0.25), Vec2::new(1.0, 0.25));
+        let geometry = GeometryRef::glyph_run(GlyphRun {
+            font: FontId::new(0),
+            size: 0.5,
+            text: "I".to_owned(),
+            glyphs: vec![PositionedGlyph {
+                glyph: 2,
+                origin: Vec2::new(-1.0, -0.25),
+            }],
+            bounds,
+            number: None,
+        });
+        assert_eq!(geometry.local_bounds(), Some(bounds));
+    }
+
+    #[test]
+    fn number_format_rounds_groups_and_drops_negative_zero() {
+        let format = NumberFormat::new()
+            .decimal_places(0)
+            .prefix("$")
+            .group_separator(',');
+        assert_eq!(format.format(1250.0), "$1,250");
+        assert_eq!(format.format(-1234567.0), "$-1,234,567");
+        assert_eq!(format.format(999.6), "$1,000");
+        assert_eq!(format.format(-0.4), "$0");
+        assert_eq!(NumberFormat::new().suffix("%").format(12.345), "12.35%");
+    }
+
+    #[test]
+    fn counters_retypeset_from_their_origin_without_the_font() {
+        let ink = Some(Rect::new(Vec2::ZERO, Vec2::new(0.25, 1.0)));
+        let glyphs = "0123456789-."
+            .chars()
+            .enumerate()
+            .map(|(index, character)| NumberGlyph {
+                character,
+                glyph: index as u16 + 1,
+                advance: 0.5,
+                bounds: ink,
+            })
+            .collect();
+        let layout = NumberLayout {
+            format: NumberFormat::new().decimal_places(0),
+            value: 7.0,
+            origin: Vec2::new(-1.0, 0.0),
+            glyphs,
+        };
+        let (text, glyphs, bounds) = layout.typeset(7.0);
+        let mut run = GlyphRun {
+            font: FontId::new(0),
+            size: 1.0,
+            text,
+            glyphs,
+            bounds,
+            number: Some(Box::new(layout)),
+        };
+        assert!(!run.set_number_value(7.0));
+        assert!(run.set_number_value(42.0));
+        assert_eq!(run.text, "42");
+        assert_eq!(
+            run.glyphs,
+            vec![
+                PositionedGlyph {
+                    glyph: 5,
+                    origin: Vec2::new(-1.0, 0.0),
+                },
+                PositionedGlyph {
+                    glyph: 3,
+                    origin: Vec2::new(-0.5, 0.0),
+                },
+            ]
+        );
+        assert_eq!(
+            run.bounds,
+            Rect::new(Vec2::new(-1.0, 0.0), Vec2::new(-0.25, 1.0))
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{ObjectId, ObjectSnapshot, SceneDefinition, TrackId, Vec2};
+use crate::{Labels, ObjectId, ObjectSnapshot, SceneDefinition, TrackId, Vec2, VectorPath};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -10,6 +10,36 @@ pub enum Easing {
     EaseInOutCubic,
 }
 
+impl Easing {
+    /// Eased progress at linear `progress` in `[0, 1]`.
+    pub fn apply(self, progress: f32) -> f32 {
+        match self {
+            Self::Linear => progress,
+            Self::EaseInOutCubic => {
+                if progress < 0.5 {
+                    4.0 * progress * progress * progress
+                } else {
+                    1.0 - (-2.0 * progress + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+
+    /// Linear progress at which [`apply`](Self::apply) reaches `progress`.
+    pub fn inverse(self, progress: f64) -> f64 {
+        match self {
+            Self::Linear => progress,
+            Self::EaseInOutCubic => {
+                if progress < 0.5 {
+                    (progress / 4.0).cbrt()
+                } else {
+                    1.0 - (2.0 * (1.0 - progress)).cbrt() / 2.0
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Property {
@@ -21,6 +51,8 @@ pub enum Property {
     Appearance,
     Reveal,
     Morph,
+    /// Number shown by a counter. Objects without a number layout ignore it.
+    Value,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -37,9 +69,12 @@ impl Property {
             Self::Presence => ValueKind::Bool,
             Self::Transform => ValueKind::Object,
             Self::Position => ValueKind::Vec2,
-            Self::Rotation | Self::Opacity | Self::Appearance | Self::Reveal | Self::Morph => {
-                ValueKind::Scalar
-            }
+            Self::Rotation
+            | Self::Opacity
+            | Self::Appearance
+            | Self::Reveal
+            | Self::Morph
+            | Self::Value => ValueKind::Scalar,
         }
     }
 
@@ -67,6 +102,13 @@ pub enum TrackValues {
         from: ObjectSnapshot,
         to: ObjectSnapshot,
     },
+    /// Object snapshots whose translation travels along `path` instead of a
+    /// straight line. Every other field interpolates as in `Object`.
+    Motion {
+        from: ObjectSnapshot,
+        to: ObjectSnapshot,
+        path: MotionPath,
+    },
 }
 
 impl TrackValues {
@@ -75,11 +117,40 @@ impl TrackValues {
             Self::Bool { .. } => ValueKind::Bool,
             Self::Scalar { .. } => ValueKind::Scalar,
             Self::Vec2 { .. } => ValueKind::Vec2,
-            Self::Object { .. } => ValueKind::Object,
+            Self::Object { .. } | Self::Motion { .. } => ValueKind::Object,
+        }
+    }
+
+    /// Endpoint snapshots of an object-valued track.
+    pub const fn snapshots(&self) -> Option<(&ObjectSnapshot, &ObjectSnapshot)> {
+        match self {
+            Self::Object { from, to } | Self::Motion { from, to, .. } => Some((from, to)),
+            _ => None,
         }
     }
 }
 
+/// Route taken by an object's translation during a Transform track.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MotionPath {
+    /// Circular arc from the start to the end translation, sweeping `angle`
+    /// radians counterclockwise. Zero is a straight line.
+    Arc { angle: f32 },
+    /// Rotation about a fixed `point` by `angle` radians, combined with any
+    /// uniform scale toward or away from it, so the pivot stays in place
+    /// throughout instead of only at the ends.
+    Pivot { point: Vec2, angle: f32 },
+    /// Displacement along `path` by arc length: the translation moves by the
+    /// offset of the path point from the path start. With `follow_tangent`
+    /// the rotation is the start rotation plus the path's accumulated tangent
+    /// angle, so `to` should already include the total turn.
+    Path {
+        path: VectorPath,
+        follow_tangent: bool,
+    },
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrackTiming {
     pub start_time: f64,
@@ -108,6 +179,8 @@ pub struct TrackDefinition {
     pub property: Property,
     pub values: TrackValues,
     pub timing: TrackTiming,
+    #[serde(default, skip_serializing_if = "Labels::is_empty")]
+    pub labels: Labels,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -152,6 +225,24 @@ impl std::fmt::Display for TimelineError {
 
 impl std::error::Error for TimelineError {}
 
+impl TrackDefinition {
+    /// Checks timing and value kind against the property, as every scene
+    /// mutation does. Object references are checked by the scene.
+    pub fn validate(&self) -> Result<(), TimelineError> {
+        validate_track_timing(self.property, self.timing)?;
+        let expected = self.property.value_kind();
+        let actual = self.values.value_kind();
+        if expected != actual {
+            return Err(TimelineError::ValueTypeMismatch {
+                property: self.property,
+                expected,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
+
 pub(crate) fn validate_track_timing(
     property: Property,
     timing: TrackTiming,
@@ -209,6 +300,7 @@ impl SceneDefinition {
             property,
             values,
             timing,
+            labels: Labels::new(),
         });
         Ok(id)
     }
@@ -243,6 +335,22 @@ impl SceneDefinition {
         )
     }
 
+    pub fn animate_motion(
+        &mut self,
+        object: ObjectId,
+        from: ObjectSnapshot,
+        to: ObjectSnapshot,
+        path: MotionPath,
+        timing: TrackTiming,
+    ) -> Result<TrackId, TimelineError> {
+        self.add_track(
+            object,
+            Property::Transform,
+            TrackValues::Motion { from, to, path },
+            timing,
+        )
+    }
+
     pub fn animate_position(
         &mut self,
         object: ObjectId,
@@ -299,6 +407,16 @@ impl SceneDefinition {
         self.animate_scalar(object, Property::Morph, from, to, timing)
     }
 
+    pub fn animate_value(
+        &mut self,
+        object: ObjectId,
+        from: f32,
+        to: f32,
+        timing: TrackTiming,
+    ) -> Result<TrackId, TimelineError> {
+        self.animate_scalar(object, Property::Value, from, to, timing)
+    }
+
     pub fn tracks(&self) -> &[TrackDefinition] {
         &self.tracks
     }
@@ -313,6 +431,21 @@ mod tests {
         TrackTiming::new(1.0, 2.0, Easing::Linear)
     }
 
+    #[test]
+    fn easing_inverse_undoes_apply() {
+        for easing in [Easing::Linear, Easing::EaseInOutCubic] {
+            assert_eq!((easing.apply(0.0), easing.apply(1.0)), (0.0, 1.0));
+            for step in 0..=20 {
+                let time = step as f64 / 20.0;
+                let eased = f64::from(easing.apply(time as f32));
+                assert!(
+                    (easing.inverse(eased) - time).abs() < 1e-5,
+                    "{easing:?} at {time}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn track_ids_and_order_are_deterministic() {
         let mut first = SceneDefinition::new();
@@ -447,6 +580,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn motion_tracks_are_object_valued_transforms() {
+        let mut scene = SceneDefinition::new();
+        let object = scene.add(GeometryRef::circle(1.0));
+        let from = scene.snapshot(object).expect("object exists");
+        let to = from.clone().shift(Vec2::ONE);
+        scene
+            .animate_motion(
+                object,
+                from.clone(),
+                to.clone(),
+                MotionPath::Arc { angle: 1.0 },
+                timing(),
+            )
+            .expect("valid motion track");
+
+        let track = &scene.tracks()[0];
+        assert_eq!(track.property, Property::Transform);
+        assert_eq!(track.values.value_kind(), ValueKind::Object);
+        assert_eq!(track.values.snapshots(), Some((&from, &to)));
+        assert_eq!(TrackValues::Scalar { from: 0.0, to: 1.0 }.snapshots(), None);
+    }
+
     #[test]
     fn unknown_objects_are_rejected() {
         let mut scene = SceneDefinition::new();
@@ -0,0 +1,549 @@
+//! Headless animation export: PNG sequences and animated GIFs.
+//!
+//! A [`FrameSequence`] evaluates a scene at a fixed frame rate through
+//! [`SceneInstance`] and rasterizes each frame on the CPU. Frames stream one
+//! at a time into [`write_png_sequence`] or a [`GifEncoder`], so long scenes
+//! never hold more than two frames in memory.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use noon_compile::{CompileError, CompiledScene, CompiledTrack};
+use noon_core::SceneDefinition;
+use noon_runtime::{EvaluationError, SceneInstance};
+
+use crate::{rasterize_instance, RasterFrame, RasterOptions};
+
+/// NeuQuant sampling factor: 1 is slowest and best, 30 fastest.
+const GIF_QUANTIZATION_SPEED: i32 = 10;
+
+/// Highest GIF frame rate: viewers replace delays under two centiseconds
+/// with a much slower default.
+pub const MAX_GIF_FRAME_RATE: f64 = 50.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimationOptions {
+    pub raster: RasterOptions,
+    pub frame_rate: f64,
+    /// Seconds to export; defaults to the end of the last track.
+    pub duration: Option<f64>,
+}
+
+impl AnimationOptions {
+    pub const fn new(raster: RasterOptions) -> Self {
+        Self {
+            raster,
+            frame_rate: 30.0,
+            duration: None,
+        }
+    }
+
+    pub const fn frame_rate(mut self, frame_rate: f64) -> Self {
+        self.frame_rate = frame_rate;
+        self
+    }
+
+    pub const fn duration(mut self, duration: f64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+#[derive(Debug)]
+pub enum AnimationError {
+    Compile(CompileError),
+    Evaluation(EvaluationError),
+    InvalidFrameRate(f64),
+    /// Above [`MAX_GIF_FRAME_RATE`].
+    GifFrameRateTooHigh(f64),
+    InvalidDuration(f64),
+    /// Frames must be non-empty, and GIF frames at most 65535 pixels a side.
+    InvalidSize {
+        width: u32,
+        height: u32,
+    },
+    Io(std::io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+}
+
+impl std::fmt::Display for AnimationError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Compile(error) => write!(formatter, "scene compilation failed: {error}"),
+            Self::Evaluation(error) => write!(formatter, "scene evaluation failed: {error}"),
+            Self::InvalidFrameRate(frame_rate) => write!(
+                formatter,
+                "animation frame rate must be finite and positive, got {frame_rate}"
+            ),
+            Self::GifFrameRateTooHigh(frame_rate) => write!(
+                formatter,
+                "GIF frame rate must be at most {MAX_GIF_FRAME_RATE}, got {frame_rate}"
+            ),
+            Self::InvalidDuration(duration) => write!(
+                formatter,
+                "animation duration must be finite and non-negative, got {duration}"
+            ),
+            Self::InvalidSize { width, height } => {
+                write!(formatter, "unsupported frame size {width}x{height}")
+            }
+            Self::Io(error) => write!(formatter, "writing frames failed: {error}"),
+            Self::Png(error) => write!(formatter, "PNG encoding failed: {error}"),
+            Self::Gif(error) => write!(formatter, "GIF encoding failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for AnimationError {}
+
+impl From<CompileError> for AnimationError {
+    fn from(value: CompileError) -> Self {
+        Self::Compile(value)
+    }
+}
+
+impl From<EvaluationError> for AnimationError {
+    fn from(value: EvaluationError) -> Self {
+        Self::Evaluation(value)
+    }
+}
+
+impl From<std::io::Error> for AnimationError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<png::EncodingError> for AnimationError {
+    fn from(value: png::EncodingError) -> Self {
+        Self::Png(value)
+    }
+}
+
+impl From<gif::EncodingError> for AnimationError {
+    fn from(value: gif::EncodingError) -> Self {
+        Self::Gif(value)
+    }
+}
+
+/// End time of the last track, where exports stop by default.
+pub(crate) fn timeline_end(tracks: &[CompiledTrack]) -> f64 {
+    tracks
+        .iter()
+        .map(|track| track.timing.start_time + track.timing.duration)
+        .fold(0.0, f64::max)
+}
+
+/// Rasterized frames at `k / frame_rate` seconds, from time zero through the
+/// first frame at or after the end of the duration.
+#[derive(Clone, Debug)]
+pub struct FrameSequence {
+    instance: SceneInstance,
+    raster: RasterOptions,
+    frame_rate: f64,
+    len: u64,
+    next: u64,
+}
+
+impl FrameSequence {
+    pub fn new(
+        compiled: CompiledScene,
+        options: &AnimationOptions,
+    ) -> Result<Self, AnimationError> {
+        let frame_rate = options.frame_rate;
+        if !frame_rate.is_finite() || frame_rate <= 0.0 {
+            return Err(AnimationError::InvalidFrameRate(frame_rate));
+        }
+        let duration = options
+            .duration
+            .unwrap_or_else(|| timeline_end(compiled.tracks()));
+        if !duration.is_finite() || duration < 0.0 {
+            return Err(AnimationError::InvalidDuration(duration));
+        }
+        let (width, height) = (options.raster.width, options.raster.height);
+        if width == 0 || height == 0 {
+            return Err(AnimationError::InvalidSize { width, height });
+        }
+        Ok(Self {
+            instance: SceneInstance::new(compiled),
+            raster: options.raster,
+            frame_rate,
+            len: (duration * frame_rate).ceil() as u64 + 1,
+            next: 0,
+        })
+    }
+
+    pub fn from_scene(
+        scene: &SceneDefinition,
+        options: &AnimationOptions,
+    ) -> Result<Self, AnimationError> {
+        Self::new(CompiledScene::compile(scene)?, options)
+    }
+
+    pub const fn frame_rate(&self) -> f64 {
+        self.frame_rate
+    }
+
+    /// Total number of frames, including those already yielded.
+    pub const fn frame_count(&self) -> u64 {
+        self.len
+    }
+}
+
+impl Iterator for FrameSequence {
+    type Item = Result<RasterFrame, AnimationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.len {
+            return None;
+        }
+        let time = self.next as f64 / self.frame_rate;
+        self.next += 1;
+        Some(match self.instance.advance_to(time) {
+            Ok(_) => Ok(rasterize_instance(&self.instance, &self.raster)),
+            Err(error) => Err(error.into()),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.len - self.next).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+pub(crate) fn encode_rgba_png<W: Write>(
+    writer: W,
+    width: u32,
+    height: u32,
+    pixels: &[u8],
+) -> Result<(), png::EncodingError> {
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(pixels)
+}
+
+pub fn write_png<W: Write>(frame: &RasterFrame, writer: W) -> Result<(), AnimationError> {
+    Ok(encode_rgba_png(
+        writer,
+        frame.width,
+        frame.height,
+        &frame.pixels,
+    )?)
+}
+
+/// Writes `frame-00000.png`, `frame-00001.png`, ... into `directory`,
+/// creating it if needed, and returns the paths written.
+pub fn write_png_sequence(
+    frames: impl IntoIterator<Item = Result<RasterFrame, AnimationError>>,
+    directory: &Path,
+) -> Result<Vec<PathBuf>, AnimationError> {
+    std::fs::create_dir_all(directory)?;
+    let mut paths = Vec::new();
+    for (index, frame) in frames.into_iter().enumerate() {
+        let path = directory.join(format!("frame-{index:05}.png"));
+        let mut writer = BufWriter::new(File::create(&path)?);
+        write_png(&frame?, &mut writer)?;
+        writer.flush()?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Streams frames into a looping animated GIF.
+///
+/// Each frame gets its own 256-color palette. Only the rectangle that
+/// changed since the previous frame is stored, with unchanged pixels inside
+/// it left transparent, and repeated frames extend the previous delay.
+/// Frames are flattened onto black, since GIF transparency is all or
+/// nothing.
+pub struct GifEncoder<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u32,
+    height: u32,
+    frame_rate: f64,
+    /// Frames pushed so far; delays round each frame's end time to
+    /// centiseconds so they never drift.
+    frames: u64,
+    /// The canvas as the last pushed frame left it.
+    canvas: Option<Vec<u8>>,
+    pending: Option<PendingFrame>,
+}
+
+struct PendingFrame {
+    frame: gif::Frame<'static>,
+    start: u64,
+}
+
+impl<W: Write> GifEncoder<W> {
+    pub fn new(
+        writer: W,
+        width: u32,
+        height: u32,
+        frame_rate: f64,
+    ) -> Result<Self, AnimationError> {
+        if !frame_rate.is_finite() || frame_rate <= 0.0 {
+            return Err(AnimationError::InvalidFrameRate(frame_rate));
+        }
+        if frame_rate > MAX_GIF_FRAME_RATE {
+            return Err(AnimationError::GifFrameRateTooHigh(frame_rate));
+        }
+        let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(AnimationError::InvalidSize { width, height });
+        };
+        if width == 0 || height == 0 {
+            return Err(AnimationError::InvalidSize { width, height });
+        }
+        let mut encoder = gif::Encoder::new(writer, gif_width, gif_height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            frame_rate,
+            frames: 0,
+            canvas: None,
+            pending: None,
+        })
+    }
+
+    pub fn push(&mut self, frame: &RasterFrame) -> Result<(), AnimationError> {
+        if (frame.width, frame.height) != (self.width, self.height) {
+            return Err(AnimationError::InvalidSize {
+                width: frame.width,
+                height: frame.height,
+            });
+        }
+        let opaque = flatten_onto_black(&frame.pixels);
+        let index = self.frames;
+        self.frames += 1;
+        let next = match &self.canvas {
+            None => Some(self.full_frame(&opaque)),
+            Some(previous) => self.delta_frame(previous, &opaque),
+        };
+        let Some(next) = next else {
+            return Ok(());
+        };
+        self.flush_pending(index)?;
+        self.pending = Some(PendingFrame {
+            frame: next,
+            start: index,
+        });
+        self.canvas = Some(opaque);
+        Ok(())
+    }
+
+    /// Writes the last frame and the trailer, returning the writer.
+    pub fn finish(mut self) -> Result<W, AnimationError> {
+        self.flush_pending(self.frames)?;
+        Ok(self.encoder.into_inner()?)
+    }
+
+    fn full_frame(&self, opaque: &[u8]) -> gif::Frame<'static> {
+        let mut pixels = opaque.to_vec();
+        gif::Frame::from_rgba_speed(
+            self.width as u16,
+            self.height as u16,
+            &mut pixels,
+            GIF_QUANTIZATION_SPEED,
+        )
+    }
+
+    /// The changed rectangle of `next` over `previous`, or `None` when
+    /// nothing changed.
+    fn delta_frame(&self, previous: &[u8], next: &[u8]) -> Option<gif::Frame<'static>> {
+        let width = self.width as usize;
+        let changed =
+            |index: usize| previous[index * 4..index * 4 + 3] != next[index * 4..index * 4 + 3];
+        let (mut left, mut top, mut right, mut bottom) = (width, usize::MAX, 0, 0);
+        for index in (0..next.len() / 4).filter(|index| changed(*index)) {
+            let (x, y) = (index % width, index / width);
+            left = left.min(x);
+            top = top.min(y);
+            right = right.max(x + 1);
+            bottom = bottom.max(y + 1);
+        }
+        if right == 0 {
+            return None;
+        }
+        let mut pixels = Vec::with_capacity((right - left) * (bottom - top) * 4);
+        for y in top..bottom {
+            for index in y * width + left..y * width + right {
+                if changed(index) {
+                    pixels.extend_from_slice(&next[index * 4..index * 4 + 4]);
+                } else {
+                    pixels.extend([0; 4]);
+                }
+            }
+        }
+        let mut frame = gif::Frame::from_rgba_speed(
+            (right - left) as u16,
+            (bottom - top) as u16,
+            &mut pixels,
+            GIF_QUANTIZATION_SPEED,
+        );
+        frame.left = left as u16;
+        frame.top = top as u16;
+        Some(frame)
+    }
+
+    fn flush_pending(&mut self, end: u64) -> Result<(), AnimationError> {
+        let Some(PendingFrame { mut frame, start }) = self.pending.take() else {
+            return Ok(());
+        };
+        let centiseconds = |frame: u64| (frame as f64 * 100.0 / self.frame_rate).round();
+        frame.delay =
+            (centiseconds(end) - centiseconds(start)).clamp(1.0, f64::from(u16::MAX)) as u16;
+        frame.dispose = gif::DisposalMethod::Keep;
+        self.encoder.write_frame(&frame)?;
+        Ok(())
+    }
+}
+
+/// RGBA composited over opaque black, the only backdrop every GIF viewer
+/// agrees on.
+fn flatten_onto_black(pixels: &[u8]) -> Vec<u8> {
+    let mut opaque = Vec::with_capacity(pixels.len());
+    for pixel in pixels.chunks_exact(4) {
+        let alpha = u16::from(pixel[3]);
+        opaque.extend(
+            pixel[..3]
+                .iter()
+                .map(|channel| ((u16::from(*channel) * alpha + 127) / 255) as u8),
+        );
+        opaque.push(255);
+    }
+    opaque
+}
+
+/// Renders every frame of `frames` into a GIF written to `writer`.
+pub fn write_gif<W: Write>(frames: FrameSequence, writer: W) -> Result<W, AnimationError> {
+    let (width, height) = (frames.raster.width, frames.raster.height);
+    let mut encoder = GifEncoder::new(writer, width, height, frames.frame_rate())?;
+    for frame in frames {
+        encoder.push(&frame?)?;
+    }
+    encoder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Easing, GeometryRef, TrackTiming, Vec2};
+    use noon_render_wgpu::Camera2D;
+
+    use super::*;
+
+    fn options() -> AnimationOptions {
+        AnimationOptions::new(RasterOptions::new(
+            Camera2D::new(Vec2::ZERO, Vec2::new(8.0, 8.0)).unwrap(),
+            16,
+            16,
+        ))
+        .frame_rate(10.0)
+    }
+
+    /// A circle that moves for half a second, then holds until one second.
+    fn scene() -> SceneDefinition {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(1.0));
+        scene
+            .animate_position(
+                circle,
+                Vec2::new(-2.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                TrackTiming::new(0.0, 0.5, Easing::Linear),
+            )
+            .unwrap();
+        scene
+            .animate_appearance(circle, 1.0, 1.0, TrackTiming::new(0.5, 0.5, Easing::Linear))
+            .unwrap();
+        scene
+    }
+
+    #[test]
+    fn sequences_cover_the_timeline_at_the_frame_rate() {
+        let frames = FrameSequence::from_scene(&scene(), &options()).unwrap();
+        assert_eq!(frames.frame_count(), 11);
+        assert_eq!(frames.size_hint(), (11, Some(11)));
+        let frames: Vec<_> = frames.map(Result::unwrap).collect();
+        assert_ne!(frames[0].pixels, frames[5].pixels);
+        assert_eq!(frames[5].pixels, frames[10].pixels);
+
+        let short = FrameSequence::from_scene(&scene(), &options().duration(0.25)).unwrap();
+        assert_eq!(short.count(), 4);
+        assert!(matches!(
+            FrameSequence::from_scene(&scene(), &options().frame_rate(0.0)),
+            Err(AnimationError::InvalidFrameRate(_))
+        ));
+        assert!(matches!(
+            FrameSequence::from_scene(&scene(), &options().duration(f64::NAN)),
+            Err(AnimationError::InvalidDuration(_))
+        ));
+    }
+
+    #[test]
+    fn gifs_store_changed_rectangles_and_merge_repeated_frames() {
+        let frames = FrameSequence::from_scene(&scene(), &options()).unwrap();
+        let bytes = write_gif(frames, Vec::new()).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = decoder.read_info(bytes.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (16, 16));
+        let mut stored = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            stored.push((
+                frame.left,
+                frame.top,
+                frame.width,
+                frame.height,
+                frame.delay,
+            ));
+        }
+        // Six distinct frames; the last holds for the remaining half second.
+        assert_eq!(stored.len(), 6);
+        assert_eq!(stored[0], (0, 0, 16, 16, 10));
+        assert!(stored[1..].iter().all(|frame| frame.3 < 16 && frame.1 > 0));
+        assert_eq!(stored.iter().map(|frame| frame.4).sum::<u16>(), 110);
+        assert_eq!(stored[5].4, 60);
+    }
+
+    #[test]
+    fn gif_frame_rates_above_fifty_are_rejected() {
+        let frames = FrameSequence::from_scene(&scene(), &options().frame_rate(60.0)).unwrap();
+        assert!(matches!(
+            write_gif(frames, Vec::new()),
+            Err(AnimationError::GifFrameRateTooHigh(rate)) if rate == 60.0
+        ));
+
+        let frames = FrameSequence::from_scene(&scene(), &options().frame_rate(50.0)).unwrap();
+        let bytes = write_gif(frames, Vec::new()).unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(bytes.as_slice())
+            .unwrap();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert!(frame.delay >= 2);
+        }
+    }
+
+    #[test]
+    fn png_sequences_number_their_frames() {
+        let directory =
+            std::env::temp_dir().join(format!("noon-export-png-{}", std::process::id()));
+        let frames = FrameSequence::from_scene(&scene(), &options().duration(0.1)).unwrap();
+        let paths = write_png_sequence(frames, &directory).unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                directory.join("frame-00000.png"),
+                directory.join("frame-00001.png")
+            ]
+        );
+        let decoder = png::Decoder::new(std::io::BufReader::new(File::open(&paths[1]).unwrap()));
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (16, 16));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
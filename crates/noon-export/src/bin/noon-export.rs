@@ -0,0 +1,224 @@
+//! Renders a scene document to an animated GIF or a PNG sequence on the CPU,
+//! or packs it with its assets into a self-contained bundle.
+//!
+//! The camera matches the web player's: a center and a world height, with
+//! the width following the output aspect ratio.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use noon_core::{Color, SceneDefinition, Vec2};
+use noon_export::{write_gif, write_png_sequence, AnimationOptions, FrameSequence, RasterOptions};
+use noon_render_wgpu::Camera2D;
+
+const USAGE: &str = "usage: noon-export <scene.json | scene.bin | scene.noon>
+       (--gif <file> | --png-dir <directory> | --bundle <file>)
+       [--fps <rate>] [--size <width>x<height>] [--duration <seconds>]
+       [--camera <x>,<y>,<height>] [--background <rrggbb[aa]>]";
+
+enum Output {
+    Gif(PathBuf),
+    PngDirectory(PathBuf),
+    Bundle(PathBuf),
+}
+
+struct Config {
+    scene: PathBuf,
+    output: Output,
+    frame_rate: f64,
+    size: (u32, u32),
+    duration: Option<f64>,
+    camera: (Vec2, f32),
+    background: Color,
+}
+
+fn main() -> ExitCode {
+    if std::env::args().any(|argument| argument == "-h" || argument == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let config = match parse_args(std::env::args().skip(1)) {
+        Ok(config) => config,
+        Err(message) => {
+            eprintln!("noon-export: {message}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(config) {
+        Ok(summary) => {
+            println!("{summary}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("noon-export: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(config: Config) -> Result<String, String> {
+    let scene = load_scene(&config.scene)?;
+    match &config.output {
+        Output::Gif(path) => {
+            let frames = frame_sequence(&scene, &config)?;
+            let count = frames.frame_count();
+            let file = File::create(path)
+                .map_err(|error| format!("cannot create {}: {error}", path.display()))?;
+            write_gif(frames, BufWriter::new(file))
+                .map_err(|error| error.to_string())?
+                .into_inner()
+                .map_err(|error| format!("cannot write {}: {}", path.display(), error.error()))?;
+            Ok(format!("wrote {count} frames to {}", path.display()))
+        }
+        Output::PngDirectory(directory) => {
+            let frames = frame_sequence(&scene, &config)?;
+            let count = frames.frame_count();
+            write_png_sequence(frames, directory).map_err(|error| error.to_string())?;
+            Ok(format!("wrote {count} frames to {}", directory.display()))
+        }
+        Output::Bundle(path) => {
+            let bundle = noon_ir::encode_bundle(&scene).map_err(|error| error.to_string())?;
+            std::fs::write(path, &bundle)
+                .map_err(|error| format!("cannot write {}: {error}", path.display()))?;
+            Ok(format!(
+                "wrote {} bytes to {}",
+                bundle.len(),
+                path.display()
+            ))
+        }
+    }
+}
+
+fn frame_sequence(scene: &SceneDefinition, config: &Config) -> Result<FrameSequence, String> {
+    let (width, height) = config.size;
+    let (center, world_height) = config.camera;
+    let camera = Camera2D::new(
+        center,
+        Vec2::new(world_height * width as f32 / height as f32, world_height),
+    )
+    .map_err(|error| error.to_string())?;
+    let mut options = AnimationOptions::new(
+        RasterOptions::new(camera, width, height).background(config.background),
+    )
+    .frame_rate(config.frame_rate);
+    if let Some(duration) = config.duration {
+        options = options.duration(duration);
+    }
+    FrameSequence::from_scene(scene, &options).map_err(|error| error.to_string())
+}
+
+/// Bundles are recognized by their magic and JSON documents start with `{`;
+/// anything else is read as the binary encoding.
+fn load_scene(path: &PathBuf) -> Result<SceneDefinition, String> {
+    let bytes =
+        std::fs::read(path).map_err(|error| format!("cannot read {}: {error}", path.display()))?;
+    let is_json = bytes
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| *byte == b'{');
+    let scene = if noon_ir::is_bundle(&bytes) {
+        noon_ir::decode_bundle(&bytes)
+    } else if is_json {
+        let json = std::str::from_utf8(&bytes).map_err(|error| error.to_string())?;
+        noon_ir::decode_scene(json)
+    } else {
+        noon_ir::decode_scene_binary(&bytes)
+    };
+    scene.map_err(|error| format!("cannot decode {}: {error}", path.display()))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+    let mut scene = None;
+    let mut output = None;
+    let mut frame_rate = 30.0;
+    let mut size = (640, 360);
+    let mut duration = None;
+    let mut camera = (Vec2::ZERO, 6.0);
+    let mut background = Color::BLACK;
+    while let Some(argument) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("{name} requires a value"))
+        };
+        match argument.as_str() {
+            "--gif" => output = Some(Output::Gif(value("--gif")?.into())),
+            "--png-dir" => output = Some(Output::PngDirectory(value("--png-dir")?.into())),
+            "--bundle" => output = Some(Output::Bundle(value("--bundle")?.into())),
+            "--fps" => frame_rate = parse_number("--fps", &value("--fps")?)?,
+            "--size" => size = parse_size(&value("--size")?)?,
+            "--duration" => duration = Some(parse_number("--duration", &value("--duration")?)?),
+            "--camera" => camera = parse_camera(&value("--camera")?)?,
+            "--background" => background = parse_color(&value("--background")?)?,
+            flag if flag.starts_with("--") => return Err(format!("unknown option {flag}")),
+            path if scene.is_none() => scene = Some(PathBuf::from(path)),
+            extra => return Err(format!("unexpected argument {extra}")),
+        }
+    }
+    Ok(Config {
+        scene: scene.ok_or("missing scene file")?,
+        output: output.ok_or("missing --gif, --png-dir or --bundle")?,
+        frame_rate,
+        size,
+        duration,
+        camera,
+        background,
+    })
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("{name} expects a number, got {value}"))
+}
+
+fn parse_size(value: &str) -> Result<(u32, u32), String> {
+    let (width, height) = value
+        .split_once('x')
+        .ok_or_else(|| format!("--size expects <width>x<height>, got {value}"))?;
+    let size = (
+        parse_number("--size", width)?,
+        parse_number("--size", height)?,
+    );
+    if size.0 == 0 || size.1 == 0 {
+        return Err(format!("--size must be non-empty, got {value}"));
+    }
+    Ok(size)
+}
+
+fn parse_camera(value: &str) -> Result<(Vec2, f32), String> {
+    let parts = value
+        .split(',')
+        .map(|part| parse_number::<f32>("--camera", part))
+        .collect::<Result<Vec<_>, _>>()?;
+    match parts[..] {
+        [x, y, height] => Ok((Vec2::new(x, y), height)),
+        _ => Err(format!("--camera expects <x>,<y>,<height>, got {value}")),
+    }
+}
+
+fn parse_color(value: &str) -> Result<Color, String> {
+    let hex = value.trim_start_matches('#');
+    let channels = (0..hex.len())
+        .step_by(2)
+        .map(|start| {
+            hex.get(start..start + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect::<Option<Vec<_>>>();
+    let channel = |byte: u8| f32::from(byte) / 255.0;
+    match channels.as_deref() {
+        Some(&[red, green, blue]) => Ok(Color::rgb(channel(red), channel(green), channel(blue))),
+        Some(&[red, green, blue, alpha]) => Ok(Color::rgba(
+            channel(red),
+            channel(green),
+            channel(blue),
+            channel(alpha),
+        )),
+        _ => Err(format!(
+            "--background expects rrggbb or rrggbbaa, got {value}"
+        )),
+    }
+}
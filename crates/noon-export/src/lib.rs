@@ -0,0 +1,20 @@
+//! GPU-free exporters for Noon scenes and evaluated frames.
+//!
+//! Exporters read the same [`noon_runtime::FrameState`] the renderer draws
+//! and follow its conventions: y-up world space framed by a
+//! [`noon_render_wgpu::Camera2D`], appearance composed into style opacity,
+//! and reveal measured along each outline. SVG captures one frame; Lottie
+//! captures the whole timeline; the CPU rasterizer renders frames to PNG
+//! sequences and animated GIFs.
+
+#![forbid(unsafe_code)]
+
+mod animation;
+mod lottie;
+mod raster;
+mod svg;
+
+pub use animation::*;
+pub use lottie::*;
+pub use raster::*;
+pub use svg::*;
@@ -0,0 +1,1314 @@
+//! Lottie JSON for whole scenes.
+//!
+//! Every object becomes a shape layer parented to a null layer that maps the
+//! camera rectangle onto the composition, so object layers keep y-up world
+//! units. Tracks Lottie can interpolate the way the runtime does become eased
+//! keyframes. Everything else is sampled once per frame, and the tracks
+//! behind those samples are reported.
+
+use noon_compile::{CompileError, CompiledScene, CompiledTrack, MotionPlan, TransformGeometryPlan};
+use noon_core::{
+    Color, Easing, GeometryRef, ObjectId, PathCommand, Property, SceneDefinition, StrokeCap,
+    StrokeJoin, Style, TrackId, TrackTiming, TrackValues, Transform2D, Vec2, VectorPath,
+};
+use noon_geometry::{plan_morph, FontFace, MorphOptions, MorphPlan};
+use noon_render_wgpu::Camera2D;
+use noon_runtime::{EvaluationError, FrameState, SceneInstance};
+use serde_json::{json, Value};
+
+use crate::timeline_end;
+
+const LOTTIE_VERSION: &str = "5.7.0";
+/// Bézier handles `[x1, y1, x2, y2]` approximating ease-in-out cubic, which
+/// Lottie cannot express exactly.
+const EASE_IN_OUT_CUBIC: [f64; 4] = [0.65, 0.0, 0.35, 1.0];
+/// Handle length of a quarter circle as a cubic Bézier, per unit radius.
+const KAPPA: f32 = 0.552_284_8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LottieOptions {
+    pub camera: Camera2D,
+    /// Composition size in pixels. The camera rectangle is stretched to fill
+    /// it.
+    pub width: u32,
+    pub height: u32,
+    pub frame_rate: f64,
+    /// Length in seconds. Defaults to the end of the last track.
+    pub duration: Option<f64>,
+}
+
+impl LottieOptions {
+    pub const fn new(camera: Camera2D, width: u32, height: u32) -> Self {
+        Self {
+            camera,
+            width,
+            height,
+            frame_rate: 60.0,
+            duration: None,
+        }
+    }
+
+    pub const fn frame_rate(mut self, frame_rate: f64) -> Self {
+        self.frame_rate = frame_rate;
+        self
+    }
+
+    pub const fn duration(mut self, duration: f64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+/// Why a track was sampled per frame instead of keyframed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BakeReason {
+    /// Fills stay hidden until the outline completes, which trim paths
+    /// cannot express.
+    Reveal,
+    Morph,
+    /// Curved translation routes and tangent-following rotation.
+    MotionPath,
+    /// Several properties drive the same Lottie property.
+    CombinedProperties,
+    /// A track starts before the previous one on the same property ends.
+    OverlappingTracks,
+    /// Values leave the range the runtime clamps to.
+    Clamped,
+}
+
+impl std::fmt::Display for BakeReason {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(match self {
+            Self::Reveal => "reveal",
+            Self::Morph => "morph",
+            Self::MotionPath => "motion path",
+            Self::CombinedProperties => "combined properties",
+            Self::OverlappingTracks => "overlapping tracks",
+            Self::Clamped => "clamped values",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BakedTrack {
+    pub track: TrackId,
+    pub object: ObjectId,
+    pub property: Property,
+    pub reason: BakeReason,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LottieExport {
+    pub json: String,
+    /// Tracks exported as per-frame samples, in track id order.
+    pub baked: Vec<BakedTrack>,
+    /// Objects with no Lottie equivalent, left out of the animation: external
+    /// geometry, images, animated counters, unparsable fonts and morphs that
+    /// cannot be planned.
+    pub unsupported: Vec<ObjectId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LottieError {
+    Compile(CompileError),
+    Evaluation(EvaluationError),
+    InvalidFrameRate(f64),
+    InvalidDuration(f64),
+}
+
+impl std::fmt::Display for LottieError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Compile(error) => write!(formatter, "scene compilation failed: {error}"),
+            Self::Evaluation(error) => write!(formatter, "scene evaluation failed: {error}"),
+            Self::InvalidFrameRate(frame_rate) => write!(
+                formatter,
+                "Lottie frame rate must be finite and positive, got {frame_rate}"
+            ),
+            Self::InvalidDuration(duration) => write!(
+                formatter,
+                "Lottie duration must be finite and non-negative, got {duration}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LottieError {}
+
+impl From<CompileError> for LottieError {
+    fn from(value: CompileError) -> Self {
+        Self::Compile(value)
+    }
+}
+
+impl From<EvaluationError> for LottieError {
+    fn from(value: EvaluationError) -> Self {
+        Self::Evaluation(value)
+    }
+}
+
+pub fn scene_to_lottie(
+    scene: &SceneDefinition,
+    options: &LottieOptions,
+) -> Result<LottieExport, LottieError> {
+    compiled_scene_to_lottie(&CompiledScene::compile(scene)?, options)
+}
+
+pub fn compiled_scene_to_lottie(
+    compiled: &CompiledScene,
+    options: &LottieOptions,
+) -> Result<LottieExport, LottieError> {
+    let frame_rate = options.frame_rate;
+    if !frame_rate.is_finite() || frame_rate <= 0.0 {
+        return Err(LottieError::InvalidFrameRate(frame_rate));
+    }
+    let duration = options
+        .duration
+        .unwrap_or_else(|| timeline_end(compiled.tracks()));
+    if !duration.is_finite() || duration < 0.0 {
+        return Err(LottieError::InvalidDuration(duration));
+    }
+
+    let mut exporter = Exporter {
+        compiled,
+        frame_rate,
+        last_frame: (duration * frame_rate).ceil().max(1.0),
+        samples: None,
+        plans: Vec::new(),
+        baked: Vec::new(),
+    };
+    let mut layers = vec![camera_layer(options, exporter.last_frame)];
+    let mut unsupported = Vec::new();
+    // Lottie draws its first layer on top; noon draws later objects on top.
+    for index in (0..compiled.objects().len()).rev() {
+        match exporter.object_layers(index)? {
+            Some(object_layers) => layers.extend(object_layers),
+            None => unsupported.push(compiled.objects()[index].id),
+        }
+    }
+    for (index, layer) in layers.iter_mut().enumerate() {
+        layer["ind"] = json!(index + 1);
+    }
+    unsupported.reverse();
+    exporter.baked.sort_by_key(|baked| baked.track);
+
+    let json = json!({
+        "v": LOTTIE_VERSION,
+        "fr": frame_rate,
+        "ip": 0,
+        "op": exporter.last_frame,
+        "w": options.width,
+        "h": options.height,
+        "nm": "noon",
+        "ddd": 0,
+        "assets": [],
+        "layers": layers,
+    });
+    Ok(LottieExport {
+        json: json.to_string(),
+        baked: exporter.baked,
+        unsupported,
+    })
+}
+
+/// Null layer mapping y-up world units onto composition pixels. Object layers
+/// are its children.
+fn camera_layer(options: &LottieOptions, last_frame: f64) -> Value {
+    let Camera2D { center, world_size } = options.camera;
+    let scale_x = f64::from(options.width) / f64::from(world_size.x);
+    let scale_y = f64::from(options.height) / f64::from(world_size.y);
+    let left = f64::from(center.x - world_size.x * 0.5);
+    let top = f64::from(center.y + world_size.y * 0.5);
+    json!({
+        "ddd": 0,
+        "ty": 3,
+        "nm": "camera",
+        "sr": 1,
+        "ks": {
+            "o": { "a": 0, "k": 100 },
+            "r": { "a": 0, "k": 0 },
+            "p": { "a": 0, "k": [-left * scale_x, top * scale_y] },
+            "a": { "a": 0, "k": [0, 0] },
+            "s": { "a": 0, "k": [scale_x * 100.0, -scale_y * 100.0] },
+        },
+        "ao": 0,
+        "ip": 0,
+        "op": last_frame,
+        "st": 0,
+        "bm": 0,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Channel {
+    Position,
+    Rotation,
+    Scale,
+    Opacity,
+    Shape,
+    StrokeColor,
+    StrokeOpacity,
+    StrokeWidth,
+    FillColor,
+    FillOpacity,
+    Trim,
+}
+
+impl Channel {
+    const ALL: [Self; 11] = [
+        Self::Position,
+        Self::Rotation,
+        Self::Scale,
+        Self::Opacity,
+        Self::Shape,
+        Self::StrokeColor,
+        Self::StrokeOpacity,
+        Self::StrokeWidth,
+        Self::FillColor,
+        Self::FillOpacity,
+        Self::Trim,
+    ];
+
+    const fn drivers(self) -> &'static [Property] {
+        match self {
+            Self::Position => &[Property::Transform, Property::Position],
+            Self::Rotation => &[Property::Transform, Property::Rotation],
+            Self::Opacity => &[Property::Transform, Property::Opacity, Property::Appearance],
+            Self::Shape => &[Property::Transform, Property::Morph],
+            Self::Scale
+            | Self::StrokeColor
+            | Self::StrokeOpacity
+            | Self::StrokeWidth
+            | Self::FillColor => &[Property::Transform],
+            Self::FillOpacity => &[Property::Transform, Property::Reveal],
+            Self::Trim => &[Property::Reveal],
+        }
+    }
+}
+
+/// Everything the channels read about one object at one moment.
+#[derive(Clone, Debug)]
+struct ObjectState<'a> {
+    geometry: &'a GeometryRef,
+    transform: Transform2D,
+    style: Style,
+    appearance: f32,
+    reveal: f32,
+    /// Sampled morph progress. Keyframed states draw the source path.
+    morph: Option<f32>,
+}
+
+impl<'a> ObjectState<'a> {
+    /// This state with one endpoint of `track` applied.
+    fn with_endpoint(&self, track: &'a CompiledTrack, end: bool) -> Self {
+        let mut state = self.clone();
+        match &track.values {
+            TrackValues::Scalar { from, to } => {
+                let value = if end { *to } else { *from };
+                match track.property {
+                    Property::Rotation => state.transform.rotation = value,
+                    Property::Opacity => state.style.opacity = value,
+                    Property::Appearance => state.appearance = value,
+                    Property::Reveal => state.reveal = value,
+                    Property::Morph => state.morph = Some(value),
+                    _ => {}
+                }
+            }
+            TrackValues::Vec2 { from, to } => {
+                state.transform.translation = if end { *to } else { *from };
+            }
+            TrackValues::Object { from, to } | TrackValues::Motion { from, to, .. } => {
+                let snapshot = if end { to } else { from };
+                state.geometry = &snapshot.geometry;
+                state.transform = snapshot.transform;
+                state.style = snapshot.style;
+            }
+            TrackValues::Bool { .. } => {}
+        }
+        state
+    }
+
+    /// Fill, stroke and stroke width as the renderer draws them.
+    fn paint(&self) -> (Option<Color>, Option<Color>, f32) {
+        let style = &self.style;
+        let inset = |limit: f32| {
+            let width = style.stroke_width.clamp(0.0, limit);
+            (style.fill, style.stroke.filter(|_| width > 0.0), width)
+        };
+        match self.geometry {
+            GeometryRef::Circle { radius } => inset(radius.abs()),
+            GeometryRef::Rectangle { size } => inset(size.x.abs().min(size.y.abs()) * 0.5),
+            GeometryRef::Line { .. } => {
+                let width = style.stroke_width.max(0.0);
+                (
+                    None,
+                    style.stroke.or(style.fill).filter(|_| width > 0.0),
+                    width,
+                )
+            }
+            GeometryRef::VectorPath(_) => inset(f32::INFINITY),
+            GeometryRef::GlyphRun(_) => (style.fill, None, 0.0),
+            GeometryRef::External(_) | GeometryRef::Image { .. } => (None, None, 0.0),
+        }
+    }
+}
+
+/// A keyframe value: `None` for a paint the style leaves unset.
+#[derive(Clone, Debug, PartialEq)]
+struct Key {
+    frame: f64,
+    value: Option<Value>,
+    /// Easing toward the next key, or `None` to hold.
+    easing: Option<Easing>,
+}
+
+struct Unsupported;
+
+struct Exporter<'a> {
+    compiled: &'a CompiledScene,
+    frame_rate: f64,
+    last_frame: f64,
+    /// One frame per Lottie frame, evaluated on first use.
+    samples: Option<Vec<FrameState>>,
+    plans: Vec<(VectorPath, MorphPlan)>,
+    baked: Vec<BakedTrack>,
+}
+
+impl<'a> Exporter<'a> {
+    /// One layer per interval the object is present, or `None` when the
+    /// object has no Lottie equivalent.
+    fn object_layers(&mut self, index: usize) -> Result<Option<Vec<Value>>, LottieError> {
+        let compiled = self.compiled;
+        let object = &compiled.objects()[index];
+        let groups = groups(compiled.tracks(), index);
+        let group = |property| {
+            groups
+                .iter()
+                .find(|(candidate, _)| *candidate == property)
+                .map_or(&[][..], |(_, tracks)| *tracks)
+        };
+        let geometries = std::iter::once(&object.geometry).chain(
+            group(Property::Transform)
+                .iter()
+                .filter_map(|track| track.values.snapshots())
+                .flat_map(|(from, to)| [&from.geometry, &to.geometry]),
+        );
+        let mut text = false;
+        for geometry in geometries {
+            match geometry {
+                GeometryRef::External(_) | GeometryRef::Image { .. } => return Ok(None),
+                GeometryRef::GlyphRun(run) => {
+                    let counting = run.number.is_some() && !group(Property::Value).is_empty();
+                    let font = compiled.font(run.font);
+                    if counting || font.is_none_or(|font| FontFace::parse(font.bytes()).is_err()) {
+                        return Ok(None);
+                    }
+                    text = true;
+                }
+                _ => {}
+            }
+        }
+        let initial = |property, default: f32| {
+            group(property)
+                .first()
+                .map_or(default, |track| match track.values {
+                    TrackValues::Scalar { from, .. } => from.clamp(0.0, 1.0),
+                    _ => default,
+                })
+        };
+        let base = ObjectState {
+            geometry: &object.geometry,
+            transform: object.base_transform,
+            style: object.base_style,
+            appearance: initial(Property::Appearance, 1.0),
+            reveal: initial(Property::Reveal, 1.0),
+            morph: None,
+        };
+        // Glyph runs ignore reveal, as in the renderer.
+        let reveals = !text && !group(Property::Reveal).is_empty();
+
+        let mut channels = Vec::with_capacity(Channel::ALL.len());
+        for channel in Channel::ALL {
+            let active: Vec<_> = groups
+                .iter()
+                .filter(|(property, _)| {
+                    channel.drivers().contains(property)
+                        && (reveals || *property != Property::Reveal)
+                })
+                .collect();
+            let mut reason = bake_reason(channel, &active);
+            let mut keys = None;
+            if reason.is_none() {
+                let Ok(eased) = self.eased_keys(channel, &base, &active) else {
+                    return Ok(None);
+                };
+                let in_range = |key: &Key| {
+                    key.value
+                        .as_ref()
+                        .and_then(|value| value[0].as_f64())
+                        .is_none_or(|value| (0.0..=100.0).contains(&value))
+                };
+                if channel == Channel::Opacity && !eased.iter().all(in_range) {
+                    reason = Some(BakeReason::Clamped);
+                } else {
+                    keys = Some(eased);
+                }
+            }
+            let keys = match (keys, reason) {
+                (Some(keys), _) => keys,
+                (None, reason) => {
+                    let reason = reason.expect("channels are either keyframed or baked");
+                    self.record_baked(object.id, &active, reason);
+                    let Ok(sampled) = self.sampled_keys(channel, index)? else {
+                        return Ok(None);
+                    };
+                    sampled
+                }
+            };
+            channels.push(keys);
+        }
+        let [position, rotation, scale, opacity, shape, stroke_color, stroke_opacity, stroke_width, fill_color, fill_opacity, trim]: [Vec<Key>; 11] =
+            channels.try_into().expect("one key list per channel");
+
+        let mut shapes = subpath_properties(shape);
+        if reveals {
+            shapes.push(json!({
+                "ty": "tm",
+                "nm": "reveal",
+                "s": { "a": 0, "k": 0 },
+                "e": property(trim),
+                "o": { "a": 0, "k": 0 },
+                // Trim across all subpaths in order, like the renderer.
+                "m": 2,
+            }));
+        }
+        if let Some(color) = paint_property(stroke_color) {
+            let line = matches!(object.geometry, GeometryRef::Line { .. });
+            shapes.push(json!({
+                "ty": "st",
+                "nm": "stroke",
+                "c": color,
+                "o": property(stroke_opacity),
+                "w": property(stroke_width),
+                "lc": if line { 2 } else { cap(object.base_style.stroke_cap) },
+                "lj": join(object.base_style.stroke_join),
+                "ml": 4,
+            }));
+        }
+        if let Some(color) = paint_property(fill_color) {
+            shapes.push(json!({
+                "ty": "fl",
+                "nm": "fill",
+                "c": color,
+                "o": property(fill_opacity),
+                // Glyph outlines wind nonzero; paths fill even-odd.
+                "r": if text { 1 } else { 2 },
+            }));
+        }
+
+        let transform = json!({
+            "o": property(opacity),
+            "r": property(rotation),
+            "p": property(position),
+            "a": { "a": 0, "k": [0, 0] },
+            "s": property(scale),
+        });
+        let layers = self
+            .presence(group(Property::Presence))
+            .into_iter()
+            .map(|(in_point, out_point)| {
+                json!({
+                    "ddd": 0,
+                    "ty": 4,
+                    "nm": format!("object-{}", object.id.get()),
+                    "parent": 1,
+                    "sr": 1,
+                    "ks": transform,
+                    "ao": 0,
+                    "shapes": shapes,
+                    "ip": in_point,
+                    "op": out_point,
+                    "st": 0,
+                    "bm": 0,
+                })
+            })
+            .collect();
+        Ok(Some(layers))
+    }
+
+    /// Keys holding the base value, then easing from and to each track's
+    /// endpoints. Only used for a single non-overlapping driving group.
+    fn eased_keys(
+        &mut self,
+        channel: Channel,
+        base: &ObjectState<'a>,
+        active: &[&(Property, &'a [CompiledTrack])],
+    ) -> Result<Vec<Key>, Unsupported> {
+        let mut keys = vec![Key {
+            frame: 0.0,
+            value: self.extract(channel, base)?,
+            easing: None,
+        }];
+        for track in active.iter().flat_map(|(_, tracks)| tracks.iter()) {
+            let TrackTiming {
+                start_time,
+                duration,
+                easing,
+            } = track.timing;
+            let start = start_time * self.frame_rate;
+            let end = (start_time + duration) * self.frame_rate;
+            // From its start on, a track replaces whatever held before it.
+            while keys.last().is_some_and(|key| key.frame >= start) {
+                keys.pop();
+            }
+            if end > start {
+                keys.push(Key {
+                    frame: start,
+                    value: self.extract(channel, &base.with_endpoint(track, false))?,
+                    easing: Some(easing),
+                });
+            }
+            keys.push(Key {
+                frame: end,
+                value: self.extract(channel, &base.with_endpoint(track, true))?,
+                easing: None,
+            });
+        }
+        Ok(keys)
+    }
+
+    /// One linear key per frame, with runs of equal values collapsed. Keys
+    /// whose shape topology changes hold instead of interpolating.
+    fn sampled_keys(
+        &mut self,
+        channel: Channel,
+        index: usize,
+    ) -> Result<Result<Vec<Key>, Unsupported>, LottieError> {
+        if self.samples.is_none() {
+            let mut instance = SceneInstance::new(self.compiled.clone());
+            let mut samples = Vec::with_capacity(self.last_frame as usize + 1);
+            for frame in 0..=self.last_frame as u64 {
+                samples.push(instance.seek(frame as f64 / self.frame_rate)?.clone());
+            }
+            self.samples = Some(samples);
+        }
+        let samples = self.samples.take().expect("samples were just evaluated");
+        let keys = samples
+            .iter()
+            .enumerate()
+            .map(|(frame, sample)| {
+                let object = &sample.objects[index];
+                let state = ObjectState {
+                    geometry: sample.render_geometry(index),
+                    transform: object.transform,
+                    style: object.style,
+                    appearance: object.appearance,
+                    reveal: sample.reveal(index),
+                    morph: Some(sample.morph(index)),
+                };
+                Ok::<_, Unsupported>(Key {
+                    frame: frame as f64,
+                    value: self.extract(channel, &state)?,
+                    easing: Some(Easing::Linear),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>();
+        self.samples = Some(samples);
+        let Ok(mut keys) = keys else {
+            return Ok(Err(Unsupported));
+        };
+
+        for index in 0..keys.len().saturating_sub(1) {
+            if !same_layout(&keys[index].value, &keys[index + 1].value) {
+                keys[index].easing = None;
+            }
+        }
+        let mut collapsed: Vec<Key> = Vec::with_capacity(keys.len());
+        for key in keys {
+            // The last key is redundant inside a run of three equal values.
+            if let [.., before, last] = collapsed.as_slice() {
+                if before.value == last.value && last.value == key.value {
+                    collapsed.pop();
+                }
+            }
+            collapsed.push(key);
+        }
+        Ok(Ok(collapsed))
+    }
+
+    fn record_baked(
+        &mut self,
+        object: ObjectId,
+        active: &[&(Property, &[CompiledTrack])],
+        reason: BakeReason,
+    ) {
+        for track in active.iter().flat_map(|(_, tracks)| tracks.iter()) {
+            if self.baked.iter().all(|baked| baked.track != track.id) {
+                self.baked.push(BakedTrack {
+                    track: track.id,
+                    object,
+                    property: track.property,
+                    reason,
+                });
+            }
+        }
+    }
+
+    /// Frame intervals in which presence events leave the object visible.
+    fn presence(&self, tracks: &[CompiledTrack]) -> Vec<(f64, f64)> {
+        let mut visible = tracks
+            .first()
+            .is_none_or(|track| matches!(track.values, TrackValues::Bool { from: true, .. }));
+        let mut intervals = Vec::new();
+        let mut start = 0.0;
+        for track in tracks {
+            let TrackValues::Bool { to, .. } = track.values else {
+                continue;
+            };
+            let frame = (track.timing.start_time * self.frame_rate).min(self.last_frame);
+            if to != visible {
+                if visible {
+                    intervals.push((start, frame));
+                } else {
+                    start = frame;
+                }
+                visible = to;
+            }
+        }
+        if visible {
+            intervals.push((start, self.last_frame));
+        }
+        intervals.retain(|(start, end)| end > start);
+        intervals
+    }
+
+    fn extract(
+        &mut self,
+        channel: Channel,
+        state: &ObjectState,
+    ) -> Result<Option<Value>, Unsupported> {
+        let (fill, stroke, stroke_width) = state.paint();
+        let hidden_fill = state.reveal < 1.0 && !matches!(state.geometry, GeometryRef::GlyphRun(_));
+        Ok(Some(match channel {
+            Channel::Position => {
+                let translation = state.transform.translation;
+                json!([number(translation.x), number(translation.y)])
+            }
+            Channel::Rotation => json!([number(state.transform.rotation.to_degrees())]),
+            Channel::Scale => {
+                let scale = state.transform.scale * 100.0;
+                json!([number(scale.x), number(scale.y)])
+            }
+            Channel::Opacity => {
+                json!([number(state.style.opacity * state.appearance * 100.0)])
+            }
+            Channel::Shape => Value::Array(self.beziers(state, stroke.map(|_| stroke_width))?),
+            Channel::StrokeColor => return Ok(stroke.map(color)),
+            Channel::StrokeOpacity => json!([alpha(stroke)]),
+            Channel::StrokeWidth => json!([number(stroke_width)]),
+            Channel::FillColor => return Ok(fill.map(color)),
+            Channel::FillOpacity => json!([if hidden_fill {
+                number(0.0)
+            } else {
+                alpha(fill)
+            }]),
+            Channel::Trim => json!([number(state.reveal.clamp(0.0, 1.0) * 100.0)]),
+        }))
+    }
+
+    /// Lottie Béziers for each subpath. Analytic shapes are inset by half
+    /// their stroke and start at +x running counterclockwise, matching the
+    /// renderer's reveal order.
+    fn beziers(
+        &mut self,
+        state: &ObjectState,
+        stroke_width: Option<f32>,
+    ) -> Result<Vec<Value>, Unsupported> {
+        let inset = stroke_width.unwrap_or(0.0) * 0.5;
+        Ok(match state.geometry {
+            GeometryRef::Circle { radius } => {
+                let radius = radius.abs() - inset;
+                let handle = radius * KAPPA;
+                let points = [
+                    Vec2::new(radius, 0.0),
+                    Vec2::new(0.0, radius),
+                    Vec2::new(-radius, 0.0),
+                    Vec2::new(0.0, -radius),
+                ];
+                let tangents = [
+                    Vec2::new(0.0, handle),
+                    Vec2::new(-handle, 0.0),
+                    Vec2::new(0.0, -handle),
+                    Vec2::new(handle, 0.0),
+                ];
+                vec![bezier(
+                    true,
+                    &points,
+                    &tangents.map(|tangent| -tangent),
+                    &tangents,
+                )]
+            }
+            GeometryRef::Rectangle { size } => {
+                let x = size.x.abs() * 0.5 - inset;
+                let y = size.y.abs() * 0.5 - inset;
+                let points = [
+                    Vec2::new(x, 0.0),
+                    Vec2::new(x, y),
+                    Vec2::new(-x, y),
+                    Vec2::new(-x, -y),
+                    Vec2::new(x, -y),
+                ];
+                vec![bezier(true, &points, &[Vec2::ZERO; 5], &[Vec2::ZERO; 5])]
+            }
+            GeometryRef::Line { start, end } => {
+                vec![bezier(
+                    false,
+                    &[*start, *end],
+                    &[Vec2::ZERO; 2],
+                    &[Vec2::ZERO; 2],
+                )]
+            }
+            GeometryRef::VectorPath(path) => match (path.morph_target(), state.morph) {
+                (Some(target), Some(morph)) => {
+                    let plan = match self.plans.iter().position(|(source, _)| source == path) {
+                        Some(index) => &self.plans[index].1,
+                        None => {
+                            let plan = plan_morph(path, target, MorphOptions::DEFAULT)
+                                .map_err(|_| Unsupported)?;
+                            self.plans.push((path.clone(), plan));
+                            &self.plans.last().expect("plan was just cached").1
+                        }
+                    };
+                    let morphed = plan.interpolate(morph.clamp(0.0, 1.0)).to_vector_path();
+                    path_beziers(morphed.commands(), |point| point)
+                }
+                _ => path_beziers(path.commands(), |point| point),
+            },
+            GeometryRef::GlyphRun(run) => {
+                let font = self.compiled.font(run.font).ok_or(Unsupported)?;
+                let face = FontFace::parse(font.bytes()).map_err(|_| Unsupported)?;
+                run.glyphs
+                    .iter()
+                    .filter_map(|glyph| Some((glyph, face.glyph_outline(glyph.glyph)?)))
+                    .flat_map(|(glyph, outline)| {
+                        path_beziers(outline.commands(), |point| glyph.origin + point * run.size)
+                    })
+                    .collect()
+            }
+            GeometryRef::External(_) | GeometryRef::Image { .. } => return Err(Unsupported),
+        })
+    }
+}
+
+/// Contiguous track runs for one object, one per property.
+fn groups(tracks: &[CompiledTrack], index: usize) -> Vec<(Property, &[CompiledTrack])> {
+    let start = tracks.partition_point(|track| (track.object_index as usize) < index);
+    let end = tracks.partition_point(|track| (track.object_index as usize) <= index);
+    tracks[start..end]
+        .chunk_by(|left, right| left.property == right.property)
+        .map(|tracks| (tracks[0].property, tracks))
+        .collect()
+}
+
+fn bake_reason(channel: Channel, active: &[&(Property, &[CompiledTrack])]) -> Option<BakeReason> {
+    let has = |property| active.iter().any(|(candidate, _)| *candidate == property);
+    let transforms = active
+        .iter()
+        .filter(|(property, _)| *property == Property::Transform)
+        .flat_map(|(_, tracks)| tracks.iter());
+    let moving = transforms.clone().any(|track| match &track.motion_plan {
+        Some(MotionPlan::Arc { .. }) => channel == Channel::Position,
+        Some(MotionPlan::Path { follow_tangent, .. }) => {
+            channel == Channel::Position || (channel == Channel::Rotation && *follow_tangent)
+        }
+        None => false,
+    });
+    let morphing = channel == Channel::Shape
+        && transforms.clone().any(|track| {
+            matches!(
+                track.transform_geometry_plan,
+                Some(TransformGeometryPlan::PathPair(_))
+            )
+        });
+    let overlapping = active.iter().any(|(_, tracks)| {
+        tracks.windows(2).any(|pair| {
+            pair[1].timing.start_time < pair[0].timing.start_time + pair[0].timing.duration
+        })
+    });
+    if has(Property::Reveal) {
+        Some(BakeReason::Reveal)
+    } else if has(Property::Morph) || morphing {
+        Some(BakeReason::Morph)
+    } else if moving {
+        Some(BakeReason::MotionPath)
+    } else if active.len() > 1 {
+        Some(BakeReason::CombinedProperties)
+    } else if overlapping {
+        Some(BakeReason::OverlappingTracks)
+    } else {
+        None
+    }
+}
+
+/// A Lottie property from keys, static when every key agrees. Single values
+/// are unwrapped in the static form.
+fn property(keys: Vec<Key>) -> Value {
+    let keys = fill_gaps(keys);
+    let first = keys[0].value.clone().unwrap_or(Value::Null);
+    if keys.iter().all(|key| key.value.as_ref() == Some(&first)) {
+        let value = match first {
+            Value::Array(mut values) if values.len() == 1 => values.remove(0),
+            value => value,
+        };
+        return json!({ "a": 0, "k": value });
+    }
+    let count = keys.len();
+    let keyframes: Vec<Value> = keys
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| {
+            let mut keyframe = json!({ "t": key.frame, "s": key.value });
+            if index + 1 < count {
+                match key.easing {
+                    Some(easing) => {
+                        let [out_x, out_y, in_x, in_y] = handles(easing);
+                        keyframe["o"] = json!({ "x": [out_x], "y": [out_y] });
+                        keyframe["i"] = json!({ "x": [in_x], "y": [in_y] });
+                    }
+                    None => keyframe["h"] = json!(1),
+                }
+            }
+            keyframe
+        })
+        .collect();
+    json!({ "a": 1, "k": keyframes })
+}
+
+/// `None` when the paint is never set, so the layer omits it.
+fn paint_property(keys: Vec<Key>) -> Option<Value> {
+    keys.iter()
+        .any(|key| key.value.is_some())
+        .then(|| property(keys))
+}
+
+/// Unset paints take the color of their neighbors, so that fading to or from
+/// no paint only animates opacity, as in the runtime.
+fn fill_gaps(mut keys: Vec<Key>) -> Vec<Key> {
+    let mut previous = None;
+    for key in &mut keys {
+        match &key.value {
+            Some(value) => previous = Some(value.clone()),
+            None => key.value.clone_from(&previous),
+        }
+    }
+    let mut next = None;
+    for key in keys.iter_mut().rev() {
+        match &key.value {
+            Some(value) => next = Some(value.clone()),
+            None => key.value.clone_from(&next),
+        }
+    }
+    keys
+}
+
+/// One `sh` shape item per subpath. Subpaths missing from some keys are
+/// empty there.
+fn subpath_properties(keys: Vec<Key>) -> Vec<Value> {
+    let count = keys
+        .iter()
+        .filter_map(|key| key.value.as_ref()?.as_array().map(Vec::len))
+        .max()
+        .unwrap_or(0);
+    (0..count)
+        .map(|subpath| {
+            let keys = keys
+                .iter()
+                .map(|key| Key {
+                    value: Some(json!([key
+                        .value
+                        .as_ref()
+                        .and_then(|beziers| beziers.get(subpath))
+                        .cloned()
+                        .unwrap_or_else(|| bezier(false, &[], &[], &[]))])),
+                    ..key.clone()
+                })
+                .collect();
+            json!({
+                "ty": "sh",
+                "nm": format!("subpath-{subpath}"),
+                "ks": property(keys),
+            })
+        })
+        .collect()
+}
+
+fn same_layout(left: &Option<Value>, right: &Option<Value>) -> bool {
+    fn layout(left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::Array(left), Value::Array(right)) => {
+                left.len() == right.len() && left.iter().zip(right).all(|(l, r)| layout(l, r))
+            }
+            (Value::Object(left), Value::Object(right)) => left
+                .iter()
+                .all(|(key, value)| right.get(key).is_some_and(|other| layout(value, other))),
+            _ => true,
+        }
+    }
+    match (left, right) {
+        (Some(left), Some(right)) => layout(left, right),
+        _ => true,
+    }
+}
+
+const fn handles(easing: Easing) -> [f64; 4] {
+    match easing {
+        Easing::Linear => [0.0, 0.0, 1.0, 1.0],
+        Easing::EaseInOutCubic => EASE_IN_OUT_CUBIC,
+    }
+}
+
+/// The shortest decimal that reads back as `value`, rather than its widened
+/// binary expansion.
+fn number(value: f32) -> Value {
+    value
+        .to_string()
+        .parse::<f64>()
+        .map_or(Value::Null, Value::from)
+}
+
+fn color(color: Color) -> Value {
+    json!([
+        number(color.red),
+        number(color.green),
+        number(color.blue),
+        1
+    ])
+}
+
+fn alpha(color: Option<Color>) -> Value {
+    number(color.map_or(0.0, |color| color.alpha.clamp(0.0, 1.0) * 100.0))
+}
+
+const fn join(join: StrokeJoin) -> u8 {
+    match join {
+        StrokeJoin::Miter => 1,
+        StrokeJoin::Round => 2,
+        StrokeJoin::Bevel => 3,
+    }
+}
+
+const fn cap(cap: StrokeCap) -> u8 {
+    match cap {
+        StrokeCap::Butt => 1,
+        StrokeCap::Round => 2,
+        StrokeCap::Square => 3,
+    }
+}
+
+/// A Lottie Bézier: vertices with in and out tangents relative to them.
+fn bezier(closed: bool, vertices: &[Vec2], in_tangents: &[Vec2], out_tangents: &[Vec2]) -> Value {
+    let points = |points: &[Vec2]| -> Vec<Value> {
+        points
+            .iter()
+            .map(|point| json!([number(point.x), number(point.y)]))
+            .collect()
+    };
+    json!({
+        "c": closed,
+        "v": points(vertices),
+        "i": points(in_tangents),
+        "o": points(out_tangents),
+    })
+}
+
+/// Splits path commands into Lottie Béziers, raising quadratics to cubics.
+fn path_beziers(commands: &[PathCommand], map: impl Fn(Vec2) -> Vec2) -> Vec<Value> {
+    #[derive(Default)]
+    struct Subpath {
+        vertices: Vec<Vec2>,
+        in_tangents: Vec<Vec2>,
+        out_tangents: Vec<Vec2>,
+    }
+
+    impl Subpath {
+        fn start(point: Vec2) -> Self {
+            Self {
+                vertices: vec![point],
+                in_tangents: vec![Vec2::ZERO],
+                out_tangents: vec![Vec2::ZERO],
+            }
+        }
+
+        fn push(&mut self, out_control: Vec2, in_control: Vec2, to: Vec2) {
+            let last = self.vertices.len() - 1;
+            self.out_tangents[last] = out_control - self.vertices[last];
+            self.vertices.push(to);
+            self.in_tangents.push(in_control - to);
+            self.out_tangents.push(Vec2::ZERO);
+        }
+
+        fn finish(mut self, closed: bool, beziers: &mut Vec<Value>) {
+            // Closing onto the first vertex would otherwise duplicate it.
+            if closed && self.vertices.len() > 1 && self.vertices.last() == self.vertices.first() {
+                self.vertices.pop();
+                self.out_tangents.pop();
+                self.in_tangents[0] = self.in_tangents.pop().unwrap_or(Vec2::ZERO);
+            }
+            beziers.push(bezier(
+                closed,
+                &self.vertices,
+                &self.in_tangents,
+                &self.out_tangents,
+            ));
+        }
+    }
+
+    let mut beziers = Vec::new();
+    let mut current: Option<Subpath> = None;
+    let mut start = Vec2::ZERO;
+    for command in commands {
+        if let PathCommand::MoveTo { to } = *command {
+            if let Some(subpath) = current.take() {
+                subpath.finish(false, &mut beziers);
+            }
+            start = map(to);
+            current = Some(Subpath::start(start));
+            continue;
+        }
+        if *command == PathCommand::Close {
+            if let Some(subpath) = current.take() {
+                subpath.finish(true, &mut beziers);
+            }
+            continue;
+        }
+        // Drawing after a close continues from the closed subpath's start.
+        let subpath = current.get_or_insert_with(|| Subpath::start(start));
+        let from = *subpath
+            .vertices
+            .last()
+            .expect("subpaths start with a vertex");
+        match *command {
+            PathCommand::LineTo { to } => {
+                let to = map(to);
+                subpath.push(from, to, to);
+            }
+            PathCommand::QuadraticTo { control, to } => {
+                let (control, to) = (map(control), map(to));
+                subpath.push(
+                    from + (control - from) * (2.0 / 3.0),
+                    to + (control - to) * (2.0 / 3.0),
+                    to,
+                );
+            }
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                to,
+            } => subpath.push(map(control1), map(control2), map(to)),
+            PathCommand::MoveTo { .. } | PathCommand::Close => unreachable!("handled above"),
+        }
+    }
+    if let Some(subpath) = current {
+        subpath.finish(false, &mut beziers);
+    }
+    beziers
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{GeometryId, ObjectSnapshot};
+
+    use super::*;
+
+    fn options() -> LottieOptions {
+        LottieOptions::new(Camera2D::DEFAULT, 640, 360).frame_rate(10.0)
+    }
+
+    fn export(scene: &SceneDefinition) -> (LottieExport, Value) {
+        let export = scene_to_lottie(scene, &options()).unwrap();
+        let json = serde_json::from_str(&export.json).unwrap();
+        (export, json)
+    }
+
+    /// Object layers by name, skipping the camera.
+    fn layers<'a>(json: &'a Value, name: &str) -> Vec<&'a Value> {
+        json["layers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|layer| layer["nm"] == name)
+            .collect()
+    }
+
+    #[test]
+    fn eased_tracks_become_keyframes_under_a_camera_layer() {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(1.0));
+        scene.add(GeometryRef::rectangle(2.0, 1.0));
+        scene
+            .animate_position(
+                circle,
+                Vec2::ZERO,
+                Vec2::new(2.0, 1.0),
+                TrackTiming::new(0.5, 1.0, Easing::EaseInOutCubic),
+            )
+            .unwrap();
+        scene
+            .animate_scalar(
+                circle,
+                Property::Opacity,
+                1.0,
+                0.5,
+                TrackTiming::new(1.5, 0.5, Easing::Linear),
+            )
+            .unwrap();
+        let (export, json) = export(&scene);
+        assert!(export.baked.is_empty());
+        assert!(export.unsupported.is_empty());
+        assert_eq!(json["op"], 20.0);
+
+        let camera = &json["layers"][0];
+        assert_eq!(camera["ty"], 3);
+        assert_eq!(camera["ind"], 1);
+        // Later objects draw on top, so they come first.
+        assert_eq!(json["layers"][1]["nm"], "object-1");
+        let layer = layers(&json, "object-0")[0];
+        assert_eq!(layer["parent"], 1);
+        assert_eq!(
+            layer["ks"]["p"],
+            json!({ "a": 1, "k": [
+                { "t": 0.0, "s": [0.0, 0.0], "h": 1 },
+                { "t": 5.0, "s": [0.0, 0.0], "o": { "x": [0.65], "y": [0.0] }, "i": { "x": [0.35], "y": [1.0] } },
+                { "t": 15.0, "s": [2.0, 1.0] },
+            ]})
+        );
+        // Opacity picks up exactly where the position track ends.
+        assert_eq!(
+            layer["ks"]["o"]["k"],
+            json!([
+                { "t": 0.0, "s": [100.0], "h": 1 },
+                { "t": 15.0, "s": [100.0], "o": { "x": [0.0], "y": [0.0] }, "i": { "x": [1.0], "y": [1.0] } },
+                { "t": 20.0, "s": [50.0] },
+            ])
+        );
+        assert_eq!(layer["ks"]["r"], json!({ "a": 0, "k": 0.0 }));
+        let shapes = layer["shapes"].as_array().unwrap();
+        assert_eq!(shapes[0]["ks"]["k"]["v"][0], json!([1.0, 0.0]));
+        assert_eq!(shapes.last().unwrap()["ty"], "fl");
+    }
+
+    #[test]
+    fn reveal_morph_and_combined_properties_are_baked_and_reported() {
+        let mut scene = SceneDefinition::new();
+        let timing = TrackTiming::new(0.0, 1.0, Easing::Linear);
+        let square = VectorPath::new()
+            .move_to(Vec2::new(-1.0, -1.0))
+            .line_to(Vec2::new(1.0, -1.0))
+            .line_to(Vec2::new(1.0, 1.0))
+            .line_to(Vec2::new(-1.0, 1.0))
+            .close();
+        let revealed = scene.add(GeometryRef::path(square.clone()));
+        let reveal = scene.animate_reveal(revealed, 0.0, 1.0, timing).unwrap();
+        let morphing = scene.add(GeometryRef::path(
+            square.with_morph_target(
+                VectorPath::new()
+                    .move_to(Vec2::new(0.0, -2.0))
+                    .line_to(Vec2::new(2.0, 0.0))
+                    .line_to(Vec2::new(0.0, 2.0))
+                    .line_to(Vec2::new(-2.0, 0.0))
+                    .close(),
+            ),
+        ));
+        let morph = scene.animate_morph(morphing, 0.0, 1.0, timing).unwrap();
+        let moved = scene.add(GeometryRef::circle(1.0));
+        let position = scene
+            .animate_position(moved, Vec2::ZERO, Vec2::ONE, timing)
+            .unwrap();
+        let from = scene.snapshot(moved).unwrap();
+        let to = ObjectSnapshot {
+            transform: Transform2D {
+                rotation: 1.0,
+                ..from.transform
+            },
+            ..from.clone()
+        };
+        let transform = scene.animate_transform(moved, from, to, timing).unwrap();
+
+        let (export, json) = export(&scene);
+        let reported: Vec<_> = export
+            .baked
+            .iter()
+            .map(|baked| (baked.track, baked.reason))
+            .collect();
+        assert_eq!(
+            reported,
+            vec![
+                (reveal, BakeReason::Reveal),
+                (morph, BakeReason::Morph),
+                (position, BakeReason::CombinedProperties),
+                (transform, BakeReason::CombinedProperties),
+            ]
+        );
+
+        let shapes = &layers(&json, "object-0")[0]["shapes"];
+        let trim = shapes
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|shape| shape["ty"] == "tm");
+        let trim_keys = trim.unwrap()["e"]["k"].as_array().unwrap();
+        assert_eq!(trim_keys.len(), 11);
+        assert_eq!(trim_keys[5]["s"], json!([50.0]));
+        // The fill appears only once the outline is complete.
+        let fill = shapes.as_array().unwrap().last().unwrap();
+        assert_eq!(
+            fill["o"]["k"],
+            json!([
+                { "t": 0.0, "s": [0.0], "o": { "x": [0.0], "y": [0.0] }, "i": { "x": [1.0], "y": [1.0] } },
+                { "t": 9.0, "s": [0.0], "o": { "x": [0.0], "y": [0.0] }, "i": { "x": [1.0], "y": [1.0] } },
+                { "t": 10.0, "s": [100.0] },
+            ])
+        );
+
+        let morph_keys = &layers(&json, "object-1")[0]["shapes"][0]["ks"]["k"];
+        let vertices = |key: usize| morph_keys[key]["s"][0]["v"].as_array().unwrap().len();
+        assert!((1..11).all(|key| vertices(key) == vertices(0)));
+        // Rotation is driven only by the Transform track and stays eased.
+        assert_eq!(
+            layers(&json, "object-2")[0]["ks"]["r"]["k"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn presence_splits_layers_and_unsupported_objects_are_reported() {
+        let mut scene = SceneDefinition::new();
+        let blinking = scene.add(GeometryRef::line(Vec2::ZERO, Vec2::ONE));
+        scene.set_presence_at(blinking, true, false, 0.5).unwrap();
+        scene.set_presence_at(blinking, false, true, 1.0).unwrap();
+        let external = scene.add(GeometryRef::External(GeometryId::new(1)));
+        let export = scene_to_lottie(&scene, &options().duration(2.0)).unwrap();
+        assert_eq!(export.unsupported, vec![external]);
+
+        let json: Value = serde_json::from_str(&export.json).unwrap();
+        let spans: Vec<_> = layers(&json, "object-0")
+            .iter()
+            .map(|layer| (layer["ip"].clone(), layer["op"].clone()))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![(json!(0.0), json!(5.0)), (json!(10.0), json!(20.0))]
+        );
+        let stroke = &layers(&json, "object-0")[0]["shapes"][1];
+        assert_eq!(stroke["ty"], "st");
+        assert_eq!(stroke["lc"], 2);
+
+        assert!(matches!(
+            scene_to_lottie(&scene, &options().frame_rate(0.0)),
+            Err(LottieError::InvalidFrameRate(_))
+        ));
+    }
+}
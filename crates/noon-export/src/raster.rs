@@ -0,0 +1,998 @@
+//! CPU rasterization of evaluated frames.
+//!
+//! Shapes follow the renderer's shaders sample by sample: analytic shapes by
+//! signed distance and perimeter progress, paths through the same tessellated
+//! meshes, glyphs by nonzero winding of their outlines, and images through
+//! the same texel lookup. Each pixel averages a 4x4 grid of samples, and
+//! colors blend premultiplied in sRGB space, like a non-sRGB render target.
+
+use noon_core::{
+    Color, FontDefinition, GeometryRef, GlyphRun, ImageData, ImageDefinition, ImageSampling,
+    ObjectId, PathCommand, Style, Transform2D, Vec2, VectorPath,
+};
+use noon_geometry::{tessellate_styled_with_fill, FontFace, PathSurface};
+use noon_render_wgpu::Camera2D;
+use noon_runtime::{FrameState, SceneInstance};
+
+const GRID: usize = 4;
+const SAMPLES: u32 = (GRID * GRID) as u32;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RasterOptions {
+    pub camera: Camera2D,
+    /// Output size in pixels. The camera rectangle is stretched to fill it.
+    pub width: u32,
+    pub height: u32,
+    pub background: Color,
+}
+
+impl RasterOptions {
+    pub const fn new(camera: Camera2D, width: u32, height: u32) -> Self {
+        Self {
+            camera,
+            width,
+            height,
+            background: Color::BLACK,
+        }
+    }
+
+    pub const fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RasterFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Straight-alpha RGBA8 pixels, top row first.
+    pub pixels: Vec<u8>,
+    /// Present objects left out of the image: external geometry, images and
+    /// fonts that are not registered or do not parse, and paths that fail to
+    /// tessellate.
+    pub unsupported: Vec<ObjectId>,
+}
+
+/// Rasterizes the frame over the background color.
+pub fn rasterize_frame(
+    frame: &FrameState,
+    images: &[ImageDefinition],
+    fonts: &[FontDefinition],
+    options: &RasterOptions,
+) -> RasterFrame {
+    let mut canvas = Canvas::new(options);
+    let mut unsupported = Vec::new();
+
+    for (index, object) in frame.objects.iter().enumerate() {
+        if !frame.is_present(index) {
+            continue;
+        }
+        let opacity = object.style.opacity * object.appearance.clamp(0.0, 1.0);
+        let reveal = unit(frame.reveal(index));
+        let map = PixelMap::new(options, &object.transform);
+        let drawn = match frame.render_geometry(index) {
+            GeometryRef::Circle { radius } => {
+                draw_circle(&mut canvas, map, *radius, &object.style, opacity, reveal);
+                true
+            }
+            GeometryRef::Rectangle { size } => {
+                draw_rectangle(&mut canvas, map, *size, &object.style, opacity, reveal);
+                true
+            }
+            GeometryRef::Line { start, end } => {
+                draw_line(
+                    &mut canvas,
+                    map,
+                    (*start, *end),
+                    &object.style,
+                    opacity,
+                    reveal,
+                );
+                true
+            }
+            GeometryRef::VectorPath(path) => draw_vector_path(
+                &mut canvas,
+                map,
+                path,
+                &object.style,
+                opacity,
+                (reveal, unit(frame.morph(index))),
+            ),
+            GeometryRef::Image {
+                image,
+                size,
+                sampling,
+            } => match images.iter().find(|definition| definition.id == *image) {
+                Some(definition) => {
+                    draw_image(
+                        &mut canvas,
+                        map,
+                        &definition.data,
+                        (*size, *sampling),
+                        opacity,
+                    );
+                    true
+                }
+                None => false,
+            },
+            GeometryRef::GlyphRun(run) => {
+                draw_glyph_run(&mut canvas, map, run, &object.style, opacity, fonts)
+            }
+            GeometryRef::External(_) => false,
+        };
+        if !drawn {
+            unsupported.push(object.id);
+        }
+    }
+
+    RasterFrame {
+        width: options.width,
+        height: options.height,
+        pixels: canvas.into_rgba8(),
+        unsupported,
+    }
+}
+
+pub fn rasterize_instance(instance: &SceneInstance, options: &RasterOptions) -> RasterFrame {
+    rasterize_frame(
+        instance.frame(),
+        instance.images(),
+        instance.fonts(),
+        options,
+    )
+}
+
+fn unit(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Premultiplied RGBA pixels, blended in place.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Canvas {
+    fn new(options: &RasterOptions) -> Self {
+        let (width, height) = (options.width as usize, options.height as usize);
+        Self {
+            width,
+            height,
+            pixels: vec![premultiplied(options.background, 1.0); width * height],
+        }
+    }
+
+    fn blend(&mut self, x: usize, y: usize, source: [f32; 4]) {
+        let pixel = &mut self.pixels[y * self.width + x];
+        let keep = 1.0 - source[3];
+        for (destination, source) in pixel.iter_mut().zip(source) {
+            *destination = source + *destination * keep;
+        }
+    }
+
+    /// Pixels a pixel-space box touches, or `None` when it misses the
+    /// canvas.
+    fn clip(&self, min: Vec2, max: Vec2) -> Option<Bounds> {
+        if !(min.x.is_finite() && min.y.is_finite() && max.x.is_finite() && max.y.is_finite()) {
+            return None;
+        }
+        let left = min.x.floor().max(0.0) as usize;
+        let top = min.y.floor().max(0.0) as usize;
+        let right = (max.x.ceil().max(0.0) as usize).min(self.width);
+        let bottom = (max.y.ceil().max(0.0) as usize).min(self.height);
+        (left < right && top < bottom).then_some(Bounds {
+            left,
+            top,
+            right,
+            bottom,
+        })
+    }
+
+    fn into_rgba8(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.pixels.len() * 4);
+        for [red, green, blue, alpha] in self.pixels {
+            let unpremultiply = if alpha > 0.0 { 1.0 / alpha } else { 0.0 };
+            bytes.extend([red, green, blue].map(|channel| byte(channel * unpremultiply)));
+            bytes.push(byte(alpha));
+        }
+        bytes
+    }
+}
+
+fn byte(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn premultiplied(color: Color, opacity: f32) -> [f32; 4] {
+    let alpha = color.alpha.clamp(0.0, 1.0) * opacity.clamp(0.0, 1.0);
+    [
+        color.red * alpha,
+        color.green * alpha,
+        color.blue * alpha,
+        alpha,
+    ]
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Bounds {
+    left: usize,
+    top: usize,
+    right: usize,
+    bottom: usize,
+}
+
+/// Affine map from object-local units to pixels, y down.
+#[derive(Clone, Copy, Debug)]
+struct PixelMap {
+    matrix: [f32; 4],
+    offset: Vec2,
+}
+
+impl PixelMap {
+    fn new(options: &RasterOptions, transform: &Transform2D) -> Self {
+        let camera = options.camera;
+        let scale_x = options.width as f32 / camera.world_size.x;
+        let scale_y = options.height as f32 / camera.world_size.y;
+        let (sin, cos) = transform.rotation.sin_cos();
+        let (x, y) = (transform.scale.x, transform.scale.y);
+        Self {
+            matrix: [
+                scale_x * cos * x,
+                -scale_x * sin * y,
+                -scale_y * sin * x,
+                -scale_y * cos * y,
+            ],
+            offset: Vec2::new(
+                scale_x * (transform.translation.x - camera.center.x + camera.world_size.x * 0.5),
+                scale_y * (camera.center.y + camera.world_size.y * 0.5 - transform.translation.y),
+            ),
+        }
+    }
+
+    fn apply(self, point: Vec2) -> Vec2 {
+        let [a, b, c, d] = self.matrix;
+        Vec2::new(
+            a * point.x + b * point.y + self.offset.x,
+            c * point.x + d * point.y + self.offset.y,
+        )
+    }
+
+    fn inverse(self) -> Option<Self> {
+        let [a, b, c, d] = self.matrix;
+        let determinant = a * d - b * c;
+        if !determinant.is_normal() {
+            return None;
+        }
+        let matrix = [
+            d / determinant,
+            -b / determinant,
+            -c / determinant,
+            a / determinant,
+        ];
+        let inverse = Self {
+            matrix,
+            offset: Vec2::ZERO,
+        };
+        let offset = inverse.apply(self.offset);
+        Some(Self {
+            matrix,
+            offset: Vec2::new(-offset.x, -offset.y),
+        })
+    }
+
+    /// Canvas pixels covered by a local-space box.
+    fn bounds(self, canvas: &Canvas, min: Vec2, max: Vec2) -> Option<Bounds> {
+        let corners = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)]
+            .map(|corner| self.apply(corner));
+        let (low, high) = extent(corners);
+        canvas.clip(low, high)
+    }
+}
+
+fn extent(points: impl IntoIterator<Item = Vec2>) -> (Vec2, Vec2) {
+    points.into_iter().fold(
+        (
+            Vec2::new(f32::INFINITY, f32::INFINITY),
+            Vec2::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+        ),
+        |(low, high), point| {
+            (
+                Vec2::new(low.x.min(point.x), low.y.min(point.y)),
+                Vec2::new(high.x.max(point.x), high.y.max(point.y)),
+            )
+        },
+    )
+}
+
+/// Pixel-space position of sample `index` within pixel `(x, y)`.
+fn sample_point(x: usize, y: usize, index: usize) -> Vec2 {
+    Vec2::new(
+        x as f32 + ((index % GRID) as f32 + 0.5) / GRID as f32,
+        y as f32 + ((index / GRID) as f32 + 0.5) / GRID as f32,
+    )
+}
+
+/// Per-pixel sample coverage over a canvas region; bit `row * 4 + column`
+/// is one sample.
+struct Mask {
+    bounds: Bounds,
+    bits: Vec<u16>,
+}
+
+impl Mask {
+    fn new(bounds: Bounds) -> Self {
+        let len = (bounds.right - bounds.left) * (bounds.bottom - bounds.top);
+        Self {
+            bounds,
+            bits: vec![0; len],
+        }
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        (y - self.bounds.top) * (self.bounds.right - self.bounds.left) + x - self.bounds.left
+    }
+
+    fn set(&mut self, x: usize, y: usize, sample: usize) {
+        let index = self.index(x, y);
+        self.bits[index] |= 1 << sample;
+    }
+
+    fn get(&self, x: usize, y: usize) -> u16 {
+        self.bits[self.index(x, y)]
+    }
+}
+
+/// Blends fill and stroke coverage sharing one region; strokes sit over
+/// fills where both cover a sample.
+fn composite(canvas: &mut Canvas, fill: (&Mask, [f32; 4]), stroke: Option<(&Mask, [f32; 4])>) {
+    let (fill_mask, fill_color) = fill;
+    let bounds = fill_mask.bounds;
+    let stroke_over_fill = stroke.map(|(_, stroke_color)| {
+        let keep = 1.0 - stroke_color[3];
+        [0, 1, 2, 3].map(|channel| stroke_color[channel] + fill_color[channel] * keep)
+    });
+    for y in bounds.top..bounds.bottom {
+        for x in bounds.left..bounds.right {
+            let fill_bits = fill_mask.get(x, y);
+            let stroke_bits = stroke.map_or(0, |(mask, _)| mask.get(x, y));
+            if fill_bits | stroke_bits == 0 {
+                continue;
+            }
+            let mut color = [0.0; 4];
+            let mut add = |bits: u16, source: [f32; 4]| {
+                let weight = bits.count_ones() as f32 / SAMPLES as f32;
+                for (channel, source) in color.iter_mut().zip(source) {
+                    *channel += source * weight;
+                }
+            };
+            add(fill_bits & !stroke_bits, fill_color);
+            if let (Some((_, stroke_color)), Some(both)) = (stroke, stroke_over_fill) {
+                add(stroke_bits & !fill_bits, stroke_color);
+                add(stroke_bits & fill_bits, both);
+            }
+            canvas.blend(x, y, color);
+        }
+    }
+}
+
+/// Stroke samples show where their outline progress is within the reveal;
+/// fills show once the outline is complete.
+fn stroke_revealed(reveal: f32, progress: f32) -> bool {
+    reveal >= 1.0 || (reveal > 0.0 && progress <= reveal)
+}
+
+/// Classifies every sample of a local-space box as fill, stroke or neither,
+/// then composites the result.
+fn draw_analytic(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    (min, max): (Vec2, Vec2),
+    (fill, stroke): ([f32; 4], [f32; 4]),
+    classify: impl Fn(Vec2) -> (bool, bool),
+) {
+    let (Some(bounds), Some(inverse)) = (map.bounds(canvas, min, max), map.inverse()) else {
+        return;
+    };
+    let mut fill_mask = Mask::new(bounds);
+    let mut stroke_mask = Mask::new(bounds);
+    for y in bounds.top..bounds.bottom {
+        for x in bounds.left..bounds.right {
+            for sample in 0..GRID * GRID {
+                let (in_fill, in_stroke) = classify(inverse.apply(sample_point(x, y, sample)));
+                if in_fill {
+                    fill_mask.set(x, y, sample);
+                }
+                if in_stroke {
+                    stroke_mask.set(x, y, sample);
+                }
+            }
+        }
+    }
+    composite(canvas, (&fill_mask, fill), Some((&stroke_mask, stroke)));
+}
+
+fn paints(style: &Style, opacity: f32) -> ([f32; 4], [f32; 4]) {
+    let paint =
+        |color: Option<Color>| color.map_or([0.0; 4], |color| premultiplied(color, opacity));
+    (paint(style.fill), paint(style.stroke))
+}
+
+fn draw_circle(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    radius: f32,
+    style: &Style,
+    opacity: f32,
+    reveal: f32,
+) {
+    let radius = radius.abs().max(0.000_001);
+    let stroke_width = style.stroke_width.clamp(0.0, radius);
+    let has_stroke = style.stroke.is_some() && stroke_width > 0.0;
+    let show_fill = style.fill.is_some() && reveal >= 1.0;
+    let half = Vec2::new(radius, radius);
+    draw_analytic(
+        canvas,
+        map,
+        (Vec2::new(-radius, -radius), half),
+        paints(style, opacity),
+        |point| {
+            let distance = point.length() - radius;
+            if distance > 0.0 {
+                (false, false)
+            } else if has_stroke && distance > -stroke_width {
+                let progress = (point.y.atan2(point.x) / std::f32::consts::TAU + 1.0).fract();
+                (false, stroke_revealed(reveal, progress))
+            } else {
+                (show_fill, false)
+            }
+        },
+    );
+}
+
+fn draw_rectangle(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    size: Vec2,
+    style: &Style,
+    opacity: f32,
+    reveal: f32,
+) {
+    let half = Vec2::new(
+        (size.x.abs() * 0.5).max(0.000_001),
+        (size.y.abs() * 0.5).max(0.000_001),
+    );
+    let stroke_width = style.stroke_width.clamp(0.0, half.x.min(half.y));
+    let has_stroke = style.stroke.is_some() && stroke_width > 0.0;
+    let show_fill = style.fill.is_some() && reveal >= 1.0;
+    draw_analytic(
+        canvas,
+        map,
+        (Vec2::new(-half.x, -half.y), half),
+        paints(style, opacity),
+        |point| {
+            let distance = (point.x.abs() - half.x).max(point.y.abs() - half.y);
+            if distance > 0.0 {
+                (false, false)
+            } else if has_stroke && distance > -stroke_width {
+                (
+                    false,
+                    stroke_revealed(reveal, rectangle_progress(point, half)),
+                )
+            } else {
+                (show_fill, false)
+            }
+        },
+    );
+}
+
+/// Arc length from the middle of the right edge, counterclockwise, along the
+/// ray from the center through `point`; matches the analytic shader.
+fn rectangle_progress(point: Vec2, half: Vec2) -> f32 {
+    let ratio = Vec2::new(point.x.abs() / half.x, point.y.abs() / half.y);
+    let boundary = point * (1.0 / ratio.x.max(ratio.y).max(0.000_001));
+    let perimeter = 4.0 * (half.x + half.y);
+    let distance = if ratio.x >= ratio.y {
+        if boundary.x > 0.0 {
+            if boundary.y >= 0.0 {
+                boundary.y
+            } else {
+                perimeter + boundary.y
+            }
+        } else {
+            2.0 * (half.x + half.y) - boundary.y
+        }
+    } else if boundary.y > 0.0 {
+        half.y + half.x - boundary.x
+    } else {
+        3.0 * (half.y + half.x) + boundary.x
+    };
+    distance / perimeter
+}
+
+fn draw_line(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    (start, end): (Vec2, Vec2),
+    style: &Style,
+    opacity: f32,
+    reveal: f32,
+) {
+    let Some(color) = style.stroke.or(style.fill) else {
+        return;
+    };
+    let radius = style.stroke_width.max(0.0) * 0.5;
+    if radius <= 0.0 {
+        return;
+    }
+    let delta = end - start;
+    let length = delta.length();
+    let tangent = if length > 0.000_001 {
+        delta * (1.0 / length)
+    } else {
+        Vec2::new(1.0, 0.0)
+    };
+    let (low, high) = extent([start, end]);
+    draw_analytic(
+        canvas,
+        map,
+        (
+            Vec2::new(low.x - radius, low.y - radius),
+            Vec2::new(high.x + radius, high.y + radius),
+        ),
+        ([0.0; 4], premultiplied(color, opacity)),
+        |point| {
+            let offset = point - start;
+            let along = offset.x * tangent.x + offset.y * tangent.y;
+            let across = offset.y * tangent.x - offset.x * tangent.y;
+            let beyond = if along < 0.0 {
+                -along
+            } else {
+                (along - length).max(0.0)
+            };
+            let inside = beyond * beyond + across * across <= radius * radius;
+            let progress = (along / length.max(0.000_001)).clamp(0.0, 1.0);
+            (false, inside && stroke_revealed(reveal, progress))
+        },
+    );
+}
+
+fn draw_vector_path(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    path: &VectorPath,
+    style: &Style,
+    opacity: f32,
+    (reveal, morph): (f32, f32),
+) -> bool {
+    let Ok(mesh) = tessellate_styled_with_fill(
+        path,
+        style.stroke_width,
+        style.stroke_join,
+        style.stroke_cap,
+        style.fill.is_some(),
+    ) else {
+        return false;
+    };
+    let points: Vec<Vec2> = mesh
+        .vertices
+        .iter()
+        .map(|vertex| {
+            map.apply(vertex.position + (vertex.target_position - vertex.position) * morph)
+        })
+        .collect();
+    let (low, high) = extent(points.iter().copied());
+    let Some(bounds) = canvas.clip(low, high) else {
+        return true;
+    };
+    let mut fill_mask = Mask::new(bounds);
+    let mut stroke_mask = Mask::new(bounds);
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|corner| triangle[corner] as usize);
+        match mesh.vertices[a].surface {
+            PathSurface::Fill if style.fill.is_some() && reveal >= 1.0 => {
+                fill_triangle(&mut fill_mask, [points[a], points[b], points[c]], |_| true);
+            }
+            PathSurface::Stroke if style.stroke.is_some() && reveal > 0.0 => {
+                let progress = [a, b, c].map(|vertex| mesh.vertices[vertex].path_progress);
+                fill_triangle(
+                    &mut stroke_mask,
+                    [points[a], points[b], points[c]],
+                    |weights| {
+                        let progress = weights[0] * progress[0]
+                            + weights[1] * progress[1]
+                            + weights[2] * progress[2];
+                        stroke_revealed(reveal, progress)
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+    let (fill, stroke) = paints(style, opacity);
+    composite(canvas, (&fill_mask, fill), Some((&stroke_mask, stroke)));
+    true
+}
+
+/// Marks samples inside a pixel-space triangle, passing their barycentric
+/// weights to `keep`.
+fn fill_triangle(mask: &mut Mask, [a, b, c]: [Vec2; 3], keep: impl Fn([f32; 3]) -> bool) {
+    let area = edge(a, b, c);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+    let (low, high) = extent([a, b, c]);
+    let bounds = mask.bounds;
+    let left = (low.x.floor().max(bounds.left as f32) as usize).min(bounds.right);
+    let top = (low.y.floor().max(bounds.top as f32) as usize).min(bounds.bottom);
+    let right = (high.x.ceil().max(0.0) as usize).clamp(left, bounds.right);
+    let bottom = (high.y.ceil().max(0.0) as usize).clamp(top, bounds.bottom);
+    for y in top..bottom {
+        for x in left..right {
+            for sample in 0..GRID * GRID {
+                let point = sample_point(x, y, sample);
+                let weights = [edge(b, c, point), edge(c, a, point), edge(a, b, point)]
+                    .map(|weight| weight / area);
+                if weights.iter().all(|weight| *weight >= 0.0) && keep(weights) {
+                    mask.set(x, y, sample);
+                }
+            }
+        }
+    }
+}
+
+fn edge(from: Vec2, to: Vec2, point: Vec2) -> f32 {
+    (to.x - from.x) * (point.y - from.y) - (to.y - from.y) * (point.x - from.x)
+}
+
+fn draw_glyph_run(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    run: &GlyphRun,
+    style: &Style,
+    opacity: f32,
+    fonts: &[FontDefinition],
+) -> bool {
+    let Some(font) = fonts.iter().find(|font| font.id == run.font) else {
+        return false;
+    };
+    let Ok(face) = FontFace::parse(font.data.bytes()) else {
+        return false;
+    };
+    let Some(fill) = style.fill else {
+        return true;
+    };
+    let mut contours = Vec::new();
+    for glyph in &run.glyphs {
+        if let Some(outline) = face.glyph_outline(glyph.glyph) {
+            flatten(outline.commands(), &mut contours, |point| {
+                map.apply(glyph.origin + point * run.size)
+            });
+        }
+    }
+    let (low, high) = extent(contours.iter().flatten().copied());
+    let Some(bounds) = canvas.clip(low, high) else {
+        return true;
+    };
+    let mut mask = Mask::new(bounds);
+    fill_nonzero(&mut mask, &contours);
+    composite(canvas, (&mask, premultiplied(fill, opacity)), None);
+    true
+}
+
+/// Flattens path commands into closed pixel-space polygons.
+fn flatten(commands: &[PathCommand], contours: &mut Vec<Vec<Vec2>>, map: impl Fn(Vec2) -> Vec2) {
+    let mut current: Vec<Vec2> = Vec::new();
+    let mut finish = |current: &mut Vec<Vec2>| {
+        if current.len() > 2 {
+            contours.push(std::mem::take(current));
+        } else {
+            current.clear();
+        }
+    };
+    for command in commands {
+        let last = current.last().copied().unwrap_or(Vec2::ZERO);
+        match *command {
+            PathCommand::MoveTo { to } => {
+                finish(&mut current);
+                current.push(map(to));
+            }
+            PathCommand::LineTo { to } => current.push(map(to)),
+            PathCommand::QuadraticTo { control, to } => {
+                let [control, to] = [map(control), map(to)];
+                let steps = curve_steps((control - last).length() + (to - control).length());
+                current.extend((1..=steps).map(|step| {
+                    let t = step as f32 / steps as f32;
+                    let u = 1.0 - t;
+                    last * (u * u) + control * (2.0 * u * t) + to * (t * t)
+                }));
+            }
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                to,
+            } => {
+                let [control1, control2, to] = [map(control1), map(control2), map(to)];
+                let steps = curve_steps(
+                    (control1 - last).length()
+                        + (control2 - control1).length()
+                        + (to - control2).length(),
+                );
+                current.extend((1..=steps).map(|step| {
+                    let t = step as f32 / steps as f32;
+                    let u = 1.0 - t;
+                    last * (u * u * u)
+                        + control1 * (3.0 * u * u * t)
+                        + control2 * (3.0 * u * t * t)
+                        + to * (t * t * t)
+                }));
+            }
+            PathCommand::Close => finish(&mut current),
+        }
+    }
+    finish(&mut current);
+}
+
+/// Segments for a curve whose control polygon spans `length` pixels.
+fn curve_steps(length: f32) -> usize {
+    if length.is_finite() {
+        (length / 2.0).ceil().clamp(1.0, 64.0) as usize
+    } else {
+        1
+    }
+}
+
+/// Scanline fill of closed polygons under the nonzero winding rule, one
+/// scanline per sample row.
+fn fill_nonzero(mask: &mut Mask, contours: &[Vec<Vec2>]) {
+    let bounds = mask.bounds;
+    let mut crossings: Vec<(f32, i32)> = Vec::new();
+    for y in bounds.top..bounds.bottom {
+        for row in 0..GRID {
+            let scan = y as f32 + (row as f32 + 0.5) / GRID as f32;
+            crossings.clear();
+            for contour in contours {
+                for (index, from) in contour.iter().enumerate() {
+                    let to = contour[(index + 1) % contour.len()];
+                    if (from.y <= scan) != (to.y <= scan) {
+                        let x = from.x + (scan - from.y) * (to.x - from.x) / (to.y - from.y);
+                        crossings.push((x, if to.y > from.y { 1 } else { -1 }));
+                    }
+                }
+            }
+            crossings.sort_by(|left, right| left.0.total_cmp(&right.0));
+            let mut winding = 0;
+            for pair in crossings.windows(2) {
+                winding += pair[0].1;
+                if winding == 0 {
+                    continue;
+                }
+                // Sample column `c` sits at x = (c + 0.5) / GRID.
+                let first = ((pair[0].0 * GRID as f32 - 0.5).ceil().max(0.0) as usize)
+                    .max(bounds.left * GRID);
+                let last = ((pair[1].0 * GRID as f32 - 0.5).ceil().max(0.0) as usize)
+                    .min(bounds.right * GRID);
+                for column in first..last {
+                    mask.set(column / GRID, y, row * GRID + column % GRID);
+                }
+            }
+        }
+    }
+}
+
+fn draw_image(
+    canvas: &mut Canvas,
+    map: PixelMap,
+    image: &ImageData,
+    (size, sampling): (Vec2, ImageSampling),
+    opacity: f32,
+) {
+    let half = Vec2::new(size.x * 0.5, size.y * 0.5);
+    let (Some(bounds), Some(inverse)) = (
+        map.bounds(canvas, Vec2::new(-half.x, -half.y), half),
+        map.inverse(),
+    ) else {
+        return;
+    };
+    if size.x == 0.0 || size.y == 0.0 {
+        return;
+    }
+    for y in bounds.top..bounds.bottom {
+        for x in bounds.left..bounds.right {
+            let mut color = [0.0; 4];
+            for sample in 0..GRID * GRID {
+                let local = inverse.apply(sample_point(x, y, sample));
+                // Pixel rows are stored top-down while world space is y-up.
+                let uv = Vec2::new(local.x / size.x + 0.5, 0.5 - local.y / size.y);
+                if !(0.0..1.0).contains(&uv.x) || !(0.0..1.0).contains(&uv.y) {
+                    continue;
+                }
+                let texel = match sampling {
+                    ImageSampling::Nearest => nearest(image, uv),
+                    ImageSampling::Linear => bilinear(image, uv),
+                };
+                let alpha = texel[3] * opacity.clamp(0.0, 1.0) / SAMPLES as f32;
+                for channel in 0..3 {
+                    color[channel] += texel[channel] * alpha;
+                }
+                color[3] += alpha;
+            }
+            if color[3] > 0.0 {
+                canvas.blend(x, y, color);
+            }
+        }
+    }
+}
+
+fn texel(image: &ImageData, x: usize, y: usize) -> [f32; 4] {
+    let start = (y * image.width() as usize + x) * 4;
+    let pixel = &image.pixels()[start..start + 4];
+    [0, 1, 2, 3].map(|channel| f32::from(pixel[channel]) / 255.0)
+}
+
+fn nearest(image: &ImageData, uv: Vec2) -> [f32; 4] {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    texel(
+        image,
+        ((uv.x * width as f32) as usize).min(width - 1),
+        ((uv.y * height as f32) as usize).min(height - 1),
+    )
+}
+
+/// Bilinear filtering between texel centers, clamped to the edges.
+fn bilinear(image: &ImageData, uv: Vec2) -> [f32; 4] {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let x = (uv.x * width as f32 - 0.5).clamp(0.0, (width - 1) as f32);
+    let y = (uv.y * height as f32 - 0.5).clamp(0.0, (height - 1) as f32);
+    let (left, top) = (x.floor() as usize, y.floor() as usize);
+    let (right, bottom) = ((left + 1).min(width - 1), (top + 1).min(height - 1));
+    let (across, down) = (x - left as f32, y - top as f32);
+    let [top_left, top_right, bottom_left, bottom_right] = [
+        texel(image, left, top),
+        texel(image, right, top),
+        texel(image, left, bottom),
+        texel(image, right, bottom),
+    ];
+    [0, 1, 2, 3].map(|channel| {
+        let upper = top_left[channel] + (top_right[channel] - top_left[channel]) * across;
+        let lower = bottom_left[channel] + (bottom_right[channel] - bottom_left[channel]) * across;
+        upper + (lower - upper) * down
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_compile::CompiledScene;
+    use noon_core::{Easing, FontData, GeometryId, SceneDefinition, TrackTiming};
+
+    use super::*;
+
+    /// Two pixels per world unit, with the top edge at y = 4.
+    fn options() -> RasterOptions {
+        RasterOptions::new(
+            Camera2D::new(Vec2::ZERO, Vec2::new(8.0, 8.0)).unwrap(),
+            16,
+            16,
+        )
+    }
+
+    fn rasterize(scene: &SceneDefinition, time: f64) -> RasterFrame {
+        let mut instance = SceneInstance::new(CompiledScene::compile(scene).unwrap());
+        instance.seek(time).unwrap();
+        rasterize_instance(&instance, &options())
+    }
+
+    fn pixel(frame: &RasterFrame, x: usize, y: usize) -> [u8; 4] {
+        let start = (y * frame.width as usize + x) * 4;
+        frame.pixels[start..start + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn analytic_shapes_stroke_their_outer_band() {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(3.0));
+        scene.object_mut(circle).unwrap().style = Style {
+            fill: Some(Color::rgb(1.0, 0.0, 0.0)),
+            stroke: Some(Color::rgb(0.0, 0.0, 1.0)),
+            stroke_width: 1.0,
+            ..Style::default()
+        };
+        let square = scene.add(GeometryRef::rectangle(2.0, 2.0));
+        scene.object_mut(square).unwrap().transform.translation = Vec2::new(3.0, -3.0);
+        scene.object_mut(square).unwrap().style.fill = Some(Color::rgb(0.0, 1.0, 0.0));
+
+        let frame = rasterize(&scene, 0.0);
+        assert_eq!(frame.pixels.len(), 16 * 16 * 4);
+        assert!(frame.unsupported.is_empty());
+        assert_eq!(pixel(&frame, 8, 8), [255, 0, 0, 255]);
+        assert_eq!(pixel(&frame, 12, 8), [0, 0, 255, 255]);
+        assert_eq!(pixel(&frame, 0, 0), [0, 0, 0, 255]);
+        assert_eq!(pixel(&frame, 14, 14), [0, 255, 0, 255]);
+        assert_eq!(pixel(&frame, 14, 11), [0, 0, 0, 255]);
+        // Pixels on the outline blend partial coverage.
+        let edge = pixel(&frame, 13, 5);
+        assert!(edge[2] > 0 && edge[2] < 255, "{edge:?}");
+    }
+
+    #[test]
+    fn partial_reveal_hides_fills_and_the_rest_of_the_outline() {
+        let mut scene = SceneDefinition::new();
+        let square = scene.add(GeometryRef::path(
+            VectorPath::new()
+                .move_to(Vec2::new(-3.0, -3.0))
+                .line_to(Vec2::new(3.0, -3.0))
+                .line_to(Vec2::new(3.0, 3.0))
+                .line_to(Vec2::new(-3.0, 3.0))
+                .close(),
+        ));
+        scene.object_mut(square).unwrap().style = Style {
+            fill: Some(Color::WHITE),
+            stroke: Some(Color::rgb(1.0, 0.0, 0.0)),
+            stroke_width: 1.0,
+            ..Style::default()
+        };
+        let full = rasterize(&scene, 0.0);
+        assert_eq!(pixel(&full, 8, 8), [255, 255, 255, 255]);
+        assert_eq!(pixel(&full, 8, 14), [255, 0, 0, 255]);
+        assert_eq!(pixel(&full, 2, 8), [255, 0, 0, 255]);
+
+        scene
+            .animate_reveal(square, 0.0, 1.0, TrackTiming::new(0.0, 1.0, Easing::Linear))
+            .unwrap();
+        let half = rasterize(&scene, 0.5);
+        assert_eq!(pixel(&half, 8, 8), [0, 0, 0, 255]);
+        // The bottom edge is drawn first and the left edge last.
+        assert_eq!(pixel(&half, 8, 14), [255, 0, 0, 255]);
+        assert_eq!(pixel(&half, 2, 8), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn images_keep_their_top_row_at_positive_y() {
+        let mut scene = SceneDefinition::new();
+        let mut pixels = Vec::new();
+        for color in [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255], [0; 4]] {
+            pixels.extend(color);
+        }
+        let image = scene.register_image(ImageData::new(2, 2, pixels).unwrap());
+        scene.add(GeometryRef::Image {
+            image,
+            size: Vec2::new(8.0, 8.0),
+            sampling: ImageSampling::Nearest,
+        });
+        let external = scene.add(GeometryRef::External(GeometryId::new(3)));
+
+        let frame = rasterize(&scene, 0.0);
+        assert_eq!(frame.unsupported, vec![external]);
+        assert_eq!(pixel(&frame, 0, 0), [255, 0, 0, 255]);
+        assert_eq!(pixel(&frame, 15, 0), [0, 255, 0, 255]);
+        assert_eq!(pixel(&frame, 0, 15), [0, 0, 255, 255]);
+        assert_eq!(pixel(&frame, 15, 15), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn glyph_runs_fill_their_outlines() {
+        let mut scene = SceneDefinition::new();
+        let font = scene.register_font(FontData::new(
+            include_bytes!("../../../assets/fonts/noon-test.ttf").to_vec(),
+        ));
+        let face = FontFace::parse(scene.fonts()[0].data.bytes()).unwrap();
+        let text = scene.add(GeometryRef::glyph_run(face.glyph_run(font, "NN", 3.0)));
+        let instance = SceneInstance::new(CompiledScene::compile(&scene).unwrap());
+        let options = options().background(Color::rgba(0.0, 0.0, 0.0, 0.0));
+
+        let frame = rasterize_instance(&instance, &options);
+        assert!(frame.unsupported.is_empty());
+        let covered = frame.pixels.chunks_exact(4).filter(|pixel| pixel[3] > 0);
+        assert!(covered.clone().count() > 8);
+        assert!(covered.clone().all(|pixel| pixel[..3] == [255, 255, 255]));
+
+        let missing = rasterize_frame(instance.frame(), &[], &[], &options);
+        assert_eq!(missing.unsupported, vec![text]);
+        assert!(missing.pixels.iter().all(|byte| *byte == 0));
+    }
+}
@@ -0,0 +1,725 @@
+//! Standalone SVG documents for single frames.
+//!
+//! Analytic shapes become native SVG elements and paths become `d` strings.
+//! World space is y-up, so content sits inside a `scale(1 -1)` group and the
+//! camera rectangle becomes a flipped `viewBox`. Partial reveal is a dash over
+//! a unit `pathLength`; fills appear once the outline completes, as in the
+//! renderer.
+
+use std::fmt::Write;
+
+use noon_core::{
+    Color, FontDefinition, GeometryRef, GlyphRun, ImageData, ImageDefinition, ImageId,
+    ImageSampling, ObjectId, PathCommand, StrokeCap, StrokeJoin, Style, Transform2D, Vec2,
+    VectorPath,
+};
+use noon_geometry::{plan_morph, FontFace, MorphOptions, MotionSampler};
+use noon_render_wgpu::Camera2D;
+use noon_runtime::{FrameState, SceneInstance};
+
+use crate::encode_rgba_png;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgOptions {
+    pub camera: Camera2D,
+    /// Output size in CSS pixels. The camera rectangle is stretched to fill
+    /// it, as on a canvas of this size.
+    pub width: u32,
+    pub height: u32,
+    pub background: Option<Color>,
+}
+
+impl SvgOptions {
+    pub const fn new(camera: Camera2D, width: u32, height: u32) -> Self {
+        Self {
+            camera,
+            width,
+            height,
+            background: None,
+        }
+    }
+
+    pub const fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgFrame {
+    pub svg: String,
+    /// Present objects left out of the document: external geometry, images
+    /// and fonts that are not registered or do not parse, and morphs that
+    /// cannot be planned.
+    pub unsupported: Vec<ObjectId>,
+}
+
+/// Writes the frame as a standalone SVG document. Images are embedded once
+/// each as PNG data URIs.
+pub fn frame_to_svg(
+    frame: &FrameState,
+    images: &[ImageDefinition],
+    fonts: &[FontDefinition],
+    options: &SvgOptions,
+) -> SvgFrame {
+    let mut defs = String::new();
+    let mut embedded = Vec::new();
+    let mut body = String::new();
+    let mut unsupported = Vec::new();
+
+    for (index, object) in frame.objects.iter().enumerate() {
+        if !frame.is_present(index) {
+            continue;
+        }
+        let opacity = object.style.opacity * object.appearance.clamp(0.0, 1.0);
+        let reveal = unit(frame.reveal(index));
+        let mut shape = String::new();
+        let drawn = match frame.render_geometry(index) {
+            GeometryRef::Circle { radius } => {
+                write_circle(&mut shape, *radius, &object.style, reveal);
+                true
+            }
+            GeometryRef::Rectangle { size } => {
+                write_rectangle(&mut shape, *size, &object.style, reveal);
+                true
+            }
+            GeometryRef::Line { start, end } => {
+                write_line(&mut shape, *start, *end, &object.style, reveal);
+                true
+            }
+            GeometryRef::VectorPath(path) => {
+                write_vector_path(&mut shape, path, &object.style, reveal, frame.morph(index))
+            }
+            GeometryRef::Image {
+                image,
+                size,
+                sampling,
+            } => match images.iter().find(|definition| definition.id == *image) {
+                Some(definition) => {
+                    if !embedded.contains(image) {
+                        embedded.push(*image);
+                        write_image_def(&mut defs, definition);
+                    }
+                    write_image_use(&mut shape, *image, *size, *sampling);
+                    true
+                }
+                None => false,
+            },
+            GeometryRef::GlyphRun(run) => write_glyph_run(&mut shape, run, &object.style, fonts),
+            GeometryRef::External(_) => false,
+        };
+        if !drawn {
+            unsupported.push(object.id);
+            continue;
+        }
+        if shape.is_empty() || opacity <= 0.0 {
+            continue;
+        }
+        write!(
+            body,
+            "<g id=\"object-{}\" transform=\"{}\"",
+            object.id.get(),
+            transform(&object.transform)
+        )
+        .unwrap();
+        if opacity < 1.0 {
+            write!(body, " opacity=\"{opacity}\"").unwrap();
+        }
+        writeln!(body, ">{shape}</g>").unwrap();
+    }
+
+    let camera = options.camera;
+    let (width, height) = (camera.world_size.x, camera.world_size.y);
+    let (left, top) = (
+        camera.center.x - width * 0.5,
+        -(camera.center.y + height * 0.5),
+    );
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+         width=\"{}\" height=\"{}\" viewBox=\"{left} {top} {width} {height}\" \
+         preserveAspectRatio=\"none\">",
+        options.width, options.height
+    )
+    .unwrap();
+    if !defs.is_empty() {
+        writeln!(svg, "<defs>\n{defs}</defs>").unwrap();
+    }
+    if let Some(background) = options.background {
+        write!(
+            svg,
+            "<rect x=\"{left}\" y=\"{top}\" width=\"{width}\" height=\"{height}\""
+        )
+        .unwrap();
+        write_paint(&mut svg, "fill", Some(background));
+        svg.push_str("/>\n");
+    }
+    writeln!(svg, "<g transform=\"scale(1 -1)\">\n{body}</g>\n</svg>").unwrap();
+    SvgFrame { svg, unsupported }
+}
+
+/// [`frame_to_svg`] for the instance's current frame and assets.
+pub fn instance_to_svg(instance: &SceneInstance, options: &SvgOptions) -> SvgFrame {
+    frame_to_svg(
+        instance.frame(),
+        instance.images(),
+        instance.fonts(),
+        options,
+    )
+}
+
+fn unit(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+fn transform(transform: &Transform2D) -> String {
+    let Transform2D {
+        translation,
+        rotation,
+        scale,
+    } = *transform;
+    format!(
+        "translate({} {}) rotate({}) scale({} {})",
+        translation.x,
+        translation.y,
+        rotation.to_degrees(),
+        scale.x,
+        scale.y
+    )
+}
+
+fn hex(color: Color) -> String {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue)
+    )
+}
+
+fn write_paint(out: &mut String, attribute: &str, color: Option<Color>) {
+    let Some(color) = color else {
+        write!(out, " {attribute}=\"none\"").unwrap();
+        return;
+    };
+    write!(out, " {attribute}=\"{}\"", hex(color)).unwrap();
+    if color.alpha < 1.0 {
+        write!(out, " {attribute}-opacity=\"{}\"", color.alpha.max(0.0)).unwrap();
+    }
+}
+
+fn write_stroke(out: &mut String, stroke: Option<Color>, width: f32) {
+    write_paint(out, "stroke", stroke);
+    if stroke.is_some() {
+        write!(out, " stroke-width=\"{width}\"").unwrap();
+    }
+}
+
+/// Shows `reveal` of the outline. `offset` is the SVG dash offset as a
+/// fraction of the element's own path.
+fn write_dash(out: &mut String, reveal: f32, offset: f32) {
+    if reveal >= 1.0 {
+        return;
+    }
+    write!(
+        out,
+        " pathLength=\"1\" stroke-dasharray=\"{reveal} {}\"",
+        1.0 - reveal
+    )
+    .unwrap();
+    if offset != 0.0 {
+        write!(out, " stroke-dashoffset=\"{offset}\"").unwrap();
+    }
+}
+
+/// Strokes are drawn inside the shape edge, so the element is inset by half
+/// the stroke width. Both reveal dashes start at +x and run counterclockwise.
+fn write_circle(out: &mut String, radius: f32, style: &Style, reveal: f32) {
+    let radius = radius.abs();
+    let width = style.stroke_width.clamp(0.0, radius);
+    let stroke = style.stroke.filter(|_| width > 0.0);
+    let fill = style.fill.filter(|_| reveal >= 1.0);
+    if reveal <= 0.0 || (stroke.is_none() && fill.is_none()) {
+        return;
+    }
+    let inset = if stroke.is_some() { width * 0.5 } else { 0.0 };
+    write!(out, "<circle r=\"{}\"", radius - inset).unwrap();
+    write_paint(out, "fill", fill);
+    write_stroke(out, stroke, width);
+    if stroke.is_some() {
+        write_dash(out, reveal, 0.0);
+    }
+    out.push_str("/>");
+}
+
+fn write_rectangle(out: &mut String, size: Vec2, style: &Style, reveal: f32) {
+    let half = Vec2::new(size.x.abs(), size.y.abs()) * 0.5;
+    let width = style.stroke_width.clamp(0.0, half.x.min(half.y));
+    let stroke = style.stroke.filter(|_| width > 0.0);
+    let fill = style.fill.filter(|_| reveal >= 1.0);
+    if reveal <= 0.0 || (stroke.is_none() && fill.is_none()) {
+        return;
+    }
+    let inset = if stroke.is_some() { width * 0.5 } else { 0.0 };
+    let (x, y) = (half.x - inset, half.y - inset);
+    write!(
+        out,
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+        -x,
+        -y,
+        x * 2.0,
+        y * 2.0
+    )
+    .unwrap();
+    write_paint(out, "fill", fill);
+    write_stroke(out, stroke, width);
+    if stroke.is_some() {
+        // SVG rectangles start at their (-x, -y) corner; a negative offset
+        // of `2x + y` moves the dash start to the middle of the +x edge.
+        let perimeter = 4.0 * (x + y);
+        let offset = if perimeter > 0.0 {
+            (3.0 * y + 2.0 * x) / perimeter
+        } else {
+            0.0
+        };
+        write_dash(out, reveal, offset);
+    }
+    out.push_str("/>");
+}
+
+/// Lines are round-capped capsules in the stroke color, or the fill color
+/// when the style has no stroke.
+fn write_line(out: &mut String, start: Vec2, end: Vec2, style: &Style, reveal: f32) {
+    let Some(color) = style.stroke.or(style.fill) else {
+        return;
+    };
+    if reveal <= 0.0 || style.stroke_width <= 0.0 {
+        return;
+    }
+    write!(
+        out,
+        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+        start.x, start.y, end.x, end.y
+    )
+    .unwrap();
+    write_stroke(out, Some(color), style.stroke_width);
+    out.push_str(" stroke-linecap=\"round\"");
+    write_dash(out, reveal, 0.0);
+    out.push_str("/>");
+}
+
+/// Returns `false` when a partial morph cannot be planned.
+fn write_vector_path(
+    out: &mut String,
+    path: &VectorPath,
+    style: &Style,
+    reveal: f32,
+    morph: f32,
+) -> bool {
+    let morph = unit(morph);
+    let morphed;
+    let path = match path.morph_target() {
+        Some(target) if morph >= 1.0 => target,
+        Some(target) if morph > 0.0 => {
+            let Ok(plan) = plan_morph(path, target, MorphOptions::DEFAULT) else {
+                return false;
+            };
+            morphed = plan.interpolate(morph).to_vector_path();
+            &morphed
+        }
+        _ => path,
+    };
+    let stroke = style.stroke.filter(|_| style.stroke_width > 0.0);
+    let fill = style.fill.filter(|_| reveal >= 1.0);
+    if reveal <= 0.0 || (stroke.is_none() && fill.is_none()) {
+        return true;
+    }
+    let write_element = |out: &mut String, commands: &[PathCommand], fill, fraction| {
+        write!(
+            out,
+            "<path d=\"{}\" fill-rule=\"evenodd\"",
+            path_data(commands, |point| point)
+        )
+        .unwrap();
+        write_paint(out, "fill", fill);
+        write_stroke(out, stroke, style.stroke_width);
+        if stroke.is_some() {
+            write!(
+                out,
+                " stroke-linejoin=\"{}\" stroke-linecap=\"{}\"",
+                join(style.stroke_join),
+                cap(style.stroke_cap)
+            )
+            .unwrap();
+            write_dash(out, fraction, 0.0);
+        }
+        out.push_str("/>");
+    };
+    if reveal >= 1.0 {
+        write_element(out, path.commands(), fill, 1.0);
+        return true;
+    }
+
+    // Reveal runs through the subpaths in order, so each one is its own
+    // element dashed by the share of its length already drawn.
+    let subpaths = subpaths(path.commands());
+    let lengths: Vec<f32> = subpaths.iter().map(|commands| length(commands)).collect();
+    let mut remaining = reveal * lengths.iter().sum::<f32>();
+    for (commands, length) in subpaths.into_iter().zip(lengths) {
+        if remaining <= 0.0 {
+            break;
+        }
+        if length > 0.0 {
+            write_element(out, commands, None, (remaining / length).min(1.0));
+        }
+        remaining -= length;
+    }
+    true
+}
+
+fn subpaths(commands: &[PathCommand]) -> Vec<&[PathCommand]> {
+    let mut subpaths = Vec::new();
+    let mut start = 0;
+    for (index, command) in commands.iter().enumerate() {
+        if matches!(command, PathCommand::MoveTo { .. }) && index > start {
+            subpaths.push(&commands[start..index]);
+            start = index;
+        }
+    }
+    if start < commands.len() {
+        subpaths.push(&commands[start..]);
+    }
+    subpaths
+}
+
+fn length(commands: &[PathCommand]) -> f32 {
+    let path = commands
+        .iter()
+        .fold(VectorPath::new(), |path, command| match *command {
+            PathCommand::MoveTo { to } => path.move_to(to),
+            PathCommand::LineTo { to } => path.line_to(to),
+            PathCommand::QuadraticTo { control, to } => path.quadratic_to(control, to),
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                to,
+            } => path.cubic_to(control1, control2, to),
+            PathCommand::Close => path.close(),
+        });
+    MotionSampler::new(&path).map_or(0.0, |sampler| sampler.length())
+}
+
+const fn join(join: StrokeJoin) -> &'static str {
+    match join {
+        StrokeJoin::Round => "round",
+        StrokeJoin::Miter => "miter",
+        StrokeJoin::Bevel => "bevel",
+    }
+}
+
+const fn cap(cap: StrokeCap) -> &'static str {
+    match cap {
+        StrokeCap::Round => "round",
+        StrokeCap::Butt => "butt",
+        StrokeCap::Square => "square",
+    }
+}
+
+/// SVG path data for `commands`, with every point passed through `map`.
+fn path_data(commands: &[PathCommand], map: impl Fn(Vec2) -> Vec2) -> String {
+    let mut out = String::new();
+    write_commands(&mut out, commands, map);
+    out
+}
+
+fn write_commands(out: &mut String, commands: &[PathCommand], map: impl Fn(Vec2) -> Vec2) {
+    for command in commands {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        match *command {
+            PathCommand::MoveTo { to } => {
+                let to = map(to);
+                write!(out, "M{} {}", to.x, to.y)
+            }
+            PathCommand::LineTo { to } => {
+                let to = map(to);
+                write!(out, "L{} {}", to.x, to.y)
+            }
+            PathCommand::QuadraticTo { control, to } => {
+                let (control, to) = (map(control), map(to));
+                write!(out, "Q{} {} {} {}", control.x, control.y, to.x, to.y)
+            }
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                to,
+            } => {
+                let (control1, control2, to) = (map(control1), map(control2), map(to));
+                write!(
+                    out,
+                    "C{} {} {} {} {} {}",
+                    control1.x, control1.y, control2.x, control2.y, to.x, to.y
+                )
+            }
+            PathCommand::Close => write!(out, "Z"),
+        }
+        .unwrap();
+    }
+}
+
+/// Glyph runs are filled only, one path per run. Returns `false` when the
+/// font is missing or does not parse.
+fn write_glyph_run(
+    out: &mut String,
+    run: &GlyphRun,
+    style: &Style,
+    fonts: &[FontDefinition],
+) -> bool {
+    let Some(font) = fonts.iter().find(|font| font.id == run.font) else {
+        return false;
+    };
+    let Ok(face) = FontFace::parse(font.data.bytes()) else {
+        return false;
+    };
+    let Some(fill) = style.fill else {
+        return true;
+    };
+    let mut d = String::new();
+    for glyph in &run.glyphs {
+        if let Some(outline) = face.glyph_outline(glyph.glyph) {
+            write_commands(&mut d, outline.commands(), |point| {
+                glyph.origin + point * run.size
+            });
+        }
+    }
+    // Overlapping glyphs and counters follow the glyph winding rather than
+    // the even-odd rule paths use.
+    if !d.is_empty() {
+        write!(out, "<path d=\"{d}\" fill-rule=\"nonzero\"").unwrap();
+        write_paint(out, "fill", Some(fill));
+        out.push_str("/>");
+    }
+    true
+}
+
+/// A unit quad centered on the origin with the top pixel row at -y; uses
+/// flip it back to y-up while scaling to the object size.
+fn write_image_def(out: &mut String, image: &ImageDefinition) {
+    writeln!(
+        out,
+        "<image id=\"image-{}\" x=\"-0.5\" y=\"-0.5\" width=\"1\" height=\"1\" \
+         preserveAspectRatio=\"none\" xlink:href=\"data:image/png;base64,{}\"/>",
+        image.id.get(),
+        base64(&encode_png(&image.data))
+    )
+    .unwrap();
+}
+
+fn write_image_use(out: &mut String, image: ImageId, size: Vec2, sampling: ImageSampling) {
+    write!(
+        out,
+        "<use xlink:href=\"#image-{}\" transform=\"scale({} {})\"",
+        image.get(),
+        size.x,
+        -size.y
+    )
+    .unwrap();
+    if sampling == ImageSampling::Nearest {
+        out.push_str(" style=\"image-rendering:pixelated\"");
+    }
+    out.push_str("/>");
+}
+
+fn encode_png(image: &ImageData) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // Writing to memory only fails on bad dimensions, which `ImageData`
+    // already rules out.
+    encode_rgba_png(&mut bytes, image.width(), image.height(), image.pixels())
+        .expect("valid RGBA8 images encode to PNG");
+    bytes
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |triple, (index, byte)| {
+                triple | u32::from(*byte) << (16 - 8 * index)
+            });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                out.push(char::from(
+                    ALPHABET[(triple >> (18 - 6 * index)) as usize & 63],
+                ));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_compile::CompiledScene;
+    use noon_core::{Easing, FontData, GeometryId, SceneDefinition, TrackTiming};
+
+    use super::*;
+
+    fn svg(scene: &SceneDefinition, time: f64) -> SvgFrame {
+        let mut instance = SceneInstance::new(CompiledScene::compile(scene).unwrap());
+        instance.seek(time).unwrap();
+        instance_to_svg(&instance, &SvgOptions::new(Camera2D::DEFAULT, 640, 360))
+    }
+
+    #[test]
+    fn analytic_shapes_are_inset_by_half_their_stroke() {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(1.0));
+        let rectangle = scene.add(GeometryRef::rectangle(4.0, 2.0));
+        scene.add(GeometryRef::line(Vec2::ZERO, Vec2::new(1.0, 1.0)));
+        scene.object_mut(circle).unwrap().style = Style {
+            fill: None,
+            stroke: Some(Color::rgba(1.0, 0.0, 0.0, 0.5)),
+            stroke_width: 0.5,
+            opacity: 0.5,
+            ..Style::default()
+        };
+        scene.object_mut(rectangle).unwrap().transform = Transform2D {
+            translation: Vec2::new(2.0, -1.0),
+            rotation: std::f32::consts::FRAC_PI_2,
+            scale: Vec2::ONE,
+        };
+        let svg = svg(&scene, 0.0).svg;
+
+        let camera = Camera2D::DEFAULT;
+        assert!(svg.contains(&format!(
+            "viewBox=\"{} {} {} {}\"",
+            camera.center.x - camera.world_size.x * 0.5,
+            -(camera.center.y + camera.world_size.y * 0.5),
+            camera.world_size.x,
+            camera.world_size.y
+        )));
+        assert!(svg.contains(
+            "<g id=\"object-0\" transform=\"translate(0 0) rotate(0) scale(1 1)\" opacity=\"0.5\">\
+             <circle r=\"0.75\" fill=\"none\" stroke=\"#ff0000\" stroke-opacity=\"0.5\" \
+             stroke-width=\"0.5\"/></g>"
+        ));
+        assert!(svg.contains("transform=\"translate(2 -1) rotate(90) scale(1 1)\""));
+        assert!(svg.contains("<rect x=\"-2\" y=\"-1\" width=\"4\" height=\"2\""));
+        assert!(svg.contains("<line x1=\"0\" y1=\"0\" x2=\"1\" y2=\"1\""));
+        assert!(svg.contains("stroke-linecap=\"round\""));
+    }
+
+    #[test]
+    fn partial_reveal_dashes_strokes_and_hides_fills() {
+        let mut scene = SceneDefinition::new();
+        let style = Style {
+            fill: Some(Color::WHITE),
+            stroke: Some(Color::BLACK),
+            stroke_width: 0.1,
+            ..Style::default()
+        };
+        let circle = scene.add(GeometryRef::circle(1.0));
+        let path = scene.add(GeometryRef::path(
+            VectorPath::new()
+                .move_to(Vec2::ZERO)
+                .line_to(Vec2::new(1.0, 0.0))
+                .move_to(Vec2::new(0.0, 1.0))
+                .line_to(Vec2::new(3.0, 1.0)),
+        ));
+        let timing = TrackTiming::new(0.0, 1.0, Easing::Linear);
+        for object in [circle, path] {
+            scene.object_mut(object).unwrap().style = style;
+            scene.animate_reveal(object, 0.0, 1.0, timing).unwrap();
+        }
+
+        let half = svg(&scene, 0.5).svg;
+        assert!(half.contains(
+            "<circle r=\"0.95\" fill=\"none\" stroke=\"#000000\" stroke-width=\"0.1\" \
+             pathLength=\"1\" stroke-dasharray=\"0.5 0.5\"/>"
+        ));
+        // Half of the total length covers the first subpath and a third of
+        // the second.
+        assert!(half.contains("d=\"M0 0 L1 0\""));
+        assert!(half.contains("d=\"M0 1 L3 1\""));
+        assert_eq!(half.matches("stroke-dasharray").count(), 2);
+        assert!(!half.contains("fill=\"#ffffff\""));
+
+        let full = svg(&scene, 1.0).svg;
+        assert!(full.contains("d=\"M0 0 L1 0 M0 1 L3 1\" fill-rule=\"evenodd\" fill=\"#ffffff\""));
+        assert!(!full.contains("stroke-dasharray"));
+        assert!(!svg(&scene, 0.0).svg.contains("<path"));
+    }
+
+    #[test]
+    fn images_are_embedded_once_and_unsupported_objects_are_reported() {
+        let mut scene = SceneDefinition::new();
+        let image = scene.register_image(ImageData::new(1, 1, vec![255, 0, 0, 255]).unwrap());
+        for sampling in [ImageSampling::Linear, ImageSampling::Nearest] {
+            scene.add(GeometryRef::Image {
+                image,
+                size: Vec2::new(2.0, 1.0),
+                sampling,
+            });
+        }
+        let external = scene.add(GeometryRef::External(GeometryId::new(3)));
+        let hidden = scene.add(GeometryRef::External(GeometryId::new(4)));
+        scene.set_presence_at(hidden, false, false, 0.0).unwrap();
+
+        let frame = svg(&scene, 0.0);
+        assert_eq!(frame.unsupported, vec![external]);
+        assert_eq!(frame.svg.matches("data:image/png;base64,").count(), 1);
+        assert_eq!(
+            frame
+                .svg
+                .matches("<use xlink:href=\"#image-0\" transform=\"scale(2 -1)\"")
+                .count(),
+            2
+        );
+        assert_eq!(frame.svg.matches("image-rendering:pixelated").count(), 1);
+    }
+
+    #[test]
+    fn glyph_runs_become_filled_outlines() {
+        let mut scene = SceneDefinition::new();
+        let font = scene.register_font(FontData::new(
+            include_bytes!("../../../assets/fonts/noon-test.ttf").to_vec(),
+        ));
+        let face = FontFace::parse(scene.fonts()[0].data.bytes()).unwrap();
+        let text = scene.add(GeometryRef::glyph_run(face.glyph_run(font, "NN", 2.0)));
+        let instance = SceneInstance::new(CompiledScene::compile(&scene).unwrap());
+        let options = SvgOptions::new(Camera2D::DEFAULT, 100, 100).background(Color::BLACK);
+
+        let frame = instance_to_svg(&instance, &options);
+        assert!(frame.unsupported.is_empty());
+        assert!(frame.svg.contains("<rect x=\""));
+        let path = frame.svg.split("<path d=\"").nth(1).unwrap();
+        assert!(path.starts_with('M'));
+        assert_eq!(frame.svg.matches("<path").count(), 1);
+        assert_eq!(path.matches('Z').count() % 2, 0);
+
+        let missing = frame_to_svg(instance.frame(), &[], &[], &options);
+        assert_eq!(missing.unsupported, vec![text]);
+        assert!(!missing.svg.contains("<path"));
+    }
+
+    #[test]
+    fn base64_pads_partial_chunks() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+}
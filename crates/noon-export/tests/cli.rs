@@ -0,0 +1,76 @@
+use std::process::Command;
+
+use noon_core::{Easing, GeometryRef, SceneDefinition, TrackTiming, Vec2};
+
+fn scene_file(directory: &std::path::Path) -> std::path::PathBuf {
+    let mut scene = SceneDefinition::new();
+    let circle = scene.add(GeometryRef::circle(1.0));
+    scene
+        .animate_position(
+            circle,
+            Vec2::new(-2.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            TrackTiming::new(0.0, 0.5, Easing::EaseInOutCubic),
+        )
+        .unwrap();
+    let path = directory.join("scene.json");
+    std::fs::write(&path, noon_ir::encode_scene(&scene).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn exports_gifs_and_png_sequences_from_scene_files() {
+    let directory = std::env::temp_dir().join(format!("noon-export-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let scene = scene_file(&directory);
+    let export = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_noon-export"))
+            .arg(&scene)
+            .args(["--fps", "10", "--size", "32x18", "--camera", "0,0,4"])
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let gif = directory.join("scene.gif");
+    let output = export(&["--gif", gif.to_str().unwrap(), "--background", "#202020"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        format!("wrote 6 frames to {}", gif.display())
+    );
+    assert!(std::fs::read(&gif).unwrap().starts_with(b"GIF89a"));
+
+    let frames = directory.join("frames");
+    let output = export(&["--png-dir", frames.to_str().unwrap(), "--duration", "0.2"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(std::fs::read_dir(&frames).unwrap().count(), 3);
+    assert!(frames.join("frame-00002.png").exists());
+
+    let bundle = directory.join("scene.noon");
+    let output = export(&["--bundle", bundle.to_str().unwrap()]);
+    assert!(output.status.success(), "{output:?}");
+    let gif_from_bundle = directory.join("bundle.gif");
+    let output = Command::new(env!("CARGO_BIN_EXE_noon-export"))
+        .arg(&bundle)
+        .args(["--fps", "10", "--size", "32x18", "--camera", "0,0,4"])
+        .args([
+            "--gif",
+            gif_from_bundle.to_str().unwrap(),
+            "--background",
+            "#202020",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        std::fs::read(&gif_from_bundle).unwrap(),
+        std::fs::read(&gif).unwrap()
+    );
+
+    let output = export(&["--size", "32"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--size expects"));
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
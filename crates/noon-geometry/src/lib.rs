@@ -1,9 +1,15 @@
-//! Deterministic renderer-independent path geometry for Noon.
+//! Deterministic renderer-independent path and glyph geometry for Noon.
 
 #![forbid(unsafe_code)]
 
+mod math;
 mod morph;
+mod motion;
 mod tessellation;
+mod text;
 
+pub use math::*;
 pub use morph::*;
+pub use motion::*;
 pub use tessellation::*;
+pub use text::*;
@@ -0,0 +1,706 @@
+use noon_core::{PathCommand, Rect, Vec2, VectorPath};
+
+use crate::FontFace;
+
+// Layout constants in em units of the current style, loosely following the
+// TeX font parameters of Computer Modern.
+const SCRIPT_SCALE: f32 = 0.7;
+const SUPERSCRIPT_SHIFT: f32 = 0.41;
+const SUPERSCRIPT_DROP: f32 = 0.25;
+const SUBSCRIPT_SHIFT: f32 = 0.15;
+const SUBSCRIPT_DROP: f32 = 0.05;
+const SCRIPT_SPACE: f32 = 0.05;
+const AXIS_HEIGHT: f32 = 0.25;
+const RULE_THICKNESS: f32 = 0.04;
+const FRACTION_GAP: f32 = 0.1;
+const FRACTION_PADDING: f32 = 0.1;
+const RADICAL_GAP: f32 = 0.1;
+const RADICAL_WIDTH: f32 = 0.55;
+const BINARY_SPACE: f32 = 4.0 / 18.0;
+const RELATION_SPACE: f32 = 5.0 / 18.0;
+const PUNCTUATION_SPACE: f32 = 3.0 / 18.0;
+
+/// Source recorded for the rule of a `\frac`.
+pub const FRACTION_RULE_SOURCE: &str = "\\frac";
+/// Source recorded for the sign and overbar of a `\sqrt`.
+pub const RADICAL_SOURCE: &str = "\\sqrt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MathError {
+    UnexpectedEnd,
+    UnmatchedBrace,
+    UnknownCommand(String),
+    MissingArgument(String),
+    DoubleScript(char),
+}
+
+impl std::fmt::Display for MathError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(formatter, "formula ended unexpectedly"),
+            Self::UnmatchedBrace => write!(formatter, "formula has an unmatched brace"),
+            Self::UnknownCommand(name) => write!(formatter, "unsupported command \\{name}"),
+            Self::MissingArgument(name) => write!(formatter, "{name} is missing an argument"),
+            Self::DoubleScript(marker) => write!(formatter, "double script '{marker}'"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// One typeset symbol of a formula.
+///
+/// `path` is centered on its own bounds so identical symbols compare equal
+/// wherever they sit; `center` places it in formula coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MathSymbol {
+    /// TeX source of the symbol, such as `x`, `\alpha` or [`RADICAL_SOURCE`].
+    pub source: String,
+    pub path: VectorPath,
+    pub center: Vec2,
+}
+
+impl FontFace<'_> {
+    /// Typesets a TeX math subset into one outline per symbol, `size` world
+    /// units per em and centered on the formula's bounds.
+    ///
+    /// Supported: grouping braces, `^` and `_` scripts, `\frac`, `\sqrt`,
+    /// Greek letters, common operators and relations, and the `\,` `\:` `\;`
+    /// `\!` `\quad` `\qquad` spacing commands. Whitespace is ignored as in
+    /// TeX math mode. Characters the font lacks render as `.notdef`.
+    pub fn typeset_math(&self, tex: &str, size: f32) -> Result<Vec<MathSymbol>, MathError> {
+        let nodes = Parser::new(tex).parse_list(false)?;
+        let layout = MathLayout { face: self }.list(&nodes, Style::DISPLAY);
+
+        let center = Rect::from_points(layout.items.iter().flat_map(|item| {
+            item.path
+                .conservative_bounds()
+                .map(|bounds| [bounds.min + item.offset, bounds.max + item.offset])
+                .into_iter()
+                .flatten()
+        }))
+        .map_or(Vec2::ZERO, Rect::center);
+
+        Ok(layout
+            .items
+            .into_iter()
+            .filter_map(|item| {
+                let bounds = item.path.conservative_bounds()?;
+                let local = bounds.center();
+                Some(MathSymbol {
+                    source: item.source,
+                    path: map_path(&item.path, |point| (point - local) * size),
+                    center: (local + item.offset - center) * size,
+                })
+            })
+            .collect())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AtomClass {
+    Ordinary,
+    Binary,
+    Relation,
+    Punctuation,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum MathNode {
+    Symbol {
+        character: char,
+        source: String,
+        class: AtomClass,
+    },
+    /// Explicit kerning in em units of the current style.
+    Space(f32),
+    Group(Vec<MathNode>),
+    Fraction(Box<MathNode>, Box<MathNode>),
+    Radical(Box<MathNode>),
+    Scripts {
+        base: Box<MathNode>,
+        superscript: Option<Box<MathNode>>,
+        subscript: Option<Box<MathNode>>,
+    },
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let character = self.peek()?;
+        self.position += character.len_utf8();
+        Some(character)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Parses atoms until the input ends or, inside a group, a closing brace.
+    fn parse_list(&mut self, in_group: bool) -> Result<Vec<MathNode>, MathError> {
+        let mut nodes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None if in_group => return Err(MathError::UnmatchedBrace),
+                None => return Ok(nodes),
+                Some('}') if in_group => {
+                    self.bump();
+                    return Ok(nodes);
+                }
+                Some('}') => return Err(MathError::UnmatchedBrace),
+                Some(marker @ ('^' | '_')) => {
+                    self.bump();
+                    let script = Box::new(self.parse_argument(&marker.to_string())?);
+                    let base = match nodes.pop() {
+                        Some(MathNode::Scripts {
+                            base,
+                            superscript,
+                            subscript,
+                        }) => (base, superscript, subscript),
+                        Some(node) => (Box::new(node), None, None),
+                        None => (Box::new(MathNode::Group(Vec::new())), None, None),
+                    };
+                    let (base, mut superscript, mut subscript) = base;
+                    let slot = if marker == '^' {
+                        &mut superscript
+                    } else {
+                        &mut subscript
+                    };
+                    if slot.replace(script).is_some() {
+                        return Err(MathError::DoubleScript(marker));
+                    }
+                    nodes.push(MathNode::Scripts {
+                        base,
+                        superscript,
+                        subscript,
+                    });
+                }
+                Some(_) => nodes.push(self.parse_atom()?),
+            }
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<MathNode, MathError> {
+        match self.bump().ok_or(MathError::UnexpectedEnd)? {
+            '{' => Ok(MathNode::Group(self.parse_list(true)?)),
+            '\\' => self.parse_command(),
+            character => Ok(MathNode::Symbol {
+                character,
+                source: character.to_string(),
+                class: character_class(character),
+            }),
+        }
+    }
+
+    /// A script or command argument: one atom, usually a braced group.
+    fn parse_argument(&mut self, owner: &str) -> Result<MathNode, MathError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None | Some('}' | '^' | '_') => Err(MathError::MissingArgument(owner.to_owned())),
+            Some(_) => self.parse_atom(),
+        }
+    }
+
+    fn parse_command(&mut self) -> Result<MathNode, MathError> {
+        let start = self.position;
+        match self.bump().ok_or(MathError::UnexpectedEnd)? {
+            letter if letter.is_ascii_alphabetic() => {
+                while self.peek().is_some_and(|next| next.is_ascii_alphabetic()) {
+                    self.bump();
+                }
+            }
+            _ => {}
+        }
+        let name = &self.source[start..self.position];
+        let source = format!("\\{name}");
+        match name {
+            "frac" => {
+                let numerator = self.parse_argument(&source)?;
+                let denominator = self.parse_argument(&source)?;
+                Ok(MathNode::Fraction(
+                    Box::new(numerator),
+                    Box::new(denominator),
+                ))
+            }
+            "sqrt" => Ok(MathNode::Radical(Box::new(self.parse_argument(&source)?))),
+            "," => Ok(MathNode::Space(3.0 / 18.0)),
+            ":" => Ok(MathNode::Space(4.0 / 18.0)),
+            ";" => Ok(MathNode::Space(5.0 / 18.0)),
+            "!" => Ok(MathNode::Space(-3.0 / 18.0)),
+            " " => Ok(MathNode::Space(0.25)),
+            "quad" => Ok(MathNode::Space(1.0)),
+            "qquad" => Ok(MathNode::Space(2.0)),
+            _ => {
+                let (character, class) = command_symbol(name)
+                    .ok_or_else(|| MathError::UnknownCommand(name.to_owned()))?;
+                Ok(MathNode::Symbol {
+                    character,
+                    source,
+                    class,
+                })
+            }
+        }
+    }
+}
+
+fn character_class(character: char) -> AtomClass {
+    match character {
+        '+' | '-' | '*' | '/' => AtomClass::Binary,
+        '=' | '<' | '>' => AtomClass::Relation,
+        ',' | ';' => AtomClass::Punctuation,
+        _ => AtomClass::Ordinary,
+    }
+}
+
+fn command_symbol(name: &str) -> Option<(char, AtomClass)> {
+    use AtomClass::{Binary, Ordinary, Punctuation, Relation};
+    let symbol = match name {
+        "alpha" => ('α', Ordinary),
+        "beta" => ('β', Ordinary),
+        "gamma" => ('γ', Ordinary),
+        "delta" => ('δ', Ordinary),
+        "epsilon" => ('ϵ', Ordinary),
+        "varepsilon" => ('ε', Ordinary),
+        "zeta" => ('ζ', Ordinary),
+        "eta" => ('η', Ordinary),
+        "theta" => ('θ', Ordinary),
+        "vartheta" => ('ϑ', Ordinary),
+        "iota" => ('ι', Ordinary),
+        "kappa" => ('κ', Ordinary),
+        "lambda" => ('λ', Ordinary),
+        "mu" => ('μ', Ordinary),
+        "nu" => ('ν', Ordinary),
+        "xi" => ('ξ', Ordinary),
+        "pi" => ('π', Ordinary),
+        "rho" => ('ρ', Ordinary),
+        "sigma" => ('σ', Ordinary),
+        "tau" => ('τ', Ordinary),
+        "upsilon" => ('υ', Ordinary),
+        "phi" => ('ϕ', Ordinary),
+        "varphi" => ('φ', Ordinary),
+        "chi" => ('χ', Ordinary),
+        "psi" => ('ψ', Ordinary),
+        "omega" => ('ω', Ordinary),
+        "Gamma" => ('Γ', Ordinary),
+        "Delta" => ('Δ', Ordinary),
+        "Theta" => ('Θ', Ordinary),
+        "Lambda" => ('Λ', Ordinary),
+        "Xi" => ('Ξ', Ordinary),
+        "Pi" => ('Π', Ordinary),
+        "Sigma" => ('Σ', Ordinary),
+        "Upsilon" => ('Υ', Ordinary),
+        "Phi" => ('Φ', Ordinary),
+        "Psi" => ('Ψ', Ordinary),
+        "Omega" => ('Ω', Ordinary),
+        "infty" => ('∞', Ordinary),
+        "partial" => ('∂', Ordinary),
+        "nabla" => ('∇', Ordinary),
+        "prime" => ('′', Ordinary),
+        "ldots" => ('…', Ordinary),
+        "cdots" => ('⋯', Ordinary),
+        "sum" => ('∑', Ordinary),
+        "prod" => ('∏', Ordinary),
+        "int" => ('∫', Ordinary),
+        "pm" => ('±', Binary),
+        "mp" => ('∓', Binary),
+        "times" => ('×', Binary),
+        "div" => ('÷', Binary),
+        "cdot" => ('⋅', Binary),
+        "leq" | "le" => ('≤', Relation),
+        "geq" | "ge" => ('≥', Relation),
+        "neq" | "ne" => ('≠', Relation),
+        "approx" => ('≈', Relation),
+        "equiv" => ('≡', Relation),
+        "sim" => ('∼', Relation),
+        "to" | "rightarrow" => ('→', Relation),
+        "leftarrow" => ('←', Relation),
+        "in" => ('∈', Relation),
+        "{" => ('{', Ordinary),
+        "}" => ('}', Ordinary),
+        "|" => ('‖', Ordinary),
+        "colon" => (':', Punctuation),
+        _ => return None,
+    };
+    Some(symbol)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Style {
+    scale: f32,
+    /// Display style keeps fraction parts at full size; nested fractions and
+    /// scripts switch to text style.
+    display: bool,
+}
+
+impl Style {
+    const DISPLAY: Self = Self {
+        scale: 1.0,
+        display: true,
+    };
+
+    fn script(self) -> Self {
+        Self {
+            scale: self.scale * SCRIPT_SCALE,
+            display: false,
+        }
+    }
+
+    fn fraction(self) -> Self {
+        if self.display {
+            Self {
+                display: false,
+                ..self
+            }
+        } else {
+            self.script()
+        }
+    }
+}
+
+/// A symbol outline placed at `offset` from its box origin.
+struct PlacedPath {
+    source: String,
+    path: VectorPath,
+    offset: Vec2,
+}
+
+/// A TeX box: origin on the left of the baseline, extents in em units.
+#[derive(Default)]
+struct MathBox {
+    width: f32,
+    height: f32,
+    depth: f32,
+    items: Vec<PlacedPath>,
+}
+
+impl MathBox {
+    /// Appends `other` with its origin at `origin` in this box.
+    fn place(&mut self, other: MathBox, origin: Vec2) {
+        self.height = self.height.max(other.height + origin.y);
+        self.depth = self.depth.max(other.depth - origin.y);
+        self.items.extend(other.items.into_iter().map(|mut item| {
+            item.offset += origin;
+            item
+        }));
+    }
+}
+
+struct MathLayout<'f, 'a> {
+    face: &'f FontFace<'a>,
+}
+
+impl MathLayout<'_, '_> {
+    fn node(&self, node: &MathNode, style: Style) -> MathBox {
+        match node {
+            MathNode::Symbol {
+                character, source, ..
+            } => self.symbol(*character, source, style),
+            MathNode::Space(width) => MathBox {
+                width: width * style.scale,
+                ..MathBox::default()
+            },
+            MathNode::Group(nodes) => self.list(nodes, style),
+            MathNode::Fraction(numerator, denominator) => {
+                self.fraction(numerator, denominator, style)
+            }
+            MathNode::Radical(body) => self.radical(body, style),
+            MathNode::Scripts {
+                base,
+                superscript,
+                subscript,
+            } => self.scripts(base, superscript.as_deref(), subscript.as_deref(), style),
+        }
+    }
+
+    fn list(&self, nodes: &[MathNode], style: Style) -> MathBox {
+        let mut result = MathBox::default();
+        let mut previous: Option<AtomClass> = None;
+        for (index, node) in nodes.iter().enumerate() {
+            let mut class = node_class(node);
+            // A binary operator with no left operand is unary, as in `-x`.
+            if class == AtomClass::Binary
+                && matches!(
+                    previous,
+                    None | Some(AtomClass::Binary | AtomClass::Relation | AtomClass::Punctuation)
+                )
+            {
+                class = AtomClass::Ordinary;
+            }
+            if class == AtomClass::Binary && index + 1 == nodes.len() {
+                class = AtomClass::Ordinary;
+            }
+            let space = match (previous, class) {
+                (Some(_), AtomClass::Binary) | (Some(AtomClass::Binary), _) => BINARY_SPACE,
+                (Some(AtomClass::Relation), AtomClass::Relation) => 0.0,
+                (Some(_), AtomClass::Relation) | (Some(AtomClass::Relation), _) => RELATION_SPACE,
+                (Some(AtomClass::Punctuation), _) => PUNCTUATION_SPACE,
+                _ => 0.0,
+            };
+            // Scripts keep operators tight, as TeX does in script styles.
+            if style.scale >= 1.0 {
+                result.width += space * style.scale;
+            }
+            let child = self.node(node, style);
+            let width = child.width;
+            let origin = Vec2::new(result.width, 0.0);
+            result.place(child, origin);
+            result.width += width;
+            if !matches!(node, MathNode::Space(_)) {
+                previous = Some(class);
+            }
+        }
+        result
+    }
+
+    fn symbol(&self, character: char, source: &str, style: Style) -> MathBox {
+        // Prefer a true minus sign when the font provides one.
+        let character = if character == '-' && self.face.has_glyph('\u{2212}') {
+            '\u{2212}'
+        } else {
+            character
+        };
+        let glyph = self.face.glyph_index(character);
+        let width = self.face.glyph_advance(glyph) * style.scale;
+        let (height, depth) = self.face.glyph_bounds(glyph).map_or((0.0, 0.0), |bounds| {
+            (bounds.max.y * style.scale, -bounds.min.y * style.scale)
+        });
+        let items = self
+            .face
+            .glyph_outline(glyph)
+            .map(|outline| PlacedPath {
+                source: source.to_owned(),
+                path: map_path(&outline, |point| point * style.scale),
+                offset: Vec2::ZERO,
+            })
+            .into_iter()
+            .collect();
+        MathBox {
+            width,
+            height: height.max(0.0),
+            depth: depth.max(0.0),
+            items,
+        }
+    }
+
+    fn scripts(
+        &self,
+        base: &MathNode,
+        superscript: Option<&MathNode>,
+        subscript: Option<&MathNode>,
+        style: Style,
+    ) -> MathBox {
+        let base = self.node(base, style);
+        let script_style = style.script();
+        let superscript = superscript.map(|node| self.node(node, script_style));
+        let subscript = subscript.map(|node| self.node(node, script_style));
+
+        let mut up = superscript.as_ref().map(|script| {
+            (SUPERSCRIPT_SHIFT * style.scale)
+                .max(base.height - SUPERSCRIPT_DROP * style.scale)
+                .max(script.depth + 0.25 * AXIS_HEIGHT * style.scale)
+        });
+        let mut down = subscript.as_ref().map(|script| {
+            (SUBSCRIPT_SHIFT * style.scale)
+                .max(base.depth + SUBSCRIPT_DROP * style.scale)
+                .max(script.height - 0.8 * SUPERSCRIPT_SHIFT * style.scale)
+        });
+        if let (Some(sup), Some(sub), Some(up), Some(down)) =
+            (&superscript, &subscript, up.as_mut(), down.as_mut())
+        {
+            // Keep at least four rule thicknesses between the two scripts.
+            let gap = (*up - sup.depth) - (sub.height - *down);
+            let minimum = 4.0 * RULE_THICKNESS * style.scale;
+            if gap < minimum {
+                *down += minimum - gap;
+            }
+        }
+
+        let script_x = base.width;
+        let mut result = MathBox::default();
+        result.place(base, Vec2::ZERO);
+        let mut script_width: f32 = 0.0;
+        if let (Some(script), Some(up)) = (superscript, up) {
+            script_width = script_width.max(script.width);
+            result.place(script, Vec2::new(script_x, up));
+        }
+        if let (Some(script), Some(down)) = (subscript, down) {
+            script_width = script_width.max(script.width);
+            result.place(script, Vec2::new(script_x, -down));
+        }
+        result.width = script_x + script_width + SCRIPT_SPACE * style.scale;
+        result
+    }
+
+    fn fraction(&self, numerator: &MathNode, denominator: &MathNode, style: Style) -> MathBox {
+        let part_style = style.fraction();
+        let numerator = self.node(numerator, part_style);
+        let denominator = self.node(denominator, part_style);
+        let padding = FRACTION_PADDING * style.scale;
+        let thickness = RULE_THICKNESS * style.scale;
+        let gap = FRACTION_GAP * style.scale;
+        let axis = AXIS_HEIGHT * style.scale;
+        let inner = numerator.width.max(denominator.width);
+        let width = inner + 2.0 * padding;
+
+        let up = axis + 0.5 * thickness + gap + numerator.depth;
+        let down = -(axis - 0.5 * thickness - gap - denominator.height);
+        let mut result = MathBox {
+            width,
+            ..MathBox::default()
+        };
+        let numerator_x = padding + 0.5 * (inner - numerator.width);
+        let denominator_x = padding + 0.5 * (inner - denominator.width);
+        result.place(numerator, Vec2::new(numerator_x, up));
+        result.place(denominator, Vec2::new(denominator_x, -down));
+        let rule = rectangle(
+            Vec2::new(0.5 * padding, axis - 0.5 * thickness),
+            Vec2::new(width - 0.5 * padding, axis + 0.5 * thickness),
+        );
+        result.items.push(PlacedPath {
+            source: FRACTION_RULE_SOURCE.to_owned(),
+            path: rule,
+            offset: Vec2::ZERO,
+        });
+        result
+    }
+
+    fn radical(&self, body: &MathNode, style: Style) -> MathBox {
+        let body = self.node(body, style);
+        let thickness = RULE_THICKNESS * style.scale;
+        let gap = RADICAL_GAP * style.scale;
+        let sign_width = RADICAL_WIDTH * style.scale;
+        let bottom = -body.depth.max(0.1 * style.scale) - gap * 0.5;
+        let top = body.height.max(0.7 * style.scale) + gap + thickness;
+        let right = sign_width + body.width + 1.5 * gap;
+        let height = top - bottom;
+        let at = |x: f32, y: f32| Vec2::new(x * sign_width, bottom + y * height);
+
+        // Tick, descending stroke and long ascending stroke, then the overbar.
+        let sign = VectorPath::new()
+            .move_to(at(0.0, 0.45))
+            .line_to(at(0.14, 0.52))
+            .line_to(at(0.4, 0.16))
+            .line_to(Vec2::new(0.92 * sign_width, top))
+            .line_to(Vec2::new(right, top))
+            .line_to(Vec2::new(right, top - thickness))
+            .line_to(Vec2::new(sign_width, top - thickness))
+            .line_to(at(0.46, 0.0))
+            .line_to(at(0.36, 0.0))
+            .line_to(at(0.06, 0.41))
+            .close();
+
+        let mut result = MathBox {
+            width: right + 0.5 * gap,
+            height: top,
+            depth: -bottom,
+            items: vec![PlacedPath {
+                source: RADICAL_SOURCE.to_owned(),
+                path: sign,
+                offset: Vec2::ZERO,
+            }],
+        };
+        result.place(body, Vec2::new(sign_width + gap, 0.0));
+        result
+    }
+}
+
+fn node_class(node: &MathNode) -> AtomClass {
+    match node {
+        MathNode::Symbol { class, .. } => *class,
+        _ => AtomClass::Ordinary,
+    }
+}
+
+fn rectangle(min: Vec2, max: Vec2) -> VectorPath {
+    VectorPath::new()
+        .move_to(min)
+        .line_to(Vec2::new(max.x, min.y))
+        .line_to(max)
+        .line_to(Vec2::new(min.x, max.y))
+        .close()
+}
+
+fn map_path(path: &VectorPath, map: impl Fn(Vec2) -> Vec2) -> VectorPath {
+    path.commands()
+        .iter()
+        .fold(VectorPath::new(), |result, command| match *command {
+            PathCommand::MoveTo { to } => result.move_to(map(to)),
+            PathCommand::LineTo { to } => result.line_to(map(to)),
+            PathCommand::QuadraticTo { control, to } => result.quadratic_to(map(control), map(to)),
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                to,
+            } => result.cubic_to(map(control1), map(control2), map(to)),
+            PathCommand::Close => result.close(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(tex: &str) -> Result<Vec<MathNode>, MathError> {
+        Parser::new(tex).parse_list(false)
+    }
+
+    #[test]
+    fn parser_attaches_scripts_and_reports_malformed_input() {
+        let nodes = parse("x_i^{2}").expect("scripts must parse");
+        assert_eq!(nodes.len(), 1);
+        let MathNode::Scripts {
+            superscript,
+            subscript,
+            ..
+        } = &nodes[0]
+        else {
+            panic!("expected scripts");
+        };
+        assert!(superscript.is_some() && subscript.is_some());
+
+        assert_eq!(parse("{x"), Err(MathError::UnmatchedBrace));
+        assert_eq!(parse("x}"), Err(MathError::UnmatchedBrace));
+        assert_eq!(parse("x^2^3"), Err(MathError::DoubleScript('^')));
+        assert_eq!(
+            parse("\\frac{1}"),
+            Err(MathError::MissingArgument("\\frac".to_owned()))
+        );
+        assert_eq!(
+            parse("\\begin"),
+            Err(MathError::UnknownCommand("begin".to_owned()))
+        );
+    }
+
+    #[test]
+    fn commands_map_to_classified_symbols_and_spaces() {
+        let nodes = parse("\\alpha \\leq \\,\\Omega").expect("commands must parse");
+        assert_eq!(
+            nodes[1],
+            MathNode::Symbol {
+                character: '≤',
+                source: "\\leq".to_owned(),
+                class: AtomClass::Relation,
+            }
+        );
+        assert_eq!(nodes[2], MathNode::Space(3.0 / 18.0));
+        assert_eq!(node_class(&nodes[3]), AtomClass::Ordinary);
+    }
+}
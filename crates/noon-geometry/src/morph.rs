@@ -164,10 +164,10 @@ impl From<GeometryError> for MorphError {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct FlattenedContour {
-    points: Vec<Vec2>,
+pub(crate) struct FlattenedContour {
+    pub(crate) points: Vec<Vec2>,
     feature_indices: Vec<usize>,
-    closed: bool,
+    pub(crate) closed: bool,
 }
 
 pub fn plan_morph(
@@ -230,7 +230,10 @@ fn validate_options(options: MorphOptions) -> Result<(), MorphError> {
     Ok(())
 }
 
-fn flatten_path(path: &VectorPath, tolerance: f32) -> Result<Vec<FlattenedContour>, GeometryError> {
+pub(crate) fn flatten_path(
+    path: &VectorPath,
+    tolerance: f32,
+) -> Result<Vec<FlattenedContour>, GeometryError> {
     let mut contours = Vec::new();
     let mut points = Vec::new();
     let mut feature_indices = Vec::new();
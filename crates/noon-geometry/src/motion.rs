@@ -0,0 +1,201 @@
+use std::f32::consts::{PI, TAU};
+
+use noon_core::{Vec2, VectorPath};
+
+use crate::{morph::flatten_path, GeometryError};
+
+const MOTION_FLATTEN_TOLERANCE: f32 = 0.001;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MotionSample {
+    pub point: Vec2,
+    /// Tangent angle turned since the start of the path, in radians. Turns
+    /// accumulate instead of wrapping, so repeated loops keep counting.
+    pub turning: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MotionSegment {
+    start: Vec2,
+    end: Vec2,
+    start_distance: f32,
+    end_distance: f32,
+    heading: f32,
+}
+
+/// Deterministic arc-length parameterization of a flattened path.
+///
+/// Contours are traversed in order. Moving from one contour to the next adds
+/// no length, so the sampled point jumps between them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MotionSampler {
+    origin: Vec2,
+    segments: Vec<MotionSegment>,
+}
+
+impl MotionSampler {
+    pub fn new(path: &VectorPath) -> Result<Self, GeometryError> {
+        let contours = flatten_path(path, MOTION_FLATTEN_TOLERANCE)?;
+        let origin = contours
+            .first()
+            .and_then(|contour| contour.points.first())
+            .copied()
+            .ok_or(GeometryError::EmptyPath)?;
+
+        let mut segments = Vec::new();
+        let mut distance = 0.0;
+        let mut previous_heading = None;
+        for contour in &contours {
+            let closing = contour
+                .closed
+                .then(|| contour.points.first().copied())
+                .flatten();
+            let points = contour.points.iter().copied().chain(closing);
+            for (start, end) in points.clone().zip(points.skip(1)) {
+                let delta = end - start;
+                let length = delta.length();
+                if length <= 0.0 {
+                    continue;
+                }
+                let direction = delta.y.atan2(delta.x);
+                let heading = match previous_heading {
+                    Some(previous) => previous + wrap_angle(direction - previous),
+                    None => direction,
+                };
+                previous_heading = Some(heading);
+                segments.push(MotionSegment {
+                    start,
+                    end,
+                    start_distance: distance,
+                    end_distance: distance + length,
+                    heading,
+                });
+                distance += length;
+            }
+        }
+        Ok(Self { origin, segments })
+    }
+
+    pub fn length(&self) -> f32 {
+        self.segments
+            .last()
+            .map_or(0.0, |segment| segment.end_distance)
+    }
+
+    /// Point and turning after `progress` of the total arc length.
+    pub fn sample(&self, progress: f32) -> MotionSample {
+        let Some(first) = self.segments.first() else {
+            return MotionSample {
+                point: self.origin,
+                turning: 0.0,
+            };
+        };
+        let distance = self.length() * progress.clamp(0.0, 1.0);
+        let index = self
+            .segments
+            .partition_point(|segment| segment.end_distance < distance)
+            .min(self.segments.len() - 1);
+        let segment = self.segments[index];
+        let along = ((distance - segment.start_distance)
+            / (segment.end_distance - segment.start_distance))
+            .clamp(0.0, 1.0);
+        MotionSample {
+            point: segment.start + (segment.end - segment.start) * along,
+            turning: self.heading_at(index, distance) - first.heading,
+        }
+    }
+
+    /// Headings are knots at segment midpoints, blended linearly so the
+    /// turning stays continuous through the corners of the flattened path.
+    fn heading_at(&self, index: usize, distance: f32) -> f32 {
+        let segment = &self.segments[index];
+        let middle = midpoint_distance(segment);
+        let neighbor = if distance < middle {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&next| next < self.segments.len())
+        };
+        let Some(neighbor) = neighbor else {
+            return segment.heading;
+        };
+        let other = &self.segments[neighbor];
+        let blend = (distance - middle) / (midpoint_distance(other) - middle);
+        segment.heading + (other.heading - segment.heading) * blend
+    }
+}
+
+fn midpoint_distance(segment: &MotionSegment) -> f32 {
+    (segment.start_distance + segment.end_distance) * 0.5
+}
+
+fn wrap_angle(angle: f32) -> f32 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn polylines_are_sampled_by_arc_length_with_blended_turning() {
+        let path = VectorPath::new()
+            .move_to(Vec2::ZERO)
+            .line_to(Vec2::new(3.0, 0.0))
+            .line_to(Vec2::new(3.0, 1.0));
+        let sampler = MotionSampler::new(&path).expect("valid path");
+
+        assert_eq!(sampler.length(), 4.0);
+        assert_eq!(sampler.sample(0.0).point, Vec2::ZERO);
+        assert_eq!(sampler.sample(0.5).point, Vec2::new(2.0, 0.0));
+        assert_eq!(sampler.sample(1.0).point, Vec2::new(3.0, 1.0));
+        // Midpoints sit at distances 1.5 and 3.5; the corner lies between.
+        assert_eq!(sampler.sample(0.25).turning, 0.0);
+        assert_close(sampler.sample(0.625).turning, PI * 0.25, 1.0e-6);
+        assert_close(sampler.sample(1.0).turning, PI * 0.5, 1.0e-6);
+    }
+
+    #[test]
+    fn closed_loops_and_clockwise_curves_accumulate_signed_turning() {
+        let square = VectorPath::new()
+            .move_to(Vec2::ZERO)
+            .line_to(Vec2::new(1.0, 0.0))
+            .line_to(Vec2::new(1.0, 1.0))
+            .line_to(Vec2::new(0.0, 1.0))
+            .close();
+        let sampler = MotionSampler::new(&square).expect("valid path");
+        assert_eq!(sampler.length(), 4.0);
+        assert_eq!(sampler.sample(1.0).point, Vec2::ZERO);
+        assert_close(sampler.sample(1.0).turning, 1.5 * PI, 1.0e-6);
+
+        let clockwise = VectorPath::new()
+            .move_to(Vec2::new(1.0, 0.0))
+            .quadratic_to(Vec2::new(1.0, -1.0), Vec2::new(0.0, -1.0));
+        let sampler = MotionSampler::new(&clockwise).expect("valid path");
+        assert_close(sampler.sample(1.0).turning, -PI * 0.5, 0.05);
+        assert_close(sampler.sample(1.0).point.y, -1.0, 1.0e-6);
+    }
+
+    #[test]
+    fn empty_and_single_point_paths_are_handled_intentionally() {
+        assert_eq!(
+            MotionSampler::new(&VectorPath::new()),
+            Err(GeometryError::EmptyPath)
+        );
+        let point = MotionSampler::new(&VectorPath::new().move_to(Vec2::ONE)).expect("valid path");
+        assert_eq!(point.length(), 0.0);
+        assert_eq!(
+            point.sample(0.5),
+            MotionSample {
+                point: Vec2::ONE,
+                turning: 0.0
+            }
+        );
+    }
+}
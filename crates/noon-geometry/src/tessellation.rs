@@ -56,6 +56,7 @@ pub enum GeometryError {
     NonFinitePoint,
     InvalidStrokeWidth(f32),
     Tessellation(String),
+    EmptyPath,
 }
 
 impl std::fmt::Display for GeometryError {
@@ -69,6 +70,7 @@ impl std::fmt::Display for GeometryError {
                 "path stroke width must be finite and non-negative: {width}"
             ),
             Self::Tessellation(message) => write!(formatter, "path tessellation failed: {message}"),
+            Self::EmptyPath => formatter.write_str("path has no points"),
         }
     }
 }
@@ -0,0 +1,590 @@
+use noon_core::{
+    FontId, GlyphRun, NumberFormat, NumberGlyph, NumberLayout, PathCommand, PositionedGlyph, Rect,
+    Vec2, VectorPath,
+};
+
+/// Transparent pixels kept around every rasterized glyph so linear sampling
+/// at quad edges never bleeds into a neighbouring atlas entry.
+pub const GLYPH_BITMAP_PADDING: u32 = 1;
+
+/// Upper bound on flattening segments emitted for one curve.
+const MAX_CURVE_SEGMENTS: f32 = 64.0;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FontError {
+    Malformed(String),
+}
+
+impl std::fmt::Display for FontError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed(message) => write!(formatter, "invalid font data: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+/// Single-channel glyph coverage, stored row-major from the top row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphBitmap {
+    pub width: u32,
+    pub height: u32,
+    /// Bottom-left corner of the bitmap quad in em units, relative to the
+    /// glyph's baseline origin.
+    pub origin: Vec2,
+    /// Extent of the bitmap quad in em units, padding included.
+    pub size: Vec2,
+    pub coverage: Vec<u8>,
+}
+
+/// A parsed font borrowing registered font bytes.
+///
+/// All metrics and outlines are reported in em units so callers only scale
+/// by the run's world-space font size.
+pub struct FontFace<'a> {
+    face: ttf_parser::Face<'a>,
+    units_per_em: f32,
+}
+
+impl std::fmt::Debug for FontFace<'_> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("FontFace")
+            .field("units_per_em", &self.units_per_em)
+            .field("glyph_count", &self.face.number_of_glyphs())
+            .finish()
+    }
+}
+
+impl<'a> FontFace<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, FontError> {
+        let face = ttf_parser::Face::parse(bytes, 0)
+            .map_err(|error| FontError::Malformed(error.to_string()))?;
+        let units_per_em = f32::from(face.units_per_em());
+        Ok(Self { face, units_per_em })
+    }
+
+    pub fn glyph_count(&self) -> u16 {
+        self.face.number_of_glyphs()
+    }
+
+    /// Baseline-to-baseline distance between consecutive lines, in em units.
+    pub fn line_height(&self) -> f32 {
+        let height = i32::from(self.face.ascender()) - i32::from(self.face.descender())
+            + i32::from(self.face.line_gap());
+        height as f32 / self.units_per_em
+    }
+
+    /// Maps characters through the font's cmap and advances a pen with
+    /// horizontal pair kerning: GPOS `kern` pair adjustments when the font
+    /// has them, otherwise the legacy `kern` table. Each `'\n'` starts a
+    /// new line one [`line_height`](Self::line_height) lower; characters the
+    /// font lacks fall back to glyph 0 (`.notdef`).
+    ///
+    /// Returned origins are in em units with the first baseline at `y = 0`.
+    pub fn shape(&self, text: &str) -> Vec<PositionedGlyph> {
+        let line_height = self.line_height();
+        let mut glyphs = Vec::with_capacity(text.len());
+        for (line_index, line) in text.split('\n').enumerate() {
+            let baseline = -(line_index as f32) * line_height;
+            let mut pen = 0.0;
+            let mut previous = None;
+            for character in line.chars().filter(|character| *character != '\r') {
+                let glyph = self.glyph_index(character);
+                if let Some(previous) = previous {
+                    pen += f32::from(self.kerning(previous, glyph)) / self.units_per_em;
+                }
+                glyphs.push(PositionedGlyph {
+                    glyph,
+                    origin: Vec2::new(pen, baseline),
+                });
+                pen += self.glyph_advance(glyph);
+                previous = Some(glyph);
+            }
+        }
+        glyphs
+    }
+
+    /// The glyph for `character`, falling back to glyph 0 (`.notdef`).
+    pub fn glyph_index(&self, character: char) -> u16 {
+        self.face.glyph_index(character).map_or(0, |glyph| glyph.0)
+    }
+
+    /// True when the font's cmap maps `character` to a real glyph.
+    pub fn has_glyph(&self, character: char) -> bool {
+        self.face.glyph_index(character).is_some()
+    }
+
+    /// Horizontal advance of one glyph in em units.
+    pub fn glyph_advance(&self, glyph: u16) -> f32 {
+        f32::from(
+            self.face
+                .glyph_hor_advance(ttf_parser::GlyphId(glyph))
+                .unwrap_or(0),
+        ) / self.units_per_em
+    }
+
+    /// Shapes `text` at `size` world units per em, centered on its ink bounds.
+    pub fn glyph_run(&self, font: FontId, text: &str, size: f32) -> GlyphRun {
+        let mut glyphs = self.shape(text);
+        let bounds = Rect::from_points(glyphs.iter().flat_map(|positioned| {
+            self.glyph_bounds(positioned.glyph)
+                .map(|bounds| {
+                    [
+                        positioned.origin + bounds.min,
+                        positioned.origin + bounds.max,
+                    ]
+                })
+                .into_iter()
+                .flatten()
+        }));
+        let center = bounds.map_or(Vec2::ZERO, |bounds| (bounds.min + bounds.max) * 0.5);
+        for positioned in &mut glyphs {
+            positioned.origin = (positioned.origin - center) * size;
+        }
+        let bounds = bounds.map_or(Rect::new(Vec2::ZERO, Vec2::ZERO), |bounds| {
+            Rect::new((bounds.min - center) * size, (bounds.max - center) * size)
+        });
+        GlyphRun {
+            font,
+            size,
+            text: text.to_owned(),
+            glyphs,
+            bounds,
+            number: None,
+        }
+    }
+
+    /// Shapes a counter showing `value`, centered on its ink like
+    /// [`glyph_run`](Self::glyph_run). Every character `format` can produce
+    /// is shaped up front so the run can be re-typeset without the font.
+    pub fn number_run(
+        &self,
+        font: FontId,
+        format: NumberFormat,
+        value: f32,
+        size: f32,
+    ) -> GlyphRun {
+        let mut glyphs: Vec<NumberGlyph> = Vec::new();
+        for character in format.characters() {
+            if glyphs.iter().any(|entry| entry.character == character) {
+                continue;
+            }
+            let glyph = self.glyph_index(character);
+            glyphs.push(NumberGlyph {
+                character,
+                glyph,
+                advance: self.glyph_advance(glyph) * size,
+                bounds: self
+                    .glyph_bounds(glyph)
+                    .map(|bounds| Rect::new(bounds.min * size, bounds.max * size)),
+            });
+        }
+        let mut layout = NumberLayout {
+            format,
+            value,
+            origin: Vec2::ZERO,
+            glyphs,
+        };
+        let (_, _, ink) = layout.typeset(value);
+        layout.origin = -ink.center();
+        let (text, glyphs, bounds) = layout.typeset(value);
+        GlyphRun {
+            font,
+            size,
+            text,
+            glyphs,
+            bounds,
+            number: Some(Box::new(layout)),
+        }
+    }
+
+    /// Ink bounds of one glyph in em units, `None` for empty glyphs.
+    pub fn glyph_bounds(&self, glyph: u16) -> Option<Rect> {
+        let bounds = self.face.glyph_bounding_box(ttf_parser::GlyphId(glyph))?;
+        Some(Rect::new(
+            Vec2::new(f32::from(bounds.x_min), f32::from(bounds.y_min)) / self.units_per_em,
+            Vec2::new(f32::from(bounds.x_max), f32::from(bounds.y_max)) / self.units_per_em,
+        ))
+    }
+
+    /// The glyph outline in em units relative to its baseline origin.
+    pub fn glyph_outline(&self, glyph: u16) -> Option<VectorPath> {
+        let commands = self.outline_commands(glyph)?;
+        Some(
+            commands
+                .into_iter()
+                .fold(VectorPath::new(), |path, command| match command {
+                    PathCommand::MoveTo { to } => path.move_to(to),
+                    PathCommand::LineTo { to } => path.line_to(to),
+                    PathCommand::QuadraticTo { control, to } => path.quadratic_to(control, to),
+                    PathCommand::CubicTo {
+                        control1,
+                        control2,
+                        to,
+                    } => path.cubic_to(control1, control2, to),
+                    PathCommand::Close => path.close(),
+                }),
+        )
+    }
+
+    /// Rasterizes exact-area coverage of one glyph at `pixels_per_em`.
+    ///
+    /// Returns `None` for glyphs without contours, such as spaces.
+    pub fn rasterize_glyph(&self, glyph: u16, pixels_per_em: f32) -> Option<GlyphBitmap> {
+        if !pixels_per_em.is_finite() || pixels_per_em <= 0.0 {
+            return None;
+        }
+        let commands = self.outline_commands(glyph)?;
+        let bounds = self.glyph_bounds(glyph)?;
+        let padding = GLYPH_BITMAP_PADDING as f32;
+        let left = (bounds.min.x * pixels_per_em).floor() - padding;
+        let bottom = (bounds.min.y * pixels_per_em).floor() - padding;
+        let right = (bounds.max.x * pixels_per_em).ceil() + padding;
+        let top = (bounds.max.y * pixels_per_em).ceil() + padding;
+        let width = (right - left) as u32;
+        let height = (top - bottom) as u32;
+
+        let mut raster = CoverageRaster::new(width as usize, height as usize);
+        let to_pixels = |point: Vec2| {
+            Vec2::new(
+                point.x * pixels_per_em - left,
+                top - point.y * pixels_per_em,
+            )
+        };
+        let mut start = Vec2::ZERO;
+        let mut current = Vec2::ZERO;
+        for command in commands {
+            match command {
+                PathCommand::MoveTo { to } => {
+                    raster.line(current, start);
+                    start = to_pixels(to);
+                    current = start;
+                }
+                PathCommand::LineTo { to } => {
+                    let to = to_pixels(to);
+                    raster.line(current, to);
+                    current = to;
+                }
+                PathCommand::QuadraticTo { control, to } => {
+                    let to = to_pixels(to);
+                    raster.quadratic(current, to_pixels(control), to);
+                    current = to;
+                }
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    let to = to_pixels(to);
+                    raster.cubic(current, to_pixels(control1), to_pixels(control2), to);
+                    current = to;
+                }
+                PathCommand::Close => {
+                    raster.line(current, start);
+                    current = start;
+                }
+            }
+        }
+        raster.line(current, start);
+
+        Some(GlyphBitmap {
+            width,
+            height,
+            origin: Vec2::new(left, bottom) / pixels_per_em,
+            size: Vec2::new(width as f32, height as f32) / pixels_per_em,
+            coverage: raster.into_coverage(),
+        })
+    }
+
+    fn kerning(&self, left: u16, right: u16) -> i16 {
+        let (left, right) = (ttf_parser::GlyphId(left), ttf_parser::GlyphId(right));
+        if let Some(adjustment) = self.gpos_kerning(left, right) {
+            return adjustment;
+        }
+        let Some(kern) = self.face.tables().kern else {
+            return 0;
+        };
+        kern.subtables
+            .into_iter()
+            .filter(|subtable| {
+                subtable.horizontal && !subtable.variable && !subtable.has_cross_stream
+            })
+            .filter_map(|subtable| subtable.glyphs_kerning(left, right))
+            .fold(0, i16::saturating_add)
+    }
+
+    /// Sums the first-glyph advance adjustments of the GPOS pair lookups
+    /// behind the `kern` feature. `None` when the font has no such feature,
+    /// so the legacy `kern` table still applies to older fonts.
+    fn gpos_kerning(&self, left: ttf_parser::GlyphId, right: ttf_parser::GlyphId) -> Option<i16> {
+        let gpos = self.face.tables().gpos?;
+        let mut lookups = gpos
+            .features
+            .into_iter()
+            .filter(|feature| feature.tag == ttf_parser::Tag::from_bytes(b"kern"))
+            .flat_map(|feature| feature.lookup_indices)
+            .collect::<Vec<_>>();
+        if lookups.is_empty() {
+            return None;
+        }
+        lookups.sort_unstable();
+        lookups.dedup();
+        let adjustment = lookups
+            .into_iter()
+            .filter_map(|index| gpos.lookups.get(index))
+            .filter_map(|lookup| {
+                // Within a lookup only the first subtable covering the pair applies.
+                lookup
+                    .subtables
+                    .into_iter::<ttf_parser::gpos::PositioningSubtable>()
+                    .find_map(|subtable| match subtable {
+                        ttf_parser::gpos::PositioningSubtable::Pair(pair) => {
+                            pair_advance(pair, left, right)
+                        }
+                        _ => None,
+                    })
+            })
+            .fold(0, i16::saturating_add);
+        Some(adjustment)
+    }
+
+    fn outline_commands(&self, glyph: u16) -> Option<Vec<PathCommand>> {
+        let mut collector = OutlineCollector {
+            units_per_em: self.units_per_em,
+            commands: Vec::new(),
+        };
+        self.face
+            .outline_glyph(ttf_parser::GlyphId(glyph), &mut collector)?;
+        (!collector.commands.is_empty()).then_some(collector.commands)
+    }
+}
+
+struct OutlineCollector {
+    units_per_em: f32,
+    commands: Vec<PathCommand>,
+}
+
+impl OutlineCollector {
+    fn point(&self, x: f32, y: f32) -> Vec2 {
+        Vec2::new(x, y) / self.units_per_em
+    }
+}
+
+impl ttf_parser::OutlineBuilder for OutlineCollector {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.commands.push(PathCommand::MoveTo { to });
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.commands.push(PathCommand::LineTo { to });
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let control = self.point(x1, y1);
+        let to = self.point(x, y);
+        self.commands.push(PathCommand::QuadraticTo { control, to });
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let control1 = self.point(x1, y1);
+        let control2 = self.point(x2, y2);
+        let to = self.point(x, y);
+        self.commands.push(PathCommand::CubicTo {
+            control1,
+            control2,
+            to,
+        });
+    }
+
+    fn close(&mut self) {
+        self.commands.push(PathCommand::Close);
+    }
+}
+
+/// Advance adjustment of the first glyph in a GPOS pair, if the subtable
+/// covers it.
+fn pair_advance(
+    pair: ttf_parser::gpos::PairAdjustment,
+    left: ttf_parser::GlyphId,
+    right: ttf_parser::GlyphId,
+) -> Option<i16> {
+    let (first, _) = match pair {
+        ttf_parser::gpos::PairAdjustment::Format1 { coverage, sets } => {
+            sets.get(coverage.get(left)?)?.get(right)?
+        }
+        ttf_parser::gpos::PairAdjustment::Format2 {
+            coverage,
+            classes,
+            matrix,
+        } => {
+            if !coverage.contains(left) {
+                return None;
+            }
+            matrix.get((classes.0.get(left), classes.1.get(right)))?
+        }
+    };
+    Some(first.x_advance)
+}
+
+/// Signed-area accumulation rasterizer.
+///
+/// Every edge deposits the exact area it covers in each pixel it crosses,
+/// and a running prefix sum turns those deltas into coverage. Contours are
+/// closed, so each row's deposits sum to zero and the sum may run across
+/// the whole buffer. Overlapping contours saturate, matching non-zero fill
+/// for the outlines fonts contain.
+struct CoverageRaster {
+    width: usize,
+    height: usize,
+    accumulation: Vec<f32>,
+}
+
+impl CoverageRaster {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            // Edges ending exactly on the right border deposit one cell past it.
+            accumulation: vec![0.0; width * height + 2],
+        }
+    }
+
+    fn line(&mut self, from: Vec2, to: Vec2) {
+        if from.y == to.y {
+            return;
+        }
+        let (direction, top, bottom) = if from.y < to.y {
+            (1.0, from, to)
+        } else {
+            (-1.0, to, from)
+        };
+        let dxdy = (bottom.x - top.x) / (bottom.y - top.y);
+        let mut x = top.x;
+        let first_row = top.y.max(0.0) as usize;
+        if top.y < 0.0 {
+            x -= top.y * dxdy;
+        }
+        let last_row = self.height.min(bottom.y.ceil() as usize);
+        for row in first_row..last_row {
+            let row_start = row * self.width;
+            let dy = ((row + 1) as f32).min(bottom.y) - (row as f32).max(top.y);
+            let x_next = x + dxdy * dy;
+            let delta = dy * direction;
+            let (x0, x1) = if x < x_next { (x, x_next) } else { (x_next, x) };
+            let x0_floor = x0.floor();
+            let x0_index = x0_floor.max(0.0) as usize;
+            let x1_ceil = x1.ceil();
+            let x1_index = x1_ceil.max(0.0) as usize;
+            let cell = row_start + x0_index;
+            if x1_index <= x0_index + 1 {
+                // The edge stays within one pixel column on this row.
+                let midpoint = 0.5 * (x + x_next) - x0_floor;
+                self.accumulation[cell] += delta - delta * midpoint;
+                self.accumulation[cell + 1] += delta * midpoint;
+            } else {
+                let inverse_width = (x1 - x0).recip();
+                let x0_fraction = x0 - x0_floor;
+                let first_area = 0.5 * inverse_width * (1.0 - x0_fraction) * (1.0 - x0_fraction);
+                let x1_fraction = x1 - x1_ceil + 1.0;
+                let last_area = 0.5 * inverse_width * x1_fraction * x1_fraction;
+                self.accumulation[cell] += delta * first_area;
+                if x1_index == x0_index + 2 {
+                    self.accumulation[cell + 1] += delta * (1.0 - first_area - last_area);
+                } else {
+                    let second_area = inverse_width * (1.5 - x0_fraction);
+                    self.accumulation[cell + 1] += delta * (second_area - first_area);
+                    for column in x0_index + 2..x1_index - 1 {
+                        self.accumulation[row_start + column] += delta * inverse_width;
+                    }
+                    let before_last =
+                        second_area + (x1_index - x0_index - 3) as f32 * inverse_width;
+                    self.accumulation[row_start + x1_index - 1] +=
+                        delta * (1.0 - before_last - last_area);
+                }
+                self.accumulation[row_start + x1_index] += delta * last_area;
+            }
+            x = x_next;
+        }
+    }
+
+    fn quadratic(&mut self, from: Vec2, control: Vec2, to: Vec2) {
+        let length = (control - from).length() + (to - control).length();
+        let segments = curve_segments(length);
+        let mut previous = from;
+        for step in 1..=segments {
+            let t = step as f32 / segments as f32;
+            let u = 1.0 - t;
+            let point = from * (u * u) + control * (2.0 * u * t) + to * (t * t);
+            self.line(previous, point);
+            previous = point;
+        }
+    }
+
+    fn cubic(&mut self, from: Vec2, control1: Vec2, control2: Vec2, to: Vec2) {
+        let length =
+            (control1 - from).length() + (control2 - control1).length() + (to - control2).length();
+        let segments = curve_segments(length);
+        let mut previous = from;
+        for step in 1..=segments {
+            let t = step as f32 / segments as f32;
+            let u = 1.0 - t;
+            let point = from * (u * u * u)
+                + control1 * (3.0 * u * u * t)
+                + control2 * (3.0 * u * t * t)
+                + to * (t * t * t);
+            self.line(previous, point);
+            previous = point;
+        }
+    }
+
+    fn into_coverage(self) -> Vec<u8> {
+        let mut sum = 0.0_f32;
+        self.accumulation[..self.width * self.height]
+            .iter()
+            .map(|delta| {
+                sum += delta;
+                (sum.abs().min(1.0) * 255.0 + 0.5) as u8
+            })
+            .collect()
+    }
+}
+
+/// One flattening segment per two pixels of control-polygon length.
+fn curve_segments(length_pixels: f32) -> usize {
+    (length_pixels * 0.5).ceil().clamp(1.0, MAX_CURVE_SEGMENTS) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulation_rasterizer_covers_partial_pixels_exactly() {
+        // A 2x1 pixel square offset by half a pixel horizontally.
+        let mut raster = CoverageRaster::new(4, 2);
+        let corners = [
+            Vec2::new(0.5, 0.0),
+            Vec2::new(2.5, 0.0),
+            Vec2::new(2.5, 1.0),
+            Vec2::new(0.5, 1.0),
+        ];
+        for (index, corner) in corners.iter().enumerate() {
+            raster.line(*corner, corners[(index + 1) % corners.len()]);
+        }
+        assert_eq!(raster.into_coverage(), vec![128, 255, 128, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn diagonal_edges_deposit_triangle_area() {
+        // Right triangle filling the lower-left half of a single pixel.
+        let mut raster = CoverageRaster::new(2, 1);
+        raster.line(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+        raster.line(Vec2::new(1.0, 1.0), Vec2::new(0.0, 1.0));
+        raster.line(Vec2::new(0.0, 1.0), Vec2::new(0.0, 0.0));
+        assert_eq!(raster.into_coverage(), vec![128, 0]);
+    }
+}
@@ -0,0 +1,102 @@
+use noon_core::{FontId, PathCommand, Vec2};
+use noon_geometry::{FontError, FontFace};
+
+// Generated by assets/fonts/make_test_font.py; see that script for glyph shapes.
+const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+const NOTDEF: u16 = 0;
+const I: u16 = 2;
+const L: u16 = 3;
+const O: u16 = 4;
+const V: u16 = 5;
+
+fn face() -> FontFace<'static> {
+    FontFace::parse(TEST_FONT).expect("test font must parse")
+}
+
+#[test]
+fn shaping_applies_advances_kerning_lines_and_notdef_fallback() {
+    let face = face();
+    assert_eq!(face.glyph_count(), 6);
+    assert_eq!(face.line_height(), 1.0);
+
+    let glyphs = face.shape("LV I\nZ");
+    let ids: Vec<u16> = glyphs.iter().map(|glyph| glyph.glyph).collect();
+    assert_eq!(ids, [L, V, 1, I, NOTDEF]);
+    // L advances 0.6 em and the L/V pair kerns by -0.1 em.
+    assert_eq!(glyphs[1].origin, Vec2::new(0.5, 0.0));
+    assert_eq!(glyphs[2].origin, Vec2::new(1.2, 0.0));
+    assert_eq!(glyphs[3].origin, Vec2::new(1.5, 0.0));
+    assert_eq!(glyphs[4].origin, Vec2::new(0.0, -1.0));
+
+    // V/O only kerns through the GPOS class pair (-0.05 em); the L/V pair
+    // above is present in both GPOS and `kern` but applies once.
+    let glyphs = face.shape("VO");
+    assert_eq!(glyphs[1].glyph, O);
+    assert_eq!(glyphs[1].origin, Vec2::new(0.65, 0.0));
+
+    assert!(matches!(
+        FontFace::parse(b"not a font"),
+        Err(FontError::Malformed(_))
+    ));
+}
+
+#[test]
+fn glyph_runs_scale_to_world_size_and_center_on_ink_bounds() {
+    let run = face().glyph_run(FontId::new(7), "I", 2.0);
+    assert_eq!(run.font, FontId::new(7));
+    assert_eq!(run.text, "I");
+    assert_eq!(run.glyphs.len(), 1);
+    // The bar spans x 0.1..0.3 and y 0..0.7 em.
+    assert!((run.glyphs[0].origin - Vec2::new(-0.4, -0.7)).length() < 1.0e-6);
+    assert!((run.bounds.min - Vec2::new(-0.2, -0.7)).length() < 1.0e-6);
+    assert!((run.bounds.max - Vec2::new(0.2, 0.7)).length() < 1.0e-6);
+
+    let blank = face().glyph_run(FontId::new(0), "  ", 1.0);
+    assert_eq!(blank.glyphs.len(), 2);
+    assert_eq!(blank.bounds.min, Vec2::ZERO);
+    assert_eq!(blank.bounds.max, Vec2::ZERO);
+}
+
+#[test]
+fn outlines_are_reported_in_em_units() {
+    let outline = face().glyph_outline(V).expect("V has an outline");
+    assert_eq!(
+        outline.commands().first(),
+        Some(&PathCommand::MoveTo {
+            to: Vec2::new(0.05, 0.7)
+        })
+    );
+    assert_eq!(outline.commands().last(), Some(&PathCommand::Close));
+    assert!(face().glyph_outline(1).is_none(), "space has no contours");
+}
+
+#[test]
+fn rasterized_glyphs_are_padded_and_cover_their_outline() {
+    let face = face();
+    let bitmap = face.rasterize_glyph(I, 10.0).expect("I rasterizes");
+    assert_eq!((bitmap.width, bitmap.height), (4, 9));
+    assert!((bitmap.origin - Vec2::new(0.0, -0.1)).length() < 1.0e-6);
+    assert!((bitmap.size - Vec2::new(0.4, 0.9)).length() < 1.0e-6);
+    for row in 0..9 {
+        let expected: &[u8] = if (1..8).contains(&row) {
+            &[0, 255, 255, 0]
+        } else {
+            &[0, 0, 0, 0]
+        };
+        assert_eq!(
+            &bitmap.coverage[row * 4..row * 4 + 4],
+            expected,
+            "row {row}"
+        );
+    }
+
+    let ring = face.rasterize_glyph(O, 100.0).expect("O rasterizes");
+    let coverage = |x: u32, y: u32| ring.coverage[(y * ring.width + x) as usize];
+    assert_eq!(ring.width, 62);
+    assert_eq!(coverage(31, 36), 0, "the counter stays empty");
+    assert_eq!(coverage(8, 36), 255, "the ring is solid");
+    assert_eq!(coverage(0, 0), 0, "padding stays transparent");
+
+    assert!(face.rasterize_glyph(1, 10.0).is_none());
+    assert!(face.rasterize_glyph(I, 0.0).is_none());
+}
@@ -0,0 +1,88 @@
+use noon_core::Vec2;
+use noon_geometry::{FontFace, MathError, MathSymbol, FRACTION_RULE_SOURCE, RADICAL_SOURCE};
+
+// Generated by assets/fonts/make_test_font.py. The font lacks `+` and `-`, so
+// operators typeset as the 0.5 em wide `.notdef` box.
+const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+fn typeset(tex: &str) -> Vec<MathSymbol> {
+    FontFace::parse(TEST_FONT)
+        .expect("test font must parse")
+        .typeset_math(tex, 1.0)
+        .expect("formula must typeset")
+}
+
+fn sources(symbols: &[MathSymbol]) -> Vec<&str> {
+    symbols
+        .iter()
+        .map(|symbol| symbol.source.as_str())
+        .collect()
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1.0e-5,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn binary_operators_are_spaced_unless_unary() {
+    let symbols = typeset("I + I");
+    assert_eq!(sources(&symbols), ["I", "+", "I"]);
+    // Two 4/18 em medium spaces around the 0.5 em operator.
+    assert_close(
+        symbols[2].center.x - symbols[0].center.x,
+        0.4 + 0.5 + 2.0 * 4.0 / 18.0,
+    );
+    assert_eq!(symbols[0].path, symbols[2].path, "same glyph, same path");
+    assert_close(symbols[0].center.y, 0.0);
+
+    let unary = typeset("-I");
+    assert_close(unary[1].center.x - unary[0].center.x, 0.45);
+}
+
+#[test]
+fn fractions_stack_around_a_rule_and_scripts_shrink() {
+    let symbols = typeset("\\frac{I}{LO}");
+    assert_eq!(sources(&symbols), ["I", "L", "O", FRACTION_RULE_SOURCE]);
+    let rule = symbols[3].path.conservative_bounds().expect("rule bounds");
+    assert!(symbols[0].center.y > symbols[3].center.y);
+    assert!(symbols[1].center.y < symbols[3].center.y);
+    assert_close(rule.height(), 0.04);
+    assert!(rule.width() > 1.4, "rule spans the wider denominator");
+    assert_close(symbols[0].center.x, symbols[3].center.x);
+
+    let scripted = typeset("I^I_L");
+    let base = scripted[0].path.conservative_bounds().expect("base bounds");
+    let superscript = scripted[1]
+        .path
+        .conservative_bounds()
+        .expect("script bounds");
+    assert_close(superscript.height(), 0.7 * base.height());
+    assert!(scripted[1].center.y > scripted[0].center.y);
+    assert!(scripted[2].center.y < scripted[0].center.y);
+}
+
+#[test]
+fn radicals_cover_their_body_and_errors_surface() {
+    let symbols = typeset("\\sqrt{IV}");
+    assert_eq!(sources(&symbols), [RADICAL_SOURCE, "I", "V"]);
+    let sign = symbols[0].path.conservative_bounds().expect("sign bounds");
+    let sign_top = symbols[0].center.y + sign.max.y;
+    for body in &symbols[1..] {
+        let bounds = body.path.conservative_bounds().expect("body bounds");
+        assert!(body.center.y + bounds.max.y < sign_top);
+        assert!(body.center.x + bounds.max.x < symbols[0].center.x + sign.max.x);
+    }
+
+    let face = FontFace::parse(TEST_FONT).expect("test font must parse");
+    assert_eq!(
+        face.typeset_math("\\frac{I}{", 1.0),
+        Err(MathError::UnmatchedBrace)
+    );
+    let scaled = face.typeset_math("I", 2.0).expect("formula must typeset");
+    assert_eq!(scaled[0].center, Vec2::ZERO);
+    let bounds = scaled[0].path.conservative_bounds().expect("bounds");
+    assert_close(bounds.height(), 1.4);
+}
@@ -0,0 +1,1273 @@
+//! Compact binary transport for scene documents and patch batches.
+//!
+//! The layout mirrors the JSON envelope field for field. Every value is
+//! little-endian, collections and byte strings carry a `u32` length prefix,
+//! identities are raw `u64`s and enum variants are single tag bytes. Path
+//! points and snapshot transforms are written as packed `f32` runs, so large
+//! scenes decode without any text parsing.
+//!
+//! A document starts with a four-byte magic and the binary layout version,
+//! which moves independently of the JSON [`crate::FORMAT_VERSION`] because
+//! additive JSON fields still change the binary layout. Every earlier layout
+//! stays decodable: layout 1 predates labels, which decode as empty. Decoding
+//! is bounds-checked throughout: malformed input is reported as a
+//! [`BinaryError`] and never panics, and collections reserve no more bytes
+//! than the input has left, so a forged length cannot force a large
+//! allocation up front.
+
+use noon_core::{
+    Color, Easing, FontData, FontDefinition, FontId, GeometryId, GeometryRef, GlyphRun, ImageData,
+    ImageDefinition, ImageError, ImageId, ImageSampling, Labels, MotionPath, NumberFormat,
+    NumberGlyph, NumberLayout, ObjectDefinition, ObjectId, ObjectSnapshot, PathCommand,
+    PositionedGlyph, Property, Rect, SceneDefinition, ScenePatch, StrokeCap, StrokeJoin, Style,
+    TrackDefinition, TrackId, TrackTiming, TrackValues, Transform2D, Vec2, VectorPath,
+};
+
+use crate::{ensure_version, IrError, PatchBatch, SceneDocument, FORMAT_VERSION};
+
+/// Layout written by the encoders.
+pub const BINARY_LAYOUT_VERSION: u32 = 2;
+/// Objects, tracks and patches carry no labels.
+const UNLABELLED_LAYOUT: u32 = 1;
+
+const SCENE_MAGIC: [u8; 4] = *b"NSCN";
+const PATCH_BATCH_MAGIC: [u8; 4] = *b"NPAT";
+
+const STYLE_FILL: u8 = 1;
+const STYLE_STROKE: u8 = 2;
+
+const LABELS_NAME: u8 = 1;
+const LABELS_TAGS: u8 = 2;
+const LABELS_METADATA: u8 = 4;
+
+/// Longest chain of morph targets accepted. Decoded chains become nested
+/// boxes, so this bounds the recursion of dropping or comparing them.
+const MAX_PATH_CHAIN: usize = 128;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BinaryError {
+    /// The input does not start with the magic of the expected document.
+    InvalidMagic,
+    /// The input ended while `offset` still expected more bytes.
+    UnexpectedEnd {
+        offset: usize,
+    },
+    /// Bytes remain after a complete document.
+    TrailingBytes {
+        offset: usize,
+    },
+    InvalidTag {
+        kind: &'static str,
+        tag: u8,
+        offset: usize,
+    },
+    InvalidUtf8 {
+        offset: usize,
+    },
+    InvalidChar {
+        value: u32,
+        offset: usize,
+    },
+    /// A path's packed points do not match what its commands consume.
+    PathPointMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    /// Morph targets nest deeper than the decoder accepts.
+    PathChainTooLong,
+    InvalidImage(ImageError),
+    /// A collection or byte string is too long for its `u32` prefix.
+    LengthOverflow(usize),
+}
+
+impl std::fmt::Display for BinaryError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMagic => formatter.write_str("missing Noon binary magic"),
+            Self::UnexpectedEnd { offset } => {
+                write!(formatter, "unexpected end of input at byte {offset}")
+            }
+            Self::TrailingBytes { offset } => {
+                write!(formatter, "trailing bytes after document at byte {offset}")
+            }
+            Self::InvalidTag { kind, tag, offset } => {
+                write!(formatter, "invalid {kind} tag {tag} at byte {offset}")
+            }
+            Self::InvalidUtf8 { offset } => {
+                write!(formatter, "invalid UTF-8 string at byte {offset}")
+            }
+            Self::InvalidChar { value, offset } => {
+                write!(formatter, "invalid character {value:#x} at byte {offset}")
+            }
+            Self::PathPointMismatch { expected, actual } => write!(
+                formatter,
+                "path commands need {expected} coordinates, got {actual}"
+            ),
+            Self::PathChainTooLong => write!(
+                formatter,
+                "morph targets nest deeper than {MAX_PATH_CHAIN} paths"
+            ),
+            Self::InvalidImage(error) => write!(formatter, "invalid image: {error}"),
+            Self::LengthOverflow(length) => {
+                write!(formatter, "length {length} does not fit the binary format")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+impl From<BinaryError> for IrError {
+    fn from(value: BinaryError) -> Self {
+        Self::Binary(value)
+    }
+}
+
+pub fn encode_scene_binary(scene: &SceneDefinition) -> Result<Vec<u8>, IrError> {
+    encode_document_binary(&SceneDocument::from_scene(scene))
+}
+
+pub fn decode_scene_binary(bytes: &[u8]) -> Result<SceneDefinition, IrError> {
+    decode_document_binary(bytes)?.into_scene()
+}
+
+pub(crate) fn encode_document_binary(document: &SceneDocument) -> Result<Vec<u8>, IrError> {
+    ensure_version(document.version)?;
+    let mut encoder = Encoder::new(SCENE_MAGIC, BINARY_LAYOUT_VERSION);
+    encoder.scene_document(document)?;
+    Ok(encoder.bytes)
+}
+
+pub(crate) fn decode_document_binary(bytes: &[u8]) -> Result<SceneDocument, IrError> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.layout_header(SCENE_MAGIC)?;
+    let document = decoder.scene_document(FORMAT_VERSION)?;
+    decoder.finish()?;
+    Ok(document)
+}
+
+pub fn encode_patch_batch_binary(batch: &PatchBatch) -> Result<Vec<u8>, IrError> {
+    batch.validate()?;
+    let mut encoder = Encoder::new(PATCH_BATCH_MAGIC, BINARY_LAYOUT_VERSION);
+    encoder.u64(batch.sequence);
+    encoder.len(batch.patches.len())?;
+    for patch in &batch.patches {
+        encoder.patch(patch)?;
+    }
+    Ok(encoder.bytes)
+}
+
+pub fn decode_patch_batch_binary(bytes: &[u8]) -> Result<PatchBatch, IrError> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.layout_header(PATCH_BATCH_MAGIC)?;
+    let sequence = decoder.u64()?;
+    let patches = decoder.list(Decoder::patch)?;
+    decoder.finish()?;
+    Ok(PatchBatch {
+        version: FORMAT_VERSION,
+        sequence,
+        patches,
+    })
+}
+
+pub(crate) struct Encoder {
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl Encoder {
+    pub(crate) fn new(magic: [u8; 4], version: u32) -> Self {
+        let mut encoder = Self {
+            bytes: magic.to_vec(),
+        };
+        encoder.u32(version);
+        encoder
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(u8::from(value));
+    }
+
+    pub(crate) fn len(&mut self, len: usize) -> Result<(), BinaryError> {
+        let len = u32::try_from(len).map_err(|_| BinaryError::LengthOverflow(len))?;
+        self.u32(len);
+        Ok(())
+    }
+
+    pub(crate) fn byte_string(&mut self, bytes: &[u8]) -> Result<(), BinaryError> {
+        self.len(bytes.len())?;
+        self.bytes.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    fn vec2(&mut self, value: Vec2) {
+        self.f32(value.x);
+        self.f32(value.y);
+    }
+
+    fn rect(&mut self, value: Rect) {
+        self.vec2(value.min);
+        self.vec2(value.max);
+    }
+
+    fn scene_document(&mut self, document: &SceneDocument) -> Result<(), BinaryError> {
+        self.len(document.objects.len())?;
+        for object in &document.objects {
+            self.object(object)?;
+        }
+        self.len(document.tracks.len())?;
+        for track in &document.tracks {
+            self.track(track)?;
+        }
+        self.len(document.images.len())?;
+        for image in &document.images {
+            self.image(image)?;
+        }
+        self.len(document.fonts.len())?;
+        for font in &document.fonts {
+            self.font(font)?;
+        }
+        Ok(())
+    }
+
+    fn object(&mut self, object: &ObjectDefinition) -> Result<(), BinaryError> {
+        self.u64(object.id.get());
+        self.geometry(&object.geometry)?;
+        self.transform(object.transform);
+        self.style(object.style);
+        self.labels(&object.labels)
+    }
+
+    /// Presence flags, then only the parts that are set, so unlabelled
+    /// objects and tracks cost a single byte.
+    fn labels(&mut self, labels: &Labels) -> Result<(), BinaryError> {
+        let mut flags = 0;
+        if labels.name.is_some() {
+            flags |= LABELS_NAME;
+        }
+        if !labels.tags.is_empty() {
+            flags |= LABELS_TAGS;
+        }
+        if !labels.metadata.is_empty() {
+            flags |= LABELS_METADATA;
+        }
+        self.u8(flags);
+        if let Some(name) = &labels.name {
+            self.byte_string(name.as_bytes())?;
+        }
+        if !labels.tags.is_empty() {
+            self.len(labels.tags.len())?;
+            for tag in &labels.tags {
+                self.byte_string(tag.as_bytes())?;
+            }
+        }
+        if !labels.metadata.is_empty() {
+            self.len(labels.metadata.len())?;
+            for (key, value) in &labels.metadata {
+                self.byte_string(key.as_bytes())?;
+                self.byte_string(value.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn snapshot(&mut self, snapshot: &ObjectSnapshot) -> Result<(), BinaryError> {
+        self.geometry(&snapshot.geometry)?;
+        self.transform(snapshot.transform);
+        self.style(snapshot.style);
+        Ok(())
+    }
+
+    fn transform(&mut self, transform: Transform2D) {
+        self.vec2(transform.translation);
+        self.f32(transform.rotation);
+        self.vec2(transform.scale);
+    }
+
+    /// Presence flags and enum tags first, then every float of the style in
+    /// one run. Absent colors take no space.
+    fn style(&mut self, style: Style) {
+        let mut flags = 0;
+        if style.fill.is_some() {
+            flags |= STYLE_FILL;
+        }
+        if style.stroke.is_some() {
+            flags |= STYLE_STROKE;
+        }
+        self.u8(flags);
+        self.u8(match style.stroke_join {
+            StrokeJoin::Round => 0,
+            StrokeJoin::Miter => 1,
+            StrokeJoin::Bevel => 2,
+        });
+        self.u8(match style.stroke_cap {
+            StrokeCap::Round => 0,
+            StrokeCap::Butt => 1,
+            StrokeCap::Square => 2,
+        });
+        self.f32(style.stroke_width);
+        self.f32(style.opacity);
+        for color in [style.fill, style.stroke].into_iter().flatten() {
+            self.color(color);
+        }
+    }
+
+    fn color(&mut self, color: Color) {
+        self.f32(color.red);
+        self.f32(color.green);
+        self.f32(color.blue);
+        self.f32(color.alpha);
+    }
+
+    fn geometry(&mut self, geometry: &GeometryRef) -> Result<(), BinaryError> {
+        match geometry {
+            GeometryRef::Circle { radius } => {
+                self.u8(0);
+                self.f32(*radius);
+            }
+            GeometryRef::Rectangle { size } => {
+                self.u8(1);
+                self.vec2(*size);
+            }
+            GeometryRef::Line { start, end } => {
+                self.u8(2);
+                self.vec2(*start);
+                self.vec2(*end);
+            }
+            GeometryRef::VectorPath(path) => {
+                self.u8(3);
+                self.path(path)?;
+            }
+            GeometryRef::External(id) => {
+                self.u8(4);
+                self.u64(id.get());
+            }
+            GeometryRef::Image {
+                image,
+                size,
+                sampling,
+            } => {
+                self.u8(5);
+                self.u64(image.get());
+                self.vec2(*size);
+                self.u8(match sampling {
+                    ImageSampling::Linear => 0,
+                    ImageSampling::Nearest => 1,
+                });
+            }
+            GeometryRef::GlyphRun(run) => {
+                self.u8(6);
+                self.glyph_run(run)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// A path and its chain of morph targets, written flat so decoding needs
+    /// no recursion. Each path is its command tags followed by the packed
+    /// coordinates those commands consume, then a flag for whether a morph
+    /// target follows.
+    fn path(&mut self, path: &VectorPath) -> Result<(), BinaryError> {
+        let mut next = Some(path);
+        while let Some(path) = next {
+            let mut coordinates = Vec::new();
+            self.len(path.commands().len())?;
+            for command in path.commands() {
+                let (tag, points): (u8, &[Vec2]) = match command {
+                    PathCommand::MoveTo { to } => (0, std::slice::from_ref(to)),
+                    PathCommand::LineTo { to } => (1, std::slice::from_ref(to)),
+                    PathCommand::QuadraticTo { control, to } => (2, &[*control, *to]),
+                    PathCommand::CubicTo {
+                        control1,
+                        control2,
+                        to,
+                    } => (3, &[*control1, *control2, *to]),
+                    PathCommand::Close => (4, &[]),
+                };
+                self.u8(tag);
+                coordinates.extend(points.iter().flat_map(|point| [point.x, point.y]));
+            }
+            self.floats(&coordinates)?;
+            next = path.morph_target();
+            self.bool(next.is_some());
+        }
+        Ok(())
+    }
+
+    fn floats(&mut self, values: &[f32]) -> Result<(), BinaryError> {
+        self.len(values.len())?;
+        for value in values {
+            self.f32(*value);
+        }
+        Ok(())
+    }
+
+    fn glyph_run(&mut self, run: &GlyphRun) -> Result<(), BinaryError> {
+        self.u64(run.font.get());
+        self.f32(run.size);
+        self.byte_string(run.text.as_bytes())?;
+        self.len(run.glyphs.len())?;
+        for glyph in &run.glyphs {
+            self.u16(glyph.glyph);
+        }
+        for glyph in &run.glyphs {
+            self.vec2(glyph.origin);
+        }
+        self.rect(run.bounds);
+        match &run.number {
+            Some(number) => {
+                self.u8(1);
+                self.number_layout(number)?;
+            }
+            None => self.u8(0),
+        }
+        Ok(())
+    }
+
+    fn number_layout(&mut self, number: &NumberLayout) -> Result<(), BinaryError> {
+        self.u8(number.format.decimal_places);
+        self.byte_string(number.format.prefix.as_bytes())?;
+        self.byte_string(number.format.suffix.as_bytes())?;
+        match number.format.group_separator {
+            Some(separator) => {
+                self.u8(1);
+                self.u32(u32::from(separator));
+            }
+            None => self.u8(0),
+        }
+        self.f32(number.value);
+        self.vec2(number.origin);
+        self.len(number.glyphs.len())?;
+        for glyph in &number.glyphs {
+            self.u32(u32::from(glyph.character));
+            self.u16(glyph.glyph);
+            self.f32(glyph.advance);
+            match glyph.bounds {
+                Some(bounds) => {
+                    self.u8(1);
+                    self.rect(bounds);
+                }
+                None => self.u8(0),
+            }
+        }
+        Ok(())
+    }
+
+    fn track(&mut self, track: &TrackDefinition) -> Result<(), BinaryError> {
+        self.u64(track.id.get());
+        self.u64(track.object.get());
+        self.u8(match track.property {
+            Property::Presence => 0,
+            Property::Transform => 1,
+            Property::Position => 2,
+            Property::Rotation => 3,
+            Property::Opacity => 4,
+            Property::Appearance => 5,
+            Property::Reveal => 6,
+            Property::Morph => 7,
+            Property::Value => 8,
+        });
+        match &track.values {
+            TrackValues::Bool { from, to } => {
+                self.u8(0);
+                self.bool(*from);
+                self.bool(*to);
+            }
+            TrackValues::Scalar { from, to } => {
+                self.u8(1);
+                self.f32(*from);
+                self.f32(*to);
+            }
+            TrackValues::Vec2 { from, to } => {
+                self.u8(2);
+                self.vec2(*from);
+                self.vec2(*to);
+            }
+            TrackValues::Object { from, to } => {
+                self.u8(3);
+                self.snapshot(from)?;
+                self.snapshot(to)?;
+            }
+            TrackValues::Motion { from, to, path } => {
+                self.u8(4);
+                self.snapshot(from)?;
+                self.snapshot(to)?;
+                self.motion_path(path)?;
+            }
+        }
+        self.timing(track.timing);
+        self.labels(&track.labels)
+    }
+
+    fn motion_path(&mut self, path: &MotionPath) -> Result<(), BinaryError> {
+        match path {
+            MotionPath::Arc { angle } => {
+                self.u8(0);
+                self.f32(*angle);
+            }
+            MotionPath::Pivot { point, angle } => {
+                self.u8(1);
+                self.vec2(*point);
+                self.f32(*angle);
+            }
+            MotionPath::Path {
+                path,
+                follow_tangent,
+            } => {
+                self.u8(2);
+                self.path(path)?;
+                self.bool(*follow_tangent);
+            }
+        }
+        Ok(())
+    }
+
+    fn timing(&mut self, timing: TrackTiming) {
+        self.f64(timing.start_time);
+        self.f64(timing.duration);
+        self.u8(match timing.easing {
+            Easing::Linear => 0,
+            Easing::EaseInOutCubic => 1,
+        });
+    }
+
+    fn image(&mut self, image: &ImageDefinition) -> Result<(), BinaryError> {
+        self.u64(image.id.get());
+        self.u32(image.data.width());
+        self.u32(image.data.height());
+        self.byte_string(image.data.pixels())
+    }
+
+    fn font(&mut self, font: &FontDefinition) -> Result<(), BinaryError> {
+        self.u64(font.id.get());
+        self.byte_string(font.data.bytes())
+    }
+
+    fn patch(&mut self, patch: &ScenePatch) -> Result<(), BinaryError> {
+        match patch {
+            ScenePatch::CreateObject(object) => {
+                self.u8(0);
+                self.object(object)?;
+            }
+            ScenePatch::RemoveObject(id) => {
+                self.u8(1);
+                self.u64(id.get());
+            }
+            ScenePatch::SetTransform { object, transform } => {
+                self.u8(2);
+                self.u64(object.get());
+                self.transform(*transform);
+            }
+            ScenePatch::SetStyle { object, style } => {
+                self.u8(3);
+                self.u64(object.get());
+                self.style(*style);
+            }
+            ScenePatch::AddTrack(track) => {
+                self.u8(4);
+                self.track(track)?;
+            }
+            ScenePatch::ReplaceTrack(track) => {
+                self.u8(5);
+                self.track(track)?;
+            }
+            ScenePatch::RemoveTrack(id) => {
+                self.u8(6);
+                self.u64(id.get());
+            }
+            ScenePatch::RegisterImage(image) => {
+                self.u8(7);
+                self.image(image)?;
+            }
+            ScenePatch::RegisterFont(font) => {
+                self.u8(8);
+                self.font(font)?;
+            }
+            ScenePatch::SetGeometry { object, geometry } => {
+                self.u8(9);
+                self.u64(object.get());
+                self.geometry(geometry)?;
+            }
+            ScenePatch::MoveObject { object, index } => {
+                self.u8(10);
+                self.u64(object.get());
+                self.u64(*index as u64);
+            }
+            ScenePatch::ReplaceImage(image) => {
+                self.u8(11);
+                self.image(image)?;
+            }
+            ScenePatch::RemoveImage(id) => {
+                self.u8(12);
+                self.u64(id.get());
+            }
+            ScenePatch::ReplaceFont(font) => {
+                self.u8(13);
+                self.font(font)?;
+            }
+            ScenePatch::RemoveFont(id) => {
+                self.u8(14);
+                self.u64(id.get());
+            }
+            ScenePatch::SetLabels { object, labels } => {
+                self.u8(15);
+                self.u64(object.get());
+                self.labels(labels)?;
+            }
+            ScenePatch::SetTrackLabels { track, labels } => {
+                self.u8(16);
+                self.u64(track.get());
+                self.labels(labels)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    layout: u32,
+}
+
+impl<'a> Decoder<'a> {
+    pub(crate) const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            layout: BINARY_LAYOUT_VERSION,
+        }
+    }
+
+    /// Reads a scene or patch batch header and decodes the rest of the input
+    /// with the layout it names.
+    fn layout_header(&mut self, magic: [u8; 4]) -> Result<(), IrError> {
+        let layout = self.header(magic)?;
+        if !(UNLABELLED_LAYOUT..=BINARY_LAYOUT_VERSION).contains(&layout) {
+            return Err(IrError::UnsupportedVersion(layout));
+        }
+        self.layout = layout;
+        Ok(())
+    }
+
+    pub(crate) fn header(&mut self, magic: [u8; 4]) -> Result<u32, BinaryError> {
+        if self.bytes.get(..4) != Some(&magic[..]) {
+            return Err(BinaryError::InvalidMagic);
+        }
+        self.offset = 4;
+        self.u32()
+    }
+
+    pub(crate) fn finish(&self) -> Result<(), BinaryError> {
+        if self.offset == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(BinaryError::TrailingBytes {
+                offset: self.offset,
+            })
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BinaryError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(BinaryError::UnexpectedEnd {
+                offset: self.offset,
+            })?;
+        let bytes = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], BinaryError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u8(&mut self) -> Result<u8, BinaryError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, BinaryError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, BinaryError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, BinaryError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    fn f32(&mut self) -> Result<f32, BinaryError> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+
+    fn f64(&mut self) -> Result<f64, BinaryError> {
+        Ok(f64::from_le_bytes(self.array()?))
+    }
+
+    /// Reads a tag byte, reporting it as `kind` when it exceeds `max`.
+    fn tag(&mut self, kind: &'static str, max: u8) -> Result<u8, BinaryError> {
+        let offset = self.offset;
+        let tag = self.u8()?;
+        if tag <= max {
+            Ok(tag)
+        } else {
+            Err(BinaryError::InvalidTag { kind, tag, offset })
+        }
+    }
+
+    fn bool(&mut self) -> Result<bool, BinaryError> {
+        Ok(self.tag("bool", 1)? == 1)
+    }
+
+    /// Collection length. Every element takes at least one byte, so the
+    /// returned length never exceeds the remaining input. Decoded items may
+    /// be larger than their encoding; reserve through [`Self::capacity`].
+    fn len(&mut self) -> Result<usize, BinaryError> {
+        let offset = self.offset;
+        let len = self.u32()? as usize;
+        if len <= self.bytes.len() - self.offset {
+            Ok(len)
+        } else {
+            Err(BinaryError::UnexpectedEnd { offset })
+        }
+    }
+
+    /// Room for `len` decoded items of `T`, capped at the remaining input
+    /// in bytes. Longer lists grow while they decode, backed by real input.
+    fn capacity<T>(&self, len: usize) -> usize {
+        len.min((self.bytes.len() - self.offset) / std::mem::size_of::<T>().max(1))
+    }
+
+    pub(crate) fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, BinaryError>,
+    ) -> Result<Vec<T>, BinaryError> {
+        let len = self.len()?;
+        let mut items = Vec::with_capacity(self.capacity::<T>(len));
+        for _ in 0..len {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    pub(crate) fn byte_string(&mut self) -> Result<&'a [u8], BinaryError> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Result<String, BinaryError> {
+        let offset = self.offset;
+        let bytes = self.byte_string()?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| BinaryError::InvalidUtf8 { offset })
+    }
+
+    fn char(&mut self) -> Result<char, BinaryError> {
+        let offset = self.offset;
+        let value = self.u32()?;
+        char::from_u32(value).ok_or(BinaryError::InvalidChar { value, offset })
+    }
+
+    fn vec2(&mut self) -> Result<Vec2, BinaryError> {
+        Ok(Vec2::new(self.f32()?, self.f32()?))
+    }
+
+    fn rect(&mut self) -> Result<Rect, BinaryError> {
+        Ok(Rect::new(self.vec2()?, self.vec2()?))
+    }
+
+    fn scene_document(&mut self, version: u32) -> Result<SceneDocument, BinaryError> {
+        Ok(SceneDocument {
+            version,
+            objects: self.list(Self::object)?,
+            tracks: self.list(Self::track)?,
+            images: self.list(Self::image)?,
+            fonts: self.list(Self::font)?,
+        })
+    }
+
+    fn object(&mut self) -> Result<ObjectDefinition, BinaryError> {
+        Ok(ObjectDefinition {
+            id: ObjectId::new(self.u64()?),
+            geometry: self.geometry()?,
+            transform: self.transform()?,
+            style: self.style()?,
+            labels: self.labels()?,
+        })
+    }
+
+    fn labels(&mut self) -> Result<Labels, BinaryError> {
+        if self.layout == UNLABELLED_LAYOUT {
+            return Ok(Labels::new());
+        }
+        let flags = self.tag("label flags", LABELS_NAME | LABELS_TAGS | LABELS_METADATA)?;
+        let mut labels = Labels::new();
+        if flags & LABELS_NAME != 0 {
+            labels.name = Some(self.string()?);
+        }
+        if flags & LABELS_TAGS != 0 {
+            labels.tags = self.list(Self::string)?;
+        }
+        if flags & LABELS_METADATA != 0 {
+            labels.metadata = self
+                .list(|decoder| Ok((decoder.string()?, decoder.string()?)))?
+                .into_iter()
+                .collect();
+        }
+        Ok(labels)
+    }
+
+    fn snapshot(&mut self) -> Result<ObjectSnapshot, BinaryError> {
+        Ok(ObjectSnapshot {
+            geometry: self.geometry()?,
+            transform: self.transform()?,
+            style: self.style()?,
+        })
+    }
+
+    fn transform(&mut self) -> Result<Transform2D, BinaryError> {
+        Ok(Transform2D {
+            translation: self.vec2()?,
+            rotation: self.f32()?,
+            scale: self.vec2()?,
+        })
+    }
+
+    fn style(&mut self) -> Result<Style, BinaryError> {
+        let flags = self.tag("style flags", STYLE_FILL | STYLE_STROKE)?;
+        let stroke_join = match self.tag("stroke join", 2)? {
+            0 => StrokeJoin::Round,
+            1 => StrokeJoin::Miter,
+            _ => StrokeJoin::Bevel,
+        };
+        let stroke_cap = match self.tag("stroke cap", 2)? {
+            0 => StrokeCap::Round,
+            1 => StrokeCap::Butt,
+            _ => StrokeCap::Square,
+        };
+        let stroke_width = self.f32()?;
+        let opacity = self.f32()?;
+        let fill = (flags & STYLE_FILL != 0)
+            .then(|| self.color())
+            .transpose()?;
+        let stroke = (flags & STYLE_STROKE != 0)
+            .then(|| self.color())
+            .transpose()?;
+        Ok(Style {
+            fill,
+            stroke,
+            stroke_width,
+            stroke_join,
+            stroke_cap,
+            opacity,
+        })
+    }
+
+    fn color(&mut self) -> Result<Color, BinaryError> {
+        Ok(Color {
+            red: self.f32()?,
+            green: self.f32()?,
+            blue: self.f32()?,
+            alpha: self.f32()?,
+        })
+    }
+
+    fn geometry(&mut self) -> Result<GeometryRef, BinaryError> {
+        Ok(match self.tag("geometry", 6)? {
+            0 => GeometryRef::Circle {
+                radius: self.f32()?,
+            },
+            1 => GeometryRef::Rectangle { size: self.vec2()? },
+            2 => GeometryRef::Line {
+                start: self.vec2()?,
+                end: self.vec2()?,
+            },
+            3 => GeometryRef::VectorPath(self.path()?),
+            4 => GeometryRef::External(GeometryId::new(self.u64()?)),
+            5 => GeometryRef::Image {
+                image: ImageId::new(self.u64()?),
+                size: self.vec2()?,
+                sampling: match self.tag("image sampling", 1)? {
+                    0 => ImageSampling::Linear,
+                    _ => ImageSampling::Nearest,
+                },
+            },
+            _ => GeometryRef::glyph_run(self.glyph_run()?),
+        })
+    }
+
+    fn path(&mut self) -> Result<VectorPath, BinaryError> {
+        let mut chain = vec![self.single_path()?];
+        while self.bool()? {
+            if chain.len() == MAX_PATH_CHAIN {
+                return Err(BinaryError::PathChainTooLong);
+            }
+            chain.push(self.single_path()?);
+        }
+        // Each path morphs into the next one, so fold the chain from its end.
+        let mut paths = chain.into_iter().rev();
+        let last = paths.next().unwrap_or_default();
+        Ok(paths.fold(last, |target, path| path.with_morph_target(target)))
+    }
+
+    fn single_path(&mut self) -> Result<VectorPath, BinaryError> {
+        let len = self.len()?;
+        let tags_offset = self.offset;
+        let tags = self.take(len)?;
+        let coordinates = self.list(Self::f32)?;
+        let mut expected = 0;
+        for (index, tag) in tags.iter().enumerate() {
+            expected += match tag {
+                0 | 1 => 2,
+                2 => 4,
+                3 => 6,
+                4 => 0,
+                _ => {
+                    return Err(BinaryError::InvalidTag {
+                        kind: "path command",
+                        tag: *tag,
+                        offset: tags_offset + index,
+                    })
+                }
+            };
+        }
+        if expected != coordinates.len() {
+            return Err(BinaryError::PathPointMismatch {
+                expected,
+                actual: coordinates.len(),
+            });
+        }
+        let mut points = coordinates
+            .chunks_exact(2)
+            .map(|pair| Vec2::new(pair[0], pair[1]));
+        let mut next = || points.next().unwrap_or(Vec2::ZERO);
+        let mut path = VectorPath::new();
+        for tag in tags {
+            path = match tag {
+                0 => path.move_to(next()),
+                1 => path.line_to(next()),
+                2 => path.quadratic_to(next(), next()),
+                3 => path.cubic_to(next(), next(), next()),
+                _ => path.close(),
+            };
+        }
+        Ok(path)
+    }
+
+    fn glyph_run(&mut self) -> Result<GlyphRun, BinaryError> {
+        let font = FontId::new(self.u64()?);
+        let size = self.f32()?;
+        let text = self.string()?;
+        let glyph_ids = self.list(Self::u16)?;
+        let mut glyphs = Vec::with_capacity(self.capacity::<PositionedGlyph>(glyph_ids.len()));
+        for glyph in glyph_ids {
+            glyphs.push(PositionedGlyph {
+                glyph,
+                origin: self.vec2()?,
+            });
+        }
+        let bounds = self.rect()?;
+        let number = match self.tag("option", 1)? {
+            0 => None,
+            _ => Some(Box::new(self.number_layout()?)),
+        };
+        Ok(GlyphRun {
+            font,
+            size,
+            text,
+            glyphs,
+            bounds,
+            number,
+        })
+    }
+
+    fn number_layout(&mut self) -> Result<NumberLayout, BinaryError> {
+        let format = NumberFormat {
+            decimal_places: self.u8()?,
+            prefix: self.string()?,
+            suffix: self.string()?,
+            group_separator: match self.tag("option", 1)? {
+                0 => None,
+                _ => Some(self.char()?),
+            },
+        };
+        Ok(NumberLayout {
+            format,
+            value: self.f32()?,
+            origin: self.vec2()?,
+            glyphs: self.list(|decoder| {
+                Ok(NumberGlyph {
+                    character: decoder.char()?,
+                    glyph: decoder.u16()?,
+                    advance: decoder.f32()?,
+                    bounds: match decoder.tag("option", 1)? {
+                        0 => None,
+                        _ => Some(decoder.rect()?),
+                    },
+                })
+            })?,
+        })
+    }
+
+    fn track(&mut self) -> Result<TrackDefinition, BinaryError> {
+        let id = TrackId::new(self.u64()?);
+        let object = ObjectId::new(self.u64()?);
+        let property = match self.tag("property", 8)? {
+            0 => Property::Presence,
+            1 => Property::Transform,
+            2 => Property::Position,
+            3 => Property::Rotation,
+            4 => Property::Opacity,
+            5 => Property::Appearance,
+            6 => Property::Reveal,
+            7 => Property::Morph,
+            _ => Property::Value,
+        };
+        let values = match self.tag("track values", 4)? {
+            0 => TrackValues::Bool {
+                from: self.bool()?,
+                to: self.bool()?,
+            },
+            1 => TrackValues::Scalar {
+                from: self.f32()?,
+                to: self.f32()?,
+            },
+            2 => TrackValues::Vec2 {
+                from: self.vec2()?,
+                to: self.vec2()?,
+            },
+            3 => TrackValues::Object {
+                from: self.snapshot()?,
+                to: self.snapshot()?,
+            },
+            _ => TrackValues::Motion {
+                from: self.snapshot()?,
+                to: self.snapshot()?,
+                path: self.motion_path()?,
+            },
+        };
+        Ok(TrackDefinition {
+            id,
+            object,
+            property,
+            values,
+            timing: self.timing()?,
+            labels: self.labels()?,
+        })
+    }
+
+    fn motion_path(&mut self) -> Result<MotionPath, BinaryError> {
+        Ok(match self.tag("motion path", 2)? {
+            0 => MotionPath::Arc { angle: self.f32()? },
+            1 => MotionPath::Pivot {
+                point: self.vec2()?,
+                angle: self.f32()?,
+            },
+            _ => MotionPath::Path {
+                path: self.path()?,
+                follow_tangent: self.bool()?,
+            },
+        })
+    }
+
+    fn timing(&mut self) -> Result<TrackTiming, BinaryError> {
+        Ok(TrackTiming {
+            start_time: self.f64()?,
+            duration: self.f64()?,
+            easing: match self.tag("easing", 1)? {
+                0 => Easing::Linear,
+                _ => Easing::EaseInOutCubic,
+            },
+        })
+    }
+
+    fn image(&mut self) -> Result<ImageDefinition, BinaryError> {
+        let id = ImageId::new(self.u64()?);
+        let width = self.u32()?;
+        let height = self.u32()?;
+        let pixels = self.byte_string()?.to_vec();
+        let data = ImageData::new(width, height, pixels).map_err(BinaryError::InvalidImage)?;
+        Ok(ImageDefinition { id, data })
+    }
+
+    fn font(&mut self) -> Result<FontDefinition, BinaryError> {
+        Ok(FontDefinition {
+            id: FontId::new(self.u64()?),
+            data: FontData::new(self.byte_string()?.to_vec()),
+        })
+    }
+
+    fn patch(&mut self) -> Result<ScenePatch, BinaryError> {
+        Ok(match self.tag("patch", 16)? {
+            0 => ScenePatch::CreateObject(self.object()?),
+            1 => ScenePatch::RemoveObject(ObjectId::new(self.u64()?)),
+            2 => ScenePatch::SetTransform {
+                object: ObjectId::new(self.u64()?),
+                transform: self.transform()?,
+            },
+            3 => ScenePatch::SetStyle {
+                object: ObjectId::new(self.u64()?),
+                style: self.style()?,
+            },
+            4 => ScenePatch::AddTrack(self.track()?),
+            5 => ScenePatch::ReplaceTrack(self.track()?),
+            6 => ScenePatch::RemoveTrack(TrackId::new(self.u64()?)),
+            7 => ScenePatch::RegisterImage(self.image()?),
+            8 => ScenePatch::RegisterFont(self.font()?),
+            9 => ScenePatch::SetGeometry {
+                object: ObjectId::new(self.u64()?),
+                geometry: self.geometry()?,
+            },
+            10 => ScenePatch::MoveObject {
+                object: ObjectId::new(self.u64()?),
+                // Indices beyond the platform's reach can never be valid and
+                // are rejected when the patch is applied.
+                index: usize::try_from(self.u64()?).unwrap_or(usize::MAX),
+            },
+            11 => ScenePatch::ReplaceImage(self.image()?),
+            12 => ScenePatch::RemoveImage(ImageId::new(self.u64()?)),
+            13 => ScenePatch::ReplaceFont(self.font()?),
+            14 => ScenePatch::RemoveFont(FontId::new(self.u64()?)),
+            15 => ScenePatch::SetLabels {
+                object: ObjectId::new(self.u64()?),
+                labels: self.labels()?,
+            },
+            _ => ScenePatch::SetTrackLabels {
+                track: TrackId::new(self.u64()?),
+                labels: self.labels()?,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forged_list_lengths_reserve_at_most_the_remaining_input() {
+        // A one-byte-per-item length prefix claiming 64 objects.
+        let mut bytes = 64u32.to_le_bytes().to_vec();
+        bytes.extend([0; 64]);
+        let mut decoder = Decoder::new(&bytes);
+        let len = decoder.len().expect("length fits the input");
+        assert_eq!(len, 64);
+        let reserved = decoder.capacity::<ObjectDefinition>(len);
+        assert!(reserved * std::mem::size_of::<ObjectDefinition>() <= 64);
+        assert_eq!(decoder.capacity::<u8>(len), 64);
+        assert!(Decoder::new(&bytes).list(Decoder::object).is_err());
+    }
+
+    #[test]
+    fn documents_start_with_magic_and_little_endian_version() {
+        let mut scene = SceneDefinition::new();
+        scene.add(GeometryRef::circle(1.5));
+        let bytes = encode_scene_binary(&scene).expect("scene must encode");
+
+        assert_eq!(&bytes[..4], b"NSCN");
+        assert_eq!(&bytes[4..8], &BINARY_LAYOUT_VERSION.to_le_bytes());
+        // Object count, then the id as a raw u64 and the circle tag.
+        assert_eq!(&bytes[8..12], &1_u32.to_le_bytes());
+        assert_eq!(&bytes[12..20], &0_u64.to_le_bytes());
+        assert_eq!(bytes[20], 0);
+        assert_eq!(&bytes[21..25], &1.5_f32.to_le_bytes());
+
+        let batch = encode_patch_batch_binary(&PatchBatch::new(3, Vec::new())).unwrap();
+        assert_eq!(&batch[..4], b"NPAT");
+        assert!(matches!(
+            decode_scene_binary(&batch),
+            Err(IrError::Binary(BinaryError::InvalidMagic))
+        ));
+    }
+
+    #[test]
+    fn malformed_input_is_rejected_with_its_offset() {
+        let mut bytes = encode_patch_batch_binary(&PatchBatch::new(
+            1,
+            vec![ScenePatch::RemoveObject(ObjectId::new(4))],
+        ))
+        .unwrap();
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            decode_patch_batch_binary(&trailing),
+            Err(IrError::Binary(BinaryError::TrailingBytes { offset: 29 }))
+        ));
+
+        bytes[20] = 17;
+        assert!(matches!(
+            decode_patch_batch_binary(&bytes),
+            Err(IrError::Binary(BinaryError::InvalidTag {
+                kind: "patch",
+                tag: 17,
+                offset: 20,
+            }))
+        ));
+
+        // A huge collection length is refused before anything is allocated.
+        bytes[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            decode_patch_batch_binary(&bytes),
+            Err(IrError::Binary(BinaryError::UnexpectedEnd { offset: 16 }))
+        ));
+
+        bytes[4..8].copy_from_slice(&7_u32.to_le_bytes());
+        assert!(matches!(
+            decode_patch_batch_binary(&bytes),
+            Err(IrError::UnsupportedVersion(7))
+        ));
+    }
+
+    #[test]
+    fn morph_chains_decode_without_recursion_up_to_a_limit() {
+        let step = VectorPath::new().move_to(Vec2::ZERO);
+        let chain = |depth: usize| {
+            (1..depth).fold(step.clone(), |target, _| {
+                step.clone().with_morph_target(target)
+            })
+        };
+        let mut scene = SceneDefinition::new();
+        scene.add(GeometryRef::path(chain(MAX_PATH_CHAIN)));
+        let bytes = encode_scene_binary(&scene).unwrap();
+        let decoded = decode_scene_binary(&bytes).unwrap();
+        assert_eq!(decoded.objects(), scene.objects());
+
+        let mut scene = SceneDefinition::new();
+        scene.add(GeometryRef::path(chain(MAX_PATH_CHAIN + 1)));
+        let bytes = encode_scene_binary(&scene).unwrap();
+        assert!(matches!(
+            decode_scene_binary(&bytes),
+            Err(IrError::Binary(BinaryError::PathChainTooLong))
+        ));
+    }
+}
@@ -0,0 +1,384 @@
+//! Self-contained scene bundles.
+//!
+//! A bundle is one file holding everything a scene needs to play offline:
+//! a JSON manifest, the binary scene document with its images and fonts
+//! taken out, and each asset stored once under its SHA-256 content hash.
+//!
+//! The container reuses the binary codec's conventions: the `NBDL` magic and
+//! format version, then the manifest, the scene and the asset list, each a
+//! `u32`-prefixed byte string. Every stored blob is checked against the hash
+//! and size the manifest records before anything is decoded from it.
+
+use std::collections::HashMap;
+
+use noon_core::{
+    FontData, FontDefinition, FontId, ImageData, ImageDefinition, ImageError, ImageId,
+    SceneDefinition,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::binary::{decode_document_binary, encode_document_binary, Decoder, Encoder};
+use crate::{ensure_version, IrError, SceneDocument, FORMAT_VERSION};
+
+const BUNDLE_MAGIC: [u8; 4] = *b"NBDL";
+
+/// SHA-256 digest of stored bytes, written as `sha256:<hex>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ContentHash([u8; 32]);
+
+impl ContentHash {
+    pub fn of(bytes: &[u8]) -> Self {
+        Self(Sha256::digest(bytes).into())
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ContentHash {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("sha256:")?;
+        for byte in self.0 {
+            write!(formatter, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ContentHash> for String {
+    fn from(value: ContentHash) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for ContentHash {
+    type Error = BundleError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let invalid = || BundleError::InvalidHash(value.clone());
+        let hex = value.strip_prefix("sha256:").ok_or_else(invalid)?;
+        if hex.len() != 64 {
+            return Err(invalid());
+        }
+        let mut digest = [0; 32];
+        for (byte, pair) in digest.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+        }
+        Ok(Self(digest))
+    }
+}
+
+/// Hash and length of one stored blob.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    pub hash: ContentHash,
+    pub size: u64,
+}
+
+impl BundleEntry {
+    fn of(bytes: &[u8]) -> Self {
+        Self {
+            hash: ContentHash::of(bytes),
+            size: bytes.len() as u64,
+        }
+    }
+
+    fn verify(&self, bytes: &[u8]) -> Result<(), BundleError> {
+        let actual = Self::of(bytes);
+        if actual == *self {
+            Ok(())
+        } else {
+            Err(BundleError::EntryMismatch {
+                expected: *self,
+                actual,
+            })
+        }
+    }
+}
+
+/// Straight RGBA8 pixels stored under `hash`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledImage {
+    pub id: ImageId,
+    pub width: u32,
+    pub height: u32,
+    pub hash: ContentHash,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledFont {
+    pub id: FontId,
+    pub hash: ContentHash,
+}
+
+/// Table of contents of a bundle, readable without decoding the scene.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub version: u32,
+    /// End of the last track in seconds, so players can size their loop
+    /// without decoding the scene. Zero for still scenes and for bundles
+    /// written before the field existed.
+    #[serde(default)]
+    pub duration: f64,
+    /// The binary scene document, stored without its images and fonts.
+    pub scene: BundleEntry,
+    /// Stored asset blobs in container order. Identical bytes are stored
+    /// once however many images or fonts share them.
+    pub assets: Vec<BundleEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<BundledImage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fonts: Vec<BundledFont>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BundleError {
+    /// A manifest hash is not `sha256:` followed by 64 hex digits.
+    InvalidHash(String),
+    /// Stored bytes do not match the hash and size the manifest records.
+    EntryMismatch {
+        expected: BundleEntry,
+        actual: BundleEntry,
+    },
+    /// The container stores a different number of assets than listed.
+    AssetCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    /// An image or font refers to a hash no stored asset has.
+    MissingAsset(ContentHash),
+    InvalidImage {
+        id: ImageId,
+        error: ImageError,
+    },
+}
+
+impl std::fmt::Display for BundleError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHash(value) => write!(formatter, "invalid content hash {value:?}"),
+            Self::EntryMismatch { expected, actual } => write!(
+                formatter,
+                "stored bytes hash to {} ({} bytes), manifest expects {} ({} bytes)",
+                actual.hash, actual.size, expected.hash, expected.size
+            ),
+            Self::AssetCountMismatch { expected, actual } => write!(
+                formatter,
+                "bundle stores {actual} assets, manifest lists {expected}"
+            ),
+            Self::MissingAsset(hash) => write!(formatter, "no stored asset has hash {hash}"),
+            Self::InvalidImage { id, error } => {
+                write!(formatter, "invalid image {}: {error}", id.get())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+impl From<BundleError> for IrError {
+    fn from(value: BundleError) -> Self {
+        Self::Bundle(value)
+    }
+}
+
+/// True when `bytes` start like a bundle, for loaders that accept several
+/// encodings.
+pub fn is_bundle(bytes: &[u8]) -> bool {
+    bytes.starts_with(&BUNDLE_MAGIC)
+}
+
+pub fn encode_bundle(scene: &SceneDefinition) -> Result<Vec<u8>, IrError> {
+    let mut document = SceneDocument::from_scene(scene);
+    let images = std::mem::take(&mut document.images);
+    let fonts = std::mem::take(&mut document.fonts);
+    let scene_bytes = encode_document_binary(&document)?;
+
+    let mut assets: Vec<&[u8]> = Vec::new();
+    let mut entries: Vec<BundleEntry> = Vec::new();
+    let mut store = |bytes| {
+        let entry = BundleEntry::of(bytes);
+        if !entries.contains(&entry) {
+            entries.push(entry);
+            assets.push(bytes);
+        }
+        entry.hash
+    };
+    let bundled_images = images
+        .iter()
+        .map(|image| BundledImage {
+            id: image.id,
+            width: image.data.width(),
+            height: image.data.height(),
+            hash: store(image.data.pixels()),
+        })
+        .collect();
+    let bundled_fonts = fonts
+        .iter()
+        .map(|font| BundledFont {
+            id: font.id,
+            hash: store(font.data.bytes()),
+        })
+        .collect();
+    let manifest = BundleManifest {
+        version: FORMAT_VERSION,
+        duration: scene
+            .tracks()
+            .iter()
+            .map(|track| track.timing.start_time + track.timing.duration)
+            .fold(0.0, f64::max),
+        scene: BundleEntry::of(&scene_bytes),
+        assets: entries,
+        images: bundled_images,
+        fonts: bundled_fonts,
+    };
+
+    let mut encoder = Encoder::new(BUNDLE_MAGIC, manifest.version);
+    encoder.byte_string(&serde_json::to_vec(&manifest)?)?;
+    encoder.byte_string(&scene_bytes)?;
+    encoder.len(assets.len())?;
+    for asset in assets {
+        encoder.byte_string(asset)?;
+    }
+    Ok(encoder.bytes)
+}
+
+pub fn decode_bundle(bytes: &[u8]) -> Result<SceneDefinition, IrError> {
+    let mut decoder = Decoder::new(bytes);
+    let manifest = read_manifest(&mut decoder)?;
+    let scene_bytes = decoder.byte_string()?;
+    let assets = decoder.list(Decoder::byte_string)?;
+    decoder.finish()?;
+
+    manifest.scene.verify(scene_bytes)?;
+    if assets.len() != manifest.assets.len() {
+        return Err(BundleError::AssetCountMismatch {
+            expected: manifest.assets.len(),
+            actual: assets.len(),
+        }
+        .into());
+    }
+    let mut stored = HashMap::with_capacity(assets.len());
+    for (entry, asset) in manifest.assets.iter().zip(assets) {
+        entry.verify(asset)?;
+        stored.insert(entry.hash, asset);
+    }
+    let asset = |hash: ContentHash| {
+        stored
+            .get(&hash)
+            .map(|bytes| bytes.to_vec())
+            .ok_or(BundleError::MissingAsset(hash))
+    };
+
+    let mut document = decode_document_binary(scene_bytes)?;
+    for image in &manifest.images {
+        let data =
+            ImageData::new(image.width, image.height, asset(image.hash)?).map_err(|error| {
+                BundleError::InvalidImage {
+                    id: image.id,
+                    error,
+                }
+            })?;
+        document.images.push(ImageDefinition { id: image.id, data });
+    }
+    for font in &manifest.fonts {
+        document.fonts.push(FontDefinition {
+            id: font.id,
+            data: FontData::new(asset(font.hash)?),
+        });
+    }
+    document.into_scene()
+}
+
+/// Reads only the manifest, leaving the scene and assets undecoded.
+pub fn read_bundle_manifest(bytes: &[u8]) -> Result<BundleManifest, IrError> {
+    read_manifest(&mut Decoder::new(bytes))
+}
+
+fn read_manifest(decoder: &mut Decoder<'_>) -> Result<BundleManifest, IrError> {
+    ensure_version(decoder.header(BUNDLE_MAGIC)?)?;
+    let manifest: BundleManifest = serde_json::from_slice(decoder.byte_string()?)?;
+    ensure_version(manifest.version)?;
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::GeometryRef;
+
+    use super::*;
+    use crate::BinaryError;
+
+    fn scene() -> SceneDefinition {
+        let mut scene = SceneDefinition::new();
+        let pixels = ImageData::new(1, 1, vec![200, 100, 50, 255]).unwrap();
+        let image = scene.register_image(pixels.clone());
+        scene.register_image(pixels);
+        scene.register_font(FontData::new(vec![0, 1, 0, 0]));
+        let object = scene.add(GeometryRef::Image {
+            image,
+            size: noon_core::Vec2::ONE,
+            sampling: noon_core::ImageSampling::Linear,
+        });
+        scene
+            .animate_position(
+                object,
+                noon_core::Vec2::ZERO,
+                noon_core::Vec2::ONE,
+                noon_core::TrackTiming::new(0.5, 2.0, noon_core::Easing::Linear),
+            )
+            .unwrap();
+        scene
+    }
+
+    #[test]
+    fn identical_assets_are_stored_once() {
+        let bytes = encode_bundle(&scene()).unwrap();
+        let manifest = read_bundle_manifest(&bytes).unwrap();
+
+        assert!(is_bundle(&bytes));
+        assert_eq!(manifest.duration, 2.5);
+        assert_eq!(manifest.images.len(), 2);
+        assert_eq!(manifest.images[0].hash, manifest.images[1].hash);
+        assert_eq!(manifest.assets.len(), 2);
+        assert_eq!(
+            manifest.images[0].hash,
+            ContentHash::of(&[200, 100, 50, 255])
+        );
+        assert_eq!(decode_bundle(&bytes).unwrap(), scene());
+    }
+
+    #[test]
+    fn hashes_round_trip_through_their_text_form() {
+        let hash = ContentHash::of(b"noon");
+        let text = hash.to_string();
+        assert!(text.starts_with("sha256:"));
+        assert_eq!(ContentHash::try_from(text), Ok(hash));
+        assert_eq!(
+            ContentHash::try_from("sha256:zz".to_owned()),
+            Err(BundleError::InvalidHash("sha256:zz".to_owned()))
+        );
+    }
+
+    #[test]
+    fn corrupted_assets_are_rejected_before_decoding() {
+        let mut bytes = encode_bundle(&scene()).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        assert!(matches!(
+            decode_bundle(&bytes),
+            Err(IrError::Bundle(BundleError::EntryMismatch { .. }))
+        ));
+
+        let scene_bytes = crate::encode_scene_binary(&scene()).unwrap();
+        assert!(!is_bundle(&scene_bytes));
+        assert!(matches!(
+            decode_bundle(&scene_bytes),
+            Err(IrError::Binary(BinaryError::InvalidMagic))
+        ));
+    }
+}
@@ -1,12 +1,29 @@
 //! Versioned language-neutral interchange for Noon authoring and live control.
 //!
-//! JSON is the initial transport because it is easy to inspect from Python and
+//! JSON is the default transport because it is easy to inspect from Python and
 //! browser tooling. The versioned envelope keeps the runtime protocol independent
-//! of that encoding so a compact binary representation can be added later.
+//! of that encoding; [`encode_scene_binary`] and friends write the same documents
+//! in a compact binary form for large scenes, and [`encode_bundle`] packs a
+//! scene with its assets into one self-contained file.
 
 #![forbid(unsafe_code)]
 
-use noon_core::{ObjectDefinition, PatchError, SceneDefinition, ScenePatch, TrackDefinition};
+mod binary;
+mod bundle;
+mod migrate;
+mod session;
+mod validate;
+
+pub use binary::*;
+pub use bundle::*;
+pub use migrate::*;
+pub use session::*;
+pub use validate::*;
+
+use noon_core::{
+    DiffReport, FontDefinition, ImageDefinition, ObjectDefinition, PatchError, SceneDefinition,
+    ScenePatch, TrackDefinition,
+};
 use serde::{Deserialize, Serialize};
 
 pub const FORMAT_VERSION: u32 = 1;
@@ -16,6 +33,13 @@ pub struct SceneDocument {
     pub version: u32,
     pub objects: Vec<ObjectDefinition>,
     pub tracks: Vec<TrackDefinition>,
+    /// Registered raster data referenced by image geometry. Omitted when empty
+    /// so vector-only documents keep their original shape.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ImageDefinition>,
+    /// Registered font files referenced by glyph runs, omitted when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fonts: Vec<FontDefinition>,
 }
 
 impl SceneDocument {
@@ -24,12 +48,26 @@ impl SceneDocument {
             version: FORMAT_VERSION,
             objects: scene.objects().to_vec(),
             tracks: scene.tracks().to_vec(),
+            images: scene.images().to_vec(),
+            fonts: scene.fonts().to_vec(),
         }
     }
 
     pub fn into_scene(self) -> Result<SceneDefinition, IrError> {
         ensure_version(self.version)?;
-        SceneDefinition::from_parts(self.objects, self.tracks).map_err(IrError::Patch)
+        let mut scene =
+            SceneDefinition::from_parts(self.objects, self.tracks).map_err(IrError::Patch)?;
+        for image in self.images {
+            scene
+                .apply_patch(ScenePatch::RegisterImage(image))
+                .map_err(IrError::Patch)?;
+        }
+        for font in self.fonts {
+            scene
+                .apply_patch(ScenePatch::RegisterFont(font))
+                .map_err(IrError::Patch)?;
+        }
+        Ok(scene)
     }
 }
 
@@ -58,7 +96,20 @@ impl PatchBatch {
 pub enum IrError {
     UnsupportedVersion(u32),
     Json(serde_json::Error),
+    Binary(BinaryError),
+    Bundle(BundleError),
+    /// A migration step could not upgrade a document from `version`.
+    Migration {
+        version: u32,
+        reason: String,
+    },
     Patch(PatchError),
+    /// A session log line could not be decoded; `line` is 1-based, or 0 when
+    /// the log has no events at all.
+    SessionLog {
+        line: usize,
+        reason: String,
+    },
 }
 
 impl std::fmt::Display for IrError {
@@ -68,7 +119,21 @@ impl std::fmt::Display for IrError {
                 write!(formatter, "unsupported Noon IR version {version}")
             }
             Self::Json(error) => write!(formatter, "invalid Noon JSON: {error}"),
+            Self::Binary(error) => write!(formatter, "invalid Noon binary: {error}"),
+            Self::Bundle(error) => write!(formatter, "invalid Noon bundle: {error}"),
+            Self::Migration { version, reason } => {
+                write!(
+                    formatter,
+                    "cannot migrate Noon IR version {version}: {reason}"
+                )
+            }
             Self::Patch(error) => write!(formatter, "invalid Noon scene document: {error}"),
+            Self::SessionLog { line, reason } => {
+                write!(
+                    formatter,
+                    "invalid Noon session log at line {line}: {reason}"
+                )
+            }
         }
     }
 }
@@ -85,9 +150,15 @@ pub fn encode_scene(scene: &SceneDefinition) -> Result<String, IrError> {
     Ok(serde_json::to_string(&SceneDocument::from_scene(scene))?)
 }
 
+/// Decodes a scene document, migrating it first when an older build wrote
+/// it; see [`decode_scene_with_report`].
 pub fn decode_scene(json: &str) -> Result<SceneDefinition, IrError> {
-    let document: SceneDocument = serde_json::from_str(json)?;
-    document.into_scene()
+    decode_scene_with_report(json).map(|(scene, _)| scene)
+}
+
+/// Pretty-printed JSON form of a scene diff report, for review tooling.
+pub fn encode_diff_report(report: &DiffReport) -> Result<String, IrError> {
+    Ok(serde_json::to_string_pretty(report)?)
 }
 
 pub fn encode_patch_batch(batch: &PatchBatch) -> Result<String, IrError> {
@@ -101,7 +172,7 @@ pub fn decode_patch_batch(json: &str) -> Result<PatchBatch, IrError> {
     Ok(batch)
 }
 
-fn ensure_version(version: u32) -> Result<(), IrError> {
+pub(crate) fn ensure_version(version: u32) -> Result<(), IrError> {
     if version == FORMAT_VERSION {
         Ok(())
     } else {
@@ -206,6 +277,59 @@ mod tests {
         assert_eq!(decoded.objects()[0].geometry, GeometryRef::path(path));
     }
 
+    #[test]
+    fn image_scene_round_trip_preserves_pixels_and_sampling() {
+        let pixels = noon_core::ImageData::new(2, 1, vec![10, 20, 30, 255, 40, 50, 60, 128])
+            .expect("valid image");
+        let mut scene = SceneDefinition::new();
+        let image = scene.register_image(pixels.clone());
+        let geometry = GeometryRef::Image {
+            image,
+            size: Vec2::new(2.0, 1.0),
+            sampling: noon_core::ImageSampling::Nearest,
+        };
+        scene.add(geometry.clone());
+
+        let json = encode_scene(&scene).expect("image scene must serialize");
+        assert!(json.contains("\"nearest\""));
+        let mut decoded = decode_scene(&json).expect("image scene must deserialize");
+        assert_eq!(decoded.objects()[0].geometry, geometry);
+        assert_eq!(decoded.image(image), Some(&pixels));
+        assert_eq!(decoded.register_image(pixels), noon_core::ImageId::new(1));
+
+        let vector_only = encode_scene(&sample_scene()).expect("scene must serialize");
+        assert!(!vector_only.contains("\"images\""));
+        let corrupt = json.replace("[10,20,30,255,40,50,60,128]", "[10,20,30]");
+        assert!(decode_scene(&corrupt).is_err());
+    }
+
+    #[test]
+    fn font_scene_round_trip_preserves_bytes_and_glyph_runs() {
+        let mut scene = SceneDefinition::new();
+        let data = noon_core::FontData::new(vec![0, 1, 0, 0, 7]);
+        let font = scene.register_font(data.clone());
+        let geometry = GeometryRef::glyph_run(noon_core::GlyphRun {
+            font,
+            size: 1.0,
+            text: "I".to_owned(),
+            glyphs: vec![noon_core::PositionedGlyph {
+                glyph: 2,
+                origin: Vec2::new(-0.5, 0.0),
+            }],
+            bounds: noon_core::Rect::new(Vec2::new(-0.4, 0.0), Vec2::new(0.2, 0.7)),
+            number: None,
+        });
+        scene.add(geometry.clone());
+
+        let json = encode_scene(&scene).expect("font scene must serialize");
+        let decoded = decode_scene(&json).expect("font scene must deserialize");
+        assert_eq!(decoded.objects()[0].geometry, geometry);
+        assert_eq!(decoded.font(font), Some(&data));
+
+        let vector_only = encode_scene(&sample_scene()).expect("scene must serialize");
+        assert!(!vector_only.contains("\"fonts\""));
+    }
+
     #[test]
     fn patch_batch_round_trip_preserves_order_and_sequence() {
         let batch = PatchBatch::new(
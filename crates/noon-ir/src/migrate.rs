@@ -0,0 +1,262 @@
+//! Forward migration of scene documents written by older builds.
+//!
+//! Each step upgrades a document by exactly one version while it is still a
+//! generic JSON value, so a step only has to understand the shape it renames
+//! or fills in. Steps run in order until the document reaches
+//! [`FORMAT_VERSION`], then typed decoding proceeds as usual.
+//!
+//! Version 1 is the first published format, so no steps exist yet. Patch
+//! batches and binary documents are live transports and are not migrated.
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use noon_core::SceneDefinition;
+
+use crate::{IrError, SceneDocument, FORMAT_VERSION};
+
+/// Edits a document in place, returning one line per change made.
+type MigrationFn = fn(&mut Map<String, Value>) -> Result<Vec<String>, String>;
+
+/// Upgrade from `from` to `from + 1`.
+struct Migration {
+    from: u32,
+    summary: &'static str,
+    apply: MigrationFn,
+}
+
+/// Registered steps. Add one here whenever `FORMAT_VERSION` is bumped.
+const MIGRATIONS: &[Migration] = &[];
+
+/// What upgrading a document changed, step by step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub steps: Vec<MigrationStep>,
+}
+
+impl MigrationReport {
+    /// True when the document was already at the current version.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// One version upgrade applied to a document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationStep {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub summary: &'static str,
+    /// Human-readable edits, such as which fields were renamed or filled in.
+    pub changes: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct VersionProbe {
+    version: u32,
+}
+
+/// Upgrades a scene document in place to [`FORMAT_VERSION`].
+///
+/// Only versions newer than this build, and versions older than the first
+/// published format, are rejected.
+pub fn migrate_document(document: &mut Value) -> Result<MigrationReport, IrError> {
+    migrate_with(document, MIGRATIONS, FORMAT_VERSION)
+}
+
+/// Decodes a scene document of any supported version, reporting the
+/// migrations applied on the way.
+pub fn decode_scene_with_report(json: &str) -> Result<(SceneDefinition, MigrationReport), IrError> {
+    // Probing skips over everything but the version, so current documents
+    // are decoded straight into typed values without a generic detour.
+    let VersionProbe { version } = serde_json::from_str(json)?;
+    if version == FORMAT_VERSION {
+        let document: SceneDocument = serde_json::from_str(json)?;
+        let report = MigrationReport {
+            from_version: version,
+            to_version: version,
+            steps: Vec::new(),
+        };
+        return Ok((document.into_scene()?, report));
+    }
+    let mut value: Value = serde_json::from_str(json)?;
+    let report = migrate_document(&mut value)?;
+    let document: SceneDocument = serde_json::from_value(value)?;
+    Ok((document.into_scene()?, report))
+}
+
+fn migrate_with(
+    document: &mut Value,
+    migrations: &[Migration],
+    target: u32,
+) -> Result<MigrationReport, IrError> {
+    let VersionProbe {
+        version: from_version,
+    } = VersionProbe::deserialize(&*document)?;
+    if from_version > target {
+        return Err(IrError::UnsupportedVersion(from_version));
+    }
+
+    let mut version = from_version;
+    let mut steps = Vec::new();
+    while version < target {
+        let migration = migrations
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or(IrError::UnsupportedVersion(from_version))?;
+        let object = document.as_object_mut().ok_or_else(|| IrError::Migration {
+            version,
+            reason: "document is not a JSON object".to_owned(),
+        })?;
+        let changes =
+            (migration.apply)(object).map_err(|reason| IrError::Migration { version, reason })?;
+        version += 1;
+        object.insert("version".to_owned(), Value::from(version));
+        steps.push(MigrationStep {
+            from_version: version - 1,
+            to_version: version,
+            summary: migration.summary,
+            changes,
+        });
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: version,
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn rename_radius(document: &mut Map<String, Value>) -> Result<Vec<String>, String> {
+        let mut changes = Vec::new();
+        let objects = document
+            .get_mut("objects")
+            .and_then(Value::as_array_mut)
+            .ok_or("missing objects array")?;
+        for (index, object) in objects.iter_mut().enumerate() {
+            let Some(circle) = object.pointer_mut("/geometry/circle") else {
+                continue;
+            };
+            if let Some(size) = circle.as_object_mut().and_then(|map| map.remove("r")) {
+                circle["radius"] = size;
+                changes.push(format!(
+                    "objects[{index}].geometry.circle: renamed r to radius"
+                ));
+            }
+        }
+        Ok(changes)
+    }
+
+    fn fill_tracks(document: &mut Map<String, Value>) -> Result<Vec<String>, String> {
+        if document.contains_key("tracks") {
+            return Ok(Vec::new());
+        }
+        document.insert("tracks".to_owned(), json!([]));
+        Ok(vec!["added empty tracks".to_owned()])
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            from: 1,
+            summary: "circle radius spelled out",
+            apply: rename_radius,
+        },
+        Migration {
+            from: 2,
+            summary: "tracks made mandatory",
+            apply: fill_tracks,
+        },
+    ];
+
+    #[test]
+    fn documents_are_upgraded_one_version_at_a_time() {
+        let mut document = json!({
+            "version": 1,
+            "objects": [
+                {"geometry": {"circle": {"r": 1.5}}},
+                {"geometry": {"rectangle": {"size": {"x": 1.0, "y": 1.0}}}},
+            ],
+        });
+        let report = migrate_with(&mut document, TEST_MIGRATIONS, 3).unwrap();
+
+        assert_eq!(document["version"], 3);
+        assert_eq!(document["objects"][0]["geometry"]["circle"]["radius"], 1.5);
+        assert_eq!(document["tracks"], json!([]));
+        assert_eq!(
+            report,
+            MigrationReport {
+                from_version: 1,
+                to_version: 3,
+                steps: vec![
+                    MigrationStep {
+                        from_version: 1,
+                        to_version: 2,
+                        summary: "circle radius spelled out",
+                        changes: vec!["objects[0].geometry.circle: renamed r to radius".to_owned()],
+                    },
+                    MigrationStep {
+                        from_version: 2,
+                        to_version: 3,
+                        summary: "tracks made mandatory",
+                        changes: vec!["added empty tracks".to_owned()],
+                    },
+                ],
+            }
+        );
+
+        let mut current = document.clone();
+        assert!(migrate_with(&mut current, TEST_MIGRATIONS, 3)
+            .unwrap()
+            .is_empty());
+        assert_eq!(current, document);
+    }
+
+    #[test]
+    fn only_unknown_versions_and_failing_steps_are_rejected() {
+        let mut newer = json!({"version": 4});
+        assert!(matches!(
+            migrate_with(&mut newer, TEST_MIGRATIONS, 3),
+            Err(IrError::UnsupportedVersion(4))
+        ));
+        let mut older = json!({"version": 0});
+        assert!(matches!(
+            migrate_with(&mut older, TEST_MIGRATIONS, 3),
+            Err(IrError::UnsupportedVersion(0))
+        ));
+        let mut malformed = json!({"version": 1, "objects": {}});
+        assert!(matches!(
+            migrate_with(&mut malformed, TEST_MIGRATIONS, 3),
+            Err(IrError::Migration { version: 1, .. })
+        ));
+        assert!(matches!(
+            migrate_with(&mut json!({"objects": []}), TEST_MIGRATIONS, 3),
+            Err(IrError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn current_documents_decode_with_an_empty_report() {
+        let mut scene = SceneDefinition::new();
+        scene.add(noon_core::GeometryRef::circle(1.0));
+        let json = crate::encode_scene(&scene).unwrap();
+
+        let (decoded, report) = decode_scene_with_report(&json).unwrap();
+        assert_eq!(decoded, scene);
+        assert!(report.is_empty());
+        assert_eq!(report.to_version, FORMAT_VERSION);
+
+        let future = json.replace("\"version\":1", "\"version\":2");
+        assert!(matches!(
+            decode_scene_with_report(&future),
+            Err(IrError::UnsupportedVersion(2))
+        ));
+    }
+}
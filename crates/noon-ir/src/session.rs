@@ -0,0 +1,177 @@
+//! Recorded live editing sessions.
+//!
+//! A session log is newline-delimited JSON. The first line is a `start` event
+//! carrying the scene recording began from; every later line is one player
+//! operation. Events are only ever appended, so a recorder can stream lines
+//! to a file as they happen and a replayer reproduces the same frames.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ensure_version, IrError, PatchBatch, SceneDocument};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SessionEvent {
+    /// The scene, playhead and next patch sequence recording began from.
+    Start {
+        time: f64,
+        sequence: u64,
+        scene: SceneDocument,
+    },
+    Seek {
+        time: f64,
+    },
+    Advance {
+        time: f64,
+    },
+    /// A batch applied while the playhead was at `time`.
+    Patch {
+        time: f64,
+        batch: PatchBatch,
+    },
+    Replace {
+        scene: SceneDocument,
+    },
+    Reconcile {
+        scene: SceneDocument,
+    },
+}
+
+impl SessionEvent {
+    fn validate(&self) -> Result<(), IrError> {
+        match self {
+            Self::Start { scene, .. } | Self::Replace { scene } | Self::Reconcile { scene } => {
+                ensure_version(scene.version)
+            }
+            Self::Patch { batch, .. } => batch.validate(),
+            Self::Seek { .. } | Self::Advance { .. } => Ok(()),
+        }
+    }
+}
+
+/// One log line, newline included, ready to append to a session log.
+pub fn encode_session_event(event: &SessionEvent) -> Result<String, IrError> {
+    event.validate()?;
+    let mut line = serde_json::to_string(event)?;
+    line.push('\n');
+    Ok(line)
+}
+
+/// Decodes a whole session log. Blank lines are skipped; everything else
+/// must be an event, and only the first may be `start`.
+pub fn decode_session_log(log: &str) -> Result<Vec<SessionEvent>, IrError> {
+    let mut events = Vec::new();
+    for (index, line) in log.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let session_error = |reason: String| IrError::SessionLog {
+            line: index + 1,
+            reason,
+        };
+        let event: SessionEvent =
+            serde_json::from_str(line).map_err(|error| session_error(error.to_string()))?;
+        event
+            .validate()
+            .map_err(|error| session_error(error.to_string()))?;
+        let is_start = matches!(event, SessionEvent::Start { .. });
+        if is_start != events.is_empty() {
+            return Err(session_error(
+                if is_start {
+                    "session already started"
+                } else {
+                    "session must begin with a start event"
+                }
+                .to_owned(),
+            ));
+        }
+        events.push(event);
+    }
+    if events.is_empty() {
+        return Err(IrError::SessionLog {
+            line: 0,
+            reason: "session log is empty".to_owned(),
+        });
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{GeometryRef, ObjectId, SceneDefinition, ScenePatch, Style};
+
+    use super::*;
+
+    fn start() -> SessionEvent {
+        let mut scene = SceneDefinition::new();
+        scene.add(GeometryRef::circle(1.0));
+        SessionEvent::Start {
+            time: 0.0,
+            sequence: 0,
+            scene: SceneDocument::from_scene(&scene),
+        }
+    }
+
+    #[test]
+    fn logs_are_one_event_per_line() {
+        let events = [
+            start(),
+            SessionEvent::Seek { time: 1.0 / 3.0 },
+            SessionEvent::Patch {
+                time: 1.0 / 3.0,
+                batch: PatchBatch::new(
+                    0,
+                    vec![ScenePatch::SetStyle {
+                        object: ObjectId::new(0),
+                        style: Style::default(),
+                    }],
+                ),
+            },
+            SessionEvent::Advance { time: 2.0 },
+        ];
+        let mut log = String::new();
+        for event in &events {
+            let line = encode_session_event(event).unwrap();
+            assert_eq!(line.matches('\n').count(), 1);
+            log.push_str(&line);
+        }
+        assert!(log.starts_with("{\"event\":\"start\""));
+        log.push('\n');
+        assert_eq!(decode_session_log(&log).unwrap(), events);
+    }
+
+    #[test]
+    fn malformed_logs_report_the_offending_line() {
+        let start = encode_session_event(&start()).unwrap();
+        let seek = encode_session_event(&SessionEvent::Seek { time: 1.0 }).unwrap();
+
+        for (log, expected) in [
+            (String::new(), 0),
+            (seek.clone(), 1),
+            (format!("{start}{start}"), 2),
+            (format!("{start}\n{{\"event\":\"jump\"}}\n"), 3),
+            (format!("{start}{}", seek.replace("seek", "patch")), 2),
+            (
+                format!(
+                    "{start}{}",
+                    r#"{"event":"patch","time":0.0,"batch":{"version":7,"sequence":0,"patches":[]}}"#
+                ),
+                2,
+            ),
+        ] {
+            match decode_session_log(&log) {
+                Err(IrError::SessionLog { line, .. }) => assert_eq!(line, expected, "{log}"),
+                other => panic!("expected a session log error, got {other:?}"),
+            }
+        }
+        assert!(matches!(
+            encode_session_event(&SessionEvent::Replace {
+                scene: SceneDocument {
+                    version: 7,
+                    ..SceneDocument::from_scene(&SceneDefinition::new())
+                },
+            }),
+            Err(IrError::UnsupportedVersion(7))
+        ));
+    }
+}
@@ -0,0 +1,778 @@
+//! Semantic checks over a whole scene document.
+//!
+//! Decoding stops at the first problem it meets. [`validate_document`]
+//! instead walks the document once and reports every problem it can find,
+//! each located by a JSON pointer into the encoded document, so authoring
+//! tools can highlight all of them together.
+//!
+//! The IR does not know which transforms a compiler can interpolate, so
+//! [`validate_document_with`] takes that check as a per-track hook; pass
+//! `noon_compile::check_track` to cover compilation as well.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use noon_core::{
+    GeometryRef, Labels, ObjectId, Property, TimelineError, TrackDefinition, TrackValues,
+};
+
+use crate::{migrate_document, IrError, SceneDocument, FORMAT_VERSION};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Allowed, but probably not what the author meant.
+    Warning,
+    /// The document cannot be decoded or compiled.
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    /// RFC 6901 pointer to the offending value, such as `/tracks/3/object`.
+    pub pointer: String,
+    pub message: String,
+}
+
+/// Every problem found in a document, grouped by check in document order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// True when nothing would stop the document from decoding and compiling.
+    pub fn is_valid(&self) -> bool {
+        !self
+            .issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error)
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Warning)
+    }
+
+    fn error(&mut self, pointer: String, message: String) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Error,
+            pointer,
+            message,
+        });
+    }
+
+    fn warning(&mut self, pointer: String, message: String) {
+        self.issues.push(ValidationIssue {
+            severity: Severity::Warning,
+            pointer,
+            message,
+        });
+    }
+}
+
+impl std::fmt::Display for ValidationReport {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.issues.is_empty() {
+            return formatter.write_str("no issues");
+        }
+        for (index, issue) in self.issues.iter().enumerate() {
+            if index > 0 {
+                writeln!(formatter)?;
+            }
+            write!(
+                formatter,
+                "{} at {}: {}",
+                issue.severity, issue.pointer, issue.message
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Fields that legitimately serialize as `null`.
+const OPTIONAL_FIELDS: &[&str] = &["fill", "stroke", "bounds", "group_separator"];
+
+/// Reports every semantic problem in `document` without stopping at the
+/// first. An empty report means [`SceneDocument::into_scene`] will succeed.
+pub fn validate_document(document: &SceneDocument) -> ValidationReport {
+    validate_document_with(document, |_| Ok::<(), Infallible>(()))
+}
+
+/// [`validate_document`] plus `check_track`, run on every track that passed
+/// the document's own checks. Its errors are reported at the track's
+/// values, and the track is left out of the overlap checks.
+pub fn validate_document_with<E: std::fmt::Display>(
+    document: &SceneDocument,
+    check_track: impl FnMut(&TrackDefinition) -> Result<(), E>,
+) -> ValidationReport {
+    let positions = |count: usize| (0..count).collect::<Vec<_>>();
+    let sources = Sources {
+        objects: positions(document.objects.len()),
+        tracks: positions(document.tracks.len()),
+        images: positions(document.images.len()),
+        fonts: positions(document.fonts.len()),
+        undecoded_objects: Vec::new(),
+    };
+    validate_decoded(ValidationReport::default(), document, &sources, check_track)
+}
+
+/// Position of every decoded entry in its source section. Entries that fail
+/// to decode from JSON are left out of the document but keep their place in
+/// reported pointers.
+struct Sources {
+    objects: Vec<usize>,
+    tracks: Vec<usize>,
+    images: Vec<usize>,
+    fonts: Vec<usize>,
+    /// Ids of objects that failed to decode; tracks on them are not dangling.
+    undecoded_objects: Vec<ObjectId>,
+}
+
+fn validate_decoded<E: std::fmt::Display>(
+    mut report: ValidationReport,
+    document: &SceneDocument,
+    sources: &Sources,
+    mut check_track: impl FnMut(&TrackDefinition) -> Result<(), E>,
+) -> ValidationReport {
+    if document.version != FORMAT_VERSION {
+        report.error(
+            "/version".to_owned(),
+            format!("unsupported Noon IR version {}", document.version),
+        );
+    }
+
+    check_unique_ids(
+        &mut report,
+        "objects",
+        "object",
+        &sources.objects,
+        document.objects.iter().map(|object| object.id.get()),
+    );
+    check_unique_ids(
+        &mut report,
+        "tracks",
+        "track",
+        &sources.tracks,
+        document.tracks.iter().map(|track| track.id.get()),
+    );
+    check_unique_ids(
+        &mut report,
+        "images",
+        "image",
+        &sources.images,
+        document.images.iter().map(|image| image.id.get()),
+    );
+    check_unique_ids(
+        &mut report,
+        "fonts",
+        "font",
+        &sources.fonts,
+        document.fonts.iter().map(|font| font.id.get()),
+    );
+    check_unique_names(
+        &mut report,
+        "objects",
+        "object",
+        &sources.objects,
+        document.objects.iter().map(|object| &object.labels),
+    );
+    check_unique_names(
+        &mut report,
+        "tracks",
+        "track",
+        &sources.tracks,
+        document.tracks.iter().map(|track| &track.labels),
+    );
+
+    for (index, object) in document.objects.iter().enumerate() {
+        let pointer = format!("/objects/{}", sources.objects[index]);
+        check_finite(&mut report, pointer.clone(), object);
+        check_assets(
+            &mut report,
+            document,
+            format!("{pointer}/geometry"),
+            &object.geometry,
+        );
+    }
+
+    let objects: HashMap<ObjectId, usize> = document
+        .objects
+        .iter()
+        .enumerate()
+        .map(|(index, object)| (object.id, index))
+        .collect();
+    // Tracks that passed their own checks, per object, for the checks that
+    // compare tracks with each other.
+    let mut sound: BTreeMap<(usize, u8), Vec<usize>> = BTreeMap::new();
+    for (index, track) in document.tracks.iter().enumerate() {
+        let pointer = format!("/tracks/{}", sources.tracks[index]);
+        let mut valid = check_finite(&mut report, pointer.clone(), track);
+        let object = objects.get(&track.object).copied();
+        if object.is_none() && !sources.undecoded_objects.contains(&track.object) {
+            report.error(
+                format!("{pointer}/object"),
+                format!("unknown object id {}", track.object.get()),
+            );
+        }
+        if let Err(error) = track.validate() {
+            report_timeline_error(&mut report, &pointer, error);
+            valid = false;
+        }
+        if let Some((from, to)) = track.values.snapshots() {
+            let values = format!("{pointer}/values/{}", variant_name(&track.values));
+            valid &= check_assets(
+                &mut report,
+                document,
+                format!("{values}/from/geometry"),
+                &from.geometry,
+            );
+            valid &= check_assets(
+                &mut report,
+                document,
+                format!("{values}/to/geometry"),
+                &to.geometry,
+            );
+        }
+        if !valid {
+            continue;
+        }
+        if let Err(error) = check_track(track) {
+            report.error(format!("{pointer}/values"), error.to_string());
+            continue;
+        }
+        if let Some(object) = object {
+            sound
+                .entry((object, property_key(track.property)))
+                .or_default()
+                .push(index);
+        }
+    }
+
+    for ((_, property), mut tracks) in sound {
+        tracks.sort_by(|&left, &right| {
+            let left = &document.tracks[left];
+            let right = &document.tracks[right];
+            left.timing
+                .start_time
+                .total_cmp(&right.timing.start_time)
+                .then(left.id.cmp(&right.id))
+        });
+        if property == property_key(Property::Presence) {
+            check_presence_chain(&mut report, &document.tracks, &sources.tracks, &tracks);
+        } else {
+            check_overlaps(&mut report, &document.tracks, &sources.tracks, &tracks);
+        }
+    }
+    report
+}
+
+/// Migrates and decodes a JSON scene document far enough to validate it.
+/// Each object, track, image and font decodes on its own, so wrong types,
+/// unknown variants and non-finite numbers are reported at their entries
+/// alongside every other problem. Only malformed JSON, a document missing
+/// its sections and unmigratable versions are returned as errors.
+pub fn validate_scene_json(json: &str) -> Result<ValidationReport, IrError> {
+    validate_scene_json_with(json, |_| Ok::<(), Infallible>(()))
+}
+
+/// [`validate_scene_json`] with a per-track hook, as in
+/// [`validate_document_with`].
+pub fn validate_scene_json_with<E: std::fmt::Display>(
+    json: &str,
+    check_track: impl FnMut(&TrackDefinition) -> Result<(), E>,
+) -> Result<ValidationReport, IrError> {
+    let mut value: Value = serde_json::from_str(json)?;
+    migrate_document(&mut value)?;
+    let sections: DocumentSections = serde_json::from_value(value)?;
+    let mut report = ValidationReport::default();
+    let (objects, object_sources) = decode_entries(&mut report, "objects", &sections.objects);
+    let (tracks, track_sources) = decode_entries(&mut report, "tracks", &sections.tracks);
+    let (images, image_sources) = decode_entries(&mut report, "images", &sections.images);
+    let (fonts, font_sources) = decode_entries(&mut report, "fonts", &sections.fonts);
+    let undecoded_objects = sections
+        .objects
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| object_sources.binary_search(index).is_err())
+        .filter_map(|(_, entry)| entry.get("id")?.as_u64().map(ObjectId::new))
+        .collect();
+    let document = SceneDocument {
+        version: sections.version,
+        objects,
+        tracks,
+        images,
+        fonts,
+    };
+    let sources = Sources {
+        objects: object_sources,
+        tracks: track_sources,
+        images: image_sources,
+        fonts: font_sources,
+        undecoded_objects,
+    };
+    Ok(validate_decoded(report, &document, &sources, check_track))
+}
+
+/// A scene document whose entries are still undecoded JSON.
+#[derive(Deserialize)]
+struct DocumentSections {
+    version: u32,
+    objects: Vec<Value>,
+    tracks: Vec<Value>,
+    #[serde(default)]
+    images: Vec<Value>,
+    #[serde(default)]
+    fonts: Vec<Value>,
+}
+
+/// Decodes every entry of one section, reporting the ones that fail and
+/// returning the rest with their source positions. A failed entry is
+/// reported at its non-finite numbers when it has any, since serde spells
+/// those `null`, and as a whole otherwise.
+fn decode_entries<T: DeserializeOwned>(
+    report: &mut ValidationReport,
+    section: &str,
+    entries: &[Value],
+) -> (Vec<T>, Vec<usize>) {
+    let mut decoded = Vec::with_capacity(entries.len());
+    let mut sources = Vec::with_capacity(entries.len());
+    for (index, entry) in entries.iter().enumerate() {
+        let pointer = format!("/{section}/{index}");
+        match T::deserialize(entry) {
+            Ok(item) => {
+                decoded.push(item);
+                sources.push(index);
+            }
+            Err(error) => {
+                let before = report.issues.len();
+                find_non_finite(report, &mut pointer.clone(), None, entry);
+                if report.issues.len() == before {
+                    report.error(pointer, error.to_string());
+                }
+            }
+        }
+    }
+    (decoded, sources)
+}
+
+fn check_unique_ids(
+    report: &mut ValidationReport,
+    section: &str,
+    kind: &str,
+    sources: &[usize],
+    ids: impl Iterator<Item = u64>,
+) {
+    let mut first = HashMap::new();
+    for (index, id) in ids.enumerate() {
+        let index = sources[index];
+        if let Some(original) = first.insert(id, index) {
+            first.insert(id, original);
+            report.error(
+                format!("/{section}/{index}/id"),
+                format!("duplicate {kind} id {id}, first used at /{section}/{original}"),
+            );
+        }
+    }
+}
+
+/// Name lookups return the first match, so later namesakes are unreachable
+/// by name.
+fn check_unique_names<'a>(
+    report: &mut ValidationReport,
+    section: &str,
+    kind: &str,
+    sources: &[usize],
+    labels: impl Iterator<Item = &'a Labels>,
+) {
+    let mut first = HashMap::new();
+    for (index, labels) in labels.enumerate() {
+        let index = sources[index];
+        let Some(name) = labels.name.as_deref() else {
+            continue;
+        };
+        if let Some(original) = first.get(name) {
+            report.warning(
+                format!("/{section}/{index}/labels/name"),
+                format!("duplicate {kind} name {name:?}, first used at /{section}/{original}"),
+            );
+        } else {
+            first.insert(name, index);
+        }
+    }
+}
+
+/// JSON has no spelling for NaN or infinity, so serde writes them as `null`.
+/// Every `null` outside an optional field therefore marks such a number.
+fn check_finite(report: &mut ValidationReport, pointer: String, value: &impl Serialize) -> bool {
+    let before = report.issues.len();
+    if let Ok(value) = serde_json::to_value(value) {
+        find_non_finite(report, &mut pointer.clone(), None, &value);
+    }
+    report.issues.len() == before
+}
+
+fn find_non_finite(
+    report: &mut ValidationReport,
+    pointer: &mut String,
+    key: Option<&str>,
+    value: &Value,
+) {
+    let len = pointer.len();
+    match value {
+        Value::Null if !key.is_some_and(|key| OPTIONAL_FIELDS.contains(&key)) => {
+            report.error(pointer.clone(), "number is not finite".to_owned());
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                pointer.push_str(&format!("/{index}"));
+                find_non_finite(report, pointer, None, item);
+                pointer.truncate(len);
+            }
+        }
+        Value::Object(fields) => {
+            for (field, item) in fields {
+                pointer.push('/');
+                pointer.push_str(field);
+                find_non_finite(report, pointer, Some(field), item);
+                pointer.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_assets(
+    report: &mut ValidationReport,
+    document: &SceneDocument,
+    pointer: String,
+    geometry: &GeometryRef,
+) -> bool {
+    match geometry {
+        GeometryRef::Image { image, .. }
+            if !document.images.iter().any(|known| known.id == *image) =>
+        {
+            report.error(
+                format!("{pointer}/image/image"),
+                format!("unknown image id {}", image.get()),
+            );
+            false
+        }
+        GeometryRef::GlyphRun(run) if !document.fonts.iter().any(|known| known.id == run.font) => {
+            report.error(
+                format!("{pointer}/glyph_run/font"),
+                format!("unknown font id {}", run.font.get()),
+            );
+            false
+        }
+        _ => true,
+    }
+}
+
+/// Non-finite timing was already reported by [`check_finite`].
+fn report_timeline_error(report: &mut ValidationReport, pointer: &str, error: TimelineError) {
+    let field = match &error {
+        TimelineError::InvalidStartTime(value) | TimelineError::InvalidDuration(value)
+            if !value.is_finite() =>
+        {
+            return;
+        }
+        TimelineError::InvalidStartTime(_) => "timing/start_time",
+        TimelineError::InvalidDuration(_) | TimelineError::InvalidInstantDuration { .. } => {
+            "timing/duration"
+        }
+        TimelineError::ValueTypeMismatch { .. } => "values",
+        TimelineError::UnknownObject(_) | TimelineError::TrackIdExhausted => return,
+    };
+    report.error(format!("{pointer}/{field}"), error.to_string());
+}
+
+/// Each presence event must start from the state the previous one left.
+fn check_presence_chain(
+    report: &mut ValidationReport,
+    tracks: &[TrackDefinition],
+    sources: &[usize],
+    chain: &[usize],
+) {
+    for pair in chain.windows(2) {
+        let (TrackValues::Bool { to, .. }, TrackValues::Bool { from, .. }) =
+            (&tracks[pair[0]].values, &tracks[pair[1]].values)
+        else {
+            continue;
+        };
+        if to != from {
+            report.error(
+                format!("/tracks/{}/values/bool/from", sources[pair[1]]),
+                format!(
+                    "presence starts {} but track {} leaves the object {}",
+                    presence(*from),
+                    tracks[pair[0]].id.get(),
+                    presence(*to)
+                ),
+            );
+        }
+    }
+}
+
+/// Overlapping tracks on one property compile, but the runtime samples only
+/// the one that started last, cutting the earlier one short.
+fn check_overlaps(
+    report: &mut ValidationReport,
+    tracks: &[TrackDefinition],
+    sources: &[usize],
+    sorted: &[usize],
+) {
+    let mut latest: Option<(usize, f64)> = None;
+    for &index in sorted {
+        let timing = tracks[index].timing;
+        if let Some((previous, end)) = latest {
+            if timing.start_time < end {
+                report.warning(
+                    format!("/tracks/{}/timing/start_time", sources[index]),
+                    format!(
+                        "{:?} track starts before track {} on the same object ends",
+                        tracks[index].property,
+                        tracks[previous].id.get()
+                    ),
+                );
+            }
+        }
+        let end = timing.start_time + timing.duration;
+        if latest.is_none_or(|(_, latest_end)| end > latest_end) {
+            latest = Some((index, end));
+        }
+    }
+}
+
+fn presence(present: bool) -> &'static str {
+    if present {
+        "present"
+    } else {
+        "absent"
+    }
+}
+
+fn variant_name(values: &TrackValues) -> &'static str {
+    match values {
+        TrackValues::Bool { .. } => "bool",
+        TrackValues::Scalar { .. } => "scalar",
+        TrackValues::Vec2 { .. } => "vec2",
+        TrackValues::Object { .. } => "object",
+        TrackValues::Motion { .. } => "motion",
+    }
+}
+
+/// Groups tracks by property; `Property` itself has no ordering.
+const fn property_key(property: Property) -> u8 {
+    property as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{
+        Easing, GeometryRef, ImageId, ObjectSnapshot, SceneDefinition, TrackId, TrackTiming, Vec2,
+    };
+
+    use super::*;
+
+    fn timing(start_time: f64) -> TrackTiming {
+        TrackTiming::new(start_time, 1.0, Easing::Linear)
+    }
+
+    fn scene() -> SceneDefinition {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(1.0));
+        scene.add(GeometryRef::line(Vec2::ZERO, Vec2::ONE));
+        scene.set_presence_at(circle, false, true, 0.0).unwrap();
+        scene.set_presence_at(circle, true, false, 2.0).unwrap();
+        scene
+            .animate_position(circle, Vec2::ZERO, Vec2::ONE, timing(0.0))
+            .unwrap();
+        scene
+    }
+
+    fn pointers(report: &ValidationReport, severity: Severity) -> Vec<&str> {
+        report
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .map(|issue| issue.pointer.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn decodable_documents_have_no_issues() {
+        let report = validate_document(&SceneDocument::from_scene(&scene()));
+        assert!(report.is_valid());
+        assert_eq!(report.to_string(), "no issues");
+    }
+
+    #[test]
+    fn every_problem_is_reported_in_one_pass() {
+        let scene = scene();
+        let mut document = SceneDocument::from_scene(&scene);
+        let mut circle = scene.snapshot(noon_core::ObjectId::new(0)).unwrap();
+        document.objects[1].transform.rotation = f32::NAN;
+        document.objects[1].geometry = GeometryRef::Image {
+            image: ImageId::new(4),
+            size: Vec2::ONE,
+            sampling: noon_core::ImageSampling::Linear,
+        };
+        // Presence must hand off from absent to present, not present again.
+        document.tracks[1].values = TrackValues::Bool {
+            from: false,
+            to: false,
+        };
+        document.tracks[2].timing.duration = -1.0;
+        let mut dangling = document.tracks[2].clone();
+        dangling.id = TrackId::new(7);
+        dangling.object = noon_core::ObjectId::new(9);
+        dangling.timing.duration = 1.0;
+        let mut duplicate = dangling.clone();
+        duplicate.object = noon_core::ObjectId::new(0);
+        duplicate.property = Property::Opacity;
+        let line = ObjectSnapshot {
+            geometry: GeometryRef::line(Vec2::ZERO, Vec2::ONE),
+            ..circle.clone()
+        };
+        circle.transform.translation = Vec2::ONE;
+        let mut morph = dangling.clone();
+        morph.id = TrackId::new(8);
+        morph.object = noon_core::ObjectId::new(0);
+        morph.property = Property::Transform;
+        morph.values = TrackValues::Object {
+            from: circle,
+            to: line,
+        };
+        let mut early = dangling.clone();
+        early.id = TrackId::new(9);
+        early.object = noon_core::ObjectId::new(0);
+        early.timing.start_time = 0.5;
+        // The original Position track is invalid, so only these two overlap.
+        let mut late = early.clone();
+        late.id = TrackId::new(10);
+        late.timing.start_time = 1.25;
+        document
+            .tracks
+            .extend([dangling, duplicate, morph, early, late]);
+
+        // Without the compiler hook the circle-to-line morph is accepted.
+        let structural = validate_document(&document);
+        assert!(!pointers(&structural, Severity::Error).contains(&"/tracks/5/values"));
+
+        let report = validate_document_with(&document, noon_compile::check_track);
+        assert!(!report.is_valid());
+        assert_eq!(
+            pointers(&report, Severity::Error),
+            [
+                "/tracks/4/id",
+                "/objects/1/transform/rotation",
+                "/objects/1/geometry/image/image",
+                "/tracks/2/timing/duration",
+                "/tracks/3/object",
+                "/tracks/4/values",
+                "/tracks/5/values",
+                "/tracks/1/values/bool/from",
+            ]
+        );
+        assert_eq!(
+            pointers(&report, Severity::Warning),
+            ["/tracks/7/timing/start_time"]
+        );
+        assert_eq!(
+            report.errors().next().unwrap().message,
+            "duplicate track id 7, first used at /tracks/3"
+        );
+        assert!(report
+            .to_string()
+            .contains("error at /tracks/3/object: unknown object id 9"));
+    }
+
+    #[test]
+    fn duplicate_names_are_warnings() {
+        let mut document = SceneDocument::from_scene(&scene());
+        for object in &mut document.objects {
+            object.labels = Labels::named("sun");
+        }
+        document.tracks[2].labels = Labels::named("rise");
+
+        let report = validate_document(&document);
+        assert!(report.is_valid());
+        assert_eq!(
+            pointers(&report, Severity::Warning),
+            ["/objects/1/labels/name"]
+        );
+        assert_eq!(
+            report.warnings().next().unwrap().message,
+            "duplicate object name \"sun\", first used at /objects/0"
+        );
+    }
+
+    #[test]
+    fn json_documents_are_validated_where_decoding_would_stop() {
+        let mut document = SceneDocument::from_scene(&scene());
+        document.tracks[0].object = noon_core::ObjectId::new(5);
+        document.tracks[2].object = noon_core::ObjectId::new(6);
+        let json = serde_json::to_string(&document).unwrap();
+        assert!(crate::decode_scene(&json).is_err());
+
+        let report = validate_scene_json(&json).unwrap();
+        assert_eq!(
+            pointers(&report, Severity::Error),
+            ["/tracks/0/object", "/tracks/2/object"]
+        );
+        assert!(matches!(
+            validate_scene_json("{\"version\":1}"),
+            Err(IrError::Json(_))
+        ));
+        assert!(matches!(
+            validate_scene_json(&json.replace("\"version\":1", "\"version\":9")),
+            Err(IrError::UnsupportedVersion(9))
+        ));
+    }
+
+    #[test]
+    fn json_entries_that_fail_to_decode_are_reported_together() {
+        let mut document = SceneDocument::from_scene(&scene());
+        document.objects[0].transform.rotation = f32::NAN;
+        document.tracks[2].object = noon_core::ObjectId::new(6);
+        let mut value = serde_json::to_value(&document).unwrap();
+        value["objects"][1]["style"]["opacity"] = Value::from("opaque");
+        value["tracks"][0]["property"] = Value::from("spin");
+        let json = serde_json::to_string(&value).unwrap();
+
+        let report = validate_scene_json(&json).unwrap();
+        assert_eq!(
+            pointers(&report, Severity::Error),
+            [
+                "/objects/0/transform/rotation",
+                "/objects/1",
+                "/tracks/0",
+                "/tracks/2/object",
+            ]
+        );
+        assert!(report.issues[1].message.contains("invalid type"));
+        assert!(report.issues[2].message.contains("unknown variant"));
+    }
+}
@@ -0,0 +1,312 @@
+use noon_core::{
+    Color, Easing, FontData, GeometryId, GeometryRef, GlyphRun, ImageData, ImageSampling, Labels,
+    MotionPath, NumberFormat, NumberGlyph, NumberLayout, ObjectId, ObjectSnapshot, Property, Rect,
+    SceneDefinition, ScenePatch, StrokeCap, StrokeJoin, Style, TrackId, TrackTiming, Transform2D,
+    Vec2, VectorPath,
+};
+use noon_ir::{
+    decode_patch_batch, decode_patch_batch_binary, decode_scene, decode_scene_binary,
+    encode_patch_batch, encode_patch_batch_binary, encode_scene, encode_scene_binary, IrError,
+    PatchBatch,
+};
+
+fn counter() -> GlyphRun {
+    let glyph = |character, glyph| NumberGlyph {
+        character,
+        glyph,
+        advance: 0.5,
+        bounds: (character != ' ').then_some(Rect::new(Vec2::new(0.05, 0.0), Vec2::new(0.45, 0.7))),
+    };
+    let layout = NumberLayout {
+        format: NumberFormat::new()
+            .decimal_places(1)
+            .group_separator(',')
+            .prefix("$")
+            .suffix(" €"),
+        value: 12.5,
+        origin: Vec2::new(-1.0, -0.35),
+        glyphs: vec![glyph('1', 1), glyph('2', 2), glyph('.', 3), glyph(' ', 4)],
+    };
+    let (text, glyphs, bounds) = layout.typeset(layout.value);
+    GlyphRun {
+        font: noon_core::FontId::new(0),
+        size: 1.0,
+        text,
+        glyphs,
+        bounds,
+        number: Some(Box::new(layout)),
+    }
+}
+
+/// One of everything the wire format can carry.
+fn rich_scene() -> SceneDefinition {
+    let mut scene = SceneDefinition::new();
+    let image = scene.register_image(
+        ImageData::new(2, 1, vec![10, 20, 30, 255, 40, 50, 60, 128]).expect("valid image"),
+    );
+    scene.register_font(FontData::new(vec![0, 1, 0, 0, 7]));
+
+    let morph = VectorPath::new()
+        .move_to(Vec2::new(-1.0, -1.0))
+        .line_to(Vec2::new(1.0, -1.0))
+        .quadratic_to(Vec2::new(2.0, 0.0), Vec2::new(1.0, 1.0))
+        .cubic_to(
+            Vec2::new(0.5, 2.0),
+            Vec2::new(-0.5, 2.0),
+            Vec2::new(-1.0, 1.0),
+        )
+        .close()
+        .with_morph_target(VectorPath::new().move_to(Vec2::ZERO).line_to(Vec2::ONE));
+    let circle = scene.add(GeometryRef::circle(1.5));
+    let path = scene.add(GeometryRef::path(morph.clone()));
+    scene.add(GeometryRef::rectangle(2.0, 1.0));
+    scene.add(GeometryRef::line(
+        Vec2::new(-3.0, 1.25),
+        Vec2::new(4.5, -2.0),
+    ));
+    scene.add(GeometryRef::External(GeometryId::new(9)));
+    scene.add(GeometryRef::Image {
+        image,
+        size: Vec2::new(2.0, 1.0),
+        sampling: ImageSampling::Nearest,
+    });
+    let number = scene.add(GeometryRef::glyph_run(counter()));
+    scene.object_mut(circle).expect("object exists").style = Style {
+        fill: None,
+        stroke: Some(Color::rgba(0.2, 0.5, 0.9, 0.5)),
+        stroke_width: 0.25,
+        stroke_join: StrokeJoin::Bevel,
+        stroke_cap: StrokeCap::Square,
+        opacity: 0.75,
+    };
+    scene.object_mut(circle).expect("object exists").labels = Labels::named("sun")
+        .with_tag("sky")
+        .with_tag("hero")
+        .with_metadata("source", "intro.py:12");
+    scene.object_mut(path).expect("object exists").labels = Labels::new().with_tag("sky");
+
+    let timing = TrackTiming::new(0.5, 2.0, Easing::EaseInOutCubic);
+    let from = scene.snapshot(circle).expect("object exists");
+    let to = ObjectSnapshot {
+        transform: Transform2D {
+            translation: Vec2::new(3.0, -2.0),
+            rotation: 0.4,
+            scale: Vec2::new(1.5, 0.75),
+        },
+        ..from.clone()
+    };
+    let reveal = scene.set_presence_at(path, false, true, 0.25).unwrap();
+    scene
+        .apply_patch(ScenePatch::SetTrackLabels {
+            track: reveal,
+            labels: Labels::named("reveal").with_metadata("beat", "1"),
+        })
+        .unwrap();
+    scene.animate_morph(path, 0.0, 1.0, timing).unwrap();
+    scene
+        .animate_position(path, Vec2::ZERO, Vec2::new(1.0, 2.0), timing)
+        .unwrap();
+    scene.animate_value(number, 12.5, 1000.0, timing).unwrap();
+    scene
+        .animate_transform(circle, from.clone(), to.clone(), timing)
+        .unwrap();
+    for motion in [
+        MotionPath::Arc { angle: 1.0 },
+        MotionPath::Pivot {
+            point: Vec2::new(0.5, -0.5),
+            angle: -2.0,
+        },
+        MotionPath::Path {
+            path: morph,
+            follow_tangent: true,
+        },
+    ] {
+        scene
+            .animate_motion(circle, from.clone(), to.clone(), motion, timing)
+            .unwrap();
+    }
+    scene
+}
+
+fn rich_batch() -> PatchBatch {
+    let scene = rich_scene();
+    let mut patches = vec![
+        ScenePatch::RegisterImage(scene.images()[0].clone()),
+        ScenePatch::RegisterFont(scene.fonts()[0].clone()),
+    ];
+    patches.extend(
+        scene
+            .objects()
+            .iter()
+            .cloned()
+            .map(ScenePatch::CreateObject),
+    );
+    patches.extend(scene.tracks().iter().cloned().map(ScenePatch::AddTrack));
+    patches.extend([
+        ScenePatch::ReplaceTrack(scene.tracks()[1].clone()),
+        ScenePatch::SetTransform {
+            object: ObjectId::new(1),
+            transform: Transform2D::IDENTITY,
+        },
+        ScenePatch::SetStyle {
+            object: ObjectId::new(2),
+            style: Style::default(),
+        },
+        ScenePatch::SetGeometry {
+            object: ObjectId::new(2),
+            geometry: GeometryRef::glyph_run(counter()),
+        },
+        ScenePatch::MoveObject {
+            object: ObjectId::new(2),
+            index: 0,
+        },
+        ScenePatch::SetLabels {
+            object: ObjectId::new(2),
+            labels: Labels::named("box").with_tag("ground"),
+        },
+        ScenePatch::SetTrackLabels {
+            track: TrackId::new(1),
+            labels: Labels::new(),
+        },
+        ScenePatch::ReplaceImage(scene.images()[0].clone()),
+        ScenePatch::ReplaceFont(scene.fonts()[0].clone()),
+        ScenePatch::RemoveTrack(TrackId::new(0)),
+        ScenePatch::RemoveObject(ObjectId::new(3)),
+        ScenePatch::RemoveImage(scene.images()[0].id),
+        ScenePatch::RemoveFont(scene.fonts()[0].id),
+    ]);
+    PatchBatch::new(u64::MAX, patches)
+}
+
+#[test]
+fn binary_and_json_decode_to_the_same_scene() {
+    let scene = rich_scene();
+    let json = encode_scene(&scene).unwrap();
+    let binary = encode_scene_binary(&scene).unwrap();
+    let from_json = decode_scene(&json).unwrap();
+    let from_binary = decode_scene_binary(&binary).unwrap();
+
+    assert_eq!(from_binary.objects(), scene.objects());
+    assert_eq!(from_binary.tracks(), scene.tracks());
+    assert_eq!(from_binary.images(), scene.images());
+    assert_eq!(from_binary.fonts(), scene.fonts());
+    assert_eq!(from_binary, from_json);
+    // Crossing between the encodings is lossless in both directions.
+    assert_eq!(encode_scene(&from_binary).unwrap(), json);
+    assert_eq!(encode_scene_binary(&from_json).unwrap(), binary);
+    assert!(binary.len() < json.len() / 2);
+}
+
+#[test]
+fn binary_and_json_decode_to_the_same_patch_batch() {
+    let batch = rich_batch();
+    let json = encode_patch_batch(&batch).unwrap();
+    let binary = encode_patch_batch_binary(&batch).unwrap();
+
+    assert_eq!(decode_patch_batch_binary(&binary).unwrap(), batch);
+    assert_eq!(
+        decode_patch_batch_binary(&binary).unwrap(),
+        decode_patch_batch(&json).unwrap()
+    );
+    assert_eq!(
+        encode_patch_batch_binary(&decode_patch_batch(&json).unwrap()).unwrap(),
+        binary
+    );
+}
+
+#[test]
+fn identical_scenes_produce_identical_bytes() {
+    let first = encode_scene_binary(&rich_scene()).unwrap();
+    let second = encode_scene_binary(&rich_scene()).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(
+        encode_patch_batch_binary(&rich_batch()).unwrap(),
+        encode_patch_batch_binary(&rich_batch()).unwrap()
+    );
+}
+
+#[test]
+fn every_truncation_and_corruption_is_an_error_not_a_panic() {
+    let scene = encode_scene_binary(&rich_scene()).unwrap();
+    let batch = encode_patch_batch_binary(&rich_batch()).unwrap();
+    for len in 0..scene.len() {
+        assert!(decode_scene_binary(&scene[..len]).is_err());
+    }
+    for len in 0..batch.len() {
+        assert!(decode_patch_batch_binary(&batch[..len]).is_err());
+    }
+
+    // Deterministic byte flips across both documents; any outcome but a
+    // panic is acceptable, since a flipped float still decodes.
+    let mut state = 0x2545_f491_u32;
+    for _ in 0..4000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let mut corrupt = scene.clone();
+        let index = state as usize % corrupt.len();
+        corrupt[index] ^= (state >> 24) as u8 | 1;
+        let _ = decode_scene_binary(&corrupt);
+
+        let mut corrupt = batch.clone();
+        let index = (state >> 8) as usize % corrupt.len();
+        corrupt[index] = (state >> 16) as u8;
+        let _ = decode_patch_batch_binary(&corrupt);
+    }
+}
+
+#[test]
+fn decoded_scenes_are_validated_like_json_documents() {
+    let mut scene = SceneDefinition::new();
+    scene.add(GeometryRef::circle(1.0));
+    scene.add(GeometryRef::circle(2.0));
+    let mut bytes = encode_scene_binary(&scene).unwrap();
+    // Header and count take 12 bytes and a default-styled circle 60, so the
+    // second object's id starts at 72. Reusing id 0 must fail validation.
+    bytes[72..80].copy_from_slice(&0_u64.to_le_bytes());
+    assert!(matches!(
+        decode_scene_binary(&bytes),
+        Err(IrError::Patch(_))
+    ));
+}
+
+#[test]
+fn property_tags_survive_the_round_trip() {
+    let mut scene = SceneDefinition::new();
+    let object = scene.add(GeometryRef::circle(1.0));
+    let timing = TrackTiming::new(0.0, 1.0, Easing::Linear);
+    for property in [
+        Property::Rotation,
+        Property::Opacity,
+        Property::Appearance,
+        Property::Reveal,
+    ] {
+        scene
+            .animate_scalar(object, property, 0.0, 1.0, timing)
+            .unwrap();
+    }
+    let decoded = decode_scene_binary(&encode_scene_binary(&scene).unwrap()).unwrap();
+    assert_eq!(decoded.tracks(), scene.tracks());
+}
+
+#[test]
+fn documents_written_before_labels_still_decode() {
+    let scene = decode_scene_binary(include_bytes!("fixtures/layout1.nscn")).unwrap();
+    assert_eq!(scene.objects().len(), 2);
+    assert_eq!(scene.tracks().len(), 1);
+    assert!(scene.objects()[0].labels.is_empty());
+    assert!(scene.tracks()[0].labels.is_empty());
+    assert_eq!(
+        decode_scene_binary(&encode_scene_binary(&scene).unwrap()).unwrap(),
+        scene
+    );
+
+    let batch = decode_patch_batch_binary(include_bytes!("fixtures/layout1.npat")).unwrap();
+    assert_eq!(batch.version, noon_ir::FORMAT_VERSION);
+    let [ScenePatch::CreateObject(object)] = batch.patches.as_slice() else {
+        panic!("layout 1 batch holds one object");
+    };
+    assert_eq!(object.id, ObjectId::new(5));
+    assert_eq!(object.geometry, GeometryRef::circle(0.5));
+    assert!(object.labels.is_empty());
+}
@@ -0,0 +1,49 @@
+use noon_core::{
+    Color, GeometryRef, ImageData, SceneDefinition, SceneDiff, ScenePatch, Style, Vec2,
+};
+use noon_ir::{decode_scene, encode_diff_report, encode_scene, PatchBatch};
+
+fn scene(fill: Color, pixels: u8, swap: bool) -> SceneDefinition {
+    let mut scene = SceneDefinition::new();
+    let image = scene.register_image(ImageData::new(1, 1, vec![pixels; 4]).unwrap());
+    let circle = scene.add(GeometryRef::circle(1.0));
+    scene.add(GeometryRef::image(image, Vec2::ONE));
+    scene.object_mut(circle).unwrap().style = Style {
+        fill: Some(fill),
+        ..Style::default()
+    };
+    if swap {
+        scene
+            .apply_patch(ScenePatch::MoveObject {
+                object: circle,
+                index: 1,
+            })
+            .unwrap();
+        scene.object_mut(circle).unwrap().geometry = GeometryRef::square(2.0);
+    }
+    scene
+}
+
+#[test]
+fn diffs_of_decoded_scenes_replay_and_report_as_json() {
+    let current = decode_scene(&encode_scene(&scene(Color::WHITE, 0, false)).unwrap()).unwrap();
+    let desired = decode_scene(&encode_scene(&scene(Color::BLACK, 255, true)).unwrap()).unwrap();
+
+    let diff = SceneDiff::between(&current, &desired);
+    assert_eq!(diff.patches.len(), 4);
+    let mut replayed = current.clone();
+    for patch in PatchBatch::new(0, diff.patches.clone()).patches {
+        replayed.apply_patch(patch).unwrap();
+    }
+    assert_eq!(replayed.objects(), desired.objects());
+    assert_eq!(replayed.images(), desired.images());
+
+    assert_eq!(
+        diff.report.to_string(),
+        "~ image 0: data\n~ object 0: geometry, style\n> object 0: to draw index 1\n"
+    );
+    let json = encode_diff_report(&diff.report).unwrap();
+    assert!(json.contains("\"change\": \"modified\""));
+    assert!(json.contains("\"object\": 0"));
+    assert!(json.contains("\"fields\": [\n        \"geometry\",\n        \"style\"\n      ]"));
+}
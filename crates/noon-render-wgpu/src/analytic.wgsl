@@ -18,6 +18,7 @@ struct VertexInput {
     @location(6) stroke: vec4<f32>,
     @location(7) metrics: vec2<f32>,
     @location(8) flags: vec2<u32>,
+    @location(9) reveal: f32,
 };
 
 struct LineVertexInput {
@@ -31,6 +32,7 @@ struct LineVertexInput {
     @location(7) metrics: vec2<f32>,
     @location(8) flags: vec2<u32>,
     @location(9) end: vec2<f32>,
+    @location(10) reveal: f32,
 };
 
 struct VertexOutput {
@@ -41,8 +43,11 @@ struct VertexOutput {
     @location(3) stroke: vec4<f32>,
     @location(4) metrics: vec2<f32>,
     @location(5) flags: vec2<f32>,
+    @location(6) reveal: f32,
 };
 
+const TAU: f32 = 6.283185307179586;
+
 fn rotate_vector(local: vec2<f32>, rotation: f32) -> vec2<f32> {
     let c = cos(rotation);
     let s = sin(rotation);
@@ -91,6 +96,7 @@ fn make_output(input: VertexInput, local: vec2<f32>) -> VertexOutput {
     output.stroke = input.stroke;
     output.metrics = input.metrics;
     output.flags = vec2<f32>(f32(input.flags.x), f32(input.flags.y));
+    output.reveal = clamp(input.reveal, 0.0, 1.0);
     return output;
 }
 
@@ -141,6 +147,7 @@ fn vs_line(input: LineVertexInput) -> VertexOutput {
     output.stroke = input.stroke;
     output.metrics = input.metrics;
     output.flags = vec2<f32>(f32(input.flags.x), f32(input.flags.y));
+    output.reveal = clamp(input.reveal, 0.0, 1.0);
     return output;
 }
 
@@ -213,14 +220,61 @@ fn capsule_signed_distance(position: vec2<f32>, half_length: f32, radius: f32) -
     return length(offset) - radius;
 }
 
+// Reveal clips analytic shapes by normalized perimeter progress, matching path
+// reveal. Closed outlines start on the positive x axis and run
+// counterclockwise; lines run from start to end. As with tessellated paths,
+// fill sits at progress 1 and appears once the outline is complete.
+fn reveal_coverage(reveal: f32, progress: f32, edge: f32) -> f32 {
+    if reveal <= 0.0 {
+        return 0.0;
+    }
+    if reveal >= 1.0 {
+        return 1.0;
+    }
+    return 1.0 - smoothstep(reveal, reveal + edge, progress);
+}
+
+fn revealed(color: vec4<f32>, coverage: f32) -> vec4<f32> {
+    return vec4<f32>(color.rgb, color.a * coverage);
+}
+
+fn circle_progress(position: vec2<f32>) -> f32 {
+    return fract(atan2(position.y, position.x) / TAU + 1.0);
+}
+
+fn rectangle_progress(position: vec2<f32>, half_size: vec2<f32>) -> f32 {
+    // Project along the ray from the center onto the boundary, then measure
+    // arc length from the middle of the right edge.
+    let ratio = abs(position) / half_size;
+    let boundary = position / max(max(ratio.x, ratio.y), 0.000001);
+    let perimeter = 4.0 * (half_size.x + half_size.y);
+    var distance: f32;
+    if ratio.x >= ratio.y {
+        if boundary.x > 0.0 {
+            distance = select(perimeter + boundary.y, boundary.y, boundary.y >= 0.0);
+        } else {
+            distance = 2.0 * (half_size.x + half_size.y) - boundary.y;
+        }
+    } else if boundary.y > 0.0 {
+        distance = half_size.y + half_size.x - boundary.x;
+    } else {
+        distance = 3.0 * half_size.y + 3.0 * half_size.x + boundary.x;
+    }
+    return distance / perimeter;
+}
+
 @fragment
 fn fs_circle(input: VertexOutput) -> @location(0) vec4<f32> {
     let radius = max(abs(input.geometry.x), 0.000001);
+    // Derivatives run before reveal-dependent control flow.
+    let reveal_edge = length(fwidth(input.local)) / (TAU * radius);
     let signed_distance = length(input.local) - radius;
     let stroke_width = clamp(input.metrics.x, 0.0, radius);
+    let fill_reveal = reveal_coverage(input.reveal, 1.0, reveal_edge);
+    let stroke_reveal = reveal_coverage(input.reveal, circle_progress(input.local), reveal_edge);
     return styled_shape_color(
-        input.fill,
-        input.stroke,
+        revealed(input.fill, fill_reveal),
+        revealed(input.stroke, stroke_reveal),
         input.metrics.y,
         input.flags.x > 0.5,
         input.flags.y > 0.5,
@@ -232,11 +286,15 @@ fn fs_circle(input: VertexOutput) -> @location(0) vec4<f32> {
 @fragment
 fn fs_rectangle(input: VertexOutput) -> @location(0) vec4<f32> {
     let half_size = max(abs(input.geometry) * 0.5, vec2<f32>(0.000001));
+    let reveal_edge = length(fwidth(input.local)) / (4.0 * (half_size.x + half_size.y));
     let signed_distance = rectangle_signed_distance(input.local, half_size);
     let stroke_width = clamp(input.metrics.x, 0.0, min(half_size.x, half_size.y));
+    let fill_reveal = reveal_coverage(input.reveal, 1.0, reveal_edge);
+    let progress = rectangle_progress(input.local, half_size);
+    let stroke_reveal = reveal_coverage(input.reveal, progress, reveal_edge);
     return styled_shape_color(
-        input.fill,
-        input.stroke,
+        revealed(input.fill, fill_reveal),
+        revealed(input.stroke, stroke_reveal),
         input.metrics.y,
         input.flags.x > 0.5,
         input.flags.y > 0.5,
@@ -249,7 +307,11 @@ fn fs_rectangle(input: VertexOutput) -> @location(0) vec4<f32> {
 fn fs_line(input: VertexOutput) -> @location(0) vec4<f32> {
     let half_length = input.geometry.x * 0.5;
     let radius = input.geometry.y * 0.5;
+    let segment_length = max(input.geometry.x, 0.000001);
+    let reveal_edge = length(fwidth(input.local)) / segment_length;
     let signed_distance = capsule_signed_distance(input.local, half_length, radius);
     let visible = select(0.0, 1.0, input.geometry.y > 0.0);
-    return styled_line_color(input, signed_distance) * visible;
+    let progress = clamp((input.local.x + half_length) / segment_length, 0.0, 1.0);
+    let coverage = reveal_coverage(input.reveal, progress, reveal_edge);
+    return styled_line_color(input, signed_distance) * (visible * coverage);
 }
@@ -0,0 +1,153 @@
+//! CPU-built glyph coverage atlas shared by every glyph run.
+
+use noon_core::{FontId, Vec2};
+use noon_geometry::FontFace;
+use std::collections::HashMap;
+
+/// Fixed atlas row length in texels. Growth only appends rows, so texel
+/// coordinates already packed into instances stay valid.
+pub const GLYPH_ATLAS_WIDTH: u32 = 1024;
+/// Largest atlas height; matches wgpu's default `max_texture_dimension_2d`.
+pub const MAX_GLYPH_ATLAS_HEIGHT: u32 = 8192;
+/// Resolution glyphs are rasterized at, independent of their on-screen size.
+pub const GLYPH_PIXELS_PER_EM: f32 = 64.0;
+const INITIAL_GLYPH_ATLAS_HEIGHT: u32 = 256;
+
+/// One rasterized glyph placed in the atlas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AtlasGlyph {
+    /// Texel rectangle as `[x, y, width, height]`, rows counted from the top.
+    pub texels: [u32; 4],
+    /// Bottom-left corner of the glyph quad in em units from its baseline origin.
+    pub origin: Vec2,
+    /// Quad extent in em units.
+    pub size: Vec2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct GlyphAtlasFull;
+
+/// Single-channel coverage atlas packed with simple shelves.
+///
+/// Entries are never evicted while their font stays registered; replacing or
+/// removing a font through [`crate::FramePreparer::sync_fonts`] resets the
+/// whole atlas. `generation` changes whenever pixels change so GPU backends
+/// know when to upload again.
+#[derive(Debug)]
+pub struct GlyphAtlas {
+    height: u32,
+    coverage: Vec<u8>,
+    // `None` records glyphs without contours so they are not retried.
+    entries: HashMap<(FontId, u16), Option<AtlasGlyph>>,
+    shelf_x: u32,
+    shelf_y: u32,
+    shelf_height: u32,
+    generation: u64,
+}
+
+impl Default for GlyphAtlas {
+    fn default() -> Self {
+        Self {
+            height: INITIAL_GLYPH_ATLAS_HEIGHT,
+            coverage: vec![0; (GLYPH_ATLAS_WIDTH * INITIAL_GLYPH_ATLAS_HEIGHT) as usize],
+            entries: HashMap::new(),
+            shelf_x: 0,
+            shelf_y: 0,
+            shelf_height: 0,
+            generation: 0,
+        }
+    }
+}
+
+impl GlyphAtlas {
+    pub const fn width(&self) -> u32 {
+        GLYPH_ATLAS_WIDTH
+    }
+
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Row-major coverage bytes, `width * height` long.
+    pub fn coverage(&self) -> &[u8] {
+        &self.coverage
+    }
+
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Number of cached glyphs, including empty ones such as spaces.
+    pub fn glyph_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn glyph(&self, font: FontId, glyph: u16) -> Option<&AtlasGlyph> {
+        self.entries.get(&(font, glyph))?.as_ref()
+    }
+
+    pub(crate) fn contains(&self, font: FontId, glyph: u16) -> bool {
+        self.entries.contains_key(&(font, glyph))
+    }
+
+    /// Rasterizes and packs a glyph that is not cached yet.
+    pub(crate) fn insert(
+        &mut self,
+        font: FontId,
+        glyph: u16,
+        face: &FontFace<'_>,
+    ) -> Result<Option<AtlasGlyph>, GlyphAtlasFull> {
+        let Some(bitmap) = face.rasterize_glyph(glyph, GLYPH_PIXELS_PER_EM) else {
+            self.entries.insert((font, glyph), None);
+            return Ok(None);
+        };
+        if bitmap.width > GLYPH_ATLAS_WIDTH {
+            return Err(GlyphAtlasFull);
+        }
+        if self.shelf_x + bitmap.width > GLYPH_ATLAS_WIDTH {
+            self.shelf_y += self.shelf_height;
+            self.shelf_x = 0;
+            self.shelf_height = 0;
+        }
+        let bottom = self.shelf_y + bitmap.height;
+        if bottom > self.height {
+            let mut height = self.height;
+            while height < bottom {
+                height *= 2;
+            }
+            if height > MAX_GLYPH_ATLAS_HEIGHT {
+                return Err(GlyphAtlasFull);
+            }
+            self.coverage
+                .resize((GLYPH_ATLAS_WIDTH * height) as usize, 0);
+            self.height = height;
+        }
+
+        let (x, y) = (self.shelf_x, self.shelf_y);
+        for row in 0..bitmap.height {
+            let source = (row * bitmap.width) as usize;
+            let target = ((y + row) * GLYPH_ATLAS_WIDTH + x) as usize;
+            self.coverage[target..target + bitmap.width as usize]
+                .copy_from_slice(&bitmap.coverage[source..source + bitmap.width as usize]);
+        }
+        self.shelf_x += bitmap.width;
+        self.shelf_height = self.shelf_height.max(bitmap.height);
+        self.generation += 1;
+
+        let entry = AtlasGlyph {
+            texels: [x, y, bitmap.width, bitmap.height],
+            origin: bitmap.origin,
+            size: bitmap.size,
+        };
+        self.entries.insert((font, glyph), Some(entry));
+        Ok(Some(entry))
+    }
+
+    pub(crate) fn reset(&mut self) {
+        let generation = self.generation + 1;
+        *self = Self {
+            generation,
+            ..Self::default()
+        };
+    }
+}
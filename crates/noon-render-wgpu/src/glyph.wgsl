@@ -0,0 +1,59 @@
+struct Camera {
+    center: vec2<f32>,
+    clip_scale: vec2<f32>,
+    viewport_size: vec2<f32>,
+    padding: vec2<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+@group(1) @binding(0)
+var glyph_atlas: texture_2d<f32>;
+
+@group(1) @binding(1)
+var glyph_sampler: sampler;
+
+struct GlyphVertexInput {
+    @location(0) unit: vec2<f32>,
+    @location(1) translation: vec2<f32>,
+    @location(2) scale: vec2<f32>,
+    @location(3) rotation: f32,
+    @location(4) quad: vec4<f32>,
+    @location(5) texels: vec4<f32>,
+    @location(6) color: vec4<f32>,
+};
+
+struct GlyphVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) texel: vec2<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vs_glyph(input: GlyphVertexInput) -> GlyphVertexOutput {
+    let corner = input.unit * 0.5 + vec2<f32>(0.5, 0.5);
+    let local = input.quad.xy + corner * input.quad.zw;
+    let c = cos(input.rotation);
+    let s = sin(input.rotation);
+    let scaled = local * input.scale;
+    let world = vec2<f32>(
+        c * scaled.x - s * scaled.y,
+        s * scaled.x + c * scaled.y,
+    ) + input.translation;
+
+    var output: GlyphVertexOutput;
+    output.position = vec4<f32>((world - camera.center) * camera.clip_scale, 0.0, 1.0);
+    // Atlas rows are stored top-down while world space is y-up.
+    output.texel = input.texels.xy + vec2<f32>(corner.x, 1.0 - corner.y) * input.texels.zw;
+    output.color = vec4<f32>(input.color.rgb, clamp(input.color.a, 0.0, 1.0));
+    return output;
+}
+
+@fragment
+fn fs_glyph(input: GlyphVertexOutput) -> @location(0) vec4<f32> {
+    let atlas_size = vec2<f32>(textureDimensions(glyph_atlas));
+    let coverage = textureSample(glyph_atlas, glyph_sampler, input.texel / atlas_size).r;
+    let alpha = input.color.a * coverage;
+    return vec4<f32>(input.color.rgb * alpha, alpha);
+}
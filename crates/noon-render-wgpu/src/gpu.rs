@@ -1,12 +1,12 @@
-use std::mem::size_of;
+use std::{collections::HashMap, mem::size_of};
 
 use bytemuck::{Pod, Zeroable};
-use noon_core::Vec2;
+use noon_core::{ImageData, ImageDefinition, ImageId, ImageSampling, Vec2};
 use wgpu::util::DeviceExt;
 
 use crate::{
-    CircleInstance, LineInstance, PathBatch, PathInstance, PathVertex, PreparedFrame,
-    RectangleInstance,
+    CircleInstance, GlyphAtlas, GlyphInstance, ImageInstance, LineInstance, PathBatch,
+    PathInstance, PathVertex, PreparedFrame, RectangleInstance,
 };
 
 const QUAD_VERTICES: [[f32; 2]; 6] = [
@@ -22,7 +22,7 @@ const QUAD_ATTRIBUTES: [wgpu::VertexAttribute; 1] = wgpu::vertex_attr_array![0 =
 const ANALYTIC_BLEND_STATE: wgpu::BlendState = wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING;
 const PATH_SAMPLE_COUNT: u32 = 4;
 
-const INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 8] = [
+const INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 9] = [
     wgpu::VertexAttribute {
         format: wgpu::VertexFormat::Float32x2,
         offset: 0,
@@ -63,9 +63,14 @@ const INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 8] = [
         offset: 64,
         shader_location: 8,
     },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32,
+        offset: 88,
+        shader_location: 9,
+    },
 ];
 
-const LINE_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 9] = [
+const LINE_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 10] = [
     wgpu::VertexAttribute {
         format: wgpu::VertexFormat::Float32x2,
         offset: 0,
@@ -111,6 +116,11 @@ const LINE_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 9] = [
         offset: 80,
         shader_location: 9,
     },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32,
+        offset: 88,
+        shader_location: 10,
+    },
 ];
 
 const PATH_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 3] =
@@ -158,6 +168,67 @@ const PATH_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 8] = [
     },
 ];
 
+const IMAGE_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 5] = [
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: 0,
+        shader_location: 1,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: 8,
+        shader_location: 2,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32,
+        offset: 16,
+        shader_location: 3,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: 24,
+        shader_location: 4,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32,
+        offset: 32,
+        shader_location: 5,
+    },
+];
+
+const GLYPH_INSTANCE_ATTRIBUTES: [wgpu::VertexAttribute; 6] = [
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: 0,
+        shader_location: 1,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x2,
+        offset: 8,
+        shader_location: 2,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32,
+        offset: 16,
+        shader_location: 3,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x4,
+        offset: 24,
+        shader_location: 4,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x4,
+        offset: 40,
+        shader_location: 5,
+    },
+    wgpu::VertexAttribute {
+        format: wgpu::VertexFormat::Float32x4,
+        offset: 56,
+        shader_location: 6,
+    },
+];
+
 struct AnalyticPipelineDescriptor {
     vertex_entry: &'static str,
     fragment_entry: &'static str,
@@ -238,18 +309,59 @@ pub struct UploadStats {
     pub buffer_reallocations: usize,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImageSyncStats {
+    pub textures_uploaded: usize,
+    pub textures_released: usize,
+    pub bytes_uploaded: usize,
+    /// Uploads shrunk to fit the device's `max_texture_dimension_2d`.
+    pub textures_downscaled: usize,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct DrawStats {
     pub draw_calls: usize,
     pub instances_drawn: usize,
 }
 
+#[derive(Debug)]
+struct GpuImage {
+    data: ImageData,
+    linear_bind_group: wgpu::BindGroup,
+    nearest_bind_group: wgpu::BindGroup,
+}
+
+/// The uploaded glyph atlas and the CPU atlas generation it mirrors.
+#[derive(Debug)]
+struct GpuGlyphAtlas {
+    generation: u64,
+    height: u32,
+    texture: wgpu::Texture,
+    bind_group: wgpu::BindGroup,
+}
+
+impl GpuImage {
+    const fn bind_group(&self, sampling: ImageSampling) -> &wgpu::BindGroup {
+        match sampling {
+            ImageSampling::Linear => &self.linear_bind_group,
+            ImageSampling::Nearest => &self.nearest_bind_group,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct GpuRenderer {
     circle_pipeline: wgpu::RenderPipeline,
     rectangle_pipeline: wgpu::RenderPipeline,
     line_pipeline: wgpu::RenderPipeline,
     path_pipeline: wgpu::RenderPipeline,
+    image_pipeline: wgpu::RenderPipeline,
+    image_bind_group_layout: wgpu::BindGroupLayout,
+    linear_sampler: wgpu::Sampler,
+    nearest_sampler: wgpu::Sampler,
+    textures: HashMap<ImageId, GpuImage>,
+    glyph_pipeline: wgpu::RenderPipeline,
+    glyph_atlas: Option<GpuGlyphAtlas>,
     quad_buffer: wgpu::Buffer,
     camera_buffer: wgpu::Buffer,
     camera_bind_group: wgpu::BindGroup,
@@ -264,6 +376,8 @@ pub struct GpuRenderer {
     path_vertex_buffer: wgpu::Buffer,
     path_index_buffer: wgpu::Buffer,
     path_instance_buffer: wgpu::Buffer,
+    image_buffer: wgpu::Buffer,
+    glyph_buffer: wgpu::Buffer,
     path_render_bundle: Option<wgpu::RenderBundle>,
     path_render_bundle_batches: Vec<PathBatch>,
     path_render_bundle_rebuilds: usize,
@@ -273,6 +387,8 @@ pub struct GpuRenderer {
     path_vertex_capacity_bytes: usize,
     path_index_capacity_bytes: usize,
     path_instance_capacity_bytes: usize,
+    image_capacity_bytes: usize,
+    glyph_capacity_bytes: usize,
 }
 
 impl GpuRenderer {
@@ -363,6 +479,42 @@ impl GpuRenderer {
         let path_shader = device.create_shader_module(wgpu::include_wgsl!("path.wgsl"));
         let path_pipeline =
             create_path_pipeline(device, &pipeline_layout, &path_shader, target_format);
+        let image_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Noon image bind group layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+        let image_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Noon image pipeline layout"),
+                bind_group_layouts: &[Some(&camera_layout), Some(&image_bind_group_layout)],
+                immediate_size: 0,
+            });
+        let image_shader = device.create_shader_module(wgpu::include_wgsl!("image.wgsl"));
+        let image_pipeline =
+            create_image_pipeline(device, &image_pipeline_layout, &image_shader, target_format);
+        let glyph_shader = device.create_shader_module(wgpu::include_wgsl!("glyph.wgsl"));
+        let glyph_pipeline =
+            create_glyph_pipeline(device, &image_pipeline_layout, &glyph_shader, target_format);
+        let linear_sampler = create_image_sampler(device, ImageSampling::Linear);
+        let nearest_sampler = create_image_sampler(device, ImageSampling::Nearest);
         let quad_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Noon unit quad"),
             contents: bytemuck::cast_slice(&QUAD_VERTICES),
@@ -382,6 +534,8 @@ impl GpuRenderer {
             wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
         );
         let path_instance_buffer = empty_instance_buffer(device, "Noon path instances");
+        let image_buffer = empty_instance_buffer(device, "Noon image instances");
+        let glyph_buffer = empty_instance_buffer(device, "Noon glyph instances");
         let (path_msaa_texture, path_msaa_view) =
             create_path_msaa_target(device, target_format, viewport_size);
 
@@ -390,6 +544,13 @@ impl GpuRenderer {
             rectangle_pipeline,
             line_pipeline,
             path_pipeline,
+            image_pipeline,
+            image_bind_group_layout,
+            linear_sampler,
+            nearest_sampler,
+            textures: HashMap::new(),
+            glyph_pipeline,
+            glyph_atlas: None,
             quad_buffer,
             camera_buffer,
             camera_bind_group,
@@ -404,6 +565,8 @@ impl GpuRenderer {
             path_vertex_buffer,
             path_index_buffer,
             path_instance_buffer,
+            image_buffer,
+            glyph_buffer,
             path_render_bundle: None,
             path_render_bundle_batches: Vec::new(),
             path_render_bundle_rebuilds: 0,
@@ -413,9 +576,199 @@ impl GpuRenderer {
             path_vertex_capacity_bytes: 0,
             path_index_capacity_bytes: 0,
             path_instance_capacity_bytes: 0,
+            image_capacity_bytes: 0,
+            glyph_capacity_bytes: 0,
         }
     }
 
+    /// Mirrors the scene's registered images into GPU textures.
+    ///
+    /// Unchanged images are recognized by their shared pixel storage, so calling
+    /// this after every structural change only uploads new or replaced rasters
+    /// and releases textures whose images left the scene. Images larger than
+    /// the device's texture limit are box-filtered down to fit, keeping their
+    /// aspect ratio, and counted in `textures_downscaled`.
+    pub fn sync_images(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        images: &[ImageDefinition],
+    ) -> ImageSyncStats {
+        let mut stats = ImageSyncStats::default();
+        let before = self.textures.len();
+        self.textures
+            .retain(|id, _| images.iter().any(|image| image.id == *id));
+        stats.textures_released = before - self.textures.len();
+
+        for image in images {
+            if self.textures.get(&image.id).is_some_and(|existing| {
+                existing.data.shares_pixels(&image.data) || existing.data == image.data
+            }) {
+                continue;
+            }
+            let texels = TextureTexels::new(
+                &image.data,
+                device.limits().max_texture_dimension_2d,
+                self.target_format.is_srgb(),
+            );
+            stats.textures_uploaded += 1;
+            stats.bytes_uploaded += texels.pixels.len();
+            if texels.width != image.data.width() || texels.height != image.data.height() {
+                stats.textures_downscaled += 1;
+            }
+            let gpu_image = self.create_gpu_image(device, queue, &image.data, &texels);
+            if self.textures.insert(image.id, gpu_image).is_some() {
+                stats.textures_released += 1;
+            }
+        }
+        stats
+    }
+
+    fn create_gpu_image(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        data: &ImageData,
+        texels: &TextureTexels,
+    ) -> GpuImage {
+        let size = wgpu::Extent3d {
+            width: texels.width,
+            height: texels.height,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Noon image texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: image_texture_format(self.target_format),
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &texels.pixels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(texels.width * 4),
+                rows_per_image: Some(texels.height),
+            },
+            size,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = |sampler: &wgpu::Sampler, label: &str| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &self.image_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(sampler),
+                    },
+                ],
+            })
+        };
+        GpuImage {
+            data: data.clone(),
+            linear_bind_group: bind_group(&self.linear_sampler, "Noon linear image bind group"),
+            nearest_bind_group: bind_group(&self.nearest_sampler, "Noon nearest image bind group"),
+        }
+    }
+
+    pub fn texture_count(&self) -> usize {
+        self.textures.len()
+    }
+
+    /// Uploads the CPU glyph atlas when its generation moved on, recreating
+    /// the texture only when the atlas grew. Returns the bytes written.
+    fn sync_glyph_atlas(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        atlas: &GlyphAtlas,
+    ) -> usize {
+        if self
+            .glyph_atlas
+            .as_ref()
+            .is_some_and(|uploaded| uploaded.generation == atlas.generation())
+        {
+            return 0;
+        }
+        let size = wgpu::Extent3d {
+            width: atlas.width(),
+            height: atlas.height(),
+            depth_or_array_layers: 1,
+        };
+        if self
+            .glyph_atlas
+            .as_ref()
+            .is_none_or(|uploaded| uploaded.height != atlas.height())
+        {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("Noon glyph atlas"),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::R8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Noon glyph atlas bind group"),
+                layout: &self.image_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.linear_sampler),
+                    },
+                ],
+            });
+            self.glyph_atlas = Some(GpuGlyphAtlas {
+                generation: atlas.generation(),
+                height: atlas.height(),
+                texture,
+                bind_group,
+            });
+        }
+        let uploaded = self
+            .glyph_atlas
+            .as_mut()
+            .expect("glyph atlas texture was just ensured");
+        uploaded.generation = atlas.generation();
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &uploaded.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            atlas.coverage(),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(atlas.width()),
+                rows_per_image: Some(atlas.height()),
+            },
+            size,
+        );
+        atlas.coverage().len()
+    }
+
     pub fn set_camera(&mut self, queue: &wgpu::Queue, camera: Camera2D) {
         self.camera = camera;
         self.write_camera_uniform(queue);
@@ -462,6 +815,8 @@ impl GpuRenderer {
         let path_vertex_bytes = std::mem::size_of_val(prepared.path_vertices);
         let path_index_bytes = std::mem::size_of_val(prepared.path_indices);
         let path_instance_bytes = std::mem::size_of_val(prepared.paths);
+        let image_bytes = std::mem::size_of_val(prepared.images);
+        let glyph_bytes = std::mem::size_of_val(prepared.glyphs);
         let mut buffer_reallocations = 0;
 
         let circle_reallocated = ensure_capacity(
@@ -520,6 +875,28 @@ impl GpuRenderer {
             "Noon path instances",
         );
         buffer_reallocations += usize::from(path_instance_reallocated);
+        let image_reallocated = ensure_capacity(
+            device,
+            &mut self.image_buffer,
+            &mut self.image_capacity_bytes,
+            image_bytes,
+            "Noon image instances",
+        );
+        buffer_reallocations += usize::from(image_reallocated);
+        let glyph_reallocated = ensure_capacity(
+            device,
+            &mut self.glyph_buffer,
+            &mut self.glyph_capacity_bytes,
+            glyph_bytes,
+            "Noon glyph instances",
+        );
+        buffer_reallocations += usize::from(glyph_reallocated);
+        // Scenes without text never allocate an atlas texture.
+        let atlas_bytes = if prepared.glyphs.is_empty() {
+            0
+        } else {
+            self.sync_glyph_atlas(device, queue, prepared.glyph_atlas)
+        };
 
         self.prepare_path_render_bundle(
             device,
@@ -561,7 +938,19 @@ impl GpuRenderer {
             prepared.paths,
             prepared.path_dirty_ranges,
             path_instance_reallocated,
-        );
+        ) + upload_dirty(
+            queue,
+            &self.image_buffer,
+            prepared.images,
+            prepared.image_dirty_ranges,
+            image_reallocated,
+        ) + upload_dirty(
+            queue,
+            &self.glyph_buffer,
+            prepared.glyphs,
+            prepared.glyph_dirty_ranges,
+            glyph_reallocated,
+        ) + atlas_bytes;
 
         UploadStats {
             bytes_uploaded,
@@ -653,9 +1042,14 @@ impl GpuRenderer {
         let has_analytics = !prepared.circles.is_empty()
             || !prepared.rectangles.is_empty()
             || !prepared.lines.is_empty();
+        // Images share the multisampled layer pass and draw beneath vector paths,
+        // so raster backdrops never cover strokes or fills. Glyph runs draw last
+        // so labels stay legible over both.
+        let has_layers =
+            has_paths || !prepared.image_batches.is_empty() || !prepared.glyphs.is_empty();
         let mut stats = DrawStats::default();
 
-        if has_paths {
+        if has_layers {
             let color_attachments = [Some(wgpu::RenderPassColorAttachment {
                 view: &self.path_msaa_view,
                 depth_slice: None,
@@ -678,21 +1072,23 @@ impl GpuRenderer {
                 occlusion_query_set: None,
                 multiview_mask: None,
             });
-            if let Some(bundle) = &self.path_render_bundle {
+            add_draw_stats(&mut stats, self.draw_images(&mut pass, prepared));
+            if let (true, Some(bundle)) = (has_paths, &self.path_render_bundle) {
                 pass.execute_bundles(std::iter::once(bundle));
                 add_draw_stats(&mut stats, path_draw_stats(prepared));
-            } else {
+            } else if has_paths {
                 add_draw_stats(&mut stats, self.draw_paths(&mut pass, prepared));
             }
+            add_draw_stats(&mut stats, self.draw_glyphs(&mut pass, prepared));
         }
 
-        if has_analytics || !has_paths {
+        if has_analytics || !has_layers {
             let color_attachments = [Some(wgpu::RenderPassColorAttachment {
                 view,
                 depth_slice: None,
                 resolve_target: None,
                 ops: wgpu::Operations {
-                    load: if has_paths {
+                    load: if has_layers {
                         wgpu::LoadOp::Load
                     } else {
                         wgpu::LoadOp::Clear(clear_color)
@@ -702,7 +1098,7 @@ impl GpuRenderer {
             })];
             let timestamp_writes = query_set.map(|query_set| wgpu::RenderPassTimestampWrites {
                 query_set,
-                beginning_of_pass_write_index: (!has_paths).then_some(0),
+                beginning_of_pass_write_index: (!has_layers).then_some(0),
                 end_of_pass_write_index: Some(1),
             });
             let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -760,6 +1156,56 @@ impl GpuRenderer {
         stats
     }
 
+    fn draw_images<'a>(
+        &'a self,
+        pass: &mut wgpu::RenderPass<'a>,
+        prepared: &PreparedFrame<'_>,
+    ) -> DrawStats {
+        let mut stats = DrawStats::default();
+        if prepared.image_batches.is_empty() {
+            return stats;
+        }
+        pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        pass.set_pipeline(&self.image_pipeline);
+        pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
+        pass.set_vertex_buffer(1, self.image_buffer.slice(..));
+        for batch in prepared.image_batches {
+            // Batches whose image has not been synced yet are skipped rather
+            // than drawn with a placeholder texture.
+            let Some(texture) = self.textures.get(&batch.image) else {
+                continue;
+            };
+            pass.set_bind_group(1, texture.bind_group(batch.sampling), &[]);
+            pass.draw(0..6, batch.instance_range.clone());
+            stats.draw_calls += 1;
+            stats.instances_drawn += batch.instance_range.len();
+        }
+        stats
+    }
+
+    fn draw_glyphs<'a>(
+        &'a self,
+        pass: &mut wgpu::RenderPass<'a>,
+        prepared: &PreparedFrame<'_>,
+    ) -> DrawStats {
+        let mut stats = DrawStats::default();
+        // Glyphs are skipped until `upload` has mirrored the atlas.
+        let (false, Some(atlas)) = (prepared.glyphs.is_empty(), &self.glyph_atlas) else {
+            return stats;
+        };
+        let count = u32::try_from(prepared.glyphs.len())
+            .expect("glyph instance count exceeds wgpu draw limits");
+        pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        pass.set_bind_group(1, &atlas.bind_group, &[]);
+        pass.set_pipeline(&self.glyph_pipeline);
+        pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
+        pass.set_vertex_buffer(1, self.glyph_buffer.slice(..));
+        pass.draw(0..6, 0..count);
+        stats.draw_calls += 1;
+        stats.instances_drawn += prepared.glyphs.len();
+        stats
+    }
+
     fn draw_paths<'a>(
         &'a self,
         pass: &mut wgpu::RenderPass<'a>,
@@ -807,6 +1253,19 @@ impl GpuRenderer {
         self.path_instance_capacity_bytes
     }
 
+    pub const fn image_capacity_bytes(&self) -> usize {
+        self.image_capacity_bytes
+    }
+
+    pub const fn glyph_capacity_bytes(&self) -> usize {
+        self.glyph_capacity_bytes
+    }
+
+    /// Height of the uploaded glyph atlas texture, `None` before any text drew.
+    pub fn glyph_atlas_height(&self) -> Option<u32> {
+        self.glyph_atlas.as_ref().map(|atlas| atlas.height)
+    }
+
     pub const fn path_render_bundle_rebuilds(&self) -> usize {
         self.path_render_bundle_rebuilds
     }
@@ -854,6 +1313,22 @@ pub fn line_instance_layout() -> wgpu::VertexBufferLayout<'static> {
     }
 }
 
+pub fn image_instance_layout() -> wgpu::VertexBufferLayout<'static> {
+    wgpu::VertexBufferLayout {
+        array_stride: size_of::<ImageInstance>() as wgpu::BufferAddress,
+        step_mode: wgpu::VertexStepMode::Instance,
+        attributes: &IMAGE_INSTANCE_ATTRIBUTES,
+    }
+}
+
+pub fn glyph_instance_layout() -> wgpu::VertexBufferLayout<'static> {
+    wgpu::VertexBufferLayout {
+        array_stride: size_of::<GlyphInstance>() as wgpu::BufferAddress,
+        step_mode: wgpu::VertexStepMode::Instance,
+        attributes: &GLYPH_INSTANCE_ATTRIBUTES,
+    }
+}
+
 pub fn path_vertex_layout() -> wgpu::VertexBufferLayout<'static> {
     wgpu::VertexBufferLayout {
         array_stride: size_of::<PathVertex>() as wgpu::BufferAddress,
@@ -940,6 +1415,195 @@ fn create_path_pipeline(
     })
 }
 
+fn create_image_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    target_format: wgpu::TextureFormat,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Noon image pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some("vs_image"),
+            compilation_options: Default::default(),
+            buffers: &[quad_vertex_layout(), image_instance_layout()],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some("fs_image"),
+            compilation_options: Default::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: target_format,
+                blend: Some(ANALYTIC_BLEND_STATE),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: PATH_SAMPLE_COUNT,
+            ..Default::default()
+        },
+        multiview_mask: None,
+        cache: None,
+    })
+}
+
+fn create_glyph_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    shader: &wgpu::ShaderModule,
+    target_format: wgpu::TextureFormat,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Noon glyph pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: Some("vs_glyph"),
+            compilation_options: Default::default(),
+            buffers: &[quad_vertex_layout(), glyph_instance_layout()],
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: Some("fs_glyph"),
+            compilation_options: Default::default(),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: target_format,
+                blend: Some(ANALYTIC_BLEND_STATE),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: PATH_SAMPLE_COUNT,
+            ..Default::default()
+        },
+        multiview_mask: None,
+        cache: None,
+    })
+}
+
+fn create_image_sampler(device: &wgpu::Device, sampling: ImageSampling) -> wgpu::Sampler {
+    let (label, filter) = match sampling {
+        ImageSampling::Linear => ("Noon linear image sampler", wgpu::FilterMode::Linear),
+        ImageSampling::Nearest => ("Noon nearest image sampler", wgpu::FilterMode::Nearest),
+    };
+    device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some(label),
+        mag_filter: filter,
+        min_filter: filter,
+        ..Default::default()
+    })
+}
+
+/// Image pixels are sRGB-encoded bytes. Sampling them through an sRGB view
+/// when the target re-encodes on write reproduces the source bytes exactly;
+/// linear targets receive the bytes unchanged instead.
+fn image_texture_format(target_format: wgpu::TextureFormat) -> wgpu::TextureFormat {
+    if target_format.is_srgb() {
+        wgpu::TextureFormat::Rgba8UnormSrgb
+    } else {
+        wgpu::TextureFormat::Rgba8Unorm
+    }
+}
+
+/// Premultiplied pixels of one image, sized to fit the device.
+struct TextureTexels {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl TextureTexels {
+    fn new(data: &ImageData, max_dimension: u32, srgb: bool) -> Self {
+        let pixels = premultiplied_pixels(data.pixels(), srgb);
+        let (width, height) = (data.width(), data.height());
+        let longest = width.max(height);
+        if longest <= max_dimension {
+            return Self {
+                width,
+                height,
+                pixels,
+            };
+        }
+        let fit = |extent: u32| {
+            ((u64::from(extent) * u64::from(max_dimension) / u64::from(longest)) as u32).max(1)
+        };
+        let (fit_width, fit_height) = (fit(width), fit(height));
+        // Each output texel averages the source block it covers; averaging
+        // premultiplied values keeps transparent texels from bleeding color.
+        let span = |index: u32, extent: u32, fitted: u32| {
+            let start = u64::from(index) * u64::from(extent) / u64::from(fitted);
+            let end = (u64::from(index + 1) * u64::from(extent) / u64::from(fitted)).max(start + 1);
+            start as usize..end as usize
+        };
+        let mut fitted = Vec::with_capacity(fit_width as usize * fit_height as usize * 4);
+        for y in 0..fit_height {
+            let rows = span(y, height, fit_height);
+            for x in 0..fit_width {
+                let columns = span(x, width, fit_width);
+                let mut sum = [0_u64; 4];
+                for row in rows.clone() {
+                    for column in columns.clone() {
+                        let offset = (row * width as usize + column) * 4;
+                        for (total, byte) in sum.iter_mut().zip(&pixels[offset..offset + 4]) {
+                            *total += u64::from(*byte);
+                        }
+                    }
+                }
+                let count = (rows.len() * columns.len()) as u64;
+                fitted.extend(sum.map(|total| ((total + count / 2) / count) as u8));
+            }
+        }
+        Self {
+            width: fit_width,
+            height: fit_height,
+            pixels: fitted,
+        }
+    }
+}
+
+/// Straight RGBA bytes with color scaled by alpha, so filtering between a
+/// transparent texel and an opaque one never darkens the edge. sRGB textures
+/// are decoded before blending, so their color is premultiplied in linear
+/// light and re-encoded.
+fn premultiplied_pixels(pixels: &[u8], srgb: bool) -> Vec<u8> {
+    let mut premultiplied = pixels.to_vec();
+    for texel in premultiplied.chunks_exact_mut(4) {
+        let alpha = f32::from(texel[3]) / 255.0;
+        for channel in &mut texel[..3] {
+            let value = f32::from(*channel) / 255.0;
+            let value = if srgb {
+                linear_to_srgb(srgb_to_linear(value) * alpha)
+            } else {
+                value * alpha
+            };
+            *channel = (value * 255.0).round() as u8;
+        }
+    }
+    premultiplied
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 fn empty_instance_buffer(device: &wgpu::Device, label: &str) -> wgpu::Buffer {
     empty_buffer(
         device,
@@ -1065,7 +1729,9 @@ fn upload_dirty<T: Pod>(
 
 #[cfg(test)]
 mod tests {
-    use noon_core::{GeometryRef, ObjectId, Style, Transform2D, VectorPath};
+    use noon_core::{
+        FontData, FontDefinition, FontId, GeometryRef, ObjectId, Style, Transform2D, VectorPath,
+    };
     use noon_runtime::{FrameChanges, FrameObjectState, FrameState};
 
     use crate::FramePreparer;
@@ -1170,19 +1836,23 @@ mod tests {
     #[test]
     fn instance_vertex_layout_matches_packed_struct() {
         let layout = analytic_instance_layout();
-        assert_eq!(layout.array_stride, 88);
+        assert_eq!(layout.array_stride, 92);
         assert_eq!(layout.step_mode, wgpu::VertexStepMode::Instance);
-        assert_eq!(layout.attributes.len(), 8);
+        assert_eq!(layout.attributes.len(), 9);
         assert_eq!(layout.attributes[0].offset, 0);
         assert_eq!(layout.attributes[3].offset, 72);
         assert_eq!(layout.attributes[7].offset, 64);
+        assert_eq!(layout.attributes[8].offset, 88);
+        assert_eq!(layout.attributes[8].shader_location, 9);
 
         let line_layout = line_instance_layout();
-        assert_eq!(line_layout.array_stride, 88);
-        assert_eq!(line_layout.attributes.len(), 9);
+        assert_eq!(line_layout.array_stride, 92);
+        assert_eq!(line_layout.attributes.len(), 10);
         assert_eq!(line_layout.attributes[3].offset, 72);
         assert_eq!(line_layout.attributes[8].offset, 80);
         assert_eq!(line_layout.attributes[8].shader_location, 9);
+        assert_eq!(line_layout.attributes[9].offset, 88);
+        assert_eq!(line_layout.attributes[9].shader_location, 10);
 
         let path_vertex_layout = path_vertex_layout();
         assert_eq!(path_vertex_layout.array_stride, 20);
@@ -1207,6 +1877,214 @@ mod tests {
         assert_eq!(path_instance_layout.attributes[0].shader_location, 3);
         assert_eq!(path_instance_layout.attributes[6].shader_location, 9);
         assert_eq!(path_instance_layout.attributes[7].shader_location, 10);
+
+        let image_layout = image_instance_layout();
+        assert_eq!(image_layout.array_stride, 40);
+        assert_eq!(image_layout.attributes.len(), 5);
+        assert_eq!(image_layout.attributes[3].offset, 24);
+        assert_eq!(image_layout.attributes[4].offset, 32);
+    }
+
+    #[test]
+    fn image_textures_match_target_encoding() {
+        assert_eq!(
+            image_texture_format(wgpu::TextureFormat::Bgra8UnormSrgb),
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        );
+        assert_eq!(
+            image_texture_format(wgpu::TextureFormat::Bgra8Unorm),
+            wgpu::TextureFormat::Rgba8Unorm
+        );
+    }
+
+    #[test]
+    fn image_uploads_premultiply_color_by_alpha() {
+        let straight = [255, 128, 0, 255, 255, 128, 0, 0, 200, 200, 200, 128];
+        assert_eq!(
+            premultiplied_pixels(&straight, false),
+            vec![255, 128, 0, 255, 0, 0, 0, 0, 100, 100, 100, 128]
+        );
+        let srgb = premultiplied_pixels(&straight, true);
+        assert_eq!(&srgb[..8], &[255, 128, 0, 255, 0, 0, 0, 0]);
+        // Half coverage of sRGB 200 is brighter than half the encoded byte.
+        assert_eq!(&srgb[8..], &[147, 147, 147, 128]);
+    }
+
+    #[test]
+    fn oversized_images_are_box_filtered_to_the_texture_limit() {
+        let mut pixels = Vec::new();
+        for x in 0..8_u8 {
+            pixels.extend([x * 10, 0, 0, 255]);
+        }
+        let data = ImageData::new(8, 1, pixels).expect("valid image");
+        let texels = TextureTexels::new(&data, 4, false);
+        assert_eq!((texels.width, texels.height), (4, 1));
+        assert_eq!(
+            texels.pixels,
+            vec![5, 0, 0, 255, 25, 0, 0, 255, 45, 0, 0, 255, 65, 0, 0, 255]
+        );
+        let unchanged = TextureTexels::new(&data, 8, false);
+        assert_eq!((unchanged.width, unchanged.height), (8, 1));
+
+        let (device, queue) = wgpu::Device::noop(&wgpu::DeviceDescriptor::default());
+        let mut renderer = GpuRenderer::new(&device, FORMAT);
+        let limit = device.limits().max_texture_dimension_2d;
+        let wide =
+            ImageData::new(limit * 2, 1, vec![255; limit as usize * 8]).expect("valid image");
+        let sync = renderer.sync_images(
+            &device,
+            &queue,
+            &[ImageDefinition {
+                id: ImageId::new(0),
+                data: wide,
+            }],
+        );
+        assert_eq!(sync.textures_uploaded, 1);
+        assert_eq!(sync.textures_downscaled, 1);
+        assert_eq!(sync.bytes_uploaded, limit as usize * 4);
+    }
+
+    #[test]
+    fn noop_device_syncs_image_textures_and_draws_beneath_paths() {
+        let (device, queue) = wgpu::Device::noop(&wgpu::DeviceDescriptor::default());
+        let mut renderer = GpuRenderer::new(&device, FORMAT);
+        renderer.set_viewport(&device, &queue, 64, 64);
+
+        let data = ImageData::new(2, 2, vec![255; 16]).expect("valid image");
+        let images = vec![ImageDefinition {
+            id: ImageId::new(0),
+            data: data.clone(),
+        }];
+        let sync = renderer.sync_images(&device, &queue, &images);
+        assert_eq!(sync.textures_uploaded, 1);
+        assert_eq!(sync.bytes_uploaded, 16);
+        assert_eq!(
+            renderer.sync_images(&device, &queue, &images.clone()),
+            ImageSyncStats::default()
+        );
+
+        let mut frame = test_frame_with_path();
+        frame.objects.push(FrameObjectState {
+            id: ObjectId::new(7),
+            geometry: GeometryRef::image(ImageId::new(0), Vec2::new(1.0, 1.0)),
+            transform: Transform2D::IDENTITY,
+            style: Style::default(),
+            appearance: 1.0,
+        });
+        frame.objects.push(FrameObjectState {
+            id: ObjectId::new(8),
+            geometry: GeometryRef::image(ImageId::new(9), Vec2::new(1.0, 1.0)),
+            transform: Transform2D::IDENTITY,
+            style: Style::default(),
+            appearance: 1.0,
+        });
+        frame.presences.extend([true; 2]);
+        frame.reveals.extend([1.0; 2]);
+        frame.morphs.extend([0.0; 2]);
+        frame.render_geometries.extend([None, None]);
+        let mut preparer = FramePreparer::new();
+        let prepared = preparer.prepare(&frame);
+        let upload = renderer.upload(&device, &queue, &prepared);
+        assert!(renderer.image_capacity_bytes() >= 2 * size_of::<ImageInstance>());
+        assert_eq!(upload.buffer_reallocations, 5);
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Noon image noop render target"),
+            size: wgpu::Extent3d {
+                width: 64,
+                height: 64,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        let draw = renderer.encode(&mut encoder, &view, &prepared, wgpu::Color::BLACK);
+        queue.submit(Some(encoder.finish()));
+
+        // The unsynced image batch is skipped instead of sampling a stale texture.
+        assert_eq!(draw.draw_calls, 3);
+        assert_eq!(draw.instances_drawn, 3);
+
+        let replaced = vec![ImageDefinition {
+            id: ImageId::new(0),
+            data: ImageData::new(1, 1, vec![0, 0, 0, 255]).expect("valid image"),
+        }];
+        let sync = renderer.sync_images(&device, &queue, &replaced);
+        assert_eq!(sync.textures_uploaded, 1);
+        assert_eq!(sync.textures_released, 1);
+        let sync = renderer.sync_images(&device, &queue, &[]);
+        assert_eq!(sync.textures_released, 1);
+        assert_eq!(renderer.texture_count(), 0);
+    }
+
+    #[test]
+    fn noop_device_uploads_glyph_atlas_only_when_it_changes() {
+        let (device, queue) = wgpu::Device::noop(&wgpu::DeviceDescriptor::default());
+        let mut renderer = GpuRenderer::new(&device, FORMAT);
+        let font = include_bytes!("../../../assets/fonts/noon-test.ttf");
+        let face = noon_geometry::FontFace::parse(font).expect("test font must parse");
+        let mut frame = test_frame();
+        frame.objects.push(FrameObjectState {
+            id: ObjectId::new(9),
+            geometry: GeometryRef::glyph_run(face.glyph_run(FontId::new(0), "LOVI", 0.5)),
+            transform: Transform2D::IDENTITY,
+            style: Style::default(),
+            appearance: 1.0,
+        });
+        frame.presences.push(true);
+        frame.reveals.push(1.0);
+        frame.morphs.push(0.0);
+        frame.render_geometries.push(None);
+
+        let mut preparer = FramePreparer::new();
+        preparer.sync_fonts(&[FontDefinition {
+            id: FontId::new(0),
+            data: FontData::new(font.as_slice()),
+        }]);
+        let prepared = preparer.prepare(&frame);
+        let upload = renderer.upload(&device, &queue, &prepared);
+        let atlas_bytes = (prepared.glyph_atlas.width() * prepared.glyph_atlas.height()) as usize;
+        assert_eq!(
+            upload.bytes_uploaded,
+            atlas_bytes + 3 * size_of::<CircleInstance>() + 4 * size_of::<GlyphInstance>()
+        );
+        assert_eq!(renderer.glyph_atlas_height(), Some(256));
+        assert!(renderer.glyph_capacity_bytes() >= 4 * size_of::<GlyphInstance>());
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Noon glyph noop render target"),
+            size: wgpu::Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        let draw = renderer.encode(&mut encoder, &view, &prepared, wgpu::Color::BLACK);
+        queue.submit(Some(encoder.finish()));
+        // Three analytic batches plus every glyph in one instanced draw.
+        assert_eq!(draw.draw_calls, 4);
+        assert_eq!(draw.instances_drawn, 7);
+
+        frame.objects[3].transform.rotation = 0.5;
+        let prepared = preparer.prepare_incremental(&frame, &FrameChanges::objects(vec![3]));
+        let upload = renderer.upload(&device, &queue, &prepared);
+        assert_eq!(upload.bytes_uploaded, 4 * size_of::<GlyphInstance>());
     }
 
     #[test]
@@ -0,0 +1,55 @@
+struct Camera {
+    center: vec2<f32>,
+    clip_scale: vec2<f32>,
+    viewport_size: vec2<f32>,
+    padding: vec2<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> camera: Camera;
+
+@group(1) @binding(0)
+var image_texture: texture_2d<f32>;
+
+@group(1) @binding(1)
+var image_sampler: sampler;
+
+struct ImageVertexInput {
+    @location(0) unit: vec2<f32>,
+    @location(1) translation: vec2<f32>,
+    @location(2) scale: vec2<f32>,
+    @location(3) rotation: f32,
+    @location(4) size: vec2<f32>,
+    @location(5) opacity: f32,
+};
+
+struct ImageVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) opacity: f32,
+};
+
+@vertex
+fn vs_image(input: ImageVertexInput) -> ImageVertexOutput {
+    let local = input.unit * input.size * 0.5;
+    let c = cos(input.rotation);
+    let s = sin(input.rotation);
+    let scaled = local * input.scale;
+    let world = vec2<f32>(
+        c * scaled.x - s * scaled.y,
+        s * scaled.x + c * scaled.y,
+    ) + input.translation;
+
+    var output: ImageVertexOutput;
+    output.position = vec4<f32>((world - camera.center) * camera.clip_scale, 0.0, 1.0);
+    // Pixel rows are stored top-down while world space is y-up.
+    output.uv = vec2<f32>(input.unit.x * 0.5 + 0.5, 0.5 - input.unit.y * 0.5);
+    output.opacity = clamp(input.opacity, 0.0, 1.0);
+    return output;
+}
+
+@fragment
+fn fs_image(input: ImageVertexOutput) -> @location(0) vec4<f32> {
+    // Texels are uploaded premultiplied, so opacity scales all four channels.
+    return textureSample(image_texture, image_sampler, input.uv) * input.opacity;
+}
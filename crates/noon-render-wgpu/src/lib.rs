@@ -6,16 +6,18 @@
 
 #![forbid(unsafe_code)]
 
+mod glyph;
 mod gpu;
 
+pub use glyph::*;
 pub use gpu::*;
 
 use bytemuck::{Pod, Zeroable};
 use noon_core::{
-    Color, GeometryRef, ObjectId, PathCommand, StrokeCap, StrokeJoin, Style, Transform2D,
-    VectorPath,
+    Color, FontData, FontDefinition, FontId, GeometryRef, GlyphRun, ImageId, ImageSampling,
+    ObjectId, PathCommand, StrokeCap, StrokeJoin, Style, Transform2D, VectorPath,
 };
-use noon_geometry::{PathSurface, TessellatedPath};
+use noon_geometry::{FontFace, PathSurface, TessellatedPath};
 use noon_runtime::{FrameChanges, FrameObjectState, FrameState};
 use std::{
     collections::{hash_map::DefaultHasher, HashMap},
@@ -82,6 +84,8 @@ pub struct CircleInstance {
     pub style: PackedStyle,
     pub radius: f32,
     pub padding: [f32; 3],
+    /// Normalized perimeter progress drawn, shared by every analytic shape.
+    pub reveal: f32,
 }
 
 #[repr(C)]
@@ -91,6 +95,7 @@ pub struct RectangleInstance {
     pub style: PackedStyle,
     pub size: [f32; 2],
     pub padding: [f32; 2],
+    pub reveal: f32,
 }
 
 #[repr(C)]
@@ -100,6 +105,7 @@ pub struct LineInstance {
     pub style: PackedStyle,
     pub start: [f32; 2],
     pub end: [f32; 2],
+    pub reveal: f32,
 }
 
 #[repr(C)]
@@ -122,12 +128,43 @@ pub struct PathVertex {
     pub surface: u32,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ImageInstance {
+    pub transform: PackedTransform,
+    pub size: [f32; 2],
+    /// Style opacity already multiplied by renderer-only appearance.
+    pub opacity: f32,
+    pub padding: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct GlyphInstance {
+    pub transform: PackedTransform,
+    /// Quad bottom-left corner and size in local object units.
+    pub quad: [f32; 4],
+    /// Atlas texel rectangle as top-left corner and size.
+    pub texels: [f32; 4],
+    /// Straight-alpha fill color. Alpha already includes style opacity and
+    /// renderer-only appearance.
+    pub color: [f32; 4],
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PathBatch {
     pub index_range: Range<u32>,
     pub instance_range: Range<u32>,
 }
 
+/// Instances sharing one texture binding. Batches keep first-appearance order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageBatch {
+    pub image: ImageId,
+    pub sampling: ImageSampling,
+    pub instance_range: Range<u32>,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct RenderStats {
     pub batch_count: usize,
@@ -153,11 +190,19 @@ pub struct PreparedFrame<'a> {
     pub path_vertices: &'a [PathVertex],
     pub path_indices: &'a [u32],
     pub path_batches: &'a [PathBatch],
+    pub image_ids: &'a [ObjectId],
+    pub images: &'a [ImageInstance],
+    pub image_batches: &'a [ImageBatch],
+    pub glyph_ids: &'a [ObjectId],
+    pub glyphs: &'a [GlyphInstance],
+    pub glyph_atlas: &'a GlyphAtlas,
     pub unsupported: &'a [ObjectId],
     pub circle_dirty_ranges: &'a [Range<usize>],
     pub rectangle_dirty_ranges: &'a [Range<usize>],
     pub line_dirty_ranges: &'a [Range<usize>],
     pub path_dirty_ranges: &'a [Range<usize>],
+    pub image_dirty_ranges: &'a [Range<usize>],
+    pub glyph_dirty_ranges: &'a [Range<usize>],
     pub path_geometry_dirty: bool,
     pub stats: RenderStats,
 }
@@ -168,7 +213,19 @@ enum PreparedSlot {
     Circle(usize),
     Rectangle(usize),
     Line(usize),
-    Path { index: usize, batch: usize },
+    Path {
+        index: usize,
+        batch: usize,
+    },
+    Image {
+        index: usize,
+        batch: usize,
+    },
+    Glyphs {
+        run: usize,
+        start: usize,
+        len: usize,
+    },
     Unsupported(usize),
 }
 
@@ -199,6 +256,14 @@ struct PathGroup {
     instances: Vec<PathInstance>,
 }
 
+#[derive(Debug)]
+struct ImageGroup {
+    image: ImageId,
+    sampling: ImageSampling,
+    ids: Vec<ObjectId>,
+    instances: Vec<ImageInstance>,
+}
+
 #[derive(Debug, Default)]
 pub struct FramePreparer {
     circle_ids: Vec<ObjectId>,
@@ -217,12 +282,22 @@ pub struct FramePreparer {
     path_mesh_lookup: HashMap<PathMeshKey, Vec<usize>>,
     path_mesh_cache_limit: Option<usize>,
     path_mesh_clock: u64,
+    image_ids: Vec<ObjectId>,
+    images: Vec<ImageInstance>,
+    image_batches: Vec<ImageBatch>,
+    glyph_ids: Vec<ObjectId>,
+    glyphs: Vec<GlyphInstance>,
+    glyph_runs: Vec<(ObjectId, GlyphRun)>,
+    glyph_atlas: GlyphAtlas,
+    fonts: HashMap<FontId, FontData>,
     unsupported: Vec<ObjectId>,
     slots: Vec<PreparedSlot>,
     circle_dirty_ranges: Vec<Range<usize>>,
     rectangle_dirty_ranges: Vec<Range<usize>>,
     line_dirty_ranges: Vec<Range<usize>>,
     path_dirty_ranges: Vec<Range<usize>>,
+    image_dirty_ranges: Vec<Range<usize>>,
+    glyph_dirty_ranges: Vec<Range<usize>>,
     path_geometry_dirty: bool,
     initialized: bool,
 }
@@ -263,7 +338,7 @@ impl FramePreparer {
             match self.slots[object_index] {
                 PreparedSlot::Absent => {}
                 PreparedSlot::Circle(index) => {
-                    let packed = pack_circle(object);
+                    let packed = pack_circle(object, frame.reveal(object_index));
                     instances_repacked += 1;
                     if self.circles[index] != packed {
                         self.circles[index] = packed;
@@ -271,7 +346,7 @@ impl FramePreparer {
                     }
                 }
                 PreparedSlot::Rectangle(index) => {
-                    let packed = pack_rectangle(object);
+                    let packed = pack_rectangle(object, frame.reveal(object_index));
                     instances_repacked += 1;
                     if self.rectangles[index] != packed {
                         self.rectangles[index] = packed;
@@ -279,7 +354,7 @@ impl FramePreparer {
                     }
                 }
                 PreparedSlot::Line(index) => {
-                    let packed = pack_line(object);
+                    let packed = pack_line(object, frame.reveal(object_index));
                     instances_repacked += 1;
                     if self.lines[index] != packed {
                         self.lines[index] = packed;
@@ -298,6 +373,27 @@ impl FramePreparer {
                         push_dirty_range(&mut self.path_dirty_ranges, index);
                     }
                 }
+                PreparedSlot::Image { index, .. } => {
+                    let packed = pack_image(object);
+                    instances_repacked += 1;
+                    if self.images[index] != packed {
+                        self.images[index] = packed;
+                        push_dirty_range(&mut self.image_dirty_ranges, index);
+                    }
+                }
+                PreparedSlot::Glyphs { start, len, .. } => {
+                    let transform = PackedTransform::from(object.transform);
+                    let color = pack_glyph_color(object);
+                    instances_repacked += len;
+                    for index in start..start + len {
+                        let glyph = &mut self.glyphs[index];
+                        if glyph.transform != transform || glyph.color != color {
+                            glyph.transform = transform;
+                            glyph.color = color;
+                            push_dirty_range(&mut self.glyph_dirty_ranges, index);
+                        }
+                    }
+                }
                 PreparedSlot::Unsupported(_) => {}
             }
         }
@@ -306,6 +402,8 @@ impl FramePreparer {
         normalize_dirty_ranges(&mut self.rectangle_dirty_ranges);
         normalize_dirty_ranges(&mut self.line_dirty_ranges);
         normalize_dirty_ranges(&mut self.path_dirty_ranges);
+        normalize_dirty_ranges(&mut self.image_dirty_ranges);
+        normalize_dirty_ranges(&mut self.glyph_dirty_ranges);
 
         self.prepared_frame(frame.time, 0, instances_repacked, 0)
     }
@@ -324,12 +422,20 @@ impl FramePreparer {
         self.paths.clear();
         self.path_batches.clear();
         self.path_batch_cache_indices.clear();
+        self.image_ids.clear();
+        self.images.clear();
+        self.image_batches.clear();
+        self.glyph_ids.clear();
+        self.glyphs.clear();
+        self.glyph_runs.clear();
         self.unsupported.clear();
         self.slots.clear();
         self.clear_dirty_ranges();
 
         let mut path_groups = Vec::<PathGroup>::new();
         let mut path_group_lookup = HashMap::<usize, usize>::new();
+        let mut image_groups = Vec::<ImageGroup>::new();
+        let mut image_group_lookup = HashMap::<(ImageId, ImageSampling), usize>::new();
         let mut geometry_cache_misses = 0;
         for (object_index, object) in frame.objects.iter().enumerate() {
             if !frame.is_present(object_index) {
@@ -341,18 +447,21 @@ impl FramePreparer {
                 GeometryRef::Circle { .. } => {
                     self.slots.push(PreparedSlot::Circle(self.circles.len()));
                     self.circle_ids.push(object.id);
-                    self.circles.push(pack_circle(object));
+                    self.circles
+                        .push(pack_circle(object, frame.reveal(object_index)));
                 }
                 GeometryRef::Rectangle { .. } => {
                     self.slots
                         .push(PreparedSlot::Rectangle(self.rectangles.len()));
                     self.rectangle_ids.push(object.id);
-                    self.rectangles.push(pack_rectangle(object));
+                    self.rectangles
+                        .push(pack_rectangle(object, frame.reveal(object_index)));
                 }
                 GeometryRef::Line { .. } => {
                     self.slots.push(PreparedSlot::Line(self.lines.len()));
                     self.line_ids.push(object.id);
-                    self.lines.push(pack_line(object));
+                    self.lines
+                        .push(pack_line(object, frame.reveal(object_index)));
                 }
                 GeometryRef::VectorPath(path) => {
                     let cache_index = match self.cache_path_mesh(path, object.style) {
@@ -389,6 +498,46 @@ impl FramePreparer {
                     ));
                     self.slots.push(PreparedSlot::Path { index, batch });
                 }
+                GeometryRef::Image {
+                    image, sampling, ..
+                } => {
+                    let batch = *image_group_lookup
+                        .entry((*image, *sampling))
+                        .or_insert_with(|| {
+                            image_groups.push(ImageGroup {
+                                image: *image,
+                                sampling: *sampling,
+                                ids: Vec::new(),
+                                instances: Vec::new(),
+                            });
+                            image_groups.len() - 1
+                        });
+                    let index = image_groups[batch].instances.len();
+                    image_groups[batch].ids.push(object.id);
+                    image_groups[batch].instances.push(pack_image(object));
+                    self.slots.push(PreparedSlot::Image { index, batch });
+                }
+                GeometryRef::GlyphRun(run) => {
+                    let start = self.glyphs.len();
+                    match self.pack_glyph_run(object, run) {
+                        Some(rasterized) => {
+                            geometry_cache_misses += rasterized;
+                            self.slots.push(PreparedSlot::Glyphs {
+                                run: self.glyph_runs.len(),
+                                start,
+                                len: self.glyphs.len() - start,
+                            });
+                            self.glyph_runs.push((object.id, (**run).clone()));
+                        }
+                        None => {
+                            self.glyph_ids.truncate(start);
+                            self.glyphs.truncate(start);
+                            self.slots
+                                .push(PreparedSlot::Unsupported(self.unsupported.len()));
+                            self.unsupported.push(object.id);
+                        }
+                    }
+                }
                 GeometryRef::External(_) => {
                     self.slots
                         .push(PreparedSlot::Unsupported(self.unsupported.len()));
@@ -397,6 +546,22 @@ impl FramePreparer {
             }
         }
 
+        let mut image_offsets = Vec::with_capacity(image_groups.len());
+        for group in image_groups {
+            let instance_start = self.images.len();
+            image_offsets.push(instance_start);
+            self.image_ids.extend(group.ids);
+            self.images.extend(group.instances);
+            self.image_batches.push(ImageBatch {
+                image: group.image,
+                sampling: group.sampling,
+                instance_range: u32::try_from(instance_start)
+                    .expect("image instance count exceeds renderer limits")
+                    ..u32::try_from(self.images.len())
+                        .expect("image instance count exceeds renderer limits"),
+            });
+        }
+
         let mut next_vertices = Vec::new();
         let mut next_indices = Vec::new();
         let mut group_offsets = Vec::with_capacity(path_groups.len());
@@ -434,8 +599,10 @@ impl FramePreparer {
             self.path_batch_cache_indices.push(group.cache_index);
         }
         for slot in &mut self.slots {
-            if let PreparedSlot::Path { index, batch } = slot {
-                *index += group_offsets[*batch];
+            match slot {
+                PreparedSlot::Path { index, batch } => *index += group_offsets[*batch],
+                PreparedSlot::Image { index, batch } => *index += image_offsets[*batch],
+                _ => {}
             }
         }
         self.path_geometry_dirty =
@@ -455,6 +622,12 @@ impl FramePreparer {
         if !self.paths.is_empty() {
             self.path_dirty_ranges.push(0..self.paths.len());
         }
+        if !self.images.is_empty() {
+            self.image_dirty_ranges.push(0..self.images.len());
+        }
+        if !self.glyphs.is_empty() {
+            self.glyph_dirty_ranges.push(0..self.glyphs.len());
+        }
         self.initialized = true;
 
         let capacities_after = self.capacities();
@@ -467,7 +640,12 @@ impl FramePreparer {
         self.prepared_frame(
             frame.time,
             capacity_growths,
-            self.circles.len() + self.rectangles.len() + self.lines.len() + self.paths.len(),
+            self.circles.len()
+                + self.rectangles.len()
+                + self.lines.len()
+                + self.paths.len()
+                + self.images.len()
+                + self.glyphs.len(),
             geometry_cache_misses,
         )
     }
@@ -487,11 +665,16 @@ impl FramePreparer {
                 .path_batches
                 .iter()
                 .filter(|batch| !batch.index_range.is_empty())
-                .count();
+                .count()
+            + self.image_batches.len()
+            + usize::from(!self.glyphs.is_empty());
         let dirty_instance_count = dirty_len(&self.circle_dirty_ranges)
             + dirty_len(&self.rectangle_dirty_ranges)
             + dirty_len(&self.line_dirty_ranges);
-        let dirty_instance_count = dirty_instance_count + dirty_len(&self.path_dirty_ranges);
+        let dirty_instance_count = dirty_instance_count
+            + dirty_len(&self.path_dirty_ranges)
+            + dirty_len(&self.image_dirty_ranges)
+            + dirty_len(&self.glyph_dirty_ranges);
         PreparedFrame {
             time,
             circle_ids: &self.circle_ids,
@@ -505,18 +688,28 @@ impl FramePreparer {
             path_vertices: &self.path_vertices,
             path_indices: &self.path_indices,
             path_batches: &self.path_batches,
+            image_ids: &self.image_ids,
+            images: &self.images,
+            image_batches: &self.image_batches,
+            glyph_ids: &self.glyph_ids,
+            glyphs: &self.glyphs,
+            glyph_atlas: &self.glyph_atlas,
             unsupported: &self.unsupported,
             circle_dirty_ranges: &self.circle_dirty_ranges,
             rectangle_dirty_ranges: &self.rectangle_dirty_ranges,
             line_dirty_ranges: &self.line_dirty_ranges,
             path_dirty_ranges: &self.path_dirty_ranges,
+            image_dirty_ranges: &self.image_dirty_ranges,
+            glyph_dirty_ranges: &self.glyph_dirty_ranges,
             path_geometry_dirty: self.path_geometry_dirty,
             stats: RenderStats {
                 batch_count,
                 instance_count: self.circles.len()
                     + self.rectangles.len()
                     + self.lines.len()
-                    + self.paths.len(),
+                    + self.paths.len()
+                    + self.images.len()
+                    + self.glyphs.len(),
                 unsupported_count: self.unsupported.len(),
                 capacity_growths,
                 instances_repacked,
@@ -531,6 +724,8 @@ impl FramePreparer {
         self.rectangle_dirty_ranges.clear();
         self.line_dirty_ranges.clear();
         self.path_dirty_ranges.clear();
+        self.image_dirty_ranges.clear();
+        self.glyph_dirty_ranges.clear();
         self.path_geometry_dirty = false;
     }
 
@@ -577,6 +772,27 @@ impl FramePreparer {
                     && cache.stroke_cap == object.style.stroke_cap
                     && cache.fill_enabled == object.style.fill.is_some()
             }
+            PreparedSlot::Image { index, batch } => {
+                let GeometryRef::Image {
+                    image, sampling, ..
+                } = render_geometry
+                else {
+                    return false;
+                };
+                self.image_ids.get(*index) == Some(&object.id)
+                    && self
+                        .image_batches
+                        .get(*batch)
+                        .is_some_and(|batch| batch.image == *image && batch.sampling == *sampling)
+            }
+            PreparedSlot::Glyphs { run, .. } => {
+                let GeometryRef::GlyphRun(current) = render_geometry else {
+                    return false;
+                };
+                self.glyph_runs
+                    .get(*run)
+                    .is_some_and(|(id, cached)| *id == object.id && *cached == **current)
+            }
             PreparedSlot::Unsupported(index) => {
                 matches!(render_geometry, GeometryRef::External(_))
                     && self.unsupported.get(*index) == Some(&object.id)
@@ -584,7 +800,7 @@ impl FramePreparer {
         }
     }
 
-    fn capacities(&self) -> [usize; 20] {
+    fn capacities(&self) -> [usize; 28] {
         [
             self.circle_ids.capacity(),
             self.circles.capacity(),
@@ -600,12 +816,20 @@ impl FramePreparer {
             self.path_batch_cache_indices.capacity(),
             self.path_mesh_cache.capacity(),
             self.path_mesh_lookup.capacity(),
+            self.image_ids.capacity(),
+            self.images.capacity(),
+            self.image_batches.capacity(),
+            self.glyph_ids.capacity(),
+            self.glyphs.capacity(),
+            self.glyph_runs.capacity(),
             self.unsupported.capacity(),
             self.slots.capacity(),
             self.circle_dirty_ranges.capacity(),
             self.rectangle_dirty_ranges.capacity(),
             self.line_dirty_ranges.capacity(),
             self.path_dirty_ranges.capacity(),
+            self.image_dirty_ranges.capacity(),
+            self.glyph_dirty_ranges.capacity(),
         ]
     }
 
@@ -764,6 +988,82 @@ impl FramePreparer {
     pub fn cached_path_mesh_count(&self) -> usize {
         self.path_mesh_cache.len()
     }
+
+    /// Mirrors the scene's registered fonts used to rasterize glyph runs.
+    ///
+    /// Call this whenever the font registry may have changed; glyph runs whose
+    /// font is unknown are reported as unsupported. Any change forces the next
+    /// incremental prepare to rebuild, and replacing or removing a font also
+    /// clears the glyph atlas.
+    pub fn sync_fonts(&mut self, fonts: &[FontDefinition]) {
+        let unchanged = |id: &FontId, data: &FontData| {
+            fonts
+                .iter()
+                .any(|font| font.id == *id && (font.data.shares_bytes(data) || font.data == *data))
+        };
+        if self.fonts.len() == fonts.len()
+            && self.fonts.iter().all(|(id, data)| unchanged(id, data))
+        {
+            return;
+        }
+        if !self.fonts.iter().all(|(id, data)| unchanged(id, data)) {
+            self.glyph_atlas.reset();
+        }
+        self.fonts = fonts
+            .iter()
+            .map(|font| (font.id, font.data.clone()))
+            .collect();
+        self.initialized = false;
+    }
+
+    pub fn glyph_atlas(&self) -> &GlyphAtlas {
+        &self.glyph_atlas
+    }
+
+    /// Appends one instance per inked glyph, rasterizing atlas misses first.
+    ///
+    /// Returns the number of glyphs rasterized, or `None` when the run's font is
+    /// unknown or unparsable or the atlas is full.
+    fn pack_glyph_run(&mut self, object: &FrameObjectState, run: &GlyphRun) -> Option<usize> {
+        let mut rasterized = 0;
+        if run
+            .glyphs
+            .iter()
+            .any(|positioned| !self.glyph_atlas.contains(run.font, positioned.glyph))
+        {
+            let face = FontFace::parse(self.fonts.get(&run.font)?.bytes()).ok()?;
+            for positioned in &run.glyphs {
+                if !self.glyph_atlas.contains(run.font, positioned.glyph) {
+                    self.glyph_atlas
+                        .insert(run.font, positioned.glyph, &face)
+                        .ok()?;
+                    rasterized += 1;
+                }
+            }
+        }
+
+        let transform = PackedTransform::from(object.transform);
+        let color = pack_glyph_color(object);
+        for positioned in &run.glyphs {
+            let Some(entry) = self.glyph_atlas.glyph(run.font, positioned.glyph) else {
+                continue;
+            };
+            let [x, y, width, height] = entry.texels;
+            self.glyph_ids.push(object.id);
+            self.glyphs.push(GlyphInstance {
+                transform,
+                quad: [
+                    positioned.origin.x + entry.origin.x * run.size,
+                    positioned.origin.y + entry.origin.y * run.size,
+                    entry.size.x * run.size,
+                    entry.size.y * run.size,
+                ],
+                texels: [x as f32, y as f32, width as f32, height as f32],
+                color,
+            });
+        }
+        Some(rasterized)
+    }
 }
 
 fn path_mesh_key(
@@ -834,7 +1134,7 @@ fn pack_style(object: &FrameObjectState) -> PackedStyle {
     style
 }
 
-fn pack_circle(object: &FrameObjectState) -> CircleInstance {
+fn pack_circle(object: &FrameObjectState, reveal: f32) -> CircleInstance {
     let GeometryRef::Circle { radius } = &object.geometry else {
         unreachable!("circle slot must retain circle geometry")
     };
@@ -843,10 +1143,11 @@ fn pack_circle(object: &FrameObjectState) -> CircleInstance {
         style: pack_style(object),
         radius: *radius,
         padding: [0.0; 3],
+        reveal: reveal.clamp(0.0, 1.0),
     }
 }
 
-fn pack_rectangle(object: &FrameObjectState) -> RectangleInstance {
+fn pack_rectangle(object: &FrameObjectState, reveal: f32) -> RectangleInstance {
     let GeometryRef::Rectangle { size } = &object.geometry else {
         unreachable!("rectangle slot must retain rectangle geometry")
     };
@@ -855,10 +1156,11 @@ fn pack_rectangle(object: &FrameObjectState) -> RectangleInstance {
         style: pack_style(object),
         size: [size.x, size.y],
         padding: [0.0; 2],
+        reveal: reveal.clamp(0.0, 1.0),
     }
 }
 
-fn pack_line(object: &FrameObjectState) -> LineInstance {
+fn pack_line(object: &FrameObjectState, reveal: f32) -> LineInstance {
     let GeometryRef::Line { start, end } = &object.geometry else {
         unreachable!("line slot must retain line geometry")
     };
@@ -867,6 +1169,7 @@ fn pack_line(object: &FrameObjectState) -> LineInstance {
         style: pack_style(object),
         start: [start.x, start.y],
         end: [end.x, end.y],
+        reveal: reveal.clamp(0.0, 1.0),
     }
 }
 
@@ -879,6 +1182,30 @@ fn pack_path(object: &FrameObjectState, reveal: f32, morph: f32) -> PathInstance
     }
 }
 
+fn pack_image(object: &FrameObjectState) -> ImageInstance {
+    let GeometryRef::Image { size, .. } = &object.geometry else {
+        unreachable!("image slot must retain image geometry")
+    };
+    ImageInstance {
+        transform: object.transform.into(),
+        size: [size.x, size.y],
+        opacity: object.style.opacity * object.appearance.clamp(0.0, 1.0),
+        padding: 0.0,
+    }
+}
+
+fn pack_glyph_color(object: &FrameObjectState) -> [f32; 4] {
+    let Some(fill) = object.style.fill else {
+        return [0.0; 4];
+    };
+    [
+        fill.red,
+        fill.green,
+        fill.blue,
+        fill.alpha * object.style.opacity * object.appearance.clamp(0.0, 1.0),
+    ]
+}
+
 fn pack_path_surface(surface: PathSurface, progress: f32) -> u32 {
     let progress = (progress.clamp(0.0, 1.0) * PATH_PROGRESS_MAX as f32).round() as u32;
     (progress << 1)
@@ -1040,11 +1367,13 @@ mod tests {
     fn packed_instance_layout_is_stable() {
         assert_eq!(std::mem::size_of::<PackedTransform>(), 24);
         assert_eq!(std::mem::size_of::<PackedStyle>(), 48);
-        assert_eq!(std::mem::size_of::<CircleInstance>(), 88);
-        assert_eq!(std::mem::size_of::<RectangleInstance>(), 88);
-        assert_eq!(std::mem::size_of::<LineInstance>(), 88);
+        assert_eq!(std::mem::size_of::<CircleInstance>(), 92);
+        assert_eq!(std::mem::size_of::<RectangleInstance>(), 92);
+        assert_eq!(std::mem::size_of::<LineInstance>(), 92);
         assert_eq!(std::mem::size_of::<PathInstance>(), 80);
         assert_eq!(std::mem::size_of::<PathVertex>(), 20);
+        assert_eq!(std::mem::size_of::<ImageInstance>(), 40);
+        assert_eq!(std::mem::size_of::<GlyphInstance>(), 72);
     }
 
     fn curved_path() -> VectorPath {
@@ -1166,6 +1495,28 @@ mod tests {
         assert_eq!(preparer.cached_path_mesh_count(), 1);
     }
 
+    #[test]
+    fn analytic_reveal_is_packed_and_dirties_only_its_instance() {
+        let mut frame = frame(vec![
+            object(1, GeometryRef::circle(1.0)),
+            object(2, GeometryRef::rectangle(2.0, 1.0)),
+            object(3, GeometryRef::line(Vec2::ZERO, Vec2::new(1.0, 0.0))),
+        ]);
+        let mut preparer = FramePreparer::new();
+        let prepared = preparer.prepare(&frame);
+        assert_eq!(prepared.circles[0].reveal, 1.0);
+        assert_eq!(prepared.rectangles[0].reveal, 1.0);
+        assert_eq!(prepared.lines[0].reveal, 1.0);
+
+        frame.reveals = vec![0.25, 1.0, 1.5];
+        let prepared = preparer.prepare_incremental(&frame, &FrameChanges::objects(vec![0, 2]));
+        assert_eq!(prepared.circles[0].reveal, 0.25);
+        assert_eq!(prepared.lines[0].reveal, 1.0, "reveal is clamped");
+        assert_eq!(prepared.circle_dirty_ranges, vec![0..1]);
+        assert!(prepared.line_dirty_ranges.is_empty());
+        assert!(prepared.rectangle_dirty_ranges.is_empty());
+    }
+
     #[test]
     fn path_morph_changes_only_dirty_the_instance_record() {
         let target = VectorPath::new()
@@ -1438,6 +1789,123 @@ mod tests {
         assert_eq!(prepared.circles[1].transform.translation, [3.0, 4.0]);
     }
 
+    #[test]
+    fn images_batch_by_texture_and_sampling_and_compose_appearance() {
+        let first = ImageId::new(0);
+        let second = ImageId::new(1);
+        let mut faded = object(2, GeometryRef::image(first, Vec2::new(4.0, 2.0)));
+        faded.style.opacity = 0.5;
+        faded.appearance = 0.5;
+        let mut frame = frame(vec![
+            object(1, GeometryRef::image(first, Vec2::new(2.0, 1.0))),
+            object(5, GeometryRef::circle(1.0)),
+            object(
+                3,
+                GeometryRef::Image {
+                    image: first,
+                    size: Vec2::new(1.0, 1.0),
+                    sampling: ImageSampling::Nearest,
+                },
+            ),
+            object(4, GeometryRef::image(second, Vec2::new(1.0, 1.0))),
+            faded,
+        ]);
+        let mut preparer = FramePreparer::new();
+        let prepared = preparer.prepare(&frame);
+
+        assert_eq!(
+            prepared.image_ids,
+            &[
+                ObjectId::new(1),
+                ObjectId::new(2),
+                ObjectId::new(3),
+                ObjectId::new(4)
+            ]
+        );
+        assert_eq!(prepared.image_batches.len(), 3);
+        assert_eq!(prepared.image_batches[0].image, first);
+        assert_eq!(prepared.image_batches[0].instance_range, 0..2);
+        assert_eq!(prepared.image_batches[1].sampling, ImageSampling::Nearest);
+        assert_eq!(prepared.image_batches[2].image, second);
+        assert_eq!(prepared.images[1].size, [4.0, 2.0]);
+        assert_eq!(prepared.images[1].opacity, 0.25);
+        assert_eq!(prepared.stats.batch_count, 4);
+        assert_eq!(prepared.stats.instance_count, 5);
+
+        frame.objects[4].geometry = GeometryRef::image(first, Vec2::new(8.0, 4.0));
+        let prepared = preparer.prepare_incremental(&frame, &FrameChanges::objects(vec![4]));
+        assert_eq!(prepared.stats.instances_repacked, 1);
+        assert_eq!(prepared.image_dirty_ranges.len(), 1);
+        assert_eq!(prepared.image_dirty_ranges[0], 1..2);
+        assert_eq!(prepared.images[1].size, [8.0, 4.0]);
+
+        frame.objects[4].geometry = GeometryRef::image(second, Vec2::new(8.0, 4.0));
+        let prepared = preparer.prepare_incremental(&frame, &FrameChanges::objects(vec![4]));
+        assert_eq!(prepared.image_batches.len(), 3);
+        assert_eq!(prepared.image_batches[2].instance_range, 2..4);
+    }
+
+    const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+    fn glyph_run(text: &str) -> GeometryRef {
+        let face = FontFace::parse(TEST_FONT).expect("test font must parse");
+        GeometryRef::glyph_run(face.glyph_run(FontId::new(0), text, 1.0))
+    }
+
+    #[test]
+    fn glyph_runs_share_atlas_entries_and_move_without_rasterizing() {
+        let mut faded = object(2, glyph_run("I"));
+        faded.style.fill = Some(Color::RED);
+        faded.style.opacity = 0.5;
+        faded.appearance = 0.5;
+        let mut frame = frame(vec![
+            object(1, glyph_run("L IL")),
+            faded,
+            object(3, glyph_run("  ")),
+        ]);
+        let mut preparer = FramePreparer::new();
+        preparer.sync_fonts(&[FontDefinition {
+            id: FontId::new(0),
+            data: FontData::new(TEST_FONT),
+        }]);
+        let prepared = preparer.prepare(&frame);
+
+        // L, I and the space are rasterized once; the space has no instance.
+        assert_eq!(prepared.stats.geometry_cache_misses, 3);
+        assert_eq!(prepared.glyph_atlas.glyph_count(), 3);
+        assert_eq!(
+            prepared.glyph_ids,
+            &[
+                ObjectId::new(1),
+                ObjectId::new(1),
+                ObjectId::new(1),
+                ObjectId::new(2)
+            ]
+        );
+        assert_eq!(prepared.glyphs[0].texels, prepared.glyphs[2].texels);
+        assert_eq!(prepared.glyphs[1].texels, prepared.glyphs[3].texels);
+        assert_eq!(prepared.glyphs[3].color[3], 0.25);
+        assert_eq!(prepared.stats.batch_count, 1);
+        assert!(prepared.unsupported.is_empty());
+        let generation = prepared.glyph_atlas.generation();
+        let first_quad = prepared.glyphs[0].quad;
+
+        frame.objects[0].transform.translation = Vec2::new(3.0, 0.0);
+        let prepared = preparer.prepare_incremental(&frame, &FrameChanges::objects(vec![0]));
+        assert_eq!(prepared.stats.instances_repacked, 3);
+        assert_eq!(prepared.stats.geometry_cache_misses, 0);
+        assert_eq!(prepared.glyph_dirty_ranges, vec![0..3]);
+        assert_eq!(prepared.glyphs[0].transform.translation, [3.0, 0.0]);
+        assert_eq!(prepared.glyphs[0].quad, first_quad);
+        assert_eq!(prepared.glyph_atlas.generation(), generation);
+
+        preparer.sync_fonts(&[]);
+        let prepared = preparer.prepare_incremental(&frame, &FrameChanges::objects(vec![0]));
+        assert_eq!(prepared.glyph_atlas.glyph_count(), 0);
+        assert!(prepared.glyphs.is_empty());
+        assert_eq!(prepared.unsupported.len(), 3);
+    }
+
     #[test]
     fn incompatible_incremental_layout_falls_back_to_full_rebuild() {
         let mut frame = frame(vec![object(1, GeometryRef::circle(1.0))]);
@@ -16,3 +16,21 @@ fn reveal_derivative_runs_before_reveal_control_flow() {
         "fragment derivatives must execute before reveal-dependent control flow"
     );
 }
+
+#[test]
+fn analytic_reveal_derivatives_run_before_reveal_control_flow() {
+    let shader = include_str!("../src/analytic.wgsl");
+    for entry in ["fn fs_circle", "fn fs_rectangle", "fn fs_line"] {
+        let body = &shader[shader.find(entry).expect("fragment entry point")..];
+        let derivative = body
+            .find("let reveal_edge = length(fwidth(input.local))")
+            .expect("analytic shader must evaluate a reveal derivative");
+        let coverage = body
+            .find("reveal_coverage(input.reveal")
+            .expect("analytic shader must apply reveal");
+        assert!(
+            derivative < coverage,
+            "{entry}: fragment derivatives must execute before reveal-dependent control flow"
+        );
+    }
+}
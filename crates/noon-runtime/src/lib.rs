@@ -2,10 +2,12 @@
 
 #![forbid(unsafe_code)]
 
-use noon_compile::{CompilePatchError, CompiledScene, CompiledTrack, TransformGeometryPlan};
+use noon_compile::{
+    CompilePatchError, CompiledScene, CompiledTrack, MotionPlan, TransformGeometryPlan,
+};
 use noon_core::{
-    Color, Easing, GeometryRef, ObjectId, ObjectSnapshot, Property, ScenePatch, Style, TrackValues,
-    Transform2D, Vec2,
+    Color, FontDefinition, GeometryRef, ImageDefinition, ObjectId, ObjectSnapshot, Property,
+    ScenePatch, Style, TrackValues, Transform2D, Vec2,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -195,6 +197,16 @@ impl SceneInstance {
         self.compiled.object_index(id).is_some()
     }
 
+    /// Registered pixel data referenced by `GeometryRef::Image` objects.
+    pub fn images(&self) -> &[ImageDefinition] {
+        self.compiled.images()
+    }
+
+    /// Registered font files referenced by `GeometryRef::GlyphRun` objects.
+    pub fn fonts(&self) -> &[FontDefinition] {
+        self.compiled.fonts()
+    }
+
     pub fn evaluate(&mut self, time: f64) -> Result<&FrameState, EvaluationError> {
         if !time.is_finite() {
             return Err(EvaluationError::InvalidTime(time));
@@ -235,6 +247,14 @@ impl SceneInstance {
             self.apply_value_patch(patch)?;
             return Ok(&self.frame);
         }
+        // Labels never reach the frame.
+        if matches!(
+            patch,
+            ScenePatch::SetLabels { .. } | ScenePatch::SetTrackLabels { .. }
+        ) {
+            self.compiled.apply_patch(patch)?;
+            return Ok(&self.frame);
+        }
         let current_time = self.frame.time;
         self.compiled.apply_patch(patch)?;
         self.groups = build_groups(self.compiled.tracks());
@@ -259,14 +279,24 @@ impl SceneInstance {
         match patch {
             ScenePatch::SetTransform { transform, .. } => {
                 self.frame.objects[index].transform = *transform;
+                // Transform tracks restore snapshot geometry, so counters
+                // are re-typeset after them.
                 self.reapply_properties(
                     index,
-                    &[Property::Transform, Property::Position, Property::Rotation],
+                    &[
+                        Property::Transform,
+                        Property::Position,
+                        Property::Rotation,
+                        Property::Value,
+                    ],
                 );
             }
             ScenePatch::SetStyle { style, .. } => {
                 self.frame.objects[index].style = *style;
-                self.reapply_properties(index, &[Property::Transform, Property::Opacity]);
+                self.reapply_properties(
+                    index,
+                    &[Property::Transform, Property::Opacity, Property::Value],
+                );
             }
             _ => unreachable!("value patch helper only accepts transform or style patches"),
         }
@@ -513,6 +543,12 @@ fn apply_group(
             object.style.opacity = value;
             changed
         }
+        (Property::Value, EvaluatedValue::Scalar(value)) => {
+            match &mut frame.objects[group.object_index].geometry {
+                GeometryRef::GlyphRun(run) => run.set_number_value(value),
+                _ => false,
+            }
+        }
         _ => unreachable!("compiled track value type must match its property"),
     }
 }
@@ -529,7 +565,7 @@ fn apply_transform_track(
     track: &CompiledTrack,
     time: f64,
 ) -> bool {
-    let TrackValues::Object { from, to } = &track.values else {
+    let Some((from, to)) = track.values.snapshots() else {
         unreachable!("compiled Transform track must contain object snapshots");
     };
     let progress = track_progress(track, time);
@@ -537,7 +573,10 @@ fn apply_transform_track(
         .transform_geometry_plan
         .as_ref()
         .expect("compiled Transform track must carry a geometry plan");
-    let next_transform = interpolate_transform(from.transform, to.transform, progress);
+    let mut next_transform = interpolate_transform(from.transform, to.transform, progress);
+    if let Some(motion) = &track.motion_plan {
+        apply_motion(&mut next_transform, motion, from.transform, progress);
+    }
     let next_style = interpolate_style(from.style, to.style, progress);
     let next_morph = if matches!(plan, TransformGeometryPlan::PathPair(_)) {
         progress
@@ -570,6 +609,40 @@ fn apply_transform_track(
     changed
 }
 
+/// Replaces the straight-line translation with the compiled route. Endpoints
+/// are exact: progress 1 lands on the authored target translation.
+fn apply_motion(
+    transform: &mut Transform2D,
+    motion: &MotionPlan,
+    from: Transform2D,
+    progress: f32,
+) {
+    if progress >= 1.0 {
+        return;
+    }
+    match motion {
+        MotionPlan::Arc {
+            center,
+            angle,
+            unrotated_end,
+        } => {
+            let straight = interpolate_vec2(from.translation, *unrotated_end, progress);
+            transform.translation = *center + (straight - *center).rotate(angle * progress);
+        }
+        MotionPlan::Path {
+            sampler,
+            follow_tangent,
+        } => {
+            let start = sampler.sample(0.0);
+            let sample = sampler.sample(progress);
+            transform.translation = from.translation + (sample.point - start.point);
+            if *follow_tangent {
+                transform.rotation = from.rotation + sample.turning;
+            }
+        }
+    }
+}
+
 fn apply_transform_geometry(
     current: &mut GeometryRef,
     plan: &TransformGeometryPlan,
@@ -633,6 +706,24 @@ fn apply_transform_geometry(
                 }
             }
         }
+        TransformGeometryPlan::Image { from_size, to_size } => {
+            let next = interpolate_vec2(*from_size, *to_size, progress);
+            match current {
+                GeometryRef::Image { size, .. } if *size == next => false,
+                GeometryRef::Image { size, .. } => {
+                    *size = next;
+                    true
+                }
+                _ => {
+                    let mut geometry = from.geometry.clone();
+                    if let GeometryRef::Image { size, .. } = &mut geometry {
+                        *size = next;
+                    }
+                    *current = geometry;
+                    true
+                }
+            }
+        }
         TransformGeometryPlan::PathPair(_) => {
             let semantic_geometry = if progress >= 1.0 {
                 &to.geometry
@@ -750,7 +841,7 @@ fn interpolate_color(from: Color, to: Color, progress: f32) -> Color {
 fn track_progress(track: &CompiledTrack, time: f64) -> f32 {
     debug_assert!(!track.property.is_instant());
     let raw = ((time - track.timing.start_time) / track.timing.duration).clamp(0.0, 1.0) as f32;
-    apply_easing(track.timing.easing, raw)
+    track.timing.easing.apply(raw)
 }
 
 fn interpolate(track: &CompiledTrack, time: f64) -> EvaluatedValue {
@@ -764,7 +855,7 @@ fn interpolate(track: &CompiledTrack, time: f64) -> EvaluatedValue {
         TrackValues::Bool { .. } => {
             unreachable!("Presence tracks are evaluated as discrete events")
         }
-        TrackValues::Object { .. } => {
+        TrackValues::Object { .. } | TrackValues::Motion { .. } => {
             unreachable!("Transform tracks are evaluated atomically")
         }
     }
@@ -774,24 +865,12 @@ const fn lerp(from: f32, to: f32, progress: f32) -> f32 {
     from + (to - from) * progress
 }
 
-fn apply_easing(easing: Easing, progress: f32) -> f32 {
-    match easing {
-        Easing::Linear => progress,
-        Easing::EaseInOutCubic => {
-            if progress < 0.5 {
-                4.0 * progress * progress * progress
-            } else {
-                1.0 - (-2.0 * progress + 2.0).powi(3) / 2.0
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use noon_compile::CompiledScene;
     use noon_core::{
-        Color, Easing, GeometryRef, Property, SceneDefinition, Style, TrackDefinition, TrackTiming,
+        Color, Easing, GeometryRef, Labels, MotionPath, Property, SceneDefinition, Style,
+        TrackDefinition, TrackTiming,
     };
 
     use super::*;
@@ -840,6 +919,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn motion_tracks_follow_arcs_and_paths_with_exact_endpoints() {
+        let mut scene = SceneDefinition::new();
+        let arc = scene.add(GeometryRef::circle(0.5));
+        let along = scene.add(GeometryRef::circle(0.5));
+        let from = scene
+            .snapshot(arc)
+            .expect("object exists")
+            .shift(Vec2::new(1.0, 0.0));
+        let to = from.clone().shift(Vec2::new(-2.0, 0.0));
+        let timing = TrackTiming::new(0.0, 2.0, Easing::Linear);
+        scene
+            .animate_motion(
+                arc,
+                from.clone(),
+                to,
+                MotionPath::Arc {
+                    angle: std::f32::consts::PI,
+                },
+                timing,
+            )
+            .expect("valid arc track");
+        let path = noon_core::VectorPath::new()
+            .move_to(Vec2::ZERO)
+            .line_to(Vec2::new(2.0, 0.0))
+            .line_to(Vec2::new(2.0, 2.0));
+        let mut target = from.clone().shift(Vec2::new(2.0, 2.0));
+        target.transform.rotation = std::f32::consts::FRAC_PI_2;
+        scene
+            .animate_motion(
+                along,
+                from.clone(),
+                target.clone(),
+                MotionPath::Path {
+                    path,
+                    follow_tangent: true,
+                },
+                timing,
+            )
+            .expect("valid path track");
+        let mut instance =
+            SceneInstance::new(CompiledScene::compile(&scene).expect("scene must compile"));
+
+        let frame = instance.seek(1.0).expect("valid time");
+        let swung = frame.objects[0].transform.translation;
+        assert!((swung - Vec2::new(0.0, 1.0)).length() < 1.0e-5, "{swung:?}");
+        assert_eq!(frame.objects[1].transform.translation, Vec2::new(3.0, 0.0));
+        assert!((frame.objects[1].transform.rotation - std::f32::consts::FRAC_PI_4).abs() < 1.0e-5);
+
+        let frame = instance.seek(0.5).expect("valid time");
+        assert_eq!(frame.objects[1].transform.rotation, 0.0);
+        let frame = instance.seek(2.0).expect("valid time");
+        assert_eq!(frame.objects[0].transform.translation, Vec2::new(-1.0, 0.0));
+        assert_eq!(frame.objects[1].transform, target.transform);
+    }
+
+    #[test]
+    fn pivot_motion_swings_around_the_pivot_while_scaling() {
+        let mut scene = SceneDefinition::new();
+        let bob = scene.add(GeometryRef::circle(0.25));
+        let from = scene
+            .snapshot(bob)
+            .expect("object exists")
+            .shift(Vec2::new(0.0, -2.0));
+        let pivot = Vec2::new(0.0, 0.0);
+        let to = from
+            .clone()
+            .rotate_about(std::f32::consts::FRAC_PI_2, pivot)
+            .scale_about(2.0, pivot);
+        scene
+            .animate_motion(
+                bob,
+                from,
+                to.clone(),
+                MotionPath::Pivot {
+                    point: pivot,
+                    angle: std::f32::consts::FRAC_PI_2,
+                },
+                TrackTiming::new(0.0, 1.0, Easing::Linear),
+            )
+            .expect("valid pivot track");
+        let mut instance =
+            SceneInstance::new(CompiledScene::compile(&scene).expect("scene must compile"));
+
+        let frame = instance.seek(0.5).expect("valid time");
+        let half = frame.objects[0].transform;
+        let expected = Vec2::new(1.5, -1.5) * std::f32::consts::SQRT_2;
+        assert!((half.translation - expected).length() < 1.0e-5, "{half:?}");
+        assert!((half.rotation - std::f32::consts::FRAC_PI_4).abs() < 1.0e-6);
+        assert_eq!(half.scale, Vec2::new(1.5, 1.5));
+        let frame = instance.seek(1.0).expect("valid time");
+        let end = frame.objects[0].transform;
+        assert!((end.translation - to.transform.translation).length() < 1.0e-6);
+        assert_eq!(end.rotation, to.transform.rotation);
+    }
+
     #[test]
     fn presence_events_are_discrete_and_direct_seek_matches_forward_playback() {
         let mut scene = SceneDefinition::new();
@@ -1048,6 +1223,7 @@ mod tests {
                 to: Vec2::new(8.0, 2.0),
             },
             timing: TrackTiming::new(0.0, 4.0, Easing::Linear),
+            labels: Labels::new(),
         };
         let track_patch = ScenePatch::ReplaceTrack(replacement);
         let style_patch = ScenePatch::SetStyle {
@@ -1095,6 +1271,7 @@ mod tests {
                 to: false,
             },
             timing: TrackTiming::instant(1.0),
+            labels: Labels::new(),
         };
         let patch = ScenePatch::AddTrack(presence);
         live.apply_patch(&patch).expect("presence patch must apply");
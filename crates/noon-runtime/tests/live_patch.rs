@@ -1,6 +1,6 @@
 use noon_compile::{CompilePatchError, CompiledScene};
 use noon_core::{
-    Easing, GeometryRef, ObjectDefinition, ObjectId, Property, SceneDefinition, ScenePatch,
+    Easing, GeometryRef, Labels, ObjectDefinition, ObjectId, Property, SceneDefinition, ScenePatch,
     TrackDefinition, TrackId, TrackTiming, TrackValues, Transform2D, Vec2,
 };
 use noon_runtime::SceneInstance;
@@ -47,6 +47,7 @@ fn create_add_track_and_remove_match_full_recompile() {
             to: Vec2::new(6.0, 3.0),
         },
         timing: TrackTiming::new(1.0, 2.0, Easing::Linear),
+        labels: Labels::new(),
     };
     let add_track = ScenePatch::AddTrack(track);
     live.apply_patch(&add_track)
@@ -83,6 +84,7 @@ fn rejected_patch_is_transactional() {
         property: Property::Opacity,
         values: TrackValues::Scalar { from: 1.0, to: 0.0 },
         timing: TrackTiming::new(0.0, 1.0, Easing::Linear),
+        labels: Labels::new(),
     });
 
     assert_eq!(
@@ -118,6 +120,7 @@ fn replacing_track_preserves_unrelated_object_identity_and_time() {
             to: Vec2::new(8.0, 2.0),
         },
         timing: TrackTiming::new(0.0, 4.0, Easing::Linear),
+        labels: Labels::new(),
     });
     live.apply_patch(&patch).expect("live patch must succeed");
     definition
@@ -128,3 +131,40 @@ fn replacing_track_preserves_unrelated_object_identity_and_time() {
     assert_eq!(live.frame().objects[1].id, untouched);
     assert_live_matches_definition(&mut live, &definition, 2.0);
 }
+
+#[test]
+fn relabelling_leaves_the_frame_untouched() {
+    let mut definition = SceneDefinition::new();
+    let object = definition.add(GeometryRef::circle(1.0));
+    let track = definition
+        .animate_position(
+            object,
+            Vec2::ZERO,
+            Vec2::new(4.0, 0.0),
+            TrackTiming::new(0.0, 4.0, Easing::Linear),
+        )
+        .expect("valid track");
+    let mut live = SceneInstance::new(CompiledScene::compile(&definition).expect("compiles"));
+    let before = live.seek(2.0).expect("valid seek").clone();
+    live.take_frame_changes();
+
+    for patch in [
+        ScenePatch::SetLabels {
+            object,
+            labels: Labels::named("ball").with_tag("hero"),
+        },
+        ScenePatch::SetTrackLabels {
+            track,
+            labels: Labels::named("roll"),
+        },
+    ] {
+        live.apply_patch(&patch).expect("live patch must succeed");
+        definition
+            .apply_patch(patch)
+            .expect("definition patch must succeed");
+    }
+
+    assert_eq!(live.frame(), &before);
+    assert!(live.take_frame_changes().is_empty());
+    assert_live_matches_definition(&mut live, &definition, 3.0);
+}
@@ -6,14 +6,20 @@
 #![forbid(unsafe_code)]
 
 mod clock;
+mod session;
 
 pub use clock::*;
+pub use session::*;
 
 use noon_compile::{CompileError, CompilePatchError, CompiledScene};
-use std::collections::{BTreeMap, BTreeSet};
 
-use noon_core::{ObjectId, PatchError, SceneDefinition, ScenePatch};
-use noon_ir::{decode_patch_batch, decode_scene, encode_scene, IrError};
+use noon_core::{
+    FontDefinition, ImageDefinition, ObjectId, PatchError, SceneDefinition, SceneDiff, ScenePatch,
+};
+use noon_ir::{
+    decode_bundle, decode_patch_batch, decode_scene, encode_scene, read_bundle_manifest, IrError,
+    PatchBatch, SceneDocument, SessionEvent,
+};
 use noon_runtime::{EvaluationError, FrameChanges, FrameState, SceneInstance};
 
 #[derive(Debug)]
@@ -83,40 +89,73 @@ pub struct ScenePlayer {
     definition: SceneDefinition,
     instance: SceneInstance,
     next_sequence: u64,
+    /// Session log lines not yet taken, while recording.
+    recording: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ReconcileOutcome {
     Incremental { patch_count: usize },
     Rebuilt { patch_count: usize },
-    Replaced,
 }
 
 impl ScenePlayer {
     pub fn from_scene_json(json: &str) -> Result<Self, PlayerError> {
-        let definition = decode_scene(json)?;
+        Self::from_definition(decode_scene(json)?)
+    }
+
+    /// Loads a self-contained bundle written by `noon_ir::encode_bundle`.
+    pub fn from_bundle(bytes: &[u8]) -> Result<Self, PlayerError> {
+        Self::from_definition(decode_bundle(bytes)?)
+    }
+
+    /// Timeline length recorded in a bundle's manifest, read without
+    /// decoding the scene.
+    pub fn bundle_duration(bytes: &[u8]) -> Result<f64, PlayerError> {
+        Ok(read_bundle_manifest(bytes)?.duration)
+    }
+
+    fn from_definition(definition: SceneDefinition) -> Result<Self, PlayerError> {
         let compiled = CompiledScene::compile(&definition)?;
         Ok(Self {
             definition,
             instance: SceneInstance::new(compiled),
             next_sequence: 0,
+            recording: None,
         })
     }
 
     pub fn seek(&mut self, time: f64) -> Result<&FrameState, PlayerError> {
-        Ok(self.instance.seek(time)?)
+        self.instance.seek(time)?;
+        self.record(|_| SessionEvent::Seek { time })?;
+        Ok(self.instance.frame())
     }
 
     pub fn advance_to(&mut self, time: f64) -> Result<&FrameState, PlayerError> {
-        Ok(self.instance.advance_to(time)?)
+        self.instance.advance_to(time)?;
+        self.record(|_| SessionEvent::Advance { time })?;
+        Ok(self.instance.frame())
     }
 
     pub fn take_frame_changes(&mut self) -> FrameChanges {
         self.instance.take_frame_changes()
     }
 
+    /// Registered images the renderer must mirror after structural changes.
+    pub fn images(&self) -> &[ImageDefinition] {
+        self.instance.images()
+    }
+
+    /// Registered fonts the glyph atlas must mirror after structural changes.
+    pub fn fonts(&self) -> &[FontDefinition] {
+        self.instance.fonts()
+    }
+
     pub fn apply_patch_batch_json(&mut self, json: &str) -> Result<&FrameState, PlayerError> {
-        let batch = decode_patch_batch(json)?;
+        self.apply_patch_batch(decode_patch_batch(json)?)
+    }
+
+    fn apply_patch_batch(&mut self, batch: PatchBatch) -> Result<&FrameState, PlayerError> {
         if batch.sequence != self.next_sequence {
             return Err(PlayerError::Sequence {
                 expected: self.next_sequence,
@@ -130,11 +169,20 @@ impl ScenePlayer {
             .ok_or(PlayerError::SequenceExhausted)?;
         self.apply_patches_transactionally(&batch.patches)?;
         self.next_sequence = next_sequence;
+        let time = self.instance.frame().time;
+        self.record(|_| SessionEvent::Patch { time, batch })?;
         Ok(self.instance.frame())
     }
 
     pub fn replace_scene_json(&mut self, json: &str) -> Result<&FrameState, PlayerError> {
-        let definition = decode_scene(json)?;
+        self.replace_scene(decode_scene(json)?)
+    }
+
+    pub fn replace_scene_bundle(&mut self, bytes: &[u8]) -> Result<&FrameState, PlayerError> {
+        self.replace_scene(decode_bundle(bytes)?)
+    }
+
+    fn replace_scene(&mut self, definition: SceneDefinition) -> Result<&FrameState, PlayerError> {
         let compiled = CompiledScene::compile(&definition)?;
         let playhead = self.instance.frame().time;
         let mut instance = SceneInstance::new(compiled);
@@ -143,19 +191,28 @@ impl ScenePlayer {
         self.definition = definition;
         self.instance = instance;
         self.next_sequence = 0;
+        self.record(|player| SessionEvent::Replace {
+            scene: SceneDocument::from_scene(&player.definition),
+        })?;
         Ok(self.instance.frame())
     }
 
     pub fn reconcile_scene_json(&mut self, json: &str) -> Result<ReconcileOutcome, PlayerError> {
-        let desired = decode_scene(json)?;
-        let Some(patches) = scene_diff(&self.definition, &desired) else {
-            self.replace_scene_json(json)?;
-            return Ok(ReconcileOutcome::Replaced);
-        };
+        self.reconcile_scene(decode_scene(json)?)
+    }
+
+    fn reconcile_scene(
+        &mut self,
+        desired: SceneDefinition,
+    ) -> Result<ReconcileOutcome, PlayerError> {
+        let patches = SceneDiff::between(&self.definition, &desired).patches;
         let patch_count = patches.len();
         let value_only = patches.iter().all(is_value_patch);
         self.apply_patches_transactionally(&patches)?;
         self.next_sequence = 0;
+        self.record(|_| SessionEvent::Reconcile {
+            scene: SceneDocument::from_scene(&desired),
+        })?;
         Ok(if value_only {
             ReconcileOutcome::Incremental { patch_count }
         } else {
@@ -232,114 +289,6 @@ fn value_patch_object(patch: &ScenePatch) -> ObjectId {
     }
 }
 
-fn scene_diff(current: &SceneDefinition, desired: &SceneDefinition) -> Option<Vec<ScenePatch>> {
-    let current_objects = current
-        .objects()
-        .iter()
-        .map(|object| (object.id, object))
-        .collect::<BTreeMap<_, _>>();
-    let desired_objects = desired
-        .objects()
-        .iter()
-        .map(|object| (object.id, object))
-        .collect::<BTreeMap<_, _>>();
-    if !append_compatible(
-        current.objects().iter().map(|object| object.id),
-        desired.objects().iter().map(|object| object.id),
-    ) {
-        return None;
-    }
-    for object in desired.objects() {
-        if let Some(existing) = current_objects.get(&object.id) {
-            if existing.geometry != object.geometry {
-                return None;
-            }
-        }
-    }
-
-    let current_tracks = current
-        .tracks()
-        .iter()
-        .map(|track| (track.id, track))
-        .collect::<BTreeMap<_, _>>();
-    let desired_tracks = desired
-        .tracks()
-        .iter()
-        .map(|track| (track.id, track))
-        .collect::<BTreeMap<_, _>>();
-    if !append_compatible(
-        current.tracks().iter().map(|track| track.id),
-        desired.tracks().iter().map(|track| track.id),
-    ) {
-        return None;
-    }
-
-    let removed_objects = current_objects
-        .keys()
-        .filter(|id| !desired_objects.contains_key(id))
-        .copied()
-        .collect::<BTreeSet<ObjectId>>();
-    let mut patches = Vec::new();
-    for (id, track) in &current_tracks {
-        if !desired_tracks.contains_key(id) && !removed_objects.contains(&track.object) {
-            patches.push(ScenePatch::RemoveTrack(*id));
-        }
-    }
-    for id in &removed_objects {
-        patches.push(ScenePatch::RemoveObject(*id));
-    }
-    for object in desired.objects() {
-        let id = object.id;
-        match current_objects.get(&id) {
-            Some(existing) => {
-                if existing.transform != object.transform {
-                    patches.push(ScenePatch::SetTransform {
-                        object: id,
-                        transform: object.transform,
-                    });
-                }
-                if existing.style != object.style {
-                    patches.push(ScenePatch::SetStyle {
-                        object: id,
-                        style: object.style,
-                    });
-                }
-            }
-            None => patches.push(ScenePatch::CreateObject(object.clone())),
-        }
-    }
-    for track in desired.tracks() {
-        match current_tracks.get(&track.id) {
-            Some(existing) if **existing != *track => {
-                patches.push(ScenePatch::ReplaceTrack(track.clone()));
-            }
-            None => patches.push(ScenePatch::AddTrack(track.clone())),
-            _ => {}
-        }
-    }
-    Some(patches)
-}
-
-fn append_compatible<Id: Copy + Ord>(
-    current: impl Iterator<Item = Id>,
-    desired: impl Iterator<Item = Id>,
-) -> bool {
-    let current = current.collect::<Vec<_>>();
-    let desired = desired.collect::<Vec<_>>();
-    let current_set = current.iter().copied().collect::<BTreeSet<_>>();
-    let desired_set = desired.iter().copied().collect::<BTreeSet<_>>();
-    let retained = current
-        .into_iter()
-        .filter(|id| desired_set.contains(id))
-        .collect::<Vec<_>>();
-    let desired_existing = desired
-        .iter()
-        .copied()
-        .filter(|id| current_set.contains(id))
-        .collect::<Vec<_>>();
-    retained == desired_existing && desired.iter().take(retained.len()).copied().eq(retained)
-}
-
 #[cfg(target_arch = "wasm32")]
 mod wasm {
     use std::{
@@ -552,6 +501,13 @@ mod wasm {
             })
         }
 
+        #[wasm_bindgen(js_name = fromBundle)]
+        pub fn from_bundle(bytes: &[u8]) -> Result<WasmScenePlayer, JsValue> {
+            Ok(Self {
+                inner: ScenePlayer::from_bundle(bytes).map_err(js_error)?,
+            })
+        }
+
         pub fn seek(&mut self, time: f64) -> Result<(), JsValue> {
             self.inner.seek(time).map_err(js_error)?;
             Ok(())
@@ -568,6 +524,12 @@ mod wasm {
             Ok(())
         }
 
+        #[wasm_bindgen(js_name = replaceSceneBundle)]
+        pub fn replace_scene_bundle(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+            self.inner.replace_scene_bundle(bytes).map_err(js_error)?;
+            Ok(())
+        }
+
         #[wasm_bindgen(js_name = reconcileScene)]
         pub fn reconcile_scene(&mut self, json: &str) -> Result<bool, JsValue> {
             Ok(matches!(
@@ -591,6 +553,17 @@ mod wasm {
         pub fn scene_json(&self) -> Result<String, JsValue> {
             self.inner.scene_json().map_err(js_error)
         }
+
+        #[wasm_bindgen(js_name = startRecording)]
+        pub fn start_recording(&mut self) -> Result<(), JsValue> {
+            self.inner.start_recording().map_err(js_error)
+        }
+
+        /// Session log lines recorded since the previous call.
+        #[wasm_bindgen(js_name = takeRecording)]
+        pub fn take_recording(&mut self) -> String {
+            self.inner.take_recording()
+        }
     }
 
     /// Persistent browser player that connects the deterministic runtime to a WebGPU canvas.
@@ -632,79 +605,18 @@ mod wasm {
             loop_duration_seconds: f64,
         ) -> Result<WasmCanvasPlayer, JsValue> {
             let player = ScenePlayer::from_scene_json(scene_json).map_err(js_error)?;
-            let clock = PlaybackClock::looping(loop_duration_seconds).map_err(js_error)?;
-
-            let mut instance_descriptor = wgpu::InstanceDescriptor::new_without_display_handle();
-            instance_descriptor.backends = wgpu::Backends::BROWSER_WEBGPU;
-            let instance = wgpu::Instance::new(instance_descriptor);
-            let surface = create_surface(&instance, &canvas)?;
-            let adapter = instance
-                .request_adapter(&wgpu::RequestAdapterOptions {
-                    power_preference: wgpu::PowerPreference::HighPerformance,
-                    force_fallback_adapter: false,
-                    compatible_surface: Some(&surface),
-                })
-                .await
-                .map_err(js_error)?;
-            let timestamp_queries_supported =
-                adapter.features().contains(wgpu::Features::TIMESTAMP_QUERY);
-            let required_features = if timestamp_queries_supported {
-                wgpu::Features::TIMESTAMP_QUERY
-            } else {
-                wgpu::Features::empty()
-            };
-            let (device, queue) = adapter
-                .request_device(&wgpu::DeviceDescriptor {
-                    label: Some("Noon WebGPU device"),
-                    required_features,
-                    ..Default::default()
-                })
-                .await
-                .map_err(js_error)?;
-
-            let width = canvas.width().max(1);
-            let height = canvas.height().max(1);
-            let config = surface
-                .get_default_config(&adapter, width, height)
-                .ok_or_else(|| js_message("WebGPU adapter cannot present to this canvas"))?;
-            surface.configure(&device, &config);
-            let renderer = GpuRenderer::new(&device, config.format);
-            let gpu_profiler =
-                timestamp_queries_supported.then(|| GpuFrameProfiler::new(&device, &queue));
+            Self::with_player(canvas, player, loop_duration_seconds).await
+        }
 
-            let mut result = Self {
-                instance,
-                surface,
-                device,
-                queue,
-                canvas,
-                config,
-                drawable: true,
-                player,
-                clock,
-                preparer: FramePreparer::new(),
-                renderer,
-                camera_center: Vec2::ZERO,
-                camera_height: 6.0,
-                clear_color: wgpu::Color {
-                    r: 0.035,
-                    g: 0.047,
-                    b: 0.075,
-                    a: 1.0,
-                },
-                last_draw_calls: 0,
-                last_instances_drawn: 0,
-                last_bytes_uploaded: 0,
-                last_geometry_cache_misses: 0,
-                last_cpu_frame_ms: f64::NAN,
-                last_runtime_evaluation_ms: f64::NAN,
-                last_frame_prepare_ms: f64::NAN,
-                last_upload_ms: f64::NAN,
-                last_encode_submit_ms: f64::NAN,
-                gpu_profiler,
-            };
-            result.update_camera()?;
-            Ok(result)
+        /// Like `create`, but plays a self-contained scene bundle.
+        #[wasm_bindgen(js_name = createFromBundle)]
+        pub async fn create_from_bundle(
+            canvas: HtmlCanvasElement,
+            bundle: Vec<u8>,
+            loop_duration_seconds: f64,
+        ) -> Result<WasmCanvasPlayer, JsValue> {
+            let player = ScenePlayer::from_bundle(&bundle).map_err(js_error)?;
+            Self::with_player(canvas, player, loop_duration_seconds).await
         }
 
         /// Resizes the physical canvas backing store. Zero-sized canvases are simply skipped.
@@ -767,7 +679,15 @@ mod wasm {
             Ok(())
         }
 
-        /// Reconciles compatible semantic state and falls back to atomic replacement.
+        /// Replaces the scene with a bundle's, like `replaceScene`.
+        #[wasm_bindgen(js_name = replaceSceneBundle)]
+        pub fn replace_scene_bundle(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+            self.player.replace_scene_bundle(bytes).map_err(js_error)?;
+            Ok(())
+        }
+
+        /// Reconciles semantic state through a structural scene diff, keeping the GPU and
+        /// playback clock.
         #[wasm_bindgen(js_name = reconcileScene)]
         pub fn reconcile_scene(&mut self, json: &str) -> Result<bool, JsValue> {
             Ok(matches!(
@@ -903,6 +823,87 @@ mod wasm {
     }
 
     impl WasmCanvasPlayer {
+        /// Creates the GPU device and canvas surface around a loaded player.
+        async fn with_player(
+            canvas: HtmlCanvasElement,
+            player: ScenePlayer,
+            loop_duration_seconds: f64,
+        ) -> Result<WasmCanvasPlayer, JsValue> {
+            let clock = PlaybackClock::looping(loop_duration_seconds).map_err(js_error)?;
+
+            let mut instance_descriptor = wgpu::InstanceDescriptor::new_without_display_handle();
+            instance_descriptor.backends = wgpu::Backends::BROWSER_WEBGPU;
+            let instance = wgpu::Instance::new(instance_descriptor);
+            let surface = create_surface(&instance, &canvas)?;
+            let adapter = instance
+                .request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::HighPerformance,
+                    force_fallback_adapter: false,
+                    compatible_surface: Some(&surface),
+                })
+                .await
+                .map_err(js_error)?;
+            let timestamp_queries_supported =
+                adapter.features().contains(wgpu::Features::TIMESTAMP_QUERY);
+            let required_features = if timestamp_queries_supported {
+                wgpu::Features::TIMESTAMP_QUERY
+            } else {
+                wgpu::Features::empty()
+            };
+            let (device, queue) = adapter
+                .request_device(&wgpu::DeviceDescriptor {
+                    label: Some("Noon WebGPU device"),
+                    required_features,
+                    ..Default::default()
+                })
+                .await
+                .map_err(js_error)?;
+
+            let width = canvas.width().max(1);
+            let height = canvas.height().max(1);
+            let config = surface
+                .get_default_config(&adapter, width, height)
+                .ok_or_else(|| js_message("WebGPU adapter cannot present to this canvas"))?;
+            surface.configure(&device, &config);
+            let renderer = GpuRenderer::new(&device, config.format);
+            let gpu_profiler =
+                timestamp_queries_supported.then(|| GpuFrameProfiler::new(&device, &queue));
+
+            let mut result = Self {
+                instance,
+                surface,
+                device,
+                queue,
+                canvas,
+                config,
+                drawable: true,
+                player,
+                clock,
+                preparer: FramePreparer::new(),
+                renderer,
+                camera_center: Vec2::ZERO,
+                camera_height: 6.0,
+                clear_color: wgpu::Color {
+                    r: 0.035,
+                    g: 0.047,
+                    b: 0.075,
+                    a: 1.0,
+                },
+                last_draw_calls: 0,
+                last_instances_drawn: 0,
+                last_bytes_uploaded: 0,
+                last_geometry_cache_misses: 0,
+                last_cpu_frame_ms: f64::NAN,
+                last_runtime_evaluation_ms: f64::NAN,
+                last_frame_prepare_ms: f64::NAN,
+                last_upload_ms: f64::NAN,
+                last_encode_submit_ms: f64::NAN,
+                gpu_profiler,
+            };
+            result.update_camera()?;
+            Ok(result)
+        }
+
         fn gpu_render_percentile(&self, percentile: f64) -> f64 {
             self.gpu_profiler
                 .as_ref()
@@ -937,6 +938,11 @@ mod wasm {
 
             let prepare_started_ms = performance_now_ms();
             let changes = self.player.take_frame_changes();
+            if changes.is_all() {
+                self.renderer
+                    .sync_images(&self.device, &self.queue, self.player.images());
+                self.preparer.sync_fonts(self.player.fonts());
+            }
             let prepared = self
                 .preparer
                 .prepare_incremental(self.player.frame(), &changes);
@@ -1015,6 +1021,11 @@ mod wasm {
         }
     }
 
+    #[wasm_bindgen(js_name = bundleDuration)]
+    pub fn bundle_duration(bytes: &[u8]) -> Result<f64, JsValue> {
+        ScenePlayer::bundle_duration(bytes).map_err(js_error)
+    }
+
     #[wasm_bindgen(js_name = demoSceneJson)]
     pub fn demo_scene_json() -> Result<String, JsValue> {
         let mut scene = SceneDefinition::new();
@@ -1161,9 +1172,9 @@ mod wasm {
 #[cfg(test)]
 mod tests {
     use noon_core::{
-        Easing, GeometryRef, ObjectDefinition, ObjectId, ObjectSnapshot, Property, ScenePatch,
-        StrokeCap, StrokeJoin, Style, TrackDefinition, TrackId, TrackTiming, TrackValues,
-        Transform2D, Vec2,
+        Easing, GeometryRef, Labels, ObjectDefinition, ObjectId, ObjectSnapshot, Property,
+        ScenePatch, StrokeCap, StrokeJoin, Style, TrackDefinition, TrackId, TrackTiming,
+        TrackValues, Transform2D, Vec2,
     };
     use noon_ir::{encode_patch_batch, encode_scene, PatchBatch};
 
@@ -1184,6 +1195,36 @@ mod tests {
         assert_eq!(player.object_count(), 1);
     }
 
+    #[test]
+    fn bundles_load_and_replace_scenes_with_their_images() {
+        let mut scene = SceneDefinition::new();
+        let image = scene.register_image(
+            noon_core::ImageData::new(1, 1, vec![255, 0, 0, 255]).expect("valid image"),
+        );
+        scene.add(GeometryRef::Image {
+            image,
+            size: Vec2::ONE,
+            sampling: noon_core::ImageSampling::Nearest,
+        });
+        let bundle = noon_ir::encode_bundle(&scene).expect("scene must bundle");
+        assert_eq!(ScenePlayer::bundle_duration(&bundle).unwrap(), 0.0);
+
+        let mut loaded = ScenePlayer::from_bundle(&bundle).expect("bundle must load");
+        assert_eq!(loaded.images(), scene.images());
+
+        let mut empty = player();
+        empty.seek(1.5).expect("seek must succeed");
+        empty
+            .replace_scene_bundle(&bundle)
+            .expect("bundle must replace the scene");
+        assert_eq!(empty.frame().time, 1.5);
+        assert_eq!(empty.images(), scene.images());
+        assert!(matches!(
+            loaded.replace_scene_bundle(&bundle[..bundle.len() - 1]),
+            Err(PlayerError::Ir(_))
+        ));
+    }
+
     #[test]
     fn ordered_patch_batch_preserves_playhead_and_advances_sequence() {
         let mut player = player();
@@ -1282,7 +1323,72 @@ mod tests {
     }
 
     #[test]
-    fn incompatible_geometry_reconciliation_falls_back_to_replacement() {
+    fn reconciliation_registers_new_images_before_creating_image_objects() {
+        let mut player = player();
+        let pixels =
+            noon_core::ImageData::new(1, 1, vec![255, 255, 255, 255]).expect("valid image");
+        let mut desired = SceneDefinition::new();
+        desired.add(GeometryRef::circle(1.0));
+        let image = desired.register_image(pixels.clone());
+        desired.add(GeometryRef::image(image, noon_core::Vec2::new(2.0, 2.0)));
+        let json = encode_scene(&desired).expect("scene must serialize");
+
+        let outcome = player
+            .reconcile_scene_json(&json)
+            .expect("reconciliation must succeed");
+
+        assert_eq!(outcome, ReconcileOutcome::Rebuilt { patch_count: 2 });
+        assert_eq!(player.images().len(), 1);
+        assert_eq!(player.images()[0].data, pixels);
+        assert!(player.take_frame_changes().is_all());
+
+        let mut replaced = SceneDefinition::new();
+        replaced.add(GeometryRef::circle(1.0));
+        let image = replaced.register_image(
+            noon_core::ImageData::new(1, 1, vec![0, 0, 0, 255]).expect("valid image"),
+        );
+        replaced.add(GeometryRef::image(image, noon_core::Vec2::new(2.0, 2.0)));
+        let json = encode_scene(&replaced).expect("scene must serialize");
+        assert_eq!(
+            player
+                .reconcile_scene_json(&json)
+                .expect("image replacement must succeed"),
+            ReconcileOutcome::Rebuilt { patch_count: 1 }
+        );
+        assert_eq!(player.images()[0].data.pixels(), [0, 0, 0, 255]);
+        assert!(player.take_frame_changes().is_all());
+    }
+
+    #[test]
+    fn reconciliation_registers_new_fonts_and_replaces_changed_ones() {
+        let mut player = player();
+        let mut desired = SceneDefinition::new();
+        desired.add(GeometryRef::circle(1.0));
+        desired.register_font(noon_core::FontData::new(vec![0, 1, 0, 0]));
+        let json = encode_scene(&desired).expect("scene must serialize");
+
+        let outcome = player
+            .reconcile_scene_json(&json)
+            .expect("reconciliation must succeed");
+
+        assert!(matches!(outcome, ReconcileOutcome::Rebuilt { .. }));
+        assert_eq!(player.fonts().len(), 1);
+
+        let mut replaced = SceneDefinition::new();
+        replaced.add(GeometryRef::circle(1.0));
+        replaced.register_font(noon_core::FontData::new(vec![0, 1, 0, 1]));
+        let json = encode_scene(&replaced).expect("scene must serialize");
+        assert_eq!(
+            player
+                .reconcile_scene_json(&json)
+                .expect("font replacement must succeed"),
+            ReconcileOutcome::Rebuilt { patch_count: 1 }
+        );
+        assert_eq!(player.fonts()[0].data.bytes(), [0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn geometry_reconciliation_patches_the_object_in_place() {
         let mut player = player();
         player.seek(0.75).expect("seek must succeed");
         let mut desired = SceneDefinition::new();
@@ -1291,9 +1397,10 @@ mod tests {
 
         let outcome = player
             .reconcile_scene_json(&json)
-            .expect("replacement fallback must succeed");
+            .expect("geometry reconciliation must succeed");
 
-        assert_eq!(outcome, ReconcileOutcome::Replaced);
+        assert_eq!(outcome, ReconcileOutcome::Rebuilt { patch_count: 1 });
+        assert!(player.take_frame_changes().is_all());
         assert_eq!(player.frame().time, 0.75);
         assert_eq!(
             player.frame().objects[0].geometry,
@@ -1302,7 +1409,7 @@ mod tests {
     }
 
     #[test]
-    fn reordered_scene_reconciliation_falls_back_to_preserve_draw_order() {
+    fn reordered_scene_reconciliation_moves_objects_into_draw_order() {
         let mut current = SceneDefinition::new();
         current.add(GeometryRef::circle(1.0));
         current.add(GeometryRef::rectangle(1.0, 1.0));
@@ -1324,10 +1431,12 @@ mod tests {
         assert_eq!(
             player
                 .reconcile_scene_json(&json)
-                .expect("fallback must succeed"),
-            ReconcileOutcome::Replaced
+                .expect("reorder must succeed"),
+            ReconcileOutcome::Rebuilt { patch_count: 1 }
         );
         assert_eq!(player.frame().objects[0].id, ObjectId::new(1));
+        assert_eq!(player.frame().objects[1].id, ObjectId::new(0));
+        assert_eq!(player.scene_json().expect("scene must serialize"), json);
     }
 
     #[test]
@@ -1518,6 +1627,7 @@ mod tests {
             property: Property::Transform,
             values: TrackValues::Object { from, to },
             timing: TrackTiming::new(0.0, 2.0, Easing::Linear),
+            labels: Labels::new(),
         };
         SceneDefinition::from_parts(vec![object], vec![track]).expect("transform scene is valid")
     }
@@ -0,0 +1,256 @@
+//! Recording and replaying live editing sessions.
+//!
+//! While recording, every successful player operation appends one
+//! [`SessionEvent`] line to a buffer that callers drain into an append-only
+//! log file. Replaying the log drives a fresh player through the same
+//! operations, producing the same [`FrameState`] after each one.
+
+use noon_ir::{decode_session_log, encode_session_event, SceneDocument, SessionEvent};
+use noon_runtime::FrameState;
+
+use crate::{PlayerError, ScenePlayer};
+
+impl ScenePlayer {
+    /// Starts a session log from the current scene, playhead and patch
+    /// sequence. Restarting discards lines not yet taken.
+    pub fn start_recording(&mut self) -> Result<(), PlayerError> {
+        let start = SessionEvent::Start {
+            time: self.instance.frame().time,
+            sequence: self.next_sequence,
+            scene: SceneDocument::from_scene(&self.definition),
+        };
+        self.recording = Some(encode_session_event(&start)?);
+        Ok(())
+    }
+
+    pub const fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Log lines recorded since the last call, to append to the log file.
+    /// Empty when not recording.
+    pub fn take_recording(&mut self) -> String {
+        self.recording
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Stops recording, returning the lines not yet taken.
+    pub fn stop_recording(&mut self) -> String {
+        self.recording.take().unwrap_or_default()
+    }
+
+    /// Replays a whole session log, returning the frame at its start followed
+    /// by the frame after each event.
+    pub fn replay_session(log: &str) -> Result<Vec<FrameState>, PlayerError> {
+        let mut replay = SessionReplay::new(log)?;
+        let mut frames = vec![replay.player().frame().clone()];
+        while let Some(frame) = replay.step() {
+            frames.push(frame?.clone());
+        }
+        Ok(frames)
+    }
+
+    /// Appends the event for an operation that already succeeded. Events
+    /// are only built while recording.
+    pub(crate) fn record(
+        &mut self,
+        event: impl FnOnce(&Self) -> SessionEvent,
+    ) -> Result<(), PlayerError> {
+        if self.recording.is_none() {
+            return Ok(());
+        }
+        let line = encode_session_event(&event(self))?;
+        if let Some(recording) = &mut self.recording {
+            recording.push_str(&line);
+        }
+        Ok(())
+    }
+}
+
+/// Drives a player through a recorded session one event at a time.
+#[derive(Clone, Debug)]
+pub struct SessionReplay {
+    player: ScenePlayer,
+    events: std::vec::IntoIter<SessionEvent>,
+}
+
+impl SessionReplay {
+    /// Decodes `log` and builds the player its start event describes.
+    pub fn new(log: &str) -> Result<Self, PlayerError> {
+        let mut events = decode_session_log(log)?.into_iter();
+        let Some(SessionEvent::Start {
+            time,
+            sequence,
+            scene,
+        }) = events.next()
+        else {
+            unreachable!("decoded session logs begin with a start event");
+        };
+        let mut player = ScenePlayer::from_definition(scene.into_scene()?)?;
+        player.instance.seek(time)?;
+        player.next_sequence = sequence;
+        Ok(Self { player, events })
+    }
+
+    pub const fn player(&self) -> &ScenePlayer {
+        &self.player
+    }
+
+    pub fn into_player(self) -> ScenePlayer {
+        self.player
+    }
+
+    /// Applies the next event, returning the frame it leaves behind, or
+    /// `None` once the log is exhausted.
+    pub fn step(&mut self) -> Option<Result<&FrameState, PlayerError>> {
+        let event = self.events.next()?;
+        Some(self.apply(event))
+    }
+
+    fn apply(&mut self, event: SessionEvent) -> Result<&FrameState, PlayerError> {
+        let player = &mut self.player;
+        match event {
+            SessionEvent::Start { .. } => unreachable!("decoded session logs start only once"),
+            SessionEvent::Seek { time } => player.seek(time),
+            SessionEvent::Advance { time } => player.advance_to(time),
+            SessionEvent::Patch { time, batch } => {
+                // Hand-trimmed logs may keep patches but drop the frames in
+                // between; the recorded playhead restores them.
+                if player.frame().time != time {
+                    player.seek(time)?;
+                }
+                player.apply_patch_batch(batch)
+            }
+            SessionEvent::Replace { scene } => player.replace_scene(scene.into_scene()?),
+            SessionEvent::Reconcile { scene } => {
+                player.reconcile_scene(scene.into_scene()?)?;
+                Ok(player.frame())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{
+        Easing, GeometryRef, ObjectId, SceneDefinition, ScenePatch, Style, TrackTiming,
+        Transform2D, Vec2,
+    };
+    use noon_ir::{encode_patch_batch, encode_scene, PatchBatch};
+
+    use super::*;
+
+    fn scene(radius: f32) -> SceneDefinition {
+        let mut scene = SceneDefinition::new();
+        let circle = scene.add(GeometryRef::circle(radius));
+        scene
+            .animate_position(
+                circle,
+                Vec2::ZERO,
+                Vec2::new(4.0, 1.0),
+                TrackTiming::new(0.0, 2.0, Easing::EaseInOutCubic),
+            )
+            .unwrap();
+        scene
+    }
+
+    fn translate(sequence: u64, x: f32) -> String {
+        encode_patch_batch(&PatchBatch::new(
+            sequence,
+            vec![ScenePatch::SetTransform {
+                object: ObjectId::new(0),
+                transform: Transform2D {
+                    translation: Vec2::new(x, 0.0),
+                    ..Transform2D::IDENTITY
+                },
+            }],
+        ))
+        .unwrap()
+    }
+
+    /// Drives a recording player through every kind of operation, returning
+    /// the log and the frame after each operation.
+    fn record_session() -> (String, Vec<FrameState>) {
+        let mut player = ScenePlayer::from_scene_json(&encode_scene(&scene(1.0)).unwrap()).unwrap();
+        player.seek(0.25).unwrap();
+        player.start_recording().unwrap();
+        let mut frames = vec![player.frame().clone()];
+        let mut log = String::new();
+
+        frames.push(player.advance_to(0.5).unwrap().clone());
+        frames.push(
+            player
+                .apply_patch_batch_json(&translate(0, 2.0))
+                .unwrap()
+                .clone(),
+        );
+        log.push_str(&player.take_recording());
+        frames.push(player.advance_to(1.0 / 3.0 + 0.5).unwrap().clone());
+        frames.push(
+            player
+                .apply_patch_batch_json(&translate(1, -1.0))
+                .unwrap()
+                .clone(),
+        );
+        let mut styled = scene(1.0);
+        styled.object_mut(ObjectId::new(0)).unwrap().style = Style {
+            opacity: 0.5,
+            ..Style::default()
+        };
+        player
+            .reconcile_scene_json(&encode_scene(&styled).unwrap())
+            .unwrap();
+        frames.push(player.frame().clone());
+        frames.push(player.seek(1.5).unwrap().clone());
+        frames.push(
+            player
+                .replace_scene_json(&encode_scene(&scene(0.5)).unwrap())
+                .unwrap()
+                .clone(),
+        );
+        // Failed operations leave no trace in the log.
+        assert!(player.apply_patch_batch_json(&translate(5, 0.0)).is_err());
+        frames.push(
+            player
+                .apply_patch_batch_json(&translate(0, 3.0))
+                .unwrap()
+                .clone(),
+        );
+        log.push_str(&player.stop_recording());
+        assert!(!player.is_recording());
+        assert!(player.take_recording().is_empty());
+        (log, frames)
+    }
+
+    #[test]
+    fn replay_reproduces_every_recorded_frame() {
+        let (log, frames) = record_session();
+        assert_eq!(log.lines().count(), frames.len());
+        assert_eq!(ScenePlayer::replay_session(&log).unwrap(), frames);
+
+        let mut replay = SessionReplay::new(&log).unwrap();
+        while let Some(frame) = replay.step() {
+            frame.unwrap();
+        }
+        let player = replay.into_player();
+        assert_eq!(player.next_sequence(), 1);
+        assert_eq!(player.frame(), frames.last().unwrap());
+    }
+
+    #[test]
+    fn patch_only_logs_restore_the_recorded_playhead() {
+        let (log, frames) = record_session();
+        let patches_only = log
+            .lines()
+            .filter(|line| !line.contains("\"event\":\"advance\""))
+            .map(|line| format!("{line}\n"))
+            .collect::<String>();
+
+        let replayed = ScenePlayer::replay_session(&patches_only).unwrap();
+        assert_eq!(replayed.len(), frames.len() - 2);
+        assert_eq!(replayed[1], frames[2]);
+        assert_eq!(replayed.last(), frames.last());
+    }
+}
@@ -0,0 +1,250 @@
+//! Animation composition.
+//!
+//! Groups split the time span they are played over between their children
+//! and lower each child to ordinary tracks, so the runtime never sees a
+//! group. Every child gets an equal window; with lag ratio `r` each child
+//! starts `r` windows after the previous one. Children use the easing of the
+//! enclosing play over their own window.
+
+use noon_core::TrackTiming;
+
+use crate::{Animation, AuthoringError, IntoAnimations, Mobject, Scene};
+
+/// Lag ratio used by [`LaggedStart`] and [`LaggedStartMap`].
+pub const DEFAULT_LAGGED_START_LAG_RATIO: f64 = 0.05;
+
+/// Play several animations over one time span, optionally staggered.
+///
+/// A lag ratio of zero plays every child over the whole span; one plays them
+/// back to back. Groups nest, so a child can itself be a group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationGroup {
+    animations: Vec<Animation>,
+    lag_ratio: f64,
+}
+
+impl AnimationGroup {
+    pub fn new(animations: impl IntoAnimations) -> Self {
+        Self {
+            animations: animations.into_animations(),
+            lag_ratio: 0.0,
+        }
+    }
+
+    pub fn lag_ratio(mut self, lag_ratio: f64) -> Self {
+        self.lag_ratio = lag_ratio;
+        self
+    }
+
+    pub fn animations(&self) -> &[Animation] {
+        &self.animations
+    }
+
+    /// Start and duration of every child inside `timing`.
+    pub(crate) fn child_timings(
+        &self,
+        timing: TrackTiming,
+    ) -> Result<Vec<TrackTiming>, AuthoringError> {
+        if !self.lag_ratio.is_finite() || self.lag_ratio < 0.0 {
+            return Err(AuthoringError::InvalidLagRatio(self.lag_ratio));
+        }
+        let count = self.animations.len();
+        let span = 1.0 + count.saturating_sub(1) as f64 * self.lag_ratio;
+        let window = timing.duration / span;
+        Ok((0..count)
+            .map(|index| {
+                TrackTiming::new(
+                    timing.start_time + index as f64 * self.lag_ratio * window,
+                    window,
+                    timing.easing,
+                )
+            })
+            .collect())
+    }
+}
+
+/// [`AnimationGroup`] whose children start a small fraction apart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaggedStart(AnimationGroup);
+
+impl LaggedStart {
+    pub fn new(animations: impl IntoAnimations) -> Self {
+        Self(AnimationGroup::new(animations).lag_ratio(DEFAULT_LAGGED_START_LAG_RATIO))
+    }
+
+    pub fn lag_ratio(self, lag_ratio: f64) -> Self {
+        Self(self.0.lag_ratio(lag_ratio))
+    }
+}
+
+/// [`LaggedStart`] of one animation per object, built by `animation`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaggedStartMap(LaggedStart);
+
+impl LaggedStartMap {
+    pub fn new<A>(
+        objects: impl IntoIterator<Item = Mobject>,
+        animation: impl FnMut(Mobject) -> A,
+    ) -> Self
+    where
+        A: Into<Animation>,
+    {
+        let animations: Vec<Animation> =
+            objects.into_iter().map(animation).map(Into::into).collect();
+        Self(LaggedStart::new(animations))
+    }
+
+    pub fn lag_ratio(self, lag_ratio: f64) -> Self {
+        Self(self.0.lag_ratio(lag_ratio))
+    }
+}
+
+/// [`AnimationGroup`] that plays its children one after another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Succession(AnimationGroup);
+
+impl Succession {
+    pub fn new(animations: impl IntoAnimations) -> Self {
+        Self(AnimationGroup::new(animations).lag_ratio(1.0))
+    }
+}
+
+impl From<LaggedStart> for AnimationGroup {
+    fn from(value: LaggedStart) -> Self {
+        value.0
+    }
+}
+
+impl From<LaggedStartMap> for AnimationGroup {
+    fn from(value: LaggedStartMap) -> Self {
+        value.0.into()
+    }
+}
+
+impl From<Succession> for AnimationGroup {
+    fn from(value: Succession) -> Self {
+        value.0
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_animation_group(
+        &mut self,
+        group: AnimationGroup,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let timings = group.child_timings(timing)?;
+        for (animation, timing) in group.animations.into_iter().zip(timings) {
+            self.schedule_animation(animation, timing)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Easing, Property, Vec2};
+
+    use crate::{Circle, FadeIn, FadeOut, Scene};
+
+    use super::*;
+
+    fn windows(scene: &Scene, property: Property) -> Vec<(u64, f64, f64)> {
+        scene
+            .definition()
+            .tracks()
+            .iter()
+            .filter(|track| track.property == property)
+            .map(|track| {
+                (
+                    track.object.get(),
+                    track.timing.start_time,
+                    track.timing.duration,
+                )
+            })
+            .collect()
+    }
+
+    fn assert_windows(actual: &[(u64, f64, f64)], expected: &[(u64, f64, f64)]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?}");
+        for (&(object, start, duration), &(id, at, length)) in actual.iter().zip(expected) {
+            assert_eq!(object, id);
+            assert!((start - at).abs() < 1.0e-9, "{start} != {at}");
+            assert!((duration - length).abs() < 1.0e-9, "{duration} != {length}");
+        }
+    }
+
+    #[test]
+    fn lag_ratio_staggers_equal_windows_across_the_run_time() {
+        let group = AnimationGroup::new(vec![]).lag_ratio(0.5);
+        assert!(group
+            .child_timings(TrackTiming::new(0.0, 1.0, Easing::Linear))
+            .unwrap()
+            .is_empty());
+
+        let mut scene = Scene::new();
+        let objects: Vec<_> = (0..3).map(|_| scene.add(Circle::new(0.5))).collect();
+        let first = objects[0];
+        scene.wait(1.0).unwrap();
+        scene
+            .play(AnimationGroup::new(LaggedStartMap::new(objects.clone(), FadeOut)).lag_ratio(0.0))
+            .run_time(4.0)
+            .unwrap();
+        scene
+            .play(LaggedStartMap::new(objects, FadeIn).lag_ratio(0.5))
+            .run_time(4.0)
+            .unwrap();
+
+        // The outer group holds one child, so the inner lagged start keeps
+        // the whole span: windows of 4 / 1.1.
+        let window = 4.0 / 1.1;
+        let lag = window * DEFAULT_LAGGED_START_LAG_RATIO;
+        let half = 2.0;
+        assert_windows(
+            &windows(&scene, Property::Appearance),
+            &[
+                (0, 1.0, window),
+                (1, 1.0 + lag, window),
+                (2, 1.0 + 2.0 * lag, window),
+                (0, 5.0, half),
+                (1, 6.0, half),
+                (2, 7.0, half),
+            ],
+        );
+        assert!((scene.time() - 9.0).abs() < 1.0e-9);
+        assert_eq!(
+            scene
+                .play(AnimationGroup::new(FadeOut(first)).lag_ratio(-1.0))
+                .run_time(1.0),
+            Err(AuthoringError::InvalidLagRatio(-1.0))
+        );
+    }
+
+    #[test]
+    fn succession_chains_edits_of_one_object_through_nested_groups() {
+        let mut scene = Scene::new();
+        let circle = scene.add(Circle::new(0.5));
+        let other = scene.add(Circle::new(0.5));
+        scene
+            .play(Succession::new((
+                circle.animate().shift(Vec2::new(1.0, 0.0)),
+                AnimationGroup::new((
+                    circle.animate().shift(Vec2::new(0.0, 1.0)),
+                    other.animate().scale(2.0),
+                )),
+                circle.animate().shift(Vec2::new(1.0, 0.0)),
+            )))
+            .run_time(3.0)
+            .unwrap();
+
+        assert_windows(
+            &windows(&scene, Property::Transform),
+            &[(0, 0.0, 1.0), (0, 1.0, 1.0), (1, 1.0, 1.0), (0, 2.0, 1.0)],
+        );
+        assert_eq!(
+            scene.snapshot(circle).unwrap().center(),
+            Vec2::new(2.0, 1.0)
+        );
+        assert!((scene.time() - 3.0).abs() < 1.0e-9);
+    }
+}
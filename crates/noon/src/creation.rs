@@ -0,0 +1,345 @@
+//! Creation animations built on the Reveal property.
+//!
+//! Reveal draws outlines by normalized perimeter progress. Fill sits at the
+//! end of that progress, so it appears once the outline is complete. Images
+//! and glyph runs ignore reveal and only follow the presence and fill tracks.
+
+use noon_core::{GeometryRef, ObjectSnapshot, PathCommand, TrackTiming};
+
+use crate::{AuthoringError, Mobject, Scene};
+
+/// Stroke width used to draw the border of objects that have no stroke.
+pub const DEFAULT_BORDER_WIDTH: f32 = 0.04;
+
+/// Draw an absent object's outline from nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Create(pub Mobject);
+
+impl Create {
+    pub const fn new(object: Mobject) -> Self {
+        Self(object)
+    }
+}
+
+/// Erase a present object's outline back to nothing, then remove it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Uncreate(pub Mobject);
+
+impl Uncreate {
+    pub const fn new(object: Mobject) -> Self {
+        Self(object)
+    }
+}
+
+/// Draw an absent object's border during the first half of the animation,
+/// then fade its fill in. Unstroked objects are outlined in their fill color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DrawBorderThenFill(pub Mobject);
+
+impl DrawBorderThenFill {
+    pub const fn new(object: Mobject) -> Self {
+        Self(object)
+    }
+}
+
+/// Draw border then fill for several objects, staggered per object.
+///
+/// Timing is budgeted in contours: every path contour is worth one
+/// equal-length window, each starting a fixed fraction of a window after
+/// the previous one, and analytic shapes count as one contour. Reveal is
+/// per object, so each object draws as a single stroke across the windows
+/// of all its contours; contours inside one object are not staggered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Write {
+    objects: Vec<Mobject>,
+}
+
+impl Write {
+    pub fn new(objects: impl IntoIterator<Item = Mobject>) -> Self {
+        Self {
+            objects: objects.into_iter().collect(),
+        }
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_create(
+        &mut self,
+        object: Mobject,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        self.ensure_creatable(object)?;
+        self.definition
+            .set_presence_at(object.id, false, true, timing.start_time)?;
+        self.definition
+            .animate_reveal(object.id, 0.0, 1.0, timing)?;
+        self.presence.insert(object.id, true);
+        Ok(())
+    }
+
+    pub(crate) fn schedule_uncreate(
+        &mut self,
+        object: Mobject,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let is_present = self
+            .presence
+            .get(&object.id)
+            .copied()
+            .ok_or(AuthoringError::UnknownObject(object.id))?;
+        if !is_present {
+            return Err(AuthoringError::UncreateRequiresPresent(object.id));
+        }
+        self.definition
+            .animate_reveal(object.id, 1.0, 0.0, timing)?;
+        self.definition.set_presence_at(
+            object.id,
+            true,
+            false,
+            timing.start_time + timing.duration,
+        )?;
+        self.presence.insert(object.id, false);
+        Ok(())
+    }
+
+    pub(crate) fn schedule_draw_border_then_fill(
+        &mut self,
+        object: Mobject,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        self.ensure_creatable(object)?;
+        let filled = self.snapshot(object)?.clone();
+        let border = border_snapshot(&filled);
+        let half = TrackTiming::new(timing.start_time, timing.duration * 0.5, timing.easing);
+        let fill = TrackTiming::new(
+            timing.start_time + half.duration,
+            timing.duration - half.duration,
+            timing.easing,
+        );
+
+        self.definition
+            .set_presence_at(object.id, false, true, timing.start_time)?;
+        self.definition.animate_reveal(object.id, 0.0, 1.0, half)?;
+        // Hold the border style while it is drawn so earlier transform tracks
+        // cannot leak an unstroked snapshot into the first half.
+        self.definition
+            .animate_transform(object.id, border.clone(), border.clone(), half)?;
+        self.definition
+            .animate_transform(object.id, border, filled, fill)?;
+        self.presence.insert(object.id, true);
+        Ok(())
+    }
+
+    pub(crate) fn schedule_write(
+        &mut self,
+        write: &Write,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let objects = &write.objects;
+        if objects.is_empty() {
+            return Err(AuthoringError::EmptyWrite);
+        }
+        let contours = objects
+            .iter()
+            .map(|&object| Ok(contour_count(&self.snapshot(object)?.geometry)))
+            .collect::<Result<Vec<_>, AuthoringError>>()?;
+        let total = contours.iter().sum::<usize>();
+        let lag_ratio = (4.0 / total as f64).min(0.2);
+        let window = timing.duration / (1.0 + (total - 1) as f64 * lag_ratio);
+        let lag = window * lag_ratio;
+
+        let mut first = 0;
+        for (&object, count) in objects.iter().zip(contours) {
+            let start = timing.start_time + first as f64 * lag;
+            let duration = (count - 1) as f64 * lag + window;
+            self.schedule_draw_border_then_fill(
+                object,
+                TrackTiming::new(start, duration, timing.easing),
+            )?;
+            first += count;
+        }
+        Ok(())
+    }
+
+    fn ensure_creatable(&self, object: Mobject) -> Result<(), AuthoringError> {
+        if !self.is_available(object)? {
+            return Err(AuthoringError::CreateRequiresAbsent(object.id));
+        }
+        Ok(())
+    }
+}
+
+fn border_snapshot(filled: &ObjectSnapshot) -> ObjectSnapshot {
+    let mut border = filled.clone();
+    if border.style.stroke.is_none() || border.style.stroke_width <= 0.0 {
+        border.style.stroke = filled.style.fill;
+        border.style.stroke_width = DEFAULT_BORDER_WIDTH;
+    }
+    border.style.fill = None;
+    border
+}
+
+fn contour_count(geometry: &GeometryRef) -> usize {
+    match geometry {
+        GeometryRef::VectorPath(path) => path
+            .commands()
+            .iter()
+            .filter(|command| matches!(command, PathCommand::MoveTo { .. }))
+            .count()
+            .max(1),
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Color, Property, TrackValues, Vec2, VectorPath};
+
+    use crate::{Circle, Path, Scene, Square};
+
+    use super::*;
+
+    fn tracks_of(scene: &Scene, property: Property) -> Vec<(u64, TrackValues, f64, f64)> {
+        scene
+            .definition()
+            .tracks()
+            .iter()
+            .filter(|track| track.property == property)
+            .map(|track| {
+                (
+                    track.object.get(),
+                    track.values.clone(),
+                    track.timing.start_time,
+                    track.timing.duration,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn create_and_uncreate_bracket_reveal_with_presence() {
+        let mut scene = Scene::new();
+        let circle = scene.add(Circle::new(1.0));
+        scene.play(Create(circle)).run_time(1.0).unwrap();
+        scene.play(Uncreate(circle)).run_time(2.0).unwrap();
+
+        assert_eq!(
+            tracks_of(&scene, Property::Reveal),
+            [
+                (0, TrackValues::Scalar { from: 0.0, to: 1.0 }, 0.0, 1.0),
+                (0, TrackValues::Scalar { from: 1.0, to: 0.0 }, 1.0, 2.0),
+            ]
+        );
+        assert_eq!(
+            tracks_of(&scene, Property::Presence),
+            [
+                (
+                    0,
+                    TrackValues::Bool {
+                        from: false,
+                        to: true
+                    },
+                    0.0,
+                    0.0
+                ),
+                (
+                    0,
+                    TrackValues::Bool {
+                        from: true,
+                        to: false
+                    },
+                    3.0,
+                    0.0
+                ),
+            ]
+        );
+        assert_eq!(
+            scene.play(Uncreate(circle)).run_time(1.0),
+            Err(AuthoringError::UncreateRequiresPresent(circle.id()))
+        );
+        scene.play(Create(circle)).run_time(1.0).unwrap();
+        assert_eq!(
+            scene.play(Create(circle)).run_time(1.0),
+            Err(AuthoringError::CreateRequiresAbsent(circle.id()))
+        );
+    }
+
+    #[test]
+    fn border_is_drawn_in_the_fill_color_before_the_fill_fades_in() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(1.0).color(Color::BLUE));
+        scene
+            .play(DrawBorderThenFill(square))
+            .run_time(2.0)
+            .unwrap();
+
+        let filled = scene.snapshot(square).unwrap().clone();
+        let mut border = filled.clone();
+        border.style.fill = None;
+        border.style.stroke = Some(Color::BLUE);
+        border.style.stroke_width = DEFAULT_BORDER_WIDTH;
+        assert_eq!(
+            tracks_of(&scene, Property::Transform),
+            [
+                (
+                    0,
+                    TrackValues::Object {
+                        from: border.clone(),
+                        to: border.clone()
+                    },
+                    0.0,
+                    1.0
+                ),
+                (
+                    0,
+                    TrackValues::Object {
+                        from: border,
+                        to: filled
+                    },
+                    1.0,
+                    1.0
+                ),
+            ]
+        );
+        assert_eq!(
+            tracks_of(&scene, Property::Reveal),
+            [(0, TrackValues::Scalar { from: 0.0, to: 1.0 }, 0.0, 1.0)]
+        );
+    }
+
+    #[test]
+    fn write_staggers_objects_by_their_contour_count() {
+        let mut scene = Scene::new();
+        let two_contours = VectorPath::new()
+            .move_to(Vec2::ZERO)
+            .line_to(Vec2::new(1.0, 0.0))
+            .move_to(Vec2::new(0.0, 1.0))
+            .line_to(Vec2::new(1.0, 1.0));
+        let first = scene.add(Path::new(two_contours));
+        let second = scene.add(Circle::new(0.5));
+        let third = scene.add(Circle::new(0.5));
+        scene
+            .play(Write::new([first, second, third]))
+            .run_time(2.8)
+            .unwrap();
+
+        // Four contours: lag ratio 0.2, windows of 2.8 / 1.6 = 1.75 and a
+        // 0.35 second lag between contour starts.
+        let reveals = tracks_of(&scene, Property::Reveal);
+        let windows: Vec<_> = reveals
+            .iter()
+            .map(|(object, _, start, duration)| (*object, *start, *duration * 2.0))
+            .collect();
+        let expected = [(0, 0.0, 2.1), (1, 0.7, 1.75), (2, 1.05, 1.75)];
+        for ((object, start, duration), (id, at, length)) in windows.iter().zip(expected) {
+            assert_eq!(*object, id);
+            assert!((start - at).abs() < 1.0e-9, "{start} != {at}");
+            assert!((duration - length).abs() < 1.0e-9, "{duration} != {length}");
+        }
+        assert!((scene.time() - 2.8).abs() < 1.0e-9);
+        assert_eq!(
+            scene.play(Write::new([])).run_time(1.0),
+            Err(AuthoringError::EmptyWrite)
+        );
+    }
+}
@@ -0,0 +1,319 @@
+//! Animation types defined outside this crate.
+//!
+//! A [`CustomAnimation`] is lowered once, when its play is scheduled, through
+//! a [`LoweringContext`]. It reads the authored state and writes ordinary
+//! tracks, so compiled scenes and the runtime never see the custom type.
+
+use std::sync::Arc;
+
+use noon_core::{ObjectSnapshot, Property, TrackId, TrackTiming, TrackValues};
+
+use crate::{Animation, AuthoringError, Mobject, Scene};
+
+/// Animation that third-party crates can implement and pass to
+/// [`Scene::play`] like any built-in animation.
+pub trait CustomAnimation: std::fmt::Debug + Send + Sync {
+    fn lower(&self, context: &mut LoweringContext<'_>) -> Result<(), AuthoringError>;
+}
+
+/// Shared handle to a [`CustomAnimation`]. Two handles are equal only when
+/// they point at the same value.
+#[derive(Clone, Debug)]
+pub struct Custom(Arc<dyn CustomAnimation>);
+
+impl Custom {
+    pub fn new(animation: impl CustomAnimation + 'static) -> Self {
+        Self(Arc::new(animation))
+    }
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: CustomAnimation + 'static> From<T> for Animation {
+    fn from(value: T) -> Self {
+        Self::Custom(Custom::new(value))
+    }
+}
+
+impl From<Custom> for Animation {
+    fn from(value: Custom) -> Self {
+        Self::Custom(value)
+    }
+}
+
+/// Authoring state handed to [`CustomAnimation::lower`].
+///
+/// Snapshots and presence reflect every animation scheduled so far, including
+/// earlier children of the same play. Writes keep that state current.
+pub struct LoweringContext<'a> {
+    scene: &'a mut Scene,
+    timing: TrackTiming,
+}
+
+impl LoweringContext<'_> {
+    /// Window this animation plays over.
+    pub const fn timing(&self) -> TrackTiming {
+        self.timing
+    }
+
+    pub fn snapshot(&self, object: Mobject) -> Result<&ObjectSnapshot, AuthoringError> {
+        self.scene.snapshot(object)
+    }
+
+    pub fn is_present(&self, object: Mobject) -> Result<bool, AuthoringError> {
+        self.scene
+            .presence
+            .get(&object.id)
+            .copied()
+            .ok_or(AuthoringError::UnknownObject(object.id))
+    }
+
+    /// Schedules another animation, built-in or custom, over `timing`.
+    pub fn play(
+        &mut self,
+        animation: impl Into<Animation>,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        self.scene.schedule_animation(animation.into(), timing)
+    }
+
+    /// Transforms `object` from its current snapshot to `target`.
+    pub fn transform_to(
+        &mut self,
+        object: Mobject,
+        target: ObjectSnapshot,
+        timing: TrackTiming,
+    ) -> Result<TrackId, AuthoringError> {
+        let from = self.snapshot(object)?.clone();
+        self.add_track(
+            object,
+            Property::Transform,
+            TrackValues::Object { from, to: target },
+            timing,
+        )
+    }
+
+    /// Writes one raw track. Transform, Position, Rotation and Opacity
+    /// tracks also update the object's authored snapshot and Presence tracks
+    /// its presence, so later animations start from where this one ends.
+    ///
+    /// Appearance, Reveal, Morph and Value are not part of a snapshot and are
+    /// not carried forward: later animations start from the authored state
+    /// as if those tracks had not run.
+    pub fn add_track(
+        &mut self,
+        object: Mobject,
+        property: Property,
+        values: TrackValues,
+        timing: TrackTiming,
+    ) -> Result<TrackId, AuthoringError> {
+        let mut authored = self.snapshot(object)?.clone();
+        let mut present = None;
+        match (property, &values) {
+            (
+                Property::Transform,
+                TrackValues::Object { to, .. } | TrackValues::Motion { to, .. },
+            ) => authored = to.clone(),
+            (Property::Position, TrackValues::Vec2 { to, .. }) => {
+                authored.transform.translation = *to;
+            }
+            (Property::Rotation, TrackValues::Scalar { to, .. }) => {
+                authored.transform.rotation = *to;
+            }
+            (Property::Opacity, TrackValues::Scalar { to, .. }) => authored.style.opacity = *to,
+            (Property::Presence, TrackValues::Bool { to, .. }) => present = Some(*to),
+            _ => {}
+        }
+        let id = self
+            .scene
+            .definition
+            .add_track(object.id, property, values, timing)?;
+        self.scene.authored.insert(object.id, authored);
+        if let Some(present) = present {
+            self.scene.presence.insert(object.id, present);
+        }
+        Ok(id)
+    }
+
+    /// Adds a helper object that is present only during `timing`, such as
+    /// the rays of a flash. `last` is its authored state afterwards.
+    pub fn add_transient(
+        &mut self,
+        initial: ObjectSnapshot,
+        last: ObjectSnapshot,
+        timing: TrackTiming,
+    ) -> Result<Mobject, AuthoringError> {
+        let id = self.scene.add_transient(initial, last, timing)?;
+        Ok(Mobject { id })
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_custom(
+        &mut self,
+        animation: Custom,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        animation.0.lower(&mut LoweringContext {
+            scene: self,
+            timing,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Vec2, YELLOW};
+
+    use crate::{Circle, FadeOut};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Swap(Mobject, Mobject);
+
+    impl CustomAnimation for Swap {
+        fn lower(&self, context: &mut LoweringContext<'_>) -> Result<(), AuthoringError> {
+            let first = context.snapshot(self.0)?.center();
+            let second = context.snapshot(self.1)?.center();
+            let timing = context.timing();
+            let target = context.snapshot(self.0)?.clone().move_to(second);
+            context.transform_to(self.0, target, timing)?;
+            context.play(self.1.animate().move_to(first), timing)
+        }
+    }
+
+    #[test]
+    fn custom_animations_lower_through_the_authored_state() {
+        let mut scene = Scene::new();
+        let left = scene.add(Circle::new(0.5).shift(Vec2::new(-1.0, 0.0)));
+        let right = scene.add(Circle::new(0.5).shift(Vec2::new(1.0, 0.0)));
+        scene.play(Swap(left, right)).run_time(1.0).unwrap();
+        scene
+            .play((Swap(left, right), FadeOut::new(left)))
+            .run_time(1.0)
+            .unwrap();
+
+        assert_eq!(scene.definition().tracks().len(), 6);
+        assert_eq!(scene.snapshot(left).unwrap().center(), Vec2::new(-1.0, 0.0));
+        assert_eq!(scene.snapshot(right).unwrap().center(), Vec2::new(1.0, 0.0));
+
+        let custom = Custom::new(Swap(left, right));
+        assert_eq!(custom, custom.clone());
+        assert_ne!(custom, Custom::new(Swap(left, right)));
+    }
+
+    #[derive(Debug)]
+    struct Highlight(Mobject);
+
+    impl CustomAnimation for Highlight {
+        fn lower(&self, context: &mut LoweringContext<'_>) -> Result<(), AuthoringError> {
+            if !context.is_present(self.0)? {
+                return Err(AuthoringError::FadeOutRequiresPresent(self.0.id()));
+            }
+            let timing = context.timing();
+            let rest = context.snapshot(self.0)?.clone();
+            let glow = context.add_transient(
+                rest.clone().scale_by(1.5).set_opacity(0.0),
+                rest.clone().set_color(YELLOW),
+                timing,
+            )?;
+            context.add_track(
+                glow,
+                Property::Appearance,
+                TrackValues::Scalar { from: 1.0, to: 0.0 },
+                timing,
+            )?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn helpers_and_raw_tracks_follow_the_play_window() {
+        let mut scene = Scene::new();
+        let circle = scene.add(Circle::new(0.5));
+        scene.wait(1.0).unwrap();
+        scene.play(Highlight(circle)).run_time(2.0).unwrap();
+
+        let tracks = scene.definition().tracks();
+        assert_eq!(tracks.len(), 3);
+        assert!(tracks
+            .iter()
+            .all(|track| track.object.get() == 1 && track.timing.start_time >= 1.0));
+
+        scene.play(FadeOut::new(circle)).run_time(1.0).unwrap();
+        assert_eq!(
+            scene.play(Highlight(circle)).run_time(1.0),
+            Err(AuthoringError::FadeOutRequiresPresent(circle.id()))
+        );
+    }
+
+    #[derive(Debug)]
+    struct RawTracks(Mobject);
+
+    impl CustomAnimation for RawTracks {
+        fn lower(&self, context: &mut LoweringContext<'_>) -> Result<(), AuthoringError> {
+            let timing = context.timing();
+            let raw = [
+                (
+                    Property::Opacity,
+                    TrackValues::Scalar {
+                        from: 1.0,
+                        to: 0.25,
+                    },
+                ),
+                (
+                    Property::Rotation,
+                    TrackValues::Scalar { from: 0.0, to: 0.5 },
+                ),
+                (
+                    Property::Position,
+                    TrackValues::Vec2 {
+                        from: Vec2::ZERO,
+                        to: Vec2::new(2.0, 1.0),
+                    },
+                ),
+                (
+                    Property::Appearance,
+                    TrackValues::Scalar { from: 1.0, to: 0.0 },
+                ),
+            ];
+            for (property, values) in raw {
+                context.add_track(self.0, property, values, timing)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn raw_value_tracks_carry_into_the_authored_snapshot() {
+        let mut scene = Scene::new();
+        let circle = scene.add(Circle::new(0.5));
+        scene.play(RawTracks(circle)).run_time(1.0).unwrap();
+
+        let authored = scene.snapshot(circle).unwrap().clone();
+        assert_eq!(authored.style.opacity, 0.25);
+        assert_eq!(authored.transform.rotation, 0.5);
+        assert_eq!(authored.transform.translation, Vec2::new(2.0, 1.0));
+
+        // The next built-in animation starts from the raw tracks' end state;
+        // appearance is renderer-side and leaves no trace in it.
+        scene
+            .play(circle.animate().shift(Vec2::new(1.0, 0.0)))
+            .run_time(1.0)
+            .unwrap();
+        let (from, _) = scene
+            .definition()
+            .tracks()
+            .last()
+            .unwrap()
+            .values
+            .snapshots()
+            .unwrap();
+        assert_eq!(*from, authored);
+    }
+}
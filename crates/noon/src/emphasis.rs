@@ -0,0 +1,556 @@
+//! Attention animations.
+//!
+//! Emphasis leaves the emphasized object where it found it: its tracks go
+//! out and come back within the animation. Flash, Circumscribe and FocusOn
+//! draw with transient helper objects that are present only while the
+//! animation runs.
+
+use noon_core::{
+    Color, Easing, GeometryRef, ObjectId, ObjectSnapshot, TrackTiming, Vec2, DEFAULT_FRAME_HEIGHT,
+    DEFAULT_FRAME_WIDTH, GRAY, SMALL_BUFF, TAU, YELLOW,
+};
+
+use crate::{AuthoringError, Mobject, Scene};
+
+/// Stroke width of Flash rays and the Circumscribe outline.
+pub const DEFAULT_EMPHASIS_STROKE_WIDTH: f32 = 0.04;
+
+/// Where a point-based emphasis is centered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EmphasisPoint {
+    Point(Vec2),
+    /// Center of the object when the animation is scheduled.
+    Object(Mobject),
+}
+
+impl From<Vec2> for EmphasisPoint {
+    fn from(value: Vec2) -> Self {
+        Self::Point(value)
+    }
+}
+
+impl From<Mobject> for EmphasisPoint {
+    fn from(value: Mobject) -> Self {
+        Self::Object(value)
+    }
+}
+
+/// Briefly enlarge an object and tint it, then restore it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Indicate {
+    object: Mobject,
+    scale_factor: f32,
+    color: Color,
+}
+
+impl Indicate {
+    pub const fn new(object: Mobject) -> Self {
+        Self {
+            object,
+            scale_factor: 1.2,
+            color: YELLOW,
+        }
+    }
+
+    pub const fn scale_factor(mut self, factor: f32) -> Self {
+        self.scale_factor = factor;
+        self
+    }
+
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Rays that burst outward from a point and vanish at their outer ends.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Flash {
+    point: EmphasisPoint,
+    color: Color,
+    num_lines: usize,
+    line_length: f32,
+    flash_radius: f32,
+    stroke_width: f32,
+}
+
+impl Flash {
+    pub fn new(point: impl Into<EmphasisPoint>) -> Self {
+        Self {
+            point: point.into(),
+            color: YELLOW,
+            num_lines: 12,
+            line_length: 0.2,
+            flash_radius: 0.1,
+            stroke_width: DEFAULT_EMPHASIS_STROKE_WIDTH,
+        }
+    }
+
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub const fn num_lines(mut self, count: usize) -> Self {
+        self.num_lines = count;
+        self
+    }
+
+    pub const fn line_length(mut self, length: f32) -> Self {
+        self.line_length = length;
+        self
+    }
+
+    pub const fn flash_radius(mut self, radius: f32) -> Self {
+        self.flash_radius = radius;
+        self
+    }
+
+    pub const fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+}
+
+/// Draw a rectangle around an object, then erase it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circumscribe {
+    object: Mobject,
+    color: Color,
+    buff: f32,
+    stroke_width: f32,
+}
+
+impl Circumscribe {
+    pub const fn new(object: Mobject) -> Self {
+        Self {
+            object,
+            color: YELLOW,
+            buff: SMALL_BUFF,
+            stroke_width: DEFAULT_EMPHASIS_STROKE_WIDTH,
+        }
+    }
+
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub const fn buff(mut self, buff: f32) -> Self {
+        self.buff = buff;
+        self
+    }
+
+    pub const fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+}
+
+/// Rock an object back and forth while it swells and settles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wiggle {
+    object: Mobject,
+    scale_value: f32,
+    rotation_angle: f32,
+    n_wiggles: usize,
+}
+
+impl Wiggle {
+    pub const fn new(object: Mobject) -> Self {
+        Self {
+            object,
+            scale_value: 1.1,
+            rotation_angle: 0.01 * TAU,
+            n_wiggles: 6,
+        }
+    }
+
+    pub const fn scale_value(mut self, value: f32) -> Self {
+        self.scale_value = value;
+        self
+    }
+
+    pub const fn rotation_angle(mut self, angle: f32) -> Self {
+        self.rotation_angle = angle;
+        self
+    }
+
+    pub const fn n_wiggles(mut self, count: usize) -> Self {
+        self.n_wiggles = count;
+        self
+    }
+}
+
+/// Shrink a frame-covering spotlight onto a point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FocusOn {
+    point: EmphasisPoint,
+    color: Color,
+    opacity: f32,
+}
+
+impl FocusOn {
+    pub fn new(point: impl Into<EmphasisPoint>) -> Self {
+        Self {
+            point: point.into(),
+            color: GRAY,
+            opacity: 0.2,
+        }
+    }
+
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub const fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_indicate(
+        &mut self,
+        indicate: Indicate,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let rest = self.snapshot(indicate.object)?.clone();
+        let center = rest.center();
+        let peak = rest
+            .clone()
+            .scale_about(indicate.scale_factor, center)
+            .set_color(indicate.color);
+        let (out, back) = halves(timing);
+        self.definition
+            .animate_transform(indicate.object.id, rest.clone(), peak.clone(), out)?;
+        self.definition
+            .animate_transform(indicate.object.id, peak, rest, back)?;
+        Ok(())
+    }
+
+    pub(crate) fn schedule_flash(
+        &mut self,
+        flash: Flash,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let point = self.emphasis_point(flash.point)?;
+        let (out, back) = halves(timing);
+        for index in 0..flash.num_lines {
+            let direction = Vec2::new(1.0, 0.0).rotate(TAU * index as f32 / flash.num_lines as f32);
+            let start = point + direction * flash.flash_radius;
+            let end = start + direction * flash.line_length;
+            let mut ray = ObjectSnapshot::new(GeometryRef::line(start, end));
+            ray.style.fill = None;
+            ray.style.stroke = Some(flash.color);
+            ray.style.stroke_width = flash.stroke_width;
+            let mut vanished = ray.clone();
+            vanished.geometry = GeometryRef::line(end, end);
+
+            // The ray grows out from its inner end, then its inner end
+            // chases the outer one.
+            let id = self.add_transient(ray.clone(), vanished.clone(), timing)?;
+            self.definition.animate_reveal(id, 0.0, 1.0, out)?;
+            self.definition.animate_transform(id, ray, vanished, back)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn schedule_circumscribe(
+        &mut self,
+        circumscribe: Circumscribe,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let target = self.snapshot(circumscribe.object)?;
+        let center = target.center();
+        let size = Vec2::new(target.width(), target.height())
+            + Vec2::new(1.0, 1.0) * (2.0 * circumscribe.buff);
+        let mut frame = ObjectSnapshot::new(GeometryRef::rectangle(size.x, size.y)).shift(center);
+        frame.style.fill = None;
+        frame.style.stroke = Some(circumscribe.color);
+        frame.style.stroke_width = circumscribe.stroke_width;
+
+        let (out, back) = halves(timing);
+        let id = self.add_transient(frame.clone(), frame, timing)?;
+        self.definition.animate_reveal(id, 0.0, 1.0, out)?;
+        self.definition.animate_reveal(id, 1.0, 0.0, back)?;
+        Ok(())
+    }
+
+    pub(crate) fn schedule_wiggle(
+        &mut self,
+        wiggle: Wiggle,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let rest = self.snapshot(wiggle.object)?.clone();
+        let center = rest.center();
+        // Quarter-wiggle keyframes: rotation alternates 0, +a, 0, -a under a
+        // there-and-back envelope that also drives the swell. The play's
+        // easing maps time to overall progress, so keyframes sit at evenly
+        // spaced progress and linear segments join them.
+        let segments = 4 * wiggle.n_wiggles.max(1);
+        let key = |index: usize| {
+            let progress = index as f32 / segments as f32;
+            let envelope = there_and_back(progress);
+            let swing = [0.0, 1.0, 0.0, -1.0][index % 4];
+            rest.clone()
+                .scale_about(1.0 + (wiggle.scale_value - 1.0) * envelope, center)
+                .rotate_about(wiggle.rotation_angle * swing * envelope, center)
+        };
+        let time_at = |index: usize| {
+            timing.start_time
+                + timing.duration * timing.easing.inverse(index as f64 / segments as f64)
+        };
+        let mut from = key(0);
+        for index in 1..=segments {
+            let to = if index == segments {
+                rest.clone()
+            } else {
+                key(index)
+            };
+            let start = time_at(index - 1);
+            let segment = TrackTiming::new(start, time_at(index) - start, Easing::Linear);
+            self.definition
+                .animate_transform(wiggle.object.id, from, to.clone(), segment)?;
+            from = to;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn schedule_focus_on(
+        &mut self,
+        focus: FocusOn,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let point = self.emphasis_point(focus.point)?;
+        let radius = (DEFAULT_FRAME_WIDTH + DEFAULT_FRAME_HEIGHT) * 0.5;
+        let spotlight = |radius: f32, opacity: f32| {
+            let mut snapshot = ObjectSnapshot::new(GeometryRef::circle(radius)).shift(point);
+            snapshot.style.fill = Some(focus.color);
+            snapshot.style.stroke = None;
+            snapshot.style.opacity = opacity;
+            snapshot
+        };
+        let wide = spotlight(radius, 0.0);
+        let narrow = spotlight(0.0, focus.opacity);
+        let id = self.add_transient(wide.clone(), narrow.clone(), timing)?;
+        self.definition
+            .animate_transform(id, wide, narrow, timing)?;
+        Ok(())
+    }
+
+    /// Adds a helper object that is present only during `timing`.
+    pub(crate) fn add_transient(
+        &mut self,
+        initial: ObjectSnapshot,
+        last: ObjectSnapshot,
+        timing: TrackTiming,
+    ) -> Result<ObjectId, AuthoringError> {
+        let id = self.definition.add_snapshot(initial);
+        self.definition
+            .set_presence_at(id, false, true, timing.start_time)?;
+        self.definition
+            .set_presence_at(id, true, false, timing.start_time + timing.duration)?;
+        self.authored.insert(id, last);
+        self.presence.insert(id, false);
+        Ok(id)
+    }
+
+    fn emphasis_point(&self, point: EmphasisPoint) -> Result<Vec2, AuthoringError> {
+        match point {
+            EmphasisPoint::Point(point) => Ok(point),
+            EmphasisPoint::Object(object) => Ok(self.snapshot(object)?.center()),
+        }
+    }
+}
+
+fn halves(timing: TrackTiming) -> (TrackTiming, TrackTiming) {
+    let half = timing.duration * 0.5;
+    (
+        TrackTiming::new(timing.start_time, half, timing.easing),
+        TrackTiming::new(
+            timing.start_time + half,
+            timing.duration - half,
+            timing.easing,
+        ),
+    )
+}
+
+/// Smoothstep up over the first half and back down over the second.
+fn there_and_back(progress: f32) -> f32 {
+    let t = if progress < 0.5 {
+        2.0 * progress
+    } else {
+        2.0 - 2.0 * progress
+    };
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Property, TrackValues};
+
+    use crate::{Circle, Square};
+
+    use super::*;
+
+    fn object_tracks(scene: &Scene, object: u64) -> Vec<(Property, TrackValues, f64, f64)> {
+        scene
+            .definition()
+            .tracks()
+            .iter()
+            .filter(|track| track.object.get() == object)
+            .map(|track| {
+                (
+                    track.property,
+                    track.values.clone(),
+                    track.timing.start_time,
+                    track.timing.duration,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn indicate_goes_out_and_back_about_the_center() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(1.0).shift(Vec2::new(2.0, 0.0)));
+        let rest = scene.snapshot(square).unwrap().clone();
+        scene
+            .play(Indicate::new(square).scale_factor(2.0).color(Color::RED))
+            .run_time(2.0)
+            .unwrap();
+
+        let peak = rest.clone().scale_by(2.0).set_color(Color::RED);
+        assert_eq!(
+            object_tracks(&scene, 0),
+            [
+                (
+                    Property::Transform,
+                    TrackValues::Object {
+                        from: rest.clone(),
+                        to: peak.clone()
+                    },
+                    0.0,
+                    1.0
+                ),
+                (
+                    Property::Transform,
+                    TrackValues::Object {
+                        from: peak,
+                        to: rest.clone()
+                    },
+                    1.0,
+                    1.0
+                ),
+            ]
+        );
+        assert_eq!(scene.snapshot(square).unwrap(), &rest);
+    }
+
+    #[test]
+    fn flash_rays_grow_then_collapse_onto_their_outer_ends() {
+        let mut scene = Scene::new();
+        let dot = scene.add(Circle::new(0.1).shift(Vec2::new(1.0, 1.0)));
+        scene.wait(1.0).unwrap();
+        scene
+            .play(
+                Flash::new(dot)
+                    .num_lines(4)
+                    .flash_radius(0.5)
+                    .line_length(1.0),
+            )
+            .run_time(2.0)
+            .unwrap();
+
+        assert_eq!(scene.definition().objects().len(), 5);
+        let ray = scene.definition().snapshot(ObjectId::new(1)).unwrap();
+        assert_eq!(
+            ray.geometry,
+            GeometryRef::line(Vec2::new(1.5, 1.0), Vec2::new(2.5, 1.0))
+        );
+        let tracks = object_tracks(&scene, 1);
+        let properties: Vec<_> = tracks
+            .iter()
+            .map(|(property, _, start, duration)| (*property, *start, *duration))
+            .collect();
+        assert_eq!(
+            properties,
+            [
+                (Property::Presence, 1.0, 0.0),
+                (Property::Presence, 3.0, 0.0),
+                (Property::Reveal, 1.0, 1.0),
+                (Property::Transform, 2.0, 1.0),
+            ]
+        );
+        match &tracks[3].1 {
+            TrackValues::Object { to, .. } => assert_eq!(
+                to.geometry,
+                GeometryRef::line(Vec2::new(2.5, 1.0), Vec2::new(2.5, 1.0))
+            ),
+            values => panic!("transform track holds {values:?}"),
+        }
+    }
+
+    #[test]
+    fn wiggle_keyframes_swing_both_ways_and_settle_at_rest() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(1.0));
+        let rest = scene.snapshot(square).unwrap().clone();
+        scene
+            .play(Wiggle::new(square).n_wiggles(2).rotation_angle(0.5))
+            .run_time(4.0)
+            .unwrap();
+
+        let keys: Vec<_> = object_tracks(&scene, 0)
+            .into_iter()
+            .map(|(_, values, start, duration)| match values {
+                TrackValues::Object { from, to } => (from, to, start, duration),
+                values => panic!("wiggle track holds {values:?}"),
+            })
+            .collect();
+        assert_eq!(keys.len(), 8);
+        assert_eq!(keys[0].0, rest);
+        assert_eq!(keys[7].1, rest);
+        assert!(keys
+            .windows(2)
+            .all(|pair| pair[0].1 == pair[1].0 && pair[0].2 + pair[0].3 == pair[1].2));
+        // The envelope peaks halfway through, between a positive and a
+        // negative swing.
+        let middle = &keys[3].1.transform;
+        assert_eq!(middle.rotation, 0.0);
+        assert!((middle.scale.x - 1.1).abs() < 1.0e-6);
+        assert!(keys[2].1.transform.rotation < 0.0);
+        assert!(keys[4].1.transform.rotation > 0.0);
+    }
+
+    #[test]
+    fn wiggle_eases_overall_progress_with_linear_segments() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(1.0));
+        scene
+            .play(Wiggle::new(square).n_wiggles(1))
+            .with_easing(Easing::EaseInOutCubic)
+            .run_time(2.0)
+            .unwrap();
+
+        let tracks = scene.definition().tracks();
+        assert_eq!(tracks.len(), 4);
+        assert!(tracks
+            .iter()
+            .all(|track| track.timing.easing == Easing::Linear));
+        // Quarter progress is reached where 4t^3 = 1/4, half progress
+        // exactly halfway, and the curve is symmetric about it.
+        let boundaries: Vec<f64> = tracks
+            .iter()
+            .map(|track| track.timing.start_time + track.timing.duration)
+            .collect();
+        let quarter = 2.0 * (1.0f64 / 16.0).cbrt();
+        assert!((boundaries[0] - quarter).abs() < 1.0e-9);
+        assert!((boundaries[1] - 1.0).abs() < 1.0e-9);
+        assert!((boundaries[2] - (2.0 - quarter)).abs() < 1.0e-9);
+        assert!((boundaries[3] - 2.0).abs() < 1.0e-9);
+        assert_eq!(tracks[0].timing.start_time, 0.0);
+    }
+}
@@ -0,0 +1,510 @@
+//! Tiny serializable scalar expression language for plotted curves.
+//!
+//! Expressions are kept as their source text so they can travel through
+//! language-neutral authoring layers; parsing happens once, evaluation is a
+//! plain tree walk over `f64` values.
+
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExpressionError {
+    UnexpectedCharacter { position: usize, character: char },
+    UnexpectedToken { position: usize },
+    UnexpectedEnd,
+    UnknownVariable(String),
+    UnknownFunction(String),
+}
+
+impl std::fmt::Display for ExpressionError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                formatter,
+                "unexpected character {character:?} at position {position}"
+            ),
+            Self::UnexpectedToken { position } => {
+                write!(formatter, "unexpected token at position {position}")
+            }
+            Self::UnexpectedEnd => formatter.write_str("unexpected end of expression"),
+            Self::UnknownVariable(name) => write!(formatter, "unknown variable {name}"),
+            Self::UnknownFunction(name) => write!(formatter, "unknown function {name}"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Exp,
+    Ln,
+    Log10,
+    Sqrt,
+    Abs,
+    Floor,
+    Ceil,
+    Sign,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "sin" => Self::Sin,
+            "cos" => Self::Cos,
+            "tan" => Self::Tan,
+            "asin" => Self::Asin,
+            "acos" => Self::Acos,
+            "atan" => Self::Atan,
+            "sinh" => Self::Sinh,
+            "cosh" => Self::Cosh,
+            "tanh" => Self::Tanh,
+            "exp" => Self::Exp,
+            "ln" => Self::Ln,
+            "log" => Self::Log10,
+            "sqrt" => Self::Sqrt,
+            "abs" => Self::Abs,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "sign" => Self::Sign,
+            _ => return None,
+        })
+    }
+
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Sin => value.sin(),
+            Self::Cos => value.cos(),
+            Self::Tan => value.tan(),
+            Self::Asin => value.asin(),
+            Self::Acos => value.acos(),
+            Self::Atan => value.atan(),
+            Self::Sinh => value.sinh(),
+            Self::Cosh => value.cosh(),
+            Self::Tanh => value.tanh(),
+            Self::Exp => value.exp(),
+            Self::Ln => value.ln(),
+            Self::Log10 => value.log10(),
+            Self::Sqrt => value.sqrt(),
+            Self::Abs => value.abs(),
+            Self::Floor => value.floor(),
+            Self::Ceil => value.ceil(),
+            Self::Sign => {
+                if value == 0.0 {
+                    0.0
+                } else {
+                    value.signum()
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Number(f64),
+    Variable(usize),
+    Negate(Box<Node>),
+    Binary(BinaryOperator, Box<Node>, Box<Node>),
+    Call(Function, Box<Node>),
+}
+
+impl Node {
+    fn evaluate(&self, variables: &[f64]) -> f64 {
+        match self {
+            Self::Number(value) => *value,
+            Self::Variable(index) => variables[*index],
+            Self::Negate(node) => -node.evaluate(variables),
+            Self::Binary(operator, left, right) => {
+                let left = left.evaluate(variables);
+                let right = right.evaluate(variables);
+                match operator {
+                    BinaryOperator::Add => left + right,
+                    BinaryOperator::Subtract => left - right,
+                    BinaryOperator::Multiply => left * right,
+                    BinaryOperator::Divide => left / right,
+                    BinaryOperator::Power => left.powf(right),
+                }
+            }
+            Self::Call(function, argument) => function.apply(argument.evaluate(variables)),
+        }
+    }
+}
+
+/// Parsed scalar expression over a fixed list of named variables.
+///
+/// Supports `+ - * / ^`, unary minus, parentheses, implicit constants `pi`,
+/// `tau` and `e`, and the usual elementary functions (`sin`, `exp`, `sqrt`, ...).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    source: String,
+    variables: Vec<String>,
+    root: Node,
+}
+
+impl Expression {
+    /// Parse `source` where `variables` names the positional evaluation inputs.
+    pub fn parse(source: &str, variables: &[&str]) -> Result<Self, ExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            cursor: 0,
+            variables,
+            end: source.len(),
+        };
+        let root = parser.expression()?;
+        if let Some((position, _)) = parser.peek() {
+            return Err(ExpressionError::UnexpectedToken { position });
+        }
+        Ok(Self {
+            source: source.to_owned(),
+            variables: variables.iter().map(|name| (*name).to_owned()).collect(),
+            root,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Evaluate with positional values matching [`Self::variables`]. Missing
+    /// values evaluate as zero.
+    pub fn evaluate(&self, values: &[f64]) -> f64 {
+        if values.len() >= self.variables.len() {
+            return self.root.evaluate(values);
+        }
+        let mut padded = values.to_vec();
+        padded.resize(self.variables.len(), 0.0);
+        self.root.evaluate(&padded)
+    }
+
+    /// Evaluate with named values; unnamed variables evaluate as zero.
+    pub fn evaluate_named(&self, values: &BTreeMap<String, f64>) -> f64 {
+        let positional: Vec<f64> = self
+            .variables
+            .iter()
+            .map(|name| values.get(name).copied().unwrap_or(0.0))
+            .collect();
+        self.root.evaluate(&positional)
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(&self.source)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    OpenParen,
+    CloseParen,
+}
+
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(position, character)) = chars.peek() {
+        if character.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if character.is_ascii_digit() || character == '.' {
+            let end = number_end(source, position);
+            let value = source[position..end]
+                .parse::<f64>()
+                .map_err(|_| ExpressionError::UnexpectedToken { position })?;
+            while chars.peek().is_some_and(|&(index, _)| index < end) {
+                chars.next();
+            }
+            tokens.push((position, Token::Number(value)));
+            continue;
+        }
+        if character.is_ascii_alphabetic() || character == '_' {
+            let mut end = position;
+            while let Some(&(index, next)) = chars.peek() {
+                if next.is_ascii_alphanumeric() || next == '_' {
+                    end = index + 1;
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push((
+                position,
+                Token::Identifier(source[position..end].to_owned()),
+            ));
+            continue;
+        }
+        let token = match character {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '^' => Token::Caret,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            _ => {
+                return Err(ExpressionError::UnexpectedCharacter {
+                    position,
+                    character,
+                })
+            }
+        };
+        chars.next();
+        tokens.push((position, token));
+    }
+    Ok(tokens)
+}
+
+/// Byte offset one past a numeric literal. An exponent marker is only consumed
+/// when digits follow it, so `2e` and `2exp(x)` still read as implicit products.
+fn number_end(source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut end = start;
+    while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+        end += 1;
+    }
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut exponent = end + 1;
+        if exponent < bytes.len() && (bytes[exponent] == b'+' || bytes[exponent] == b'-') {
+            exponent += 1;
+        }
+        if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+            end = exponent;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+    }
+    end
+}
+
+struct Parser<'a> {
+    tokens: &'a [(usize, Token)],
+    cursor: usize,
+    variables: &'a [&'a str],
+    end: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<(usize, &Token)> {
+        self.tokens
+            .get(self.cursor)
+            .map(|(position, token)| (*position, token))
+    }
+
+    fn next(&mut self) -> Result<(usize, Token), ExpressionError> {
+        let token = self
+            .tokens
+            .get(self.cursor)
+            .cloned()
+            .ok_or(ExpressionError::UnexpectedEnd)?;
+        self.cursor += 1;
+        Ok(token)
+    }
+
+    fn expression(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.term()?;
+        while let Some((_, token)) = self.peek() {
+            let operator = match token {
+                Token::Plus => BinaryOperator::Add,
+                Token::Minus => BinaryOperator::Subtract,
+                _ => break,
+            };
+            self.cursor += 1;
+            node = Node::Binary(operator, Box::new(node), Box::new(self.term()?));
+        }
+        Ok(node)
+    }
+
+    fn term(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.unary()?;
+        loop {
+            let operator = match self.peek() {
+                Some((_, Token::Star)) => BinaryOperator::Multiply,
+                Some((_, Token::Slash)) => BinaryOperator::Divide,
+                // Implicit multiplication such as `2x` or `3(x + 1)`.
+                Some((_, Token::Number(_) | Token::Identifier(_) | Token::OpenParen)) => {
+                    node = Node::Binary(
+                        BinaryOperator::Multiply,
+                        Box::new(node),
+                        Box::new(self.power()?),
+                    );
+                    continue;
+                }
+                _ => break,
+            };
+            self.cursor += 1;
+            node = Node::Binary(operator, Box::new(node), Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        match self.peek() {
+            Some((_, Token::Minus)) => {
+                self.cursor += 1;
+                Ok(Node::Negate(Box::new(self.unary()?)))
+            }
+            Some((_, Token::Plus)) => {
+                self.cursor += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.primary()?;
+        if let Some((_, Token::Caret)) = self.peek() {
+            self.cursor += 1;
+            // Right associative, and `-x^2` binds as `-(x^2)` via `unary`.
+            let exponent = self.unary()?;
+            return Ok(Node::Binary(
+                BinaryOperator::Power,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Node, ExpressionError> {
+        let (position, token) = self.next()?;
+        match token {
+            Token::Number(value) => Ok(Node::Number(value)),
+            Token::OpenParen => {
+                let node = self.expression()?;
+                self.expect_close()?;
+                Ok(node)
+            }
+            Token::Identifier(name) => {
+                if let Some(index) = self.variables.iter().position(|&variable| variable == name) {
+                    return Ok(Node::Variable(index));
+                }
+                match name.as_str() {
+                    "pi" => return Ok(Node::Number(std::f64::consts::PI)),
+                    "tau" => return Ok(Node::Number(std::f64::consts::TAU)),
+                    "e" => return Ok(Node::Number(std::f64::consts::E)),
+                    _ => {}
+                }
+                if let Some(function) = Function::from_name(&name) {
+                    match self.next()? {
+                        (_, Token::OpenParen) => {}
+                        (position, _) => return Err(ExpressionError::UnexpectedToken { position }),
+                    }
+                    let argument = self.expression()?;
+                    self.expect_close()?;
+                    return Ok(Node::Call(function, Box::new(argument)));
+                }
+                if let Some((_, Token::OpenParen)) = self.peek() {
+                    return Err(ExpressionError::UnknownFunction(name));
+                }
+                Err(ExpressionError::UnknownVariable(name))
+            }
+            _ => Err(ExpressionError::UnexpectedToken { position }),
+        }
+    }
+
+    fn expect_close(&mut self) -> Result<(), ExpressionError> {
+        match self.tokens.get(self.cursor) {
+            Some((_, Token::CloseParen)) => {
+                self.cursor += 1;
+                Ok(())
+            }
+            Some((position, _)) => Err(ExpressionError::UnexpectedToken {
+                position: *position,
+            }),
+            None => Err(ExpressionError::UnexpectedToken { position: self.end }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str, x: f64) -> f64 {
+        Expression::parse(source, &["x"])
+            .expect("expression parses")
+            .evaluate(&[x])
+    }
+
+    #[test]
+    fn precedence_and_associativity_follow_conventional_math() {
+        assert_eq!(eval("1 + 2 * 3", 0.0), 7.0);
+        assert_eq!(eval("2 ^ 3 ^ 2", 0.0), 512.0);
+        assert_eq!(eval("-x^2", 3.0), -9.0);
+        assert_eq!(eval("(1 + x) / 2", 3.0), 2.0);
+        assert_eq!(eval("2x + 3(x - 1)", 2.0), 7.0);
+        assert_eq!(eval("1.5e2 + 2.5E-1", 0.0), 150.25);
+        assert!((eval("2exp(x)", 0.0) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn functions_and_constants_evaluate() {
+        assert!((eval("sin(pi / 2)", 0.0) - 1.0).abs() < 1e-12);
+        assert!((eval("exp(ln(x))", 5.0) - 5.0).abs() < 1e-12);
+        assert_eq!(eval("sqrt(abs(x))", -16.0), 4.0);
+        assert!(eval("1 / x", 0.0).is_infinite());
+    }
+
+    #[test]
+    fn malformed_expressions_report_their_position() {
+        assert_eq!(
+            Expression::parse("x + $", &["x"]),
+            Err(ExpressionError::UnexpectedCharacter {
+                position: 4,
+                character: '$'
+            })
+        );
+        assert_eq!(
+            Expression::parse("(x + 1", &["x"]),
+            Err(ExpressionError::UnexpectedToken { position: 6 })
+        );
+        assert_eq!(
+            Expression::parse("y", &["x"]),
+            Err(ExpressionError::UnknownVariable("y".to_owned()))
+        );
+        assert_eq!(
+            Expression::parse("foo(x)", &["x"]),
+            Err(ExpressionError::UnknownFunction("foo".to_owned()))
+        );
+        assert_eq!(
+            Expression::parse("x *", &["x"]),
+            Err(ExpressionError::UnexpectedEnd)
+        );
+    }
+}
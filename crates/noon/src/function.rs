@@ -0,0 +1,523 @@
+//! Plotted curves sampled once at authoring time into ordinary cubic paths.
+//!
+//! Sampling is adaptive: an interval is split until its midpoint lies within
+//! `tolerance` of the chord, then consecutive samples are joined with Hermite
+//! cubics whose tangents come from parameter-space finite differences. The
+//! result is a plain [`VectorPath`], so reveal, morph and transform tracks work
+//! on plots exactly as on any other path.
+
+use std::sync::Arc;
+
+use noon_core::{Color, GeometryRef, ObjectSnapshot, Vec2, VectorPath, WHITE, YELLOW};
+
+use crate::{Expression, ExpressionError, Path};
+
+const DEFAULT_TOLERANCE: f32 = 0.005;
+const DEFAULT_STROKE_WIDTH: f32 = 0.04;
+const MIN_DEPTH: u32 = 3;
+const MAX_DEPTH: u32 = 14;
+/// At the deepest subdivision a continuous curve splits its chord roughly in
+/// half; a jump leaves nearly the whole chord on one side of the midpoint.
+const JUMP_CHORD_FRACTION: f32 = 0.95;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FunctionError {
+    InvalidRange { start: f32, end: f32 },
+    InvalidTolerance(f32),
+    EmptyCurve,
+    Expression(ExpressionError),
+}
+
+impl std::fmt::Display for FunctionError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidRange { start, end } => write!(
+                formatter,
+                "function range must be finite and increasing, got [{start}, {end}]"
+            ),
+            Self::InvalidTolerance(tolerance) => write!(
+                formatter,
+                "function sampling tolerance must be finite and positive, got {tolerance}"
+            ),
+            Self::EmptyCurve => {
+                formatter.write_str("function produced no finite segment over its range")
+            }
+            Self::Expression(error) => write!(formatter, "invalid function expression: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FunctionError {}
+
+impl From<ExpressionError> for FunctionError {
+    fn from(value: ExpressionError) -> Self {
+        Self::Expression(value)
+    }
+}
+
+type CurveFn = Arc<dyn Fn(f32) -> Vec2 + Send + Sync>;
+
+/// Curve `t -> (x(t), y(t))` over a closed parameter range.
+#[derive(Clone)]
+pub struct ParametricFunction {
+    function: CurveFn,
+    range: (f32, f32),
+    tolerance: f32,
+    discontinuities: Vec<f32>,
+    color: Color,
+    stroke_width: f32,
+}
+
+impl std::fmt::Debug for ParametricFunction {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("ParametricFunction")
+            .field("range", &self.range)
+            .field("tolerance", &self.tolerance)
+            .field("discontinuities", &self.discontinuities)
+            .field("color", &self.color)
+            .field("stroke_width", &self.stroke_width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ParametricFunction {
+    pub fn new<F>(function: F, t_range: (f32, f32)) -> Self
+    where
+        F: Fn(f32) -> Vec2 + Send + Sync + 'static,
+    {
+        Self {
+            function: Arc::new(function),
+            range: t_range,
+            tolerance: DEFAULT_TOLERANCE,
+            discontinuities: Vec::new(),
+            color: WHITE,
+            stroke_width: DEFAULT_STROKE_WIDTH,
+        }
+    }
+
+    /// Build from serialized coordinate expressions over the variable `t`.
+    pub fn from_expressions(x: &str, y: &str, t_range: (f32, f32)) -> Result<Self, FunctionError> {
+        let x = Expression::parse(x, &["t"])?;
+        let y = Expression::parse(y, &["t"])?;
+        Ok(Self::new(
+            move |t| {
+                let t = f64::from(t);
+                Vec2::new(x.evaluate(&[t]) as f32, y.evaluate(&[t]) as f32)
+            },
+            t_range,
+        ))
+    }
+
+    /// Maximum distance a sampled midpoint may deviate from its chord.
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Known parameter values where the curve must break, in addition to
+    /// automatically detected jumps and non-finite samples.
+    pub fn discontinuities(mut self, points: impl IntoIterator<Item = f32>) -> Self {
+        self.discontinuities = points.into_iter().collect();
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn range(&self) -> (f32, f32) {
+        self.range
+    }
+
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        (self.function)(t)
+    }
+
+    /// Sample the curve into cubic contours, one per continuous piece.
+    pub fn to_vector_path(&self) -> Result<VectorPath, FunctionError> {
+        let (start, end) = self.range;
+        if !start.is_finite() || !end.is_finite() || start >= end {
+            return Err(FunctionError::InvalidRange { start, end });
+        }
+        if !self.tolerance.is_finite() || self.tolerance <= 0.0 {
+            return Err(FunctionError::InvalidTolerance(self.tolerance));
+        }
+
+        let mut breaks: Vec<f32> = self
+            .discontinuities
+            .iter()
+            .copied()
+            .filter(|t| t.is_finite() && *t > start && *t < end)
+            .collect();
+        breaks.sort_by(f32::total_cmp);
+        breaks.dedup();
+
+        let mut pieces = Vec::new();
+        let mut piece_start = start;
+        for piece_end in breaks.into_iter().chain(std::iter::once(end)) {
+            // Stay strictly inside explicit breaks so poles are never sampled.
+            let span = piece_end - piece_start;
+            let epsilon = span * 1.0e-4;
+            let from = if piece_start > start {
+                piece_start + epsilon
+            } else {
+                piece_start
+            };
+            let to = if piece_end < end {
+                piece_end - epsilon
+            } else {
+                piece_end
+            };
+            self.sample_piece(from, to, &mut pieces);
+            piece_start = piece_end;
+        }
+
+        let mut path = VectorPath::new();
+        for samples in pieces.iter().filter(|samples| samples.len() >= 2) {
+            path = append_hermite_contour(path, samples);
+        }
+        if path.commands().is_empty() {
+            return Err(FunctionError::EmptyCurve);
+        }
+        Ok(path)
+    }
+
+    /// Sample into a stroked [`Path`] shape ready to add to a scene.
+    pub fn build(&self) -> Result<Path, FunctionError> {
+        let snapshot = ObjectSnapshot::new(GeometryRef::path(self.to_vector_path()?))
+            .set_fill(None, None)
+            .set_stroke(Some(self.color), Some(self.stroke_width));
+        Ok(Path(snapshot))
+    }
+
+    fn sample_piece(&self, start: f32, end: f32, pieces: &mut Vec<Vec<Sample>>) {
+        let first = self.sample(start);
+        let last = self.sample(end);
+        let mut current = Vec::new();
+        if first.is_finite() {
+            current.push(first);
+        }
+        self.subdivide(first, last, 0, &mut current, pieces);
+        if current.len() >= 2 {
+            pieces.push(current);
+        }
+    }
+
+    fn sample(&self, t: f32) -> Sample {
+        Sample {
+            t,
+            point: (self.function)(t),
+        }
+    }
+
+    fn subdivide(
+        &self,
+        left: Sample,
+        right: Sample,
+        depth: u32,
+        current: &mut Vec<Sample>,
+        pieces: &mut Vec<Vec<Sample>>,
+    ) {
+        let middle = self.sample(0.5 * (left.t + right.t));
+        let finite = left.is_finite() && right.is_finite() && middle.is_finite();
+
+        if depth >= MAX_DEPTH {
+            if finite && is_jump(left.point, middle.point, right.point, self.tolerance) {
+                finish_piece(current, pieces);
+                current.push(right);
+            } else if right.is_finite() {
+                // An empty piece here means the curve is entering its domain
+                // from a non-finite region, so it starts at `right`.
+                if current.is_empty() && left.is_finite() {
+                    current.push(left);
+                }
+                current.push(right);
+            } else {
+                finish_piece(current, pieces);
+            }
+            return;
+        }
+
+        if finite && depth >= MIN_DEPTH {
+            let deviation = (middle.point - (left.point + right.point) * 0.5).length();
+            if deviation <= self.tolerance {
+                current.push(right);
+                return;
+            }
+        }
+
+        self.subdivide(left, middle, depth + 1, current, pieces);
+        self.subdivide(middle, right, depth + 1, current, pieces);
+    }
+}
+
+/// Graph `x -> (x, f(x))` of a scalar function over an x range.
+#[derive(Clone, Debug)]
+pub struct FunctionGraph {
+    curve: ParametricFunction,
+}
+
+impl FunctionGraph {
+    pub fn new<F>(function: F, x_range: (f32, f32)) -> Self
+    where
+        F: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        Self {
+            curve: ParametricFunction::new(move |x| Vec2::new(x, function(x)), x_range)
+                .color(YELLOW),
+        }
+    }
+
+    /// Build from a serialized expression over the variable `x`.
+    pub fn from_expression(expression: &str, x_range: (f32, f32)) -> Result<Self, FunctionError> {
+        let expression = Expression::parse(expression, &["x"])?;
+        Ok(Self::new(
+            move |x| expression.evaluate(&[f64::from(x)]) as f32,
+            x_range,
+        ))
+    }
+
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.curve = self.curve.tolerance(tolerance);
+        self
+    }
+
+    pub fn discontinuities(mut self, points: impl IntoIterator<Item = f32>) -> Self {
+        self.curve = self.curve.discontinuities(points);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.curve = self.curve.color(color);
+        self
+    }
+
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.curve = self.curve.stroke_width(width);
+        self
+    }
+
+    pub fn x_range(&self) -> (f32, f32) {
+        self.curve.range()
+    }
+
+    pub fn value_at(&self, x: f32) -> f32 {
+        self.curve.point_at(x).y
+    }
+
+    pub fn to_vector_path(&self) -> Result<VectorPath, FunctionError> {
+        self.curve.to_vector_path()
+    }
+
+    pub fn build(&self) -> Result<Path, FunctionError> {
+        self.curve.build()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sample {
+    t: f32,
+    point: Vec2,
+}
+
+impl Sample {
+    fn is_finite(self) -> bool {
+        self.point.x.is_finite() && self.point.y.is_finite()
+    }
+}
+
+fn is_jump(left: Vec2, middle: Vec2, right: Vec2, tolerance: f32) -> bool {
+    let chord = (right - left).length();
+    if chord <= tolerance {
+        return false;
+    }
+    let longest_half = (middle - left).length().max((right - middle).length());
+    longest_half >= JUMP_CHORD_FRACTION * chord
+}
+
+fn finish_piece(current: &mut Vec<Sample>, pieces: &mut Vec<Vec<Sample>>) {
+    let piece = std::mem::take(current);
+    if piece.len() >= 2 {
+        pieces.push(piece);
+    }
+}
+
+fn append_hermite_contour(mut path: VectorPath, samples: &[Sample]) -> VectorPath {
+    let tangents: Vec<Vec2> = (0..samples.len())
+        .map(|index| {
+            let previous = samples[index.saturating_sub(1)];
+            let next = samples[(index + 1).min(samples.len() - 1)];
+            let dt = next.t - previous.t;
+            if dt > 0.0 {
+                (next.point - previous.point) * (1.0 / dt)
+            } else {
+                Vec2::ZERO
+            }
+        })
+        .collect();
+
+    path = path.move_to(samples[0].point);
+    for index in 0..samples.len() - 1 {
+        let start = samples[index];
+        let end = samples[index + 1];
+        let third = (end.t - start.t) / 3.0;
+        path = path.cubic_to(
+            start.point + tangents[index] * third,
+            end.point - tangents[index + 1] * third,
+            end.point,
+        );
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use noon_core::PathCommand;
+
+    fn contours(path: &VectorPath) -> usize {
+        path.commands()
+            .iter()
+            .filter(|command| matches!(command, PathCommand::MoveTo { .. }))
+            .count()
+    }
+
+    fn end_points(path: &VectorPath) -> Vec<Vec2> {
+        path.commands()
+            .iter()
+            .filter_map(|command| match command {
+                PathCommand::MoveTo { to } => Some(*to),
+                PathCommand::CubicTo { to, .. } => Some(*to),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn smooth_function_samples_into_one_cubic_contour_within_tolerance() {
+        let graph = FunctionGraph::new(|x| x.sin(), (-3.0, 3.0)).tolerance(0.001);
+        let path = graph.to_vector_path().expect("sine samples");
+
+        assert_eq!(contours(&path), 1);
+        assert!(path
+            .commands()
+            .iter()
+            .skip(1)
+            .all(|command| matches!(command, PathCommand::CubicTo { .. })));
+        let points = end_points(&path);
+        assert_eq!(
+            points.first().copied(),
+            Some(Vec2::new(-3.0, (-3.0f32).sin()))
+        );
+        assert_eq!(points.last().copied(), Some(Vec2::new(3.0, 3.0f32.sin())));
+        assert!(points
+            .iter()
+            .all(|point| (point.y - point.x.sin()).abs() < 1e-5));
+    }
+
+    #[test]
+    fn tighter_tolerance_adds_samples_where_curvature_is_high() {
+        let coarse = FunctionGraph::new(|x| x * x, (-2.0, 2.0))
+            .tolerance(0.05)
+            .to_vector_path()
+            .expect("coarse parabola");
+        let fine = FunctionGraph::new(|x| x * x, (-2.0, 2.0))
+            .tolerance(0.0005)
+            .to_vector_path()
+            .expect("fine parabola");
+        assert!(fine.commands().len() > coarse.commands().len());
+
+        let straight = FunctionGraph::new(|x| 2.0 * x + 1.0, (-2.0, 2.0))
+            .to_vector_path()
+            .expect("line");
+        assert_eq!(straight.commands().len(), 1 + (1 << MIN_DEPTH));
+    }
+
+    #[test]
+    fn jumps_and_poles_split_the_curve_into_separate_contours() {
+        let step = FunctionGraph::new(|x| if x < 0.3 { 0.0 } else { 1.0 }, (-1.0, 1.0))
+            .to_vector_path()
+            .expect("step");
+        assert_eq!(contours(&step), 2);
+
+        let reciprocal = FunctionGraph::new(|x| 1.0 / x, (-1.0, 1.0))
+            .to_vector_path()
+            .expect("reciprocal");
+        assert_eq!(contours(&reciprocal), 2);
+        assert!(end_points(&reciprocal)
+            .iter()
+            .all(|point| point.y.is_finite()));
+
+        let sqrt = FunctionGraph::new(|x| x.sqrt(), (-1.0, 1.0))
+            .to_vector_path()
+            .expect("sqrt is defined on half the range");
+        assert_eq!(contours(&sqrt), 1);
+        assert!(end_points(&sqrt).iter().all(|point| point.x >= 0.0));
+    }
+
+    #[test]
+    fn explicit_discontinuities_break_without_sampling_the_pole() {
+        let tan = FunctionGraph::new(|x| x.tan(), (-3.0, 3.0))
+            .discontinuities([-std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2])
+            .to_vector_path()
+            .expect("tangent");
+        assert_eq!(contours(&tan), 3);
+    }
+
+    #[test]
+    fn parametric_expression_matches_closure_and_closes_visually() {
+        let from_expression =
+            ParametricFunction::from_expressions("cos(t)", "sin(t)", (0.0, std::f32::consts::TAU))
+                .expect("circle expressions parse")
+                .to_vector_path()
+                .expect("circle samples");
+        let from_closure = ParametricFunction::new(
+            |t| Vec2::new(t.cos(), t.sin()),
+            (0.0, std::f32::consts::TAU),
+        )
+        .to_vector_path()
+        .expect("circle samples");
+
+        assert_eq!(
+            from_expression.commands().len(),
+            from_closure.commands().len()
+        );
+        let points = end_points(&from_expression);
+        assert!((points[0] - *points.last().unwrap()).length() < 1e-5);
+        assert!(points
+            .iter()
+            .all(|point| (point.length() - 1.0).abs() < 1e-5));
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        assert_eq!(
+            FunctionGraph::new(|x| x, (1.0, 1.0)).to_vector_path(),
+            Err(FunctionError::InvalidRange {
+                start: 1.0,
+                end: 1.0
+            })
+        );
+        assert_eq!(
+            FunctionGraph::new(|x| x, (0.0, 1.0))
+                .tolerance(0.0)
+                .to_vector_path(),
+            Err(FunctionError::InvalidTolerance(0.0))
+        );
+        assert_eq!(
+            FunctionGraph::new(|_| f32::NAN, (0.0, 1.0)).to_vector_path(),
+            Err(FunctionError::EmptyCurve)
+        );
+        assert!(matches!(
+            FunctionGraph::from_expression("x +", (0.0, 1.0)),
+            Err(FunctionError::Expression(ExpressionError::UnexpectedEnd))
+        ));
+    }
+}
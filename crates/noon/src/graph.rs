@@ -0,0 +1,784 @@
+//! Vertex/edge diagrams built from ordinary circles and lines.
+//!
+//! Layouts are computed once at authoring time and are fully deterministic:
+//! the force-directed layout draws its starting positions from an explicit
+//! seed. Edges are plain [`Line`] objects whose endpoints sit on vertex
+//! centers, so [`GraphMobject::move_vertices`] keeps them attached by moving
+//! each incident endpoint along with its vertex under the same timing.
+//!
+//! Nothing at runtime ties an edge to its vertices. Animating a vertex any
+//! other way, such as `vertex.animate().shift(...)`, would leave its edges
+//! behind, so [`Scene::play`] evaluates every touched edge across the play
+//! window and rejects the play with [`AuthoringError::DetachedGraphEdge`] if
+//! an edge detaches at any sample. Static edits of vertices are not checked.
+
+use noon_compile::CompiledScene;
+use noon_core::{
+    Color, GeometryRef, ObjectSnapshot, SceneDefinition, ScenePatch, TrackTiming, Transform2D,
+    Vec2, TAU, WHITE,
+};
+use noon_runtime::SceneInstance;
+
+use crate::{Animation, AuthoringError, Circle, Line, Mobject, ObjectId, Scene, Transform};
+
+const DEFAULT_VERTEX_RADIUS: f32 = 0.1;
+const DEFAULT_EDGE_WIDTH: f32 = 0.04;
+const DEFAULT_SPRING_ITERATIONS: u32 = 100;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphError {
+    VertexOutOfRange { vertex: usize, count: usize },
+    SelfLoop(usize),
+    InvalidDimension(f32),
+    Authoring(AuthoringError),
+}
+
+impl std::fmt::Display for GraphError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VertexOutOfRange { vertex, count } => write!(
+                formatter,
+                "graph vertex {vertex} is out of range for {count} vertices"
+            ),
+            Self::SelfLoop(vertex) => write!(formatter, "graph edge loops on vertex {vertex}"),
+            Self::InvalidDimension(value) => write!(
+                formatter,
+                "graph layout dimensions must be finite and positive, got {value}"
+            ),
+            Self::Authoring(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+impl From<AuthoringError> for GraphError {
+    fn from(value: AuthoringError) -> Self {
+        Self::Authoring(value)
+    }
+}
+
+/// Deterministic vertex placement, centered on the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GraphLayout {
+    /// Vertices evenly spaced counterclockwise from the positive x axis.
+    Circular { radius: f32 },
+    /// Row-major square-ish grid.
+    Grid { spacing: f32 },
+    /// Breadth-first layers below `root`, each parent centered over its
+    /// children. Vertices unreachable from `root` form further trees to the
+    /// right, rooted at their lowest index.
+    Tree {
+        root: usize,
+        layer_spacing: f32,
+        sibling_spacing: f32,
+    },
+    /// Fruchterman-Reingold force-directed layout from seeded random starting
+    /// positions, rescaled to fit within `radius`.
+    Spring {
+        seed: u64,
+        iterations: u32,
+        radius: f32,
+    },
+}
+
+impl GraphLayout {
+    pub const fn circular(radius: f32) -> Self {
+        Self::Circular { radius }
+    }
+
+    pub const fn grid(spacing: f32) -> Self {
+        Self::Grid { spacing }
+    }
+
+    pub const fn tree(root: usize) -> Self {
+        Self::Tree {
+            root,
+            layer_spacing: 1.0,
+            sibling_spacing: 1.0,
+        }
+    }
+
+    pub const fn spring(seed: u64) -> Self {
+        Self::Spring {
+            seed,
+            iterations: DEFAULT_SPRING_ITERATIONS,
+            radius: 2.0,
+        }
+    }
+}
+
+impl Default for GraphLayout {
+    fn default() -> Self {
+        Self::spring(0)
+    }
+}
+
+/// Builder for a graph of `vertex_count` vertices indexed from zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graph {
+    vertex_count: usize,
+    edges: Vec<(usize, usize)>,
+    layout: GraphLayout,
+    vertex_radius: f32,
+    vertex_color: Color,
+    edge_color: Color,
+    edge_width: f32,
+}
+
+impl Graph {
+    pub fn new(vertex_count: usize, edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        Self {
+            vertex_count,
+            edges: edges.into_iter().collect(),
+            layout: GraphLayout::default(),
+            vertex_radius: DEFAULT_VERTEX_RADIUS,
+            vertex_color: WHITE,
+            edge_color: WHITE,
+            edge_width: DEFAULT_EDGE_WIDTH,
+        }
+    }
+
+    pub fn layout(mut self, layout: GraphLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn vertex_radius(mut self, radius: f32) -> Self {
+        self.vertex_radius = radius;
+        self
+    }
+
+    pub fn vertex_color(mut self, color: Color) -> Self {
+        self.vertex_color = color;
+        self
+    }
+
+    pub fn edge_color(mut self, color: Color) -> Self {
+        self.edge_color = color;
+        self
+    }
+
+    pub fn edge_width(mut self, width: f32) -> Self {
+        self.edge_width = width;
+        self
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Vertex centers produced by the configured layout.
+    pub fn positions(&self) -> Result<Vec<Vec2>, GraphError> {
+        self.validate()?;
+        let count = self.vertex_count;
+        let positions = match self.layout {
+            GraphLayout::Circular { radius } => {
+                positive(radius)?;
+                if count == 1 {
+                    vec![Vec2::ZERO]
+                } else {
+                    (0..count)
+                        .map(|index| {
+                            let angle = TAU * index as f32 / count as f32;
+                            Vec2::new(angle.cos(), angle.sin()) * radius
+                        })
+                        .collect()
+                }
+            }
+            GraphLayout::Grid { spacing } => {
+                positive(spacing)?;
+                let columns = (count as f32).sqrt().ceil().max(1.0) as usize;
+                centered(
+                    (0..count)
+                        .map(|index| {
+                            let (row, column) = (index / columns, index % columns);
+                            Vec2::new(column as f32, -(row as f32)) * spacing
+                        })
+                        .collect(),
+                )
+            }
+            GraphLayout::Tree {
+                root,
+                layer_spacing,
+                sibling_spacing,
+            } => {
+                positive(layer_spacing)?;
+                positive(sibling_spacing)?;
+                self.check_vertex(root)?;
+                centered(tree_layout(
+                    &self.adjacency(),
+                    root,
+                    layer_spacing,
+                    sibling_spacing,
+                ))
+            }
+            GraphLayout::Spring {
+                seed,
+                iterations,
+                radius,
+            } => {
+                positive(radius)?;
+                spring_layout(count, &self.edges, seed, iterations, radius)
+            }
+        };
+        Ok(positions)
+    }
+
+    fn validate(&self) -> Result<(), GraphError> {
+        for &(from, to) in &self.edges {
+            self.check_vertex(from)?;
+            self.check_vertex(to)?;
+            if from == to {
+                return Err(GraphError::SelfLoop(from));
+            }
+        }
+        positive(self.vertex_radius)?;
+        positive(self.edge_width)
+    }
+
+    fn check_vertex(&self, vertex: usize) -> Result<(), GraphError> {
+        if vertex < self.vertex_count {
+            Ok(())
+        } else {
+            Err(GraphError::VertexOutOfRange {
+                vertex,
+                count: self.vertex_count,
+            })
+        }
+    }
+
+    /// Undirected neighbours in edge order, without duplicates.
+    fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.vertex_count];
+        for &(from, to) in &self.edges {
+            if !adjacency[from].contains(&to) {
+                adjacency[from].push(to);
+                adjacency[to].push(from);
+            }
+        }
+        adjacency
+    }
+}
+
+fn positive(value: f32) -> Result<(), GraphError> {
+    if value.is_finite() && value > 0.0 {
+        Ok(())
+    } else {
+        Err(GraphError::InvalidDimension(value))
+    }
+}
+
+fn centered(mut positions: Vec<Vec2>) -> Vec<Vec2> {
+    let Some(bounds) = noon_core::Rect::from_points(positions.iter().copied()) else {
+        return positions;
+    };
+    let center = bounds.center();
+    for position in &mut positions {
+        *position -= center;
+    }
+    positions
+}
+
+fn tree_layout(
+    adjacency: &[Vec<usize>],
+    root: usize,
+    layer_spacing: f32,
+    sibling_spacing: f32,
+) -> Vec<Vec2> {
+    let count = adjacency.len();
+    let mut depth = vec![usize::MAX; count];
+    let mut children = vec![Vec::new(); count];
+    let mut roots = Vec::new();
+    for start in std::iter::once(root).chain(0..count) {
+        if depth[start] != usize::MAX {
+            continue;
+        }
+        roots.push(start);
+        depth[start] = 0;
+        let mut queue = std::collections::VecDeque::from([start]);
+        while let Some(vertex) = queue.pop_front() {
+            for &neighbour in &adjacency[vertex] {
+                if depth[neighbour] == usize::MAX {
+                    depth[neighbour] = depth[vertex] + 1;
+                    children[vertex].push(neighbour);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+    }
+
+    // Leaves take consecutive slots; parents center over their children.
+    // Post-order walk with an explicit stack so deep chains cannot overflow.
+    let mut x = vec![0.0; count];
+    let mut next_slot = 0.0;
+    for root in roots {
+        let mut stack = vec![(root, false)];
+        while let Some((vertex, visited)) = stack.pop() {
+            let kids = &children[vertex];
+            if kids.is_empty() {
+                x[vertex] = next_slot;
+                next_slot += 1.0;
+            } else if visited {
+                x[vertex] = 0.5 * (x[kids[0]] + x[kids[kids.len() - 1]]);
+            } else {
+                stack.push((vertex, true));
+                stack.extend(kids.iter().rev().map(|&child| (child, false)));
+            }
+        }
+    }
+    (0..count)
+        .map(|vertex| {
+            Vec2::new(
+                x[vertex] * sibling_spacing,
+                -(depth[vertex] as f32) * layer_spacing,
+            )
+        })
+        .collect()
+}
+
+/// SplitMix64, used only to seed spring layouts reproducibly.
+fn next_random(state: &mut u64) -> f32 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut value = *state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^= value >> 31;
+    (value >> 40) as f32 / (1u64 << 24) as f32
+}
+
+fn spring_layout(
+    count: usize,
+    edges: &[(usize, usize)],
+    seed: u64,
+    iterations: u32,
+    radius: f32,
+) -> Vec<Vec2> {
+    if count <= 1 {
+        return vec![Vec2::ZERO; count];
+    }
+    let mut state = seed;
+    let mut positions: Vec<Vec2> = (0..count)
+        .map(|_| Vec2::new(next_random(&mut state), next_random(&mut state)) * 2.0 - Vec2::ONE)
+        .collect();
+    // Ideal edge length for vertices spread over the 2 x 2 starting square.
+    let ideal = (4.0 / count as f32).sqrt();
+    let initial_temperature = 0.1;
+    let mut displacement = vec![Vec2::ZERO; count];
+    for iteration in 0..iterations {
+        displacement.fill(Vec2::ZERO);
+        for a in 0..count {
+            for b in a + 1..count {
+                let delta = positions[a] - positions[b];
+                let distance = delta.length().max(1.0e-3);
+                let push = delta / distance * (ideal * ideal / distance);
+                displacement[a] += push;
+                displacement[b] -= push;
+            }
+        }
+        for &(from, to) in edges {
+            let delta = positions[from] - positions[to];
+            let distance = delta.length().max(1.0e-3);
+            let pull = delta / distance * (distance * distance / ideal);
+            displacement[from] -= pull;
+            displacement[to] += pull;
+        }
+        let temperature = initial_temperature * (1.0 - iteration as f32 / iterations as f32);
+        for (position, displacement) in positions.iter_mut().zip(&displacement) {
+            let length = displacement.length();
+            if length > 0.0 {
+                *position += *displacement / length * length.min(temperature);
+            }
+        }
+    }
+
+    let positions = centered(positions);
+    let extent = positions
+        .iter()
+        .map(|position| position.length())
+        .fold(0.0, f32::max);
+    if extent <= 0.0 {
+        return positions;
+    }
+    positions
+        .into_iter()
+        .map(|position| position * (radius / extent))
+        .collect()
+}
+
+/// An edge added to a scene, joining two vertex indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub from: usize,
+    pub to: usize,
+    pub object: Mobject,
+}
+
+/// Edge and vertex objects a scene keeps attached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct GraphEdgeBinding {
+    edge: ObjectId,
+    from: ObjectId,
+    to: ObjectId,
+}
+
+/// World-space distance within which an edge endpoint counts as attached.
+const ATTACHED_TOLERANCE: f32 = 1.0e-4;
+
+/// Evenly spaced times per play at which touched edges must be attached.
+const ATTACHMENT_SAMPLES: usize = 16;
+
+/// Objects created by [`Scene::add_graph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphMobject {
+    vertices: Vec<Mobject>,
+    edges: Vec<GraphEdge>,
+}
+
+impl GraphMobject {
+    pub fn vertices(&self) -> &[Mobject] {
+        &self.vertices
+    }
+
+    pub fn vertex(&self, index: usize) -> Option<Mobject> {
+        self.vertices.get(index).copied()
+    }
+
+    pub fn edges(&self) -> &[GraphEdge] {
+        &self.edges
+    }
+
+    /// Animations moving one vertex center to `point` with its edges attached.
+    pub fn move_vertex(
+        &self,
+        scene: &Scene,
+        vertex: usize,
+        point: Vec2,
+    ) -> Result<Vec<Animation>, GraphError> {
+        self.move_vertices(scene, [(vertex, point)])
+    }
+
+    /// Animations moving vertex centers, plus one line transform per incident
+    /// edge. Endpoints and centers interpolate linearly under the same timing,
+    /// so edges stay attached throughout the animation. Endpoints are placed
+    /// in each edge's own frame, so graphs moved as a whole keep working.
+    pub fn move_vertices(
+        &self,
+        scene: &Scene,
+        moves: impl IntoIterator<Item = (usize, Vec2)>,
+    ) -> Result<Vec<Animation>, GraphError> {
+        let mut centers = self
+            .vertices
+            .iter()
+            .map(|&vertex| Ok(scene.snapshot(vertex)?.center()))
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        let mut moved = vec![false; centers.len()];
+        let mut animations = Vec::new();
+        for (vertex, point) in moves {
+            let object = self.vertex(vertex).ok_or(GraphError::VertexOutOfRange {
+                vertex,
+                count: self.vertices.len(),
+            })?;
+            centers[vertex] = point;
+            moved[vertex] = true;
+            let current = scene.snapshot(object)?;
+            let target = current.clone().shift(point - current.center());
+            animations.push(Transform::new(object, target).into());
+        }
+        for edge in &self.edges {
+            if !moved[edge.from] && !moved[edge.to] {
+                continue;
+            }
+            let mut target = scene.snapshot(edge.object)?.clone();
+            let (start, end) = (centers[edge.from], centers[edge.to]);
+            target.geometry = match (
+                to_local(target.transform, start),
+                to_local(target.transform, end),
+            ) {
+                (Some(start), Some(end)) => GeometryRef::line(start, end),
+                // A collapsed edge frame cannot be inverted; draw in world space.
+                _ => {
+                    target.transform = Transform2D::IDENTITY;
+                    GeometryRef::line(start, end)
+                }
+            };
+            animations.push(Transform::new(edge.object, target).into());
+        }
+        Ok(animations)
+    }
+}
+
+impl Scene {
+    /// Lays out a graph and adds its edges, then its vertices so vertices draw
+    /// on top.
+    pub fn add_graph(&mut self, graph: Graph) -> Result<GraphMobject, GraphError> {
+        let positions = graph.positions()?;
+        let edges = graph
+            .edges
+            .iter()
+            .map(|&(from, to)| {
+                let line = Line::new(positions[from], positions[to])
+                    .set_stroke(Some(graph.edge_color), Some(graph.edge_width));
+                GraphEdge {
+                    from,
+                    to,
+                    object: self.add(line),
+                }
+            })
+            .collect();
+        let vertices = positions
+            .iter()
+            .map(|&position| {
+                self.add(
+                    Circle::new(graph.vertex_radius)
+                        .color(graph.vertex_color)
+                        .shift(position),
+                )
+            })
+            .collect::<Vec<_>>();
+        let graph = GraphMobject { vertices, edges };
+        self.graph_edges
+            .extend(graph.edges.iter().map(|edge| GraphEdgeBinding {
+                edge: edge.object.id,
+                from: graph.vertices[edge.from].id,
+                to: graph.vertices[edge.to].id,
+            }));
+        Ok(graph)
+    }
+
+    /// Every graph edge touched by a track from `first_track` on, directly
+    /// or through one of its vertices, must stay attached to both vertices
+    /// throughout `timing` and at its end.
+    pub(crate) fn check_graph_edges(
+        &self,
+        first_track: usize,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let touched = self.definition.tracks()[first_track..]
+            .iter()
+            .map(|track| track.object)
+            .collect::<std::collections::BTreeSet<_>>();
+        let bindings = self
+            .graph_edges
+            .iter()
+            .filter(|binding| {
+                [binding.edge, binding.from, binding.to]
+                    .iter()
+                    .any(|object| touched.contains(object))
+            })
+            .collect::<Vec<_>>();
+        for binding in &bindings {
+            let snapshot = |id| self.snapshot(Mobject { id });
+            if !binding.attached(
+                snapshot(binding.edge)?,
+                snapshot(binding.from)?,
+                snapshot(binding.to)?,
+            ) {
+                return Err(AuthoringError::DetachedGraphEdge(binding.edge));
+            }
+        }
+        if bindings.is_empty() {
+            return Ok(());
+        }
+
+        // Vertices may follow arcs or paths that a line transform cannot, so
+        // evaluate only the objects involved and check between the endpoints.
+        let Some(mut instance) = self.edge_instance(&bindings) else {
+            return Ok(());
+        };
+        for sample in 1..ATTACHMENT_SAMPLES {
+            let time =
+                timing.start_time + timing.duration * sample as f64 / ATTACHMENT_SAMPLES as f64;
+            let Ok(frame) = instance.seek(time) else {
+                continue;
+            };
+            let state = |id: ObjectId| {
+                frame
+                    .objects
+                    .iter()
+                    .find(|object| object.id == id)
+                    .map(|object| ObjectSnapshot {
+                        geometry: object.geometry.clone(),
+                        transform: object.transform,
+                        style: object.style,
+                    })
+            };
+            for binding in &bindings {
+                let (Some(edge), Some(from), Some(to)) =
+                    (state(binding.edge), state(binding.from), state(binding.to))
+                else {
+                    continue;
+                };
+                if !binding.attached(&edge, &from, &to) {
+                    return Err(AuthoringError::DetachedGraphEdge(binding.edge));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Runtime instance of just the bound edges, their vertices and their
+    /// tracks. `None` when that subset does not compile; the full scene
+    /// reports the same problem when it is compiled.
+    fn edge_instance(&self, bindings: &[&GraphEdgeBinding]) -> Option<SceneInstance> {
+        let ids = bindings
+            .iter()
+            .flat_map(|binding| [binding.edge, binding.from, binding.to])
+            .collect::<std::collections::BTreeSet<_>>();
+        let objects = self
+            .definition
+            .objects()
+            .iter()
+            .filter(|object| ids.contains(&object.id))
+            .cloned()
+            .collect();
+        let tracks = self
+            .definition
+            .tracks()
+            .iter()
+            .filter(|track| ids.contains(&track.object))
+            .cloned()
+            .collect();
+        let mut subset = SceneDefinition::from_parts(objects, tracks).ok()?;
+        for image in self.definition.images() {
+            subset
+                .apply_patch(ScenePatch::RegisterImage(image.clone()))
+                .ok()?;
+        }
+        for font in self.definition.fonts() {
+            subset
+                .apply_patch(ScenePatch::RegisterFont(font.clone()))
+                .ok()?;
+        }
+        let compiled = CompiledScene::compile(&subset).ok()?;
+        Some(SceneInstance::new(compiled))
+    }
+}
+
+impl GraphEdgeBinding {
+    fn attached(&self, edge: &ObjectSnapshot, from: &ObjectSnapshot, to: &ObjectSnapshot) -> bool {
+        let GeometryRef::Line { start, end } = edge.geometry else {
+            return false;
+        };
+        let attached = |endpoint: Vec2, vertex: &ObjectSnapshot| {
+            (edge.transform.transform_point(endpoint) - vertex.center()).length()
+                <= ATTACHED_TOLERANCE
+        };
+        attached(start, from) && attached(end, to)
+    }
+}
+
+/// Point in `transform`'s local frame that it maps to `world`.
+fn to_local(transform: Transform2D, world: Vec2) -> Option<Vec2> {
+    if transform.scale.x == 0.0 || transform.scale.y == 0.0 {
+        return None;
+    }
+    let unrotated = (world - transform.translation).rotate(-transform.rotation);
+    Some(Vec2::new(
+        unrotated.x / transform.scale.x,
+        unrotated.y / transform.scale.y,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual - expected).length() < 1.0e-5,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn circular_grid_and_tree_layouts_are_centered() {
+        let circle = Graph::new(4, [])
+            .layout(GraphLayout::circular(2.0))
+            .positions()
+            .expect("valid layout");
+        assert_close(circle[0], Vec2::new(2.0, 0.0));
+        assert_close(circle[1], Vec2::new(0.0, 2.0));
+
+        let grid = Graph::new(3, [])
+            .layout(GraphLayout::grid(1.0))
+            .positions()
+            .expect("valid layout");
+        assert_eq!(
+            grid,
+            [
+                Vec2::new(-0.5, 0.5),
+                Vec2::new(0.5, 0.5),
+                Vec2::new(-0.5, -0.5)
+            ]
+        );
+
+        // 0 has children 1 and 2; 1 has children 3 and 4; 5 is disconnected.
+        let tree = Graph::new(6, [(0, 1), (0, 2), (1, 3), (1, 4)])
+            .layout(GraphLayout::tree(0))
+            .positions()
+            .expect("valid layout");
+        assert_close(tree[3] - tree[4], Vec2::new(-1.0, 0.0));
+        assert_close(tree[1], (tree[3] + tree[4]) * 0.5 + Vec2::new(0.0, 1.0));
+        assert_close(tree[0], (tree[1] + tree[2]) * 0.5 + Vec2::new(0.0, 1.0));
+        assert_eq!(tree[5].y, tree[0].y);
+        assert!(tree[5].x > tree[2].x);
+    }
+
+    #[test]
+    fn deep_chain_tree_layout_does_not_overflow_the_stack() {
+        let count = 200_000;
+        let tree = Graph::new(count, (1..count).map(|vertex| (vertex - 1, vertex)))
+            .layout(GraphLayout::tree(0))
+            .positions()
+            .expect("valid layout");
+        assert_eq!(tree[count - 1].x, tree[0].x);
+        assert!(tree[count - 1].y < tree[0].y);
+    }
+
+    #[test]
+    fn spring_layout_is_seeded_and_fits_its_radius() {
+        let graph = Graph::new(5, [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        let first = graph
+            .clone()
+            .layout(GraphLayout::spring(7))
+            .positions()
+            .expect("valid layout");
+        let again = graph
+            .clone()
+            .layout(GraphLayout::spring(7))
+            .positions()
+            .expect("valid layout");
+        let other = graph
+            .layout(GraphLayout::spring(8))
+            .positions()
+            .expect("valid layout");
+        assert_eq!(first, again);
+        assert_ne!(first, other);
+        let extent = first.iter().map(|p| p.length()).fold(0.0, f32::max);
+        assert!((extent - 2.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn invalid_graphs_are_rejected() {
+        assert_eq!(
+            Graph::new(2, [(0, 2)]).positions(),
+            Err(GraphError::VertexOutOfRange {
+                vertex: 2,
+                count: 2
+            })
+        );
+        assert_eq!(
+            Graph::new(2, [(1, 1)]).positions(),
+            Err(GraphError::SelfLoop(1))
+        );
+        assert_eq!(
+            Graph::new(2, []).layout(GraphLayout::grid(0.0)).positions(),
+            Err(GraphError::InvalidDimension(0.0))
+        );
+    }
+}
@@ -0,0 +1,604 @@
+//! Authoring collections of scene objects.
+//!
+//! A [`Group`] is only a list of handles; it adds no runtime hierarchy.
+//! Layout and transform operations resolve member snapshots when they are
+//! applied and lower to one ordinary track or static edit per member.
+
+use noon_core::{Color, GeometryRef, ObjectSnapshot, Rect, TrackTiming, Vec2};
+
+use crate::{pivot_path, AuthoringError, Mobject, Scene};
+
+/// Ordered collection of scene objects laid out and animated together.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Group {
+    members: Vec<Mobject>,
+}
+
+/// Alias kept for Python parity, where `VGroup` restricts members to vector
+/// objects. Every Rust handle is already a single object.
+pub type VGroup = Group;
+
+impl Group {
+    pub fn new(members: impl IntoIterator<Item = Mobject>) -> Self {
+        Self {
+            members: members.into_iter().collect(),
+        }
+    }
+
+    pub fn push(&mut self, member: Mobject) {
+        self.members.push(member);
+    }
+
+    pub fn members(&self) -> &[Mobject] {
+        &self.members
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Mobject> + '_ {
+        self.members.iter().copied()
+    }
+
+    /// Union of the members' current world bounds.
+    pub fn bounds(&self, scene: &Scene) -> Result<Option<Rect>, AuthoringError> {
+        Ok(bounds_of(&scene.member_snapshots(self)?))
+    }
+
+    /// Center of [`Group::bounds`], or the origin for an unbounded group.
+    pub fn center(&self, scene: &Scene) -> Result<Vec2, AuthoringError> {
+        Ok(center_of(&scene.member_snapshots(self)?))
+    }
+
+    /// Build a transient target-state animation for every member.
+    pub fn animate(&self) -> GroupAnimate {
+        GroupAnimate {
+            group: self.clone(),
+            operations: Vec::new(),
+        }
+    }
+}
+
+impl From<Mobject> for Group {
+    fn from(value: Mobject) -> Self {
+        Self::new([value])
+    }
+}
+
+impl FromIterator<Mobject> for Group {
+    fn from_iter<T: IntoIterator<Item = Mobject>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum GroupOperation {
+    Shift(Vec2),
+    MoveTo(Vec2),
+    Scale(f32),
+    Rotate(f32),
+    SetColor(Color),
+    SetOpacity(f32),
+    NextTo {
+        target: Group,
+        direction: Vec2,
+        buff: f32,
+    },
+    AlignTo {
+        target: Group,
+        direction: Vec2,
+    },
+    Arrange {
+        direction: Vec2,
+        buff: f32,
+    },
+    ArrangeInGrid {
+        rows: Option<usize>,
+        cols: Option<usize>,
+        buff: f32,
+    },
+}
+
+/// Transient target-state builder returned by [`Group::animate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupAnimate {
+    group: Group,
+    operations: Vec<GroupOperation>,
+}
+
+impl GroupAnimate {
+    pub fn shift(mut self, offset: Vec2) -> Self {
+        self.operations.push(GroupOperation::Shift(offset));
+        self
+    }
+
+    /// Moves the group so its bounds are centered on `point`.
+    pub fn move_to(mut self, point: Vec2) -> Self {
+        self.operations.push(GroupOperation::MoveTo(point));
+        self
+    }
+
+    /// Scales every member about the group center.
+    pub fn scale(mut self, factor: f32) -> Self {
+        self.operations.push(GroupOperation::Scale(factor));
+        self
+    }
+
+    /// Rotates every member about the group center.
+    pub fn rotate(mut self, angle: f32) -> Self {
+        self.operations.push(GroupOperation::Rotate(angle));
+        self
+    }
+
+    pub fn set_color(mut self, color: Color) -> Self {
+        self.operations.push(GroupOperation::SetColor(color));
+        self
+    }
+
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.operations.push(GroupOperation::SetOpacity(opacity));
+        self
+    }
+
+    pub fn next_to(mut self, target: impl Into<Group>, direction: Vec2, buff: f32) -> Self {
+        self.operations.push(GroupOperation::NextTo {
+            target: target.into(),
+            direction,
+            buff,
+        });
+        self
+    }
+
+    pub fn align_to(mut self, target: impl Into<Group>, direction: Vec2) -> Self {
+        self.operations.push(GroupOperation::AlignTo {
+            target: target.into(),
+            direction,
+        });
+        self
+    }
+
+    /// Places each member next to the previous one, then centers the group
+    /// on the origin.
+    pub fn arrange(mut self, direction: Vec2, buff: f32) -> Self {
+        self.operations
+            .push(GroupOperation::Arrange { direction, buff });
+        self
+    }
+
+    /// Centers members in equal cells, filled row by row and centered on the
+    /// origin. Missing dimensions are derived from the member count.
+    pub fn arrange_in_grid(mut self, rows: Option<usize>, cols: Option<usize>, buff: f32) -> Self {
+        self.operations
+            .push(GroupOperation::ArrangeInGrid { rows, cols, buff });
+        self
+    }
+}
+
+/// Mutable static layout accessor for a group, returned by
+/// [`Scene::edit_group`].
+pub struct GroupEditor<'a> {
+    scene: &'a mut Scene,
+    group: Group,
+}
+
+impl GroupEditor<'_> {
+    fn apply(&mut self, operation: GroupOperation) -> Result<&mut Self, AuthoringError> {
+        let snapshots = self.scene.member_snapshots(&self.group)?;
+        let snapshots = self.scene.apply_group_operation(snapshots, &operation)?;
+        for (&member, snapshot) in self.group.members.iter().zip(snapshots) {
+            self.scene.apply_static(member, snapshot)?;
+        }
+        Ok(self)
+    }
+
+    pub fn shift(&mut self, offset: Vec2) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::Shift(offset))
+    }
+
+    pub fn move_to(&mut self, point: Vec2) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::MoveTo(point))
+    }
+
+    pub fn scale(&mut self, factor: f32) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::Scale(factor))
+    }
+
+    pub fn rotate(&mut self, angle: f32) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::Rotate(angle))
+    }
+
+    pub fn set_color(&mut self, color: Color) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::SetColor(color))
+    }
+
+    pub fn set_opacity(&mut self, opacity: f32) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::SetOpacity(opacity))
+    }
+
+    pub fn next_to(
+        &mut self,
+        target: impl Into<Group>,
+        direction: Vec2,
+        buff: f32,
+    ) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::NextTo {
+            target: target.into(),
+            direction,
+            buff,
+        })
+    }
+
+    pub fn align_to(
+        &mut self,
+        target: impl Into<Group>,
+        direction: Vec2,
+    ) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::AlignTo {
+            target: target.into(),
+            direction,
+        })
+    }
+
+    pub fn arrange(&mut self, direction: Vec2, buff: f32) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::Arrange { direction, buff })
+    }
+
+    pub fn arrange_in_grid(
+        &mut self,
+        rows: Option<usize>,
+        cols: Option<usize>,
+        buff: f32,
+    ) -> Result<&mut Self, AuthoringError> {
+        self.apply(GroupOperation::ArrangeInGrid { rows, cols, buff })
+    }
+}
+
+impl Scene {
+    /// Edit every member's static snapshot before any of them is animated.
+    pub fn edit_group(&mut self, group: &Group) -> Result<GroupEditor<'_>, AuthoringError> {
+        for &member in &group.members {
+            self.edit(member)?;
+        }
+        Ok(GroupEditor {
+            scene: self,
+            group: group.clone(),
+        })
+    }
+
+    pub(crate) fn schedule_group_animate(
+        &mut self,
+        animation: GroupAnimate,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let from = self.member_snapshots(&animation.group)?;
+        let mut to = from.clone();
+        let mut pivots = Vec::new();
+        for operation in &animation.operations {
+            match *operation {
+                GroupOperation::Scale(_) => pivots.push((center_of(&to), 0.0)),
+                GroupOperation::Rotate(angle) => pivots.push((center_of(&to), angle)),
+                _ => {}
+            }
+            to = self.apply_group_operation(to, operation)?;
+        }
+        // Members swing around the group center exactly as a single object
+        // rotated about that point would.
+        let path = pivot_path(pivots)?;
+        for ((&member, from), to) in animation.group.members.iter().zip(from).zip(to) {
+            self.animate_along(member.id, from, to.clone(), path.clone(), timing)?;
+            self.authored.insert(member.id, to);
+        }
+        Ok(())
+    }
+
+    fn member_snapshots(&self, group: &Group) -> Result<Vec<ObjectSnapshot>, AuthoringError> {
+        group
+            .members
+            .iter()
+            .map(|&member| self.snapshot(member).cloned())
+            .collect()
+    }
+
+    fn apply_group_operation(
+        &self,
+        snapshots: Vec<ObjectSnapshot>,
+        operation: &GroupOperation,
+    ) -> Result<Vec<ObjectSnapshot>, AuthoringError> {
+        let center = center_of(&snapshots);
+        Ok(match operation {
+            GroupOperation::Shift(offset) => shift_all(snapshots, *offset),
+            GroupOperation::MoveTo(point) => shift_all(snapshots, *point - center),
+            GroupOperation::Scale(factor) => snapshots
+                .into_iter()
+                .map(|snapshot| snapshot.scale_about(*factor, center))
+                .collect(),
+            GroupOperation::Rotate(angle) => snapshots
+                .into_iter()
+                .map(|snapshot| snapshot.rotate_about(*angle, center))
+                .collect(),
+            GroupOperation::SetColor(color) => snapshots
+                .into_iter()
+                .map(|snapshot| snapshot.set_color(*color))
+                .collect(),
+            GroupOperation::SetOpacity(opacity) => snapshots
+                .into_iter()
+                .map(|snapshot| snapshot.set_opacity(*opacity))
+                .collect(),
+            GroupOperation::NextTo {
+                target,
+                direction,
+                buff,
+            } => {
+                let target = self.member_snapshots(target)?;
+                let offset = match (bounds_of(&snapshots), bounds_of(&target)) {
+                    (Some(bounds), Some(target)) => {
+                        bounds_snapshot(bounds)
+                            .next_to(&bounds_snapshot(target), *direction, *buff)
+                            .transform
+                            .translation
+                            - bounds.center()
+                    }
+                    _ => Vec2::ZERO,
+                };
+                shift_all(snapshots, offset)
+            }
+            GroupOperation::AlignTo { target, direction } => {
+                let target = self.member_snapshots(target)?;
+                let offset = match (bounds_of(&snapshots), bounds_of(&target)) {
+                    (Some(bounds), Some(target)) => {
+                        bounds_snapshot(bounds)
+                            .align_to(&bounds_snapshot(target), *direction)
+                            .transform
+                            .translation
+                            - bounds.center()
+                    }
+                    _ => Vec2::ZERO,
+                };
+                shift_all(snapshots, offset)
+            }
+            GroupOperation::Arrange { direction, buff } => {
+                let mut arranged: Vec<ObjectSnapshot> = Vec::with_capacity(snapshots.len());
+                for snapshot in snapshots {
+                    let placed = match arranged.last() {
+                        Some(previous) => snapshot.next_to(previous, *direction, *buff),
+                        None => snapshot,
+                    };
+                    arranged.push(placed);
+                }
+                let center = center_of(&arranged);
+                shift_all(arranged, -center)
+            }
+            GroupOperation::ArrangeInGrid { rows, cols, buff } => {
+                let (rows, cols) = grid_shape(snapshots.len(), *rows, *cols)?;
+                let cell = Vec2::new(
+                    snapshots
+                        .iter()
+                        .map(ObjectSnapshot::width)
+                        .fold(0.0, f32::max)
+                        + buff,
+                    snapshots
+                        .iter()
+                        .map(ObjectSnapshot::height)
+                        .fold(0.0, f32::max)
+                        + buff,
+                );
+                snapshots
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, snapshot)| {
+                        let row = (index / cols) as f32;
+                        let col = (index % cols) as f32;
+                        snapshot.move_to(Vec2::new(
+                            (col - (cols - 1) as f32 * 0.5) * cell.x,
+                            ((rows - 1) as f32 * 0.5 - row) * cell.y,
+                        ))
+                    })
+                    .collect()
+            }
+        })
+    }
+}
+
+fn shift_all(snapshots: Vec<ObjectSnapshot>, offset: Vec2) -> Vec<ObjectSnapshot> {
+    snapshots
+        .into_iter()
+        .map(|snapshot| snapshot.shift(offset))
+        .collect()
+}
+
+fn bounds_of(snapshots: &[ObjectSnapshot]) -> Option<Rect> {
+    snapshots
+        .iter()
+        .filter_map(ObjectSnapshot::world_bounds)
+        .reduce(Rect::union)
+}
+
+fn center_of(snapshots: &[ObjectSnapshot]) -> Vec2 {
+    bounds_of(snapshots).map_or(Vec2::ZERO, Rect::center)
+}
+
+/// Rectangle snapshot covering `bounds`, so group layout can reuse the
+/// single-object `next_to` and `align_to` rules.
+fn bounds_snapshot(bounds: Rect) -> ObjectSnapshot {
+    ObjectSnapshot::new(GeometryRef::rectangle(bounds.width(), bounds.height()))
+        .shift(bounds.center())
+}
+
+/// Resolves missing grid dimensions the way Python's `arrange_in_grid` does.
+fn grid_shape(
+    count: usize,
+    rows: Option<usize>,
+    cols: Option<usize>,
+) -> Result<(usize, usize), AuthoringError> {
+    let count = count.max(1);
+    let (rows, cols) = match (rows, cols) {
+        (Some(rows), Some(cols)) => (rows, cols),
+        (Some(rows), None) => (rows, count.div_ceil(rows.max(1))),
+        (None, Some(cols)) => (count.div_ceil(cols.max(1)), cols),
+        (None, None) => {
+            let cols = (count as f64).sqrt().ceil() as usize;
+            (count.div_ceil(cols), cols)
+        }
+    };
+    if rows == 0 || cols == 0 {
+        return Err(AuthoringError::InvalidGridShape { rows, cols });
+    }
+    Ok((rows, cols))
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{MotionPath, Property, TrackValues, DOWN, LEFT, RIGHT, UP};
+
+    use crate::{Circle, Square};
+
+    use super::*;
+
+    fn assert_close(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual - expected).length() < 1.0e-5,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    fn center(scene: &Scene, object: Mobject) -> Vec2 {
+        scene.snapshot(object).unwrap().center()
+    }
+
+    #[test]
+    fn arrange_chains_members_and_centers_the_group_on_the_origin() {
+        let mut scene = Scene::new();
+        let small = scene.add(Square::new(1.0).shift(Vec2::new(5.0, 5.0)));
+        let large = scene.add(Square::new(2.0));
+        let group = Group::new([small, large]);
+        scene
+            .edit_group(&group)
+            .unwrap()
+            .arrange(RIGHT, 0.5)
+            .unwrap();
+
+        assert_close(center(&scene, small), Vec2::new(-1.25, 0.0));
+        assert_close(center(&scene, large), Vec2::new(0.75, 0.0));
+        assert_close(group.center(&scene).unwrap(), Vec2::ZERO);
+
+        let label = scene.add(Circle::new(0.25));
+        scene
+            .edit_group(&Group::from(label))
+            .unwrap()
+            .next_to(group.clone(), DOWN, 0.25)
+            .unwrap()
+            .align_to(group, LEFT)
+            .unwrap();
+        assert_close(center(&scene, label), Vec2::new(-1.5, -1.5));
+    }
+
+    #[test]
+    fn grid_cells_follow_the_largest_member() {
+        let mut scene = Scene::new();
+        let members: Group = (0..5).map(|_| scene.add(Circle::new(0.5))).collect();
+        scene
+            .edit_group(&members)
+            .unwrap()
+            .arrange_in_grid(None, None, 1.0)
+            .unwrap();
+
+        // Five members form a 2x3 grid with 2 x 2 cells.
+        assert_close(center(&scene, members.members()[0]), Vec2::new(-2.0, 1.0));
+        assert_close(center(&scene, members.members()[4]), Vec2::new(0.0, -1.0));
+        assert_eq!(
+            scene
+                .edit_group(&members)
+                .unwrap()
+                .arrange_in_grid(Some(0), Some(2), 1.0)
+                .err(),
+            Some(AuthoringError::InvalidGridShape { rows: 0, cols: 2 })
+        );
+    }
+
+    #[test]
+    fn group_animation_scales_about_the_group_center_with_one_track_per_member() {
+        let mut scene = Scene::new();
+        let left = scene.add(Circle::new(0.5).shift(LEFT));
+        let right = scene.add(Circle::new(0.5).shift(RIGHT + UP * 2.0));
+        let group = Group::new([left, right]);
+        scene
+            .play(group.animate().scale(2.0).rotate(std::f32::consts::PI))
+            .run_time(1.0)
+            .unwrap();
+
+        let tracks = scene.definition().tracks();
+        assert_eq!(tracks.len(), 2);
+        assert!(tracks
+            .iter()
+            .all(|track| track.property == Property::Transform));
+        let TrackValues::Motion { to, path, .. } = &tracks[0].values else {
+            panic!("group rotation lowers to motion tracks");
+        };
+        assert_eq!(to.transform.scale, Vec2::new(2.0, 2.0));
+        assert_eq!(
+            path,
+            &MotionPath::Pivot {
+                point: Vec2::new(0.0, 1.0),
+                angle: std::f32::consts::PI
+            }
+        );
+        // Center (0, 1): left sits at (-1, -1) from it, so it lands at (2, 3).
+        assert_close(center(&scene, left), Vec2::new(2.0, 3.0));
+        assert_close(center(&scene, right), Vec2::new(-2.0, -1.0));
+        assert_eq!(
+            scene.edit_group(&group).err(),
+            Some(AuthoringError::StaticMutationAfterAnimation(left.id()))
+        );
+    }
+
+    #[test]
+    fn group_rotation_matches_rotating_each_member_about_the_group_center() {
+        let mut grouped = Scene::new();
+        let square = grouped.add(Square::new(1.0).shift(RIGHT * 2.0));
+        let circle = grouped.add(Circle::new(0.5).shift(UP));
+        let group = Group::new([square, circle]);
+        let pivot = group.center(&grouped).unwrap();
+        grouped
+            .play(group.animate().rotate(1.0).scale(0.5))
+            .run_time(1.0)
+            .unwrap();
+
+        let mut single = Scene::new();
+        let square = single.add(Square::new(1.0).shift(RIGHT * 2.0));
+        let circle = single.add(Circle::new(0.5).shift(UP));
+        single
+            .play((
+                square
+                    .animate()
+                    .rotate_about(1.0, pivot)
+                    .scale_about(0.5, pivot),
+                circle
+                    .animate()
+                    .rotate_about(1.0, pivot)
+                    .scale_about(0.5, pivot),
+            ))
+            .run_time(1.0)
+            .unwrap();
+
+        for (grouped, single) in grouped
+            .definition()
+            .tracks()
+            .iter()
+            .zip(single.definition().tracks())
+        {
+            let (
+                TrackValues::Motion { path: grouped, .. },
+                TrackValues::Motion { path: single, .. },
+            ) = (&grouped.values, &single.values)
+            else {
+                panic!("pivot rotations lower to motion tracks");
+            };
+            assert_eq!(grouped, single);
+        }
+    }
+}
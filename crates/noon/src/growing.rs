@@ -0,0 +1,301 @@
+//! Growth animations.
+//!
+//! Each one brings an absent object in from a zero-scale copy collapsed onto
+//! a single point. The growth is lowered as a pivot motion about that point,
+//! so the point stays fixed while the object scales (and spins) out of it.
+
+use noon_core::{GeometryRef, MotionPath, PathCommand, TrackTiming, Vec2, PI};
+
+use crate::{AuthoringError, Mobject, Scene};
+
+/// Grow an absent object out of its own center.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GrowFromCenter(pub Mobject);
+
+impl GrowFromCenter {
+    pub const fn new(object: Mobject) -> Self {
+        Self(object)
+    }
+}
+
+/// Grow an absent object out of an arbitrary point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrowFromPoint {
+    object: Mobject,
+    point: Vec2,
+}
+
+impl GrowFromPoint {
+    pub const fn new(object: Mobject, point: Vec2) -> Self {
+        Self { object, point }
+    }
+}
+
+/// Grow an absent object out of the edge or corner of its bounds named by
+/// `edge`, such as `LEFT` or `UP + RIGHT`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrowFromEdge {
+    object: Mobject,
+    edge: Vec2,
+}
+
+impl GrowFromEdge {
+    pub const fn new(object: Mobject, edge: Vec2) -> Self {
+        Self { object, edge }
+    }
+}
+
+/// Grow an absent line or path out of its start point, so it extends in its
+/// drawing direction. Other geometry grows from its center.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GrowArrow(pub Mobject);
+
+impl GrowArrow {
+    pub const fn new(object: Mobject) -> Self {
+        Self(object)
+    }
+}
+
+/// Grow an absent object out of its center while spinning through `angle`
+/// radians, a quarter turn counterclockwise by default.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpinInFromNothing {
+    object: Mobject,
+    angle: f32,
+}
+
+impl SpinInFromNothing {
+    pub const fn new(object: Mobject) -> Self {
+        Self {
+            object,
+            angle: PI * 0.5,
+        }
+    }
+
+    pub const fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+}
+
+/// Where a growth starts, resolved against the object's authored snapshot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GrowthOrigin {
+    Center,
+    Point(Vec2),
+    Edge(Vec2),
+    Start,
+}
+
+/// Common form every growth animation lowers through.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Growth {
+    object: Mobject,
+    origin: GrowthOrigin,
+    angle: f32,
+}
+
+impl From<GrowFromCenter> for Growth {
+    fn from(value: GrowFromCenter) -> Self {
+        Self {
+            object: value.0,
+            origin: GrowthOrigin::Center,
+            angle: 0.0,
+        }
+    }
+}
+
+impl From<GrowFromPoint> for Growth {
+    fn from(value: GrowFromPoint) -> Self {
+        Self {
+            object: value.object,
+            origin: GrowthOrigin::Point(value.point),
+            angle: 0.0,
+        }
+    }
+}
+
+impl From<GrowFromEdge> for Growth {
+    fn from(value: GrowFromEdge) -> Self {
+        Self {
+            object: value.object,
+            origin: GrowthOrigin::Edge(value.edge),
+            angle: 0.0,
+        }
+    }
+}
+
+impl From<GrowArrow> for Growth {
+    fn from(value: GrowArrow) -> Self {
+        Self {
+            object: value.0,
+            origin: GrowthOrigin::Start,
+            angle: 0.0,
+        }
+    }
+}
+
+impl From<SpinInFromNothing> for Growth {
+    fn from(value: SpinInFromNothing) -> Self {
+        Self {
+            object: value.object,
+            origin: GrowthOrigin::Center,
+            angle: value.angle,
+        }
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_growth(
+        &mut self,
+        growth: impl Into<Growth>,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let Growth {
+            object,
+            origin,
+            angle,
+        } = growth.into();
+        let is_present = self
+            .presence
+            .get(&object.id)
+            .copied()
+            .ok_or(AuthoringError::UnknownObject(object.id))?;
+        if is_present {
+            return Err(AuthoringError::GrowthRequiresAbsent(object.id));
+        }
+        let to = self.snapshot(object)?.clone();
+        let point = match origin {
+            GrowthOrigin::Center => to.center(),
+            GrowthOrigin::Point(point) => point,
+            GrowthOrigin::Edge(edge) => to
+                .world_bounds()
+                .map_or(to.transform.translation, |bounds| {
+                    bounds.critical_point(edge)
+                }),
+            GrowthOrigin::Start => start_point(&to.geometry)
+                .map_or_else(|| to.center(), |start| to.transform.transform_point(start)),
+        };
+        let from = to
+            .clone()
+            .scale_about(0.0, point)
+            .rotate_about(-angle, point);
+
+        self.definition
+            .set_presence_at(object.id, false, true, timing.start_time)?;
+        self.animate_along(
+            object.id,
+            from,
+            to,
+            MotionPath::Pivot { point, angle },
+            timing,
+        )?;
+        self.presence.insert(object.id, true);
+        Ok(())
+    }
+}
+
+fn start_point(geometry: &GeometryRef) -> Option<Vec2> {
+    match geometry {
+        GeometryRef::Line { start, .. } => Some(*start),
+        GeometryRef::VectorPath(path) => path.commands().iter().find_map(|command| match command {
+            PathCommand::MoveTo { to } => Some(*to),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Property, TrackValues, LEFT};
+
+    use crate::{Animation, FadeIn, FadeOut, Line, Square};
+
+    use super::*;
+
+    fn fade_out(scene: &mut Scene, objects: &[Mobject]) {
+        let fades: Vec<Animation> = objects
+            .iter()
+            .map(|&object| FadeOut::new(object).into())
+            .collect();
+        scene.play(fades).run_time(1.0).unwrap();
+    }
+
+    #[test]
+    fn growths_start_collapsed_onto_their_origin_point() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(2.0));
+        let arrow = scene.add(Line::new(Vec2::ZERO, Vec2::new(2.0, 0.0)).shift(Vec2::ONE));
+        let spinner = scene.add(Square::new(1.0).shift(Vec2::new(3.0, 0.0)));
+        fade_out(&mut scene, &[square, arrow, spinner]);
+        let first = scene.definition().tracks().len();
+        scene
+            .play((
+                GrowFromEdge::new(square, LEFT),
+                GrowArrow::new(arrow),
+                SpinInFromNothing::new(spinner),
+            ))
+            .run_time(1.0)
+            .unwrap();
+
+        let tracks = &scene.definition().tracks()[first..];
+        let presence: Vec<_> = tracks
+            .iter()
+            .filter(|track| track.property == Property::Presence)
+            .map(|track| (track.object.get(), track.timing.start_time))
+            .collect();
+        assert_eq!(presence, vec![(0, 1.0), (1, 1.0), (2, 1.0)]);
+        let starts: Vec<_> = tracks
+            .iter()
+            .filter(|track| track.property == Property::Transform)
+            .map(|track| track.values.snapshots().unwrap().0.transform)
+            .collect();
+        assert_eq!(starts[0].translation, Vec2::new(-1.0, 0.0));
+        assert_eq!(starts[0].scale, Vec2::ZERO);
+        assert_eq!(starts[1].translation, Vec2::ONE);
+        assert_eq!(starts[2].rotation, -PI * 0.5);
+        assert!(matches!(
+            tracks.last().unwrap().values,
+            TrackValues::Motion {
+                path: MotionPath::Pivot { point, angle },
+                ..
+            } if point == Vec2::new(3.0, 0.0) && angle == PI * 0.5
+        ));
+        assert_eq!(scene.snapshot(square).unwrap().transform.scale, Vec2::ONE);
+    }
+
+    #[test]
+    fn growth_requires_an_absent_object_like_fade_in() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(1.0));
+        // Freshly added objects are present, for growth as for FadeIn.
+        assert_eq!(
+            scene.play(GrowFromCenter::new(square)).run_time(1.0),
+            Err(AuthoringError::GrowthRequiresAbsent(square.id()))
+        );
+        assert_eq!(
+            scene.play(FadeIn::new(square)).run_time(1.0),
+            Err(AuthoringError::FadeInRequiresAbsent(square.id()))
+        );
+        assert!(scene.definition().tracks().is_empty());
+
+        fade_out(&mut scene, &[square]);
+        scene
+            .play(GrowFromCenter::new(square))
+            .run_time(1.0)
+            .unwrap();
+        assert_eq!(
+            scene
+                .play(GrowFromPoint::new(square, Vec2::ONE))
+                .run_time(1.0),
+            Err(AuthoringError::GrowthRequiresAbsent(square.id()))
+        );
+
+        scene.play(FadeOut::new(square)).run_time(1.0).unwrap();
+        scene
+            .play(GrowFromPoint::new(square, Vec2::ONE))
+            .run_time(1.0)
+            .unwrap();
+    }
+}
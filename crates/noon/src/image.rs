@@ -0,0 +1,172 @@
+use std::io::Cursor;
+
+use noon_core::{
+    Color, GeometryRef, ImageData, ImageError, ImageId, ImageSampling, ObjectSnapshot, Vec2,
+};
+
+use crate::{IntoSnapshot, Scene};
+
+/// Height in world units given to images added without an explicit size.
+pub const DEFAULT_IMAGE_HEIGHT: f32 = 2.0;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImageDecodeError {
+    Png(String),
+    Image(ImageError),
+}
+
+impl std::fmt::Display for ImageDecodeError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Png(message) => write!(formatter, "invalid PNG data: {message}"),
+            Self::Image(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl std::error::Error for ImageDecodeError {}
+
+impl From<ImageError> for ImageDecodeError {
+    fn from(value: ImageError) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl From<png::DecodingError> for ImageDecodeError {
+    fn from(value: png::DecodingError) -> Self {
+        Self::Png(value.to_string())
+    }
+}
+
+/// Decodes PNG bytes into straight-alpha RGBA8 pixels.
+///
+/// Palette, grayscale and 16-bit inputs are normalized so every registered
+/// image shares one texture format regardless of its source encoding.
+pub fn decode_png(bytes: &[u8]) -> Result<ImageData, ImageDecodeError> {
+    let mut decoder = png::Decoder::new(Cursor::new(bytes));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+    let buffer_size = reader
+        .output_buffer_size()
+        .ok_or_else(|| ImageDecodeError::Png("image is too large to decode".to_owned()))?;
+    let mut buffer = vec![0; buffer_size];
+    let info = reader.next_frame(&mut buffer)?;
+    buffer.truncate(info.buffer_size());
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], u8::MAX])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+            .collect(),
+        png::ColorType::Grayscale => buffer
+            .iter()
+            .flat_map(|&gray| [gray, gray, gray, u8::MAX])
+            .collect(),
+        png::ColorType::Indexed => {
+            unreachable!("EXPAND transformation must resolve palette indices")
+        }
+    };
+    Ok(ImageData::new(info.width, info.height, pixels)?)
+}
+
+define_shape!(Image);
+
+impl Image {
+    /// A quad of `size` world units showing a registered image.
+    pub fn new(image: ImageId, size: Vec2) -> Self {
+        Self(ObjectSnapshot::new(GeometryRef::image(image, size)))
+    }
+
+    pub fn sampling(mut self, sampling: ImageSampling) -> Self {
+        if let GeometryRef::Image {
+            sampling: current, ..
+        } = &mut self.0.geometry
+        {
+            *current = sampling;
+        }
+        self
+    }
+}
+
+impl Scene {
+    /// Registers decoded pixels so image objects can reference them by id.
+    pub fn register_image(&mut self, data: ImageData) -> ImageId {
+        self.definition.register_image(data)
+    }
+
+    /// Registers pixels and returns an aspect-preserving image
+    /// [`DEFAULT_IMAGE_HEIGHT`] units tall, ready to be added to the scene.
+    pub fn image(&mut self, data: ImageData) -> Image {
+        let size = Vec2::new(
+            DEFAULT_IMAGE_HEIGHT * data.aspect_ratio(),
+            DEFAULT_IMAGE_HEIGHT,
+        );
+        Image::new(self.register_image(data), size)
+    }
+
+    /// Decodes and registers PNG bytes; see [`Scene::image`].
+    pub fn load_png(&mut self, bytes: &[u8]) -> Result<Image, ImageDecodeError> {
+        Ok(self.image(decode_png(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_png(width: u32, height: u32, color: png::ColorType, data: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(color);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().expect("valid header");
+        writer.write_image_data(data).expect("valid image data");
+        writer.finish().expect("png must finish");
+        bytes
+    }
+
+    #[test]
+    fn png_color_types_normalize_to_rgba8() {
+        let rgb = encode_png(2, 1, png::ColorType::Rgb, &[1, 2, 3, 4, 5, 6]);
+        let decoded = decode_png(&rgb).expect("rgb png must decode");
+        assert_eq!(decoded.pixels(), &[1, 2, 3, 255, 4, 5, 6, 255]);
+
+        let gray_alpha = encode_png(1, 2, png::ColorType::GrayscaleAlpha, &[7, 8, 9, 10]);
+        let decoded = decode_png(&gray_alpha).expect("gray-alpha png must decode");
+        assert_eq!((decoded.width(), decoded.height()), (1, 2));
+        assert_eq!(decoded.pixels(), &[7, 7, 7, 8, 9, 9, 9, 10]);
+
+        assert!(matches!(
+            decode_png(b"not a png"),
+            Err(ImageDecodeError::Png(_))
+        ));
+    }
+
+    #[test]
+    fn loaded_images_preserve_aspect_ratio_and_sampling() {
+        let mut scene = Scene::new();
+        let bytes = encode_png(4, 2, png::ColorType::Rgba, &[255; 32]);
+        let image = scene
+            .load_png(&bytes)
+            .expect("png must load")
+            .sampling(ImageSampling::Nearest)
+            .shift(Vec2::new(1.0, 0.0));
+        let object = scene.add(image);
+
+        let snapshot = scene.snapshot(object).expect("image object exists");
+        assert_eq!(
+            snapshot.geometry,
+            GeometryRef::Image {
+                image: ImageId::new(0),
+                size: Vec2::new(4.0, 2.0),
+                sampling: ImageSampling::Nearest,
+            }
+        );
+        assert_eq!(scene.definition().images().len(), 1);
+    }
+}
@@ -7,25 +7,54 @@
 
 #![forbid(unsafe_code)]
 
+mod composition;
+mod creation;
+mod custom;
+mod emphasis;
+mod expression;
+mod function;
+mod graph;
+mod group;
+mod growing;
+mod lifecycle;
+mod motion;
+
 use std::collections::BTreeMap;
 
+pub use composition::*;
+pub use creation::*;
+pub use custom::*;
+pub use emphasis::*;
+pub use expression::*;
+pub use function::*;
+pub use graph::*;
+pub use group::*;
+pub use growing::*;
+pub use motion::*;
+
 pub use noon_core;
 pub use noon_core::*;
 
 /// Common imports for normal Noon authoring.
 pub mod prelude {
     pub use crate::{
-        Animate, AuthoringError, Circle, FadeIn, FadeOut, Line, Mobject, MobjectEditor, Path,
-        Rectangle, Scene, Square, Transform,
+        Animate, AnimationGroup, AuthoringError, ChangeDecimalToValue, ChangeValue, Circle,
+        Circumscribe, Create, DecimalNumber, DrawBorderThenFill, FadeIn, FadeOut, Flash, FocusOn,
+        Follow, FunctionGraph, Graph, GraphLayout, Group, GrowArrow, GrowFromCenter, GrowFromEdge,
+        GrowFromPoint, Image, Indicate, LaggedStart, LaggedStartMap, Line, MathTex, Mobject,
+        MobjectEditor, MoveAlongPath, ParametricFunction, Path, Rectangle, ReplacementTransform,
+        Rotating, Scene, SpinInFromNothing, Square, Succession, Text, Transform, TransformFromCopy,
+        TransformMatchingShapes, Uncreate, VGroup, ValueTracker, Wiggle, Write,
     };
     pub use noon_core::{
-        Color, Easing, GeometryRef, ObjectId, ObjectSnapshot, Style, Vec2, VectorPath, BLACK, BLUE,
-        BLUE_A, BLUE_B, BLUE_C, BLUE_D, BLUE_E, DEFAULT_MOBJECT_TO_EDGE_BUFFER,
-        DEFAULT_MOBJECT_TO_MOBJECT_BUFFER, DEGREES, DL, DOWN, DR, GOLD, GRAY, GREEN, GREY,
-        LARGE_BUFF, LEFT, LIGHT_PINK, MAROON, MED_LARGE_BUFF, MED_SMALL_BUFF, ORANGE, ORIGIN, PI,
-        PINK, PURPLE, PURPLE_A, PURPLE_B, PURPLE_C, PURPLE_D, PURPLE_E, RED, RED_A, RED_B, RED_C,
-        RED_D, RED_E, RIGHT, SMALL_BUFF, TAU, TEAL, TEAL_A, TEAL_B, TEAL_C, TEAL_D, TEAL_E, UL, UP,
-        UR, WHITE, YELLOW, YELLOW_A, YELLOW_B, YELLOW_C, YELLOW_D, YELLOW_E,
+        Color, Easing, GeometryRef, Labels, NumberFormat, ObjectId, ObjectSnapshot, Style, Vec2,
+        VectorPath, BLACK, BLUE, BLUE_A, BLUE_B, BLUE_C, BLUE_D, BLUE_E,
+        DEFAULT_MOBJECT_TO_EDGE_BUFFER, DEFAULT_MOBJECT_TO_MOBJECT_BUFFER, DEGREES, DL, DOWN, DR,
+        GOLD, GRAY, GREEN, GREY, LARGE_BUFF, LEFT, LIGHT_PINK, MAROON, MED_LARGE_BUFF,
+        MED_SMALL_BUFF, ORANGE, ORIGIN, PI, PINK, PURPLE, PURPLE_A, PURPLE_B, PURPLE_C, PURPLE_D,
+        PURPLE_E, RED, RED_A, RED_B, RED_C, RED_D, RED_E, RIGHT, SMALL_BUFF, TAU, TEAL, TEAL_A,
+        TEAL_B, TEAL_C, TEAL_D, TEAL_E, UL, UP, UR, WHITE, YELLOW, YELLOW_A, YELLOW_B, YELLOW_C,
+        YELLOW_D, YELLOW_E,
     };
 }
 
@@ -110,6 +139,17 @@ define_shape!(Square);
 define_shape!(Line);
 define_shape!(Path);
 
+// Declared after `define_shape!` so these modules can use the macro.
+mod image;
+mod math;
+mod number;
+mod text;
+
+pub use image::*;
+pub use math::*;
+pub use number::*;
+pub use text::*;
+
 impl Circle {
     pub fn new(radius: f32) -> Self {
         Self(ObjectSnapshot::new(GeometryRef::circle(radius)))
@@ -177,6 +217,7 @@ impl Mobject {
         Animate {
             object: self,
             operations: Vec::new(),
+            path_arc: 0.0,
         }
     }
 }
@@ -188,6 +229,8 @@ enum Operation {
     Scale(f32),
     ScaleXY(Vec2),
     Rotate(f32),
+    ScaleAbout(f32, Vec2),
+    RotateAbout(f32, Vec2),
     SetColor(Color),
     SetFill(Option<Color>, Option<f32>),
     SetStroke(Option<Color>, Option<f32>),
@@ -199,9 +242,17 @@ enum Operation {
 pub struct Animate {
     object: Mobject,
     operations: Vec<Operation>,
+    path_arc: f32,
 }
 
 impl Animate {
+    /// Move along a circular arc sweeping `angle` radians counterclockwise
+    /// instead of a straight line.
+    pub fn path_arc(mut self, angle: f32) -> Self {
+        self.path_arc = angle;
+        self
+    }
+
     pub fn shift(mut self, offset: Vec2) -> Self {
         self.operations.push(Operation::Shift(offset));
         self
@@ -227,6 +278,20 @@ impl Animate {
         self
     }
 
+    /// Scale about `point` instead of the object's origin.
+    pub fn scale_about(mut self, factor: f32, point: Vec2) -> Self {
+        self.operations.push(Operation::ScaleAbout(factor, point));
+        self
+    }
+
+    /// Rotate about `point`. Unless [`Animate::path_arc`] is set, the object
+    /// swings around the pivot during the animation rather than cutting
+    /// straight to its end position.
+    pub fn rotate_about(mut self, angle: f32, point: Vec2) -> Self {
+        self.operations.push(Operation::RotateAbout(angle, point));
+        self
+    }
+
     pub fn set_color(mut self, color: Color) -> Self {
         self.operations.push(Operation::SetColor(color));
         self
@@ -253,6 +318,7 @@ impl Animate {
 pub struct Transform {
     source: Mobject,
     target: ObjectSnapshot,
+    path_arc: f32,
 }
 
 impl Transform {
@@ -260,6 +326,67 @@ impl Transform {
         Self {
             source,
             target: target.into_snapshot(),
+            path_arc: 0.0,
+        }
+    }
+
+    /// Move along a circular arc sweeping `angle` radians counterclockwise
+    /// instead of a straight line.
+    pub fn path_arc(mut self, angle: f32) -> Self {
+        self.path_arc = angle;
+        self
+    }
+}
+
+/// Transform `source` into another scene object, then hand presence over:
+/// `source` disappears and `target` appears when the transform ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplacementTransform {
+    source: Mobject,
+    target: Mobject,
+}
+
+impl ReplacementTransform {
+    pub const fn new(source: Mobject, target: Mobject) -> Self {
+        Self { source, target }
+    }
+}
+
+/// Transform a transient copy of `source` into `target` while `source` stays.
+///
+/// The copy is a new scene object present only for the duration of the
+/// animation; `target` appears as the copy disappears.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransformFromCopy {
+    source: Mobject,
+    target: Mobject,
+}
+
+impl TransformFromCopy {
+    pub const fn new(source: Mobject, target: Mobject) -> Self {
+        Self { source, target }
+    }
+}
+
+/// Pair sources with targets of the same shape, then replace each pair.
+///
+/// Circles match circles, lines match lines, rectangles match rectangles of
+/// the same aspect ratio and paths match identical paths. Each source takes
+/// the first unclaimed matching target, so list order breaks ties.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransformMatchingShapes {
+    sources: Vec<Mobject>,
+    targets: Vec<Mobject>,
+}
+
+impl TransformMatchingShapes {
+    pub fn new(
+        sources: impl IntoIterator<Item = Mobject>,
+        targets: impl IntoIterator<Item = Mobject>,
+    ) -> Self {
+        Self {
+            sources: sources.into_iter().collect(),
+            targets: targets.into_iter().collect(),
         }
     }
 }
@@ -286,8 +413,32 @@ impl FadeIn {
 pub enum Animation {
     Animate(Animate),
     Transform(Transform),
+    ReplacementTransform(ReplacementTransform),
+    TransformFromCopy(TransformFromCopy),
+    TransformMatchingShapes(TransformMatchingShapes),
     FadeOut(FadeOut),
     FadeIn(FadeIn),
+    Create(Create),
+    Uncreate(Uncreate),
+    DrawBorderThenFill(DrawBorderThenFill),
+    Write(Write),
+    GroupAnimate(GroupAnimate),
+    Group(AnimationGroup),
+    MoveAlongPath(MoveAlongPath),
+    Rotating(Rotating),
+    GrowFromCenter(GrowFromCenter),
+    GrowFromPoint(GrowFromPoint),
+    GrowFromEdge(GrowFromEdge),
+    GrowArrow(GrowArrow),
+    SpinInFromNothing(SpinInFromNothing),
+    ChangeDecimalToValue(ChangeDecimalToValue),
+    ChangeValue(ChangeValue),
+    Custom(Custom),
+    Indicate(Indicate),
+    Flash(Flash),
+    Circumscribe(Circumscribe),
+    Wiggle(Wiggle),
+    FocusOn(FocusOn),
 }
 
 impl From<Animate> for Animation {
@@ -302,6 +453,24 @@ impl From<Transform> for Animation {
     }
 }
 
+impl From<ReplacementTransform> for Animation {
+    fn from(value: ReplacementTransform) -> Self {
+        Self::ReplacementTransform(value)
+    }
+}
+
+impl From<TransformFromCopy> for Animation {
+    fn from(value: TransformFromCopy) -> Self {
+        Self::TransformFromCopy(value)
+    }
+}
+
+impl From<TransformMatchingShapes> for Animation {
+    fn from(value: TransformMatchingShapes) -> Self {
+        Self::TransformMatchingShapes(value)
+    }
+}
+
 impl From<FadeOut> for Animation {
     fn from(value: FadeOut) -> Self {
         Self::FadeOut(value)
@@ -314,6 +483,144 @@ impl From<FadeIn> for Animation {
     }
 }
 
+impl From<Create> for Animation {
+    fn from(value: Create) -> Self {
+        Self::Create(value)
+    }
+}
+
+impl From<Uncreate> for Animation {
+    fn from(value: Uncreate) -> Self {
+        Self::Uncreate(value)
+    }
+}
+
+impl From<DrawBorderThenFill> for Animation {
+    fn from(value: DrawBorderThenFill) -> Self {
+        Self::DrawBorderThenFill(value)
+    }
+}
+
+impl From<Write> for Animation {
+    fn from(value: Write) -> Self {
+        Self::Write(value)
+    }
+}
+
+impl From<GroupAnimate> for Animation {
+    fn from(value: GroupAnimate) -> Self {
+        Self::GroupAnimate(value)
+    }
+}
+
+impl From<AnimationGroup> for Animation {
+    fn from(value: AnimationGroup) -> Self {
+        Self::Group(value)
+    }
+}
+
+impl From<Indicate> for Animation {
+    fn from(value: Indicate) -> Self {
+        Self::Indicate(value)
+    }
+}
+
+impl From<Flash> for Animation {
+    fn from(value: Flash) -> Self {
+        Self::Flash(value)
+    }
+}
+
+impl From<Circumscribe> for Animation {
+    fn from(value: Circumscribe) -> Self {
+        Self::Circumscribe(value)
+    }
+}
+
+impl From<Wiggle> for Animation {
+    fn from(value: Wiggle) -> Self {
+        Self::Wiggle(value)
+    }
+}
+
+impl From<FocusOn> for Animation {
+    fn from(value: FocusOn) -> Self {
+        Self::FocusOn(value)
+    }
+}
+
+impl From<MoveAlongPath> for Animation {
+    fn from(value: MoveAlongPath) -> Self {
+        Self::MoveAlongPath(value)
+    }
+}
+
+impl From<Rotating> for Animation {
+    fn from(value: Rotating) -> Self {
+        Self::Rotating(value)
+    }
+}
+
+impl From<GrowFromCenter> for Animation {
+    fn from(value: GrowFromCenter) -> Self {
+        Self::GrowFromCenter(value)
+    }
+}
+
+impl From<GrowFromPoint> for Animation {
+    fn from(value: GrowFromPoint) -> Self {
+        Self::GrowFromPoint(value)
+    }
+}
+
+impl From<GrowFromEdge> for Animation {
+    fn from(value: GrowFromEdge) -> Self {
+        Self::GrowFromEdge(value)
+    }
+}
+
+impl From<GrowArrow> for Animation {
+    fn from(value: GrowArrow) -> Self {
+        Self::GrowArrow(value)
+    }
+}
+
+impl From<SpinInFromNothing> for Animation {
+    fn from(value: SpinInFromNothing) -> Self {
+        Self::SpinInFromNothing(value)
+    }
+}
+
+impl From<ChangeDecimalToValue> for Animation {
+    fn from(value: ChangeDecimalToValue) -> Self {
+        Self::ChangeDecimalToValue(value)
+    }
+}
+
+impl From<ChangeValue> for Animation {
+    fn from(value: ChangeValue) -> Self {
+        Self::ChangeValue(value)
+    }
+}
+
+impl From<LaggedStart> for Animation {
+    fn from(value: LaggedStart) -> Self {
+        Self::Group(value.into())
+    }
+}
+
+impl From<LaggedStartMap> for Animation {
+    fn from(value: LaggedStartMap) -> Self {
+        Self::Group(value.into())
+    }
+}
+
+impl From<Succession> for Animation {
+    fn from(value: Succession) -> Self {
+        Self::Group(value.into())
+    }
+}
+
 pub trait IntoAnimations {
     fn into_animations(self) -> Vec<Animation>;
 }
@@ -327,6 +634,12 @@ where
     }
 }
 
+impl IntoAnimations for Vec<Animation> {
+    fn into_animations(self) -> Vec<Animation> {
+        self
+    }
+}
+
 macro_rules! tuple_animations {
     ($($name:ident),+ $(,)?) => {
         impl<$($name),+> IntoAnimations for ($($name,)+)
@@ -353,6 +666,28 @@ pub enum AuthoringError {
     StaticMutationAfterAnimation(ObjectId),
     FadeInRequiresAbsent(ObjectId),
     FadeOutRequiresPresent(ObjectId),
+    LifecycleSourceRequiresPresent(ObjectId),
+    LifecycleTargetRequiresAbsent(ObjectId),
+    LifecycleSelfTarget(ObjectId),
+    EmptyShapeMatching,
+    DuplicateMatchingObject(ObjectId),
+    UnsupportedMatchingGeometry(ObjectId),
+    UnmatchedShape(ObjectId),
+    CreateRequiresAbsent(ObjectId),
+    GrowthRequiresAbsent(ObjectId),
+    UncreateRequiresPresent(ObjectId),
+    EmptyWrite,
+    InvalidGridShape {
+        rows: usize,
+        cols: usize,
+    },
+    InvalidLagRatio(f64),
+    InvalidMotionPath,
+    MixedPivotPoints,
+    NotANumber(ObjectId),
+    UnknownValueTracker,
+    /// A play moved a graph vertex without keeping this edge attached to it.
+    DetachedGraphEdge(ObjectId),
     Timeline(TimelineError),
 }
 
@@ -372,6 +707,76 @@ impl std::fmt::Display for AuthoringError {
             Self::FadeOutRequiresPresent(id) => {
                 write!(formatter, "FadeOut requires present object {}", id.get())
             }
+            Self::LifecycleSourceRequiresPresent(id) => write!(
+                formatter,
+                "lifecycle source {} must be present at animation start",
+                id.get()
+            ),
+            Self::LifecycleTargetRequiresAbsent(id) => write!(
+                formatter,
+                "lifecycle target {} must be absent before handoff",
+                id.get()
+            ),
+            Self::LifecycleSelfTarget(id) => write!(
+                formatter,
+                "lifecycle source and target must be different objects, got {} twice",
+                id.get()
+            ),
+            Self::EmptyShapeMatching => {
+                formatter.write_str("shape matching requires non-empty sources and targets")
+            }
+            Self::DuplicateMatchingObject(id) => write!(
+                formatter,
+                "object {} appears more than once in shape matching",
+                id.get()
+            ),
+            Self::UnsupportedMatchingGeometry(id) => write!(
+                formatter,
+                "shape matching does not support the geometry of object {}",
+                id.get()
+            ),
+            Self::UnmatchedShape(id) => {
+                write!(formatter, "unmatched shape for object {}", id.get())
+            }
+            Self::CreateRequiresAbsent(id) => write!(
+                formatter,
+                "object {} must be absent before it is created",
+                id.get()
+            ),
+            Self::GrowthRequiresAbsent(id) => write!(
+                formatter,
+                "object {} must be absent before it grows in",
+                id.get()
+            ),
+            Self::UncreateRequiresPresent(id) => write!(
+                formatter,
+                "object {} must be present before it is uncreated",
+                id.get()
+            ),
+            Self::EmptyWrite => formatter.write_str("Write requires at least one object"),
+            Self::InvalidGridShape { rows, cols } => write!(
+                formatter,
+                "grid layout requires positive rows and columns, got {rows}x{cols}"
+            ),
+            Self::InvalidLagRatio(value) => write!(formatter, "invalid lag ratio {value}"),
+            Self::InvalidMotionPath => {
+                formatter.write_str("MoveAlongPath requires a non-empty, well-formed path")
+            }
+            Self::MixedPivotPoints => {
+                formatter.write_str("one animation can only rotate or scale about one pivot")
+            }
+            Self::NotANumber(id) => {
+                write!(formatter, "object {} is not a DecimalNumber", id.get())
+            }
+            Self::UnknownValueTracker => {
+                formatter.write_str("value tracker was not created by this scene")
+            }
+            Self::DetachedGraphEdge(id) => write!(
+                formatter,
+                "graph edge {} would detach from its vertices; move vertices with \
+                 GraphMobject::move_vertices",
+                id.get()
+            ),
             Self::Timeline(error) => error.fmt(formatter),
         }
     }
@@ -392,6 +797,8 @@ pub struct Scene {
     cursor: f64,
     authored: BTreeMap<ObjectId, ObjectSnapshot>,
     presence: BTreeMap<ObjectId, bool>,
+    trackers: Vec<TrackerState>,
+    graph_edges: Vec<GraphEdgeBinding>,
 }
 
 impl Scene {
@@ -425,6 +832,44 @@ impl Scene {
             .ok_or(AuthoringError::UnknownObject(object.id))
     }
 
+    pub fn labels(&self, object: Mobject) -> Result<&Labels, AuthoringError> {
+        self.definition
+            .object(object.id)
+            .map(|definition| &definition.labels)
+            .ok_or(AuthoringError::UnknownObject(object.id))
+    }
+
+    /// Replace an object's authoring labels. Labels never affect evaluation,
+    /// so unlike [`Scene::edit`] this stays allowed after animation.
+    pub fn label(&mut self, object: Mobject, labels: Labels) -> Result<&mut Self, AuthoringError> {
+        self.definition
+            .object_mut(object.id)
+            .ok_or(AuthoringError::UnknownObject(object.id))?
+            .labels = labels;
+        Ok(self)
+    }
+
+    pub fn name(
+        &mut self,
+        object: Mobject,
+        name: impl Into<String>,
+    ) -> Result<&mut Self, AuthoringError> {
+        let labels = Labels {
+            name: Some(name.into()),
+            ..self.labels(object)?.clone()
+        };
+        self.label(object, labels)
+    }
+
+    pub fn tag(
+        &mut self,
+        object: Mobject,
+        tag: impl Into<String>,
+    ) -> Result<&mut Self, AuthoringError> {
+        let labels = self.labels(object)?.clone().with_tag(tag);
+        self.label(object, labels)
+    }
+
     /// Edit a static object's semantic snapshot before it is animated.
     pub fn edit(&mut self, object: Mobject) -> Result<MobjectEditor<'_>, AuthoringError> {
         if self
@@ -481,74 +926,165 @@ impl Scene {
         if !duration.is_finite() || duration <= 0.0 {
             return Err(AuthoringError::InvalidDuration(duration));
         }
-        let start = self.cursor;
-        let end = start + duration;
-        let timing = TrackTiming::new(start, duration, easing);
-
+        let timing = TrackTiming::new(self.cursor, duration, easing);
+        // Only scenes with graphs pay for a checkpoint to roll back to.
+        let checkpoint = (!self.graph_edges.is_empty()).then(|| self.clone());
+        let first_track = self.definition.tracks().len();
         for animation in animations {
-            match animation {
-                Animation::Animate(animation) => {
-                    let from = self.snapshot(animation.object)?.clone();
-                    let mut to = from.clone();
-                    for operation in animation.operations {
-                        to = apply_operation(to, operation);
-                    }
-                    self.definition.animate_transform(
-                        animation.object.id,
-                        from,
-                        to.clone(),
-                        timing,
-                    )?;
-                    self.authored.insert(animation.object.id, to);
+            self.schedule_animation(animation, timing)?;
+        }
+        if let Err(error) = self.check_graph_edges(first_track, timing) {
+            if let Some(checkpoint) = checkpoint {
+                *self = checkpoint;
+            }
+            return Err(error);
+        }
+        self.cursor = timing.start_time + duration;
+        Ok(())
+    }
+
+    pub(crate) fn schedule_animation(
+        &mut self,
+        animation: Animation,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let start = timing.start_time;
+        let end = start + timing.duration;
+        match animation {
+            Animation::Animate(animation) => {
+                let from = self.snapshot(animation.object)?.clone();
+                let path = motion_path(&animation.operations, animation.path_arc)?;
+                let mut to = from.clone();
+                for operation in animation.operations {
+                    to = apply_operation(to, operation);
                 }
-                Animation::Transform(animation) => {
-                    let from = self.snapshot(animation.source)?.clone();
-                    self.definition.animate_transform(
-                        animation.source.id,
-                        from,
-                        animation.target.clone(),
-                        timing,
-                    )?;
-                    self.authored.insert(animation.source.id, animation.target);
+                self.animate_along(animation.object.id, from, to.clone(), path, timing)?;
+                self.authored.insert(animation.object.id, to);
+            }
+            Animation::Transform(animation) => {
+                let from = self.snapshot(animation.source)?.clone();
+                self.animate_along(
+                    animation.source.id,
+                    from,
+                    animation.target.clone(),
+                    MotionPath::Arc {
+                        angle: animation.path_arc,
+                    },
+                    timing,
+                )?;
+                self.authored.insert(animation.source.id, animation.target);
+            }
+            Animation::ReplacementTransform(animation) => {
+                self.schedule_replacement(animation.source, animation.target, timing)?;
+            }
+            Animation::TransformFromCopy(animation) => {
+                self.schedule_transform_from_copy(animation.source, animation.target, timing)?;
+            }
+            Animation::TransformMatchingShapes(animation) => {
+                for (source, target) in self.match_shapes(&animation.sources, &animation.targets)? {
+                    self.schedule_replacement(source, target, timing)?;
                 }
-                Animation::FadeOut(FadeOut(object)) => {
-                    let is_present = self
-                        .presence
-                        .get(&object.id)
-                        .copied()
-                        .ok_or(AuthoringError::UnknownObject(object.id))?;
-                    if !is_present {
-                        return Err(AuthoringError::FadeOutRequiresPresent(object.id));
-                    }
-                    self.definition
-                        .animate_appearance(object.id, 1.0, 0.0, timing)?;
-                    self.definition
-                        .set_presence_at(object.id, true, false, end)?;
-                    self.presence.insert(object.id, false);
+            }
+            Animation::FadeOut(FadeOut(object)) => {
+                let is_present = self
+                    .presence
+                    .get(&object.id)
+                    .copied()
+                    .ok_or(AuthoringError::UnknownObject(object.id))?;
+                if !is_present {
+                    return Err(AuthoringError::FadeOutRequiresPresent(object.id));
                 }
-                Animation::FadeIn(FadeIn(object)) => {
-                    let is_present = self
-                        .presence
-                        .get(&object.id)
-                        .copied()
-                        .ok_or(AuthoringError::UnknownObject(object.id))?;
-                    if is_present {
-                        return Err(AuthoringError::FadeInRequiresAbsent(object.id));
-                    }
-                    self.definition
-                        .set_presence_at(object.id, false, true, start)?;
-                    self.definition
-                        .animate_appearance(object.id, 0.0, 1.0, timing)?;
-                    self.presence.insert(object.id, true);
+                self.definition
+                    .animate_appearance(object.id, 1.0, 0.0, timing)?;
+                self.definition
+                    .set_presence_at(object.id, true, false, end)?;
+                self.presence.insert(object.id, false);
+            }
+            Animation::FadeIn(FadeIn(object)) => {
+                let is_present = self
+                    .presence
+                    .get(&object.id)
+                    .copied()
+                    .ok_or(AuthoringError::UnknownObject(object.id))?;
+                if is_present {
+                    return Err(AuthoringError::FadeInRequiresAbsent(object.id));
                 }
+                self.definition
+                    .set_presence_at(object.id, false, true, start)?;
+                self.definition
+                    .animate_appearance(object.id, 0.0, 1.0, timing)?;
+                self.presence.insert(object.id, true);
+            }
+            Animation::Create(Create(object)) => self.schedule_create(object, timing)?,
+            Animation::Uncreate(Uncreate(object)) => self.schedule_uncreate(object, timing)?,
+            Animation::DrawBorderThenFill(DrawBorderThenFill(object)) => {
+                self.schedule_draw_border_then_fill(object, timing)?;
+            }
+            Animation::Write(animation) => self.schedule_write(&animation, timing)?,
+            Animation::GroupAnimate(animation) => {
+                self.schedule_group_animate(animation, timing)?;
             }
+            Animation::Group(group) => self.schedule_animation_group(group, timing)?,
+            Animation::MoveAlongPath(animation) => {
+                self.schedule_move_along_path(animation, timing)?;
+            }
+            Animation::Rotating(animation) => self.schedule_rotating(animation, timing)?,
+            Animation::GrowFromCenter(animation) => self.schedule_growth(animation, timing)?,
+            Animation::GrowFromPoint(animation) => self.schedule_growth(animation, timing)?,
+            Animation::GrowFromEdge(animation) => self.schedule_growth(animation, timing)?,
+            Animation::GrowArrow(animation) => self.schedule_growth(animation, timing)?,
+            Animation::SpinInFromNothing(animation) => self.schedule_growth(animation, timing)?,
+            Animation::ChangeDecimalToValue(animation) => {
+                self.schedule_change_decimal(animation, timing)?;
+            }
+            Animation::ChangeValue(animation) => self.schedule_change_value(animation, timing)?,
+            Animation::Custom(animation) => self.schedule_custom(animation, timing)?,
+            Animation::Indicate(animation) => self.schedule_indicate(animation, timing)?,
+            Animation::Flash(animation) => self.schedule_flash(animation, timing)?,
+            Animation::Circumscribe(animation) => self.schedule_circumscribe(animation, timing)?,
+            Animation::Wiggle(animation) => self.schedule_wiggle(animation, timing)?,
+            Animation::FocusOn(animation) => self.schedule_focus_on(animation, timing)?,
         }
-
-        self.cursor = end;
         Ok(())
     }
 }
 
+/// An explicit arc wins; otherwise rotations about a pivot swing around it.
+fn motion_path(operations: &[Operation], path_arc: f32) -> Result<MotionPath, AuthoringError> {
+    if path_arc != 0.0 {
+        return Ok(MotionPath::Arc { angle: path_arc });
+    }
+    pivot_path(operations.iter().filter_map(|operation| match *operation {
+        Operation::ScaleAbout(_, point) => Some((point, 0.0)),
+        Operation::RotateAbout(turn, point) => Some((point, turn)),
+        _ => None,
+    }))
+}
+
+/// Pivot points closer than this count as one, since group centers are
+/// recomputed from bounds after every operation.
+const PIVOT_TOLERANCE: f32 = 1.0e-4;
+
+/// Folds the `(point, angle)` of every scale and rotation in one animation
+/// into a single swing about their shared point.
+pub(crate) fn pivot_path(
+    pivots: impl IntoIterator<Item = (Vec2, f32)>,
+) -> Result<MotionPath, AuthoringError> {
+    let mut pivot: Option<Vec2> = None;
+    let mut angle = 0.0;
+    for (point, turn) in pivots {
+        if pivot.is_some_and(|pivot| (pivot - point).length() > PIVOT_TOLERANCE) {
+            return Err(AuthoringError::MixedPivotPoints);
+        }
+        pivot.get_or_insert(point);
+        angle += turn;
+    }
+    Ok(match pivot {
+        Some(point) => MotionPath::Pivot { point, angle },
+        None => MotionPath::Arc { angle: 0.0 },
+    })
+}
+
 fn apply_operation(snapshot: ObjectSnapshot, operation: Operation) -> ObjectSnapshot {
     match operation {
         Operation::Shift(value) => snapshot.shift(value),
@@ -556,6 +1092,8 @@ fn apply_operation(snapshot: ObjectSnapshot, operation: Operation) -> ObjectSnap
         Operation::Scale(value) => snapshot.scale_by(value),
         Operation::ScaleXY(value) => snapshot.scale_xy(value),
         Operation::Rotate(value) => snapshot.rotate_by(value),
+        Operation::ScaleAbout(factor, point) => snapshot.scale_about(factor, point),
+        Operation::RotateAbout(angle, point) => snapshot.rotate_about(angle, point),
         Operation::SetColor(value) => snapshot.set_color(value),
         Operation::SetFill(color, opacity) => snapshot.set_fill(color, opacity),
         Operation::SetStroke(color, width) => snapshot.set_stroke(color, width),
@@ -654,6 +1192,52 @@ impl Play<'_> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn labels_are_authored_through_the_scene_even_after_animation() {
+        let mut scene = Scene::new();
+        let sun = scene.add(Circle::new(1.0));
+        let ground = scene.add(Square::new(1.0));
+        scene.play(sun.animate().shift(UP)).run_time(1.0).unwrap();
+        scene
+            .name(sun, "sun")
+            .unwrap()
+            .tag(sun, "sky")
+            .unwrap()
+            .tag(sun, "sky")
+            .unwrap();
+        scene
+            .label(
+                ground,
+                Labels::named("ground").with_metadata("layer", "back"),
+            )
+            .unwrap();
+
+        let definition = scene.definition();
+        assert_eq!(
+            definition.find_by_name("sun").map(|object| object.id),
+            Some(sun.id())
+        );
+        assert_eq!(scene.labels(sun).unwrap().tags, vec!["sky".to_owned()]);
+        assert_eq!(
+            definition
+                .objects_tagged("sky")
+                .map(|object| object.id)
+                .collect::<Vec<_>>(),
+            vec![sun.id()]
+        );
+        assert_eq!(
+            scene
+                .labels(ground)
+                .unwrap()
+                .metadata
+                .get("layer")
+                .map(String::as_str),
+            Some("back")
+        );
+        let foreign = Scene::new().add(Circle::new(1.0));
+        assert!(Scene::new().name(foreign, "moon").is_err());
+    }
+
     #[test]
     fn facade_uses_core_named_vocabulary_without_new_scene_model() {
         let mut scene = Scene::new();
@@ -0,0 +1,321 @@
+use std::collections::BTreeSet;
+
+use noon_core::{GeometryRef, Property, TrackTiming};
+
+use crate::{AuthoringError, Mobject, Scene};
+
+/// Shape class used to pair sources with targets in
+/// [`crate::TransformMatchingShapes`].
+#[derive(Clone, Debug, PartialEq)]
+enum ShapeSignature {
+    Circle,
+    Line,
+    Rectangle { ratio: f32 },
+    VectorPath(noon_core::VectorPath),
+}
+
+impl ShapeSignature {
+    fn of(geometry: &GeometryRef) -> Option<Self> {
+        match geometry {
+            GeometryRef::Circle { .. } => Some(Self::Circle),
+            GeometryRef::Line { .. } => Some(Self::Line),
+            GeometryRef::Rectangle { size } => Some(Self::Rectangle {
+                ratio: size.x.min(size.y) / size.x.max(size.y),
+            }),
+            GeometryRef::VectorPath(path) => Some(Self::VectorPath(path.clone())),
+            _ => None,
+        }
+    }
+
+    fn matches(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Ratios are recomputed from f32 sizes, so equal aspect ratios can
+            // differ in the last bits.
+            (Self::Rectangle { ratio: left }, Self::Rectangle { ratio: right }) => {
+                (left - right).abs() <= 1.0e-6
+            }
+            _ => self == other,
+        }
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_replacement(
+        &mut self,
+        source: Mobject,
+        target: Mobject,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        self.validate_handoff(source, target)?;
+        let end = timing.start_time + timing.duration;
+        let from = self.snapshot(source)?.clone();
+        let to = self.snapshot(target)?.clone();
+
+        self.definition
+            .animate_transform(source.id, from, to.clone(), timing)?;
+        self.definition
+            .set_presence_at(source.id, true, false, end)?;
+        self.definition
+            .set_presence_at(target.id, false, true, end)?;
+        self.authored.insert(source.id, to);
+        self.presence.insert(source.id, false);
+        self.presence.insert(target.id, true);
+        Ok(())
+    }
+
+    pub(crate) fn schedule_transform_from_copy(
+        &mut self,
+        source: Mobject,
+        target: Mobject,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        self.validate_handoff(source, target)?;
+        let end = timing.start_time + timing.duration;
+        let from = self.snapshot(source)?.clone();
+        let to = self.snapshot(target)?.clone();
+
+        let copy = self.definition.add_snapshot(from.clone());
+        self.definition
+            .animate_transform(copy, from, to.clone(), timing)?;
+        self.definition
+            .set_presence_at(copy, false, true, timing.start_time)?;
+        self.definition.set_presence_at(copy, true, false, end)?;
+        self.definition
+            .set_presence_at(target.id, false, true, end)?;
+        self.authored.insert(copy, to);
+        self.presence.insert(copy, false);
+        self.presence.insert(target.id, true);
+        Ok(())
+    }
+
+    /// Pairs each source with the first unclaimed target of the same shape.
+    pub(crate) fn match_shapes(
+        &self,
+        sources: &[Mobject],
+        targets: &[Mobject],
+    ) -> Result<Vec<(Mobject, Mobject)>, AuthoringError> {
+        if sources.is_empty() || targets.is_empty() {
+            return Err(AuthoringError::EmptyShapeMatching);
+        }
+        let mut seen = BTreeSet::new();
+        for object in sources.iter().chain(targets) {
+            if !seen.insert(object.id) {
+                return Err(AuthoringError::DuplicateMatchingObject(object.id));
+            }
+        }
+        for &source in sources {
+            self.ensure_lifecycle_source(source)?;
+        }
+        for &target in targets {
+            self.ensure_lifecycle_target(target)?;
+        }
+
+        let mut remaining = targets
+            .iter()
+            .map(|&target| Ok((target, self.shape_signature(target)?)))
+            .collect::<Result<Vec<_>, AuthoringError>>()?;
+        let mut pairs = Vec::with_capacity(sources.len());
+        for &source in sources {
+            let signature = self.shape_signature(source)?;
+            let index = remaining
+                .iter()
+                .position(|(_, candidate)| signature.matches(candidate))
+                .ok_or(AuthoringError::UnmatchedShape(source.id))?;
+            let (target, _) = remaining.remove(index);
+            pairs.push((source, target));
+        }
+        if let Some((target, _)) = remaining.first() {
+            return Err(AuthoringError::UnmatchedShape(target.id));
+        }
+        Ok(pairs)
+    }
+
+    fn validate_handoff(&self, source: Mobject, target: Mobject) -> Result<(), AuthoringError> {
+        if source == target {
+            return Err(AuthoringError::LifecycleSelfTarget(source.id));
+        }
+        self.ensure_lifecycle_source(source)?;
+        self.ensure_lifecycle_target(target)
+    }
+
+    fn ensure_lifecycle_source(&self, source: Mobject) -> Result<(), AuthoringError> {
+        let is_present = self
+            .presence
+            .get(&source.id)
+            .copied()
+            .ok_or(AuthoringError::UnknownObject(source.id))?;
+        if !is_present {
+            return Err(AuthoringError::LifecycleSourceRequiresPresent(source.id));
+        }
+        Ok(())
+    }
+
+    fn ensure_lifecycle_target(&self, target: Mobject) -> Result<(), AuthoringError> {
+        if !self.is_available(target)? {
+            return Err(AuthoringError::LifecycleTargetRequiresAbsent(target.id));
+        }
+        Ok(())
+    }
+
+    /// An object can be brought in until some lifecycle event has made it
+    /// present; statically added objects without presence tracks qualify.
+    pub(crate) fn is_available(&self, object: Mobject) -> Result<bool, AuthoringError> {
+        let is_present = self
+            .presence
+            .get(&object.id)
+            .copied()
+            .ok_or(AuthoringError::UnknownObject(object.id))?;
+        let has_presence_events = self
+            .definition
+            .tracks()
+            .iter()
+            .any(|track| track.object == object.id && track.property == Property::Presence);
+        Ok(!is_present || !has_presence_events)
+    }
+
+    fn shape_signature(&self, object: Mobject) -> Result<ShapeSignature, AuthoringError> {
+        ShapeSignature::of(&self.snapshot(object)?.geometry)
+            .ok_or(AuthoringError::UnsupportedMatchingGeometry(object.id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Color, GeometryId, ObjectId, ObjectSnapshot, TrackValues, Vec2};
+
+    use crate::{
+        Circle, FadeIn, FadeOut, Rectangle, ReplacementTransform, Scene, TransformFromCopy,
+        TransformMatchingShapes,
+    };
+
+    use super::*;
+
+    fn presence_events(scene: &Scene) -> Vec<(u64, bool, f64)> {
+        scene
+            .definition()
+            .tracks()
+            .iter()
+            .filter(|track| track.property == Property::Presence)
+            .map(|track| match track.values {
+                TrackValues::Bool { to, .. } => (track.object.get(), to, track.timing.start_time),
+                ref values => panic!("presence track holds {values:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn replacement_hands_presence_to_the_target_at_the_end() {
+        let mut scene = Scene::new();
+        let first = scene.add(Circle::new(0.3));
+        let second = scene.add(Circle::new(0.6).color(Color::BLUE));
+        let third = scene.add(Circle::new(0.9));
+        scene
+            .play(ReplacementTransform::new(first, second))
+            .run_time(1.0)
+            .unwrap();
+        scene
+            .play(ReplacementTransform::new(second, third))
+            .run_time(2.0)
+            .unwrap();
+
+        assert_eq!(
+            presence_events(&scene),
+            [
+                (0, false, 1.0),
+                (1, true, 1.0),
+                (1, false, 3.0),
+                (2, true, 3.0)
+            ]
+        );
+        let transform = &scene.definition().tracks()[0];
+        assert_eq!(transform.property, Property::Transform);
+        assert_eq!(
+            transform.values,
+            TrackValues::Object {
+                from: scene.definition().snapshot(first.id()).unwrap(),
+                to: scene.definition().snapshot(second.id()).unwrap(),
+            }
+        );
+        assert_eq!(
+            scene
+                .play(ReplacementTransform::new(first, third))
+                .run_time(1.0),
+            Err(AuthoringError::LifecycleSourceRequiresPresent(first.id()))
+        );
+    }
+
+    #[test]
+    fn copies_are_transient_objects_and_leave_the_source_present() {
+        let mut scene = Scene::new();
+        let source = scene.add(Circle::new(0.3));
+        let target = scene.add(Circle::new(0.6).shift(Vec2::new(1.0, 0.0)));
+        scene.wait(0.5).unwrap();
+        scene
+            .play(TransformFromCopy::new(source, target))
+            .run_time(1.5)
+            .unwrap();
+
+        let copy = ObjectId::new(2);
+        assert_eq!(
+            scene.definition().snapshot(copy),
+            scene.definition().snapshot(source.id())
+        );
+        assert_eq!(
+            presence_events(&scene),
+            [(2, true, 0.5), (2, false, 2.0), (1, true, 2.0)]
+        );
+        assert!(scene.play(FadeOut(source)).run_time(1.0).is_ok());
+        assert_eq!(
+            scene
+                .play(TransformFromCopy::new(target, target))
+                .run_time(1.0),
+            Err(AuthoringError::LifecycleSelfTarget(target.id()))
+        );
+    }
+
+    #[test]
+    fn matching_pairs_by_shape_and_reports_leftovers() {
+        let mut scene = Scene::new();
+        let circle = scene.add(Circle::new(0.3));
+        let rectangle = scene.add(Rectangle::new(0.8, 0.4));
+        let wide = scene.add(Rectangle::new(1.6, 0.8));
+        let round = scene.add(Circle::new(0.5));
+        assert_eq!(
+            scene.match_shapes(&[circle, rectangle], &[wide, round]),
+            Ok(vec![(circle, round), (rectangle, wide)])
+        );
+        assert_eq!(
+            scene.match_shapes(&[circle], &[wide, round]),
+            Err(AuthoringError::UnmatchedShape(wide.id()))
+        );
+        assert_eq!(
+            scene.match_shapes(&[rectangle], &[round]),
+            Err(AuthoringError::UnmatchedShape(rectangle.id()))
+        );
+        assert_eq!(
+            scene.match_shapes(&[circle], &[circle]),
+            Err(AuthoringError::DuplicateMatchingObject(circle.id()))
+        );
+        assert_eq!(
+            scene.match_shapes(&[], &[round]),
+            Err(AuthoringError::EmptyShapeMatching)
+        );
+
+        let label = scene.add(ObjectSnapshot::new(GeometryRef::External(GeometryId::new(
+            0,
+        ))));
+        assert_eq!(
+            scene.match_shapes(&[label], &[round]),
+            Err(AuthoringError::UnsupportedMatchingGeometry(label.id()))
+        );
+
+        scene.play(FadeOut(round)).run_time(1.0).unwrap();
+        scene.play(FadeIn(round)).run_time(1.0).unwrap();
+        assert_eq!(
+            scene
+                .play(TransformMatchingShapes::new([circle], [round]))
+                .run_time(1.0),
+            Err(AuthoringError::LifecycleTargetRequiresAbsent(round.id()))
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use noon_core::{Color, FontId, Rect, Vec2};
+
+use crate::{Mobject, Path, Scene, TextError};
+
+/// A typeset formula held as one [`Path`] per symbol, in reading order.
+///
+/// Keeping symbols separate lets each term be animated, recolored or matched
+/// on its own. Fraction rules and radical signs are symbols too, recorded
+/// with the `\frac` and `\sqrt` sources.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MathTex {
+    symbols: Vec<(String, Path)>,
+}
+
+impl MathTex {
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// TeX source of each symbol, such as `x` or `\alpha`.
+    pub fn sources(&self) -> impl Iterator<Item = &str> {
+        self.symbols.iter().map(|(source, _)| source.as_str())
+    }
+
+    pub fn symbol(&self, index: usize) -> Option<&Path> {
+        self.symbols.get(index).map(|(_, path)| path)
+    }
+
+    /// Center of the combined symbol bounds.
+    pub fn center(&self) -> Vec2 {
+        self.symbols
+            .iter()
+            .filter_map(|(_, path)| path.snapshot().world_bounds())
+            .reduce(Rect::union)
+            .map_or(Vec2::ZERO, Rect::center)
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        self.map(|path| path.color(color))
+    }
+
+    pub fn shift(self, offset: Vec2) -> Self {
+        self.map(|path| path.shift(offset))
+    }
+
+    pub fn move_to(self, point: Vec2) -> Self {
+        let offset = point - self.center();
+        self.shift(offset)
+    }
+
+    /// Scales every symbol about the formula center.
+    pub fn scale(self, factor: f32) -> Self {
+        let center = self.center();
+        self.map(|path| {
+            let translation = path.snapshot().transform.translation;
+            let moved = center + (translation - center) * factor;
+            path.scale(factor).shift(moved - translation)
+        })
+    }
+
+    pub fn into_paths(self) -> Vec<Path> {
+        self.symbols.into_iter().map(|(_, path)| path).collect()
+    }
+
+    fn map(mut self, mut update: impl FnMut(Path) -> Path) -> Self {
+        self.symbols = self
+            .symbols
+            .into_iter()
+            .map(|(source, path)| (source, update(path)))
+            .collect();
+        self
+    }
+}
+
+impl Scene {
+    /// Typesets a TeX math subset with a registered font, `size` world units
+    /// per em; see [`noon_geometry::FontFace::typeset_math`].
+    pub fn math_tex(&self, font: FontId, tex: &str, size: f32) -> Result<MathTex, TextError> {
+        let symbols = self
+            .font_face(font)?
+            .typeset_math(tex, size)?
+            .into_iter()
+            .map(|symbol| (symbol.source, Path::new(symbol.path).shift(symbol.center)))
+            .collect();
+        Ok(MathTex { symbols })
+    }
+
+    /// Adds every symbol of a formula, returning handles in reading order.
+    pub fn add_math_tex(&mut self, tex: MathTex) -> Vec<Mobject> {
+        tex.into_paths()
+            .into_iter()
+            .map(|path| self.add(path))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_FONT_SIZE;
+
+    const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+    #[test]
+    fn formulas_add_one_object_per_symbol_and_scale_about_their_center() {
+        let mut scene = Scene::new();
+        let font = scene.load_font(TEST_FONT).expect("test font must load");
+        let formula = scene
+            .math_tex(font, "\\frac{I}{L}", DEFAULT_FONT_SIZE)
+            .expect("formula must typeset");
+        assert_eq!(formula.sources().collect::<Vec<_>>(), ["I", "L", "\\frac"]);
+        assert!(formula.center().length() < 1.0e-6);
+
+        let numerator = formula.symbol(0).expect("numerator").snapshot().clone();
+        let moved = formula
+            .scale(2.0)
+            .move_to(Vec2::new(3.0, 1.0))
+            .color(Color::RED);
+        assert!((moved.center() - Vec2::new(3.0, 1.0)).length() < 1.0e-5);
+        let scaled = moved.symbol(0).expect("numerator").snapshot();
+        assert_eq!(scaled.transform.scale, numerator.transform.scale * 2.0);
+        assert!(
+            (scaled.transform.translation
+                - (Vec2::new(3.0, 1.0) + numerator.transform.translation * 2.0))
+                .length()
+                < 1.0e-5
+        );
+
+        let objects = scene.add_math_tex(moved);
+        assert_eq!(objects.len(), 3);
+        assert_eq!(
+            scene
+                .snapshot(objects[1])
+                .expect("symbol exists")
+                .style
+                .fill,
+            Some(Color::RED)
+        );
+        assert_eq!(
+            scene.math_tex(font, "x^", 1.0),
+            Err(TextError::Math(noon_geometry::MathError::MissingArgument(
+                "^".to_owned()
+            )))
+        );
+    }
+}
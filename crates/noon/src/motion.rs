@@ -0,0 +1,283 @@
+//! Curved motion: moving along a path, transforming along an arc and
+//! rotating about a pivot.
+
+use noon_core::{Easing, MotionPath, ObjectId, ObjectSnapshot, TrackTiming, Vec2, VectorPath, TAU};
+use noon_geometry::MotionSampler;
+
+use crate::{AuthoringError, Mobject, Scene};
+
+/// Move an object's center along a path by arc length.
+///
+/// Like Manim, the object first snaps its center onto the start of the path.
+/// With [`MoveAlongPath::follow_tangent`] it also turns with the path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoveAlongPath {
+    object: Mobject,
+    path: VectorPath,
+    follow_tangent: bool,
+}
+
+impl MoveAlongPath {
+    pub fn new(object: Mobject, path: VectorPath) -> Self {
+        Self {
+            object,
+            path,
+            follow_tangent: false,
+        }
+    }
+
+    pub fn follow_tangent(mut self, follow: bool) -> Self {
+        self.follow_tangent = follow;
+        self
+    }
+}
+
+/// Continuous rotation about a pivot at constant angular speed.
+///
+/// The play's easing is ignored: like Manim, `Rotating` always runs linearly.
+/// The pivot defaults to the object's center at the start of the animation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotating {
+    object: Mobject,
+    angle: f32,
+    about_point: Option<Vec2>,
+}
+
+impl Rotating {
+    pub const fn new(object: Mobject) -> Self {
+        Self {
+            object,
+            angle: TAU,
+            about_point: None,
+        }
+    }
+
+    pub const fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    pub const fn about_point(mut self, point: Vec2) -> Self {
+        self.about_point = Some(point);
+        self
+    }
+}
+
+impl Scene {
+    pub(crate) fn schedule_move_along_path(
+        &mut self,
+        animation: MoveAlongPath,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let sampler =
+            MotionSampler::new(&animation.path).map_err(|_| AuthoringError::InvalidMotionPath)?;
+        let start = sampler.sample(0.0);
+        let end = sampler.sample(1.0);
+        let from = self
+            .snapshot(animation.object)?
+            .clone()
+            .move_to(start.point);
+        let mut to = from.clone().shift(end.point - start.point);
+        if animation.follow_tangent {
+            to.transform.rotation += end.turning;
+        }
+
+        self.definition.animate_motion(
+            animation.object.id,
+            from,
+            to.clone(),
+            MotionPath::Path {
+                path: animation.path,
+                follow_tangent: animation.follow_tangent,
+            },
+            timing,
+        )?;
+        self.authored.insert(animation.object.id, to);
+        Ok(())
+    }
+
+    pub(crate) fn schedule_rotating(
+        &mut self,
+        animation: Rotating,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let from = self.snapshot(animation.object)?.clone();
+        let point = animation.about_point.unwrap_or_else(|| from.center());
+        let to = from.clone().rotate_about(animation.angle, point);
+        self.animate_along(
+            animation.object.id,
+            from,
+            to.clone(),
+            MotionPath::Pivot {
+                point,
+                angle: animation.angle,
+            },
+            TrackTiming::new(timing.start_time, timing.duration, Easing::Linear),
+        )?;
+        self.authored.insert(animation.object.id, to);
+        Ok(())
+    }
+
+    /// Straight transforms stay plain Transform tracks; only a nonzero arc
+    /// or pivot rotation needs a Motion track.
+    pub(crate) fn animate_along(
+        &mut self,
+        object: ObjectId,
+        from: ObjectSnapshot,
+        to: ObjectSnapshot,
+        path: MotionPath,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        match path {
+            MotionPath::Arc { angle } | MotionPath::Pivot { angle, .. } if angle == 0.0 => {
+                self.definition
+                    .animate_transform(object, from, to, timing)?;
+            }
+            path => {
+                self.definition
+                    .animate_motion(object, from, to, path, timing)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Property, TrackValues, Vec2, PI};
+
+    use crate::{Circle, Square, Transform};
+
+    use super::*;
+
+    #[test]
+    fn move_along_path_snaps_to_the_start_and_turns_with_the_path() {
+        let mut scene = Scene::new();
+        let square = scene.add(Square::new(1.0).shift(Vec2::new(5.0, 5.0)));
+        let path = VectorPath::new()
+            .move_to(Vec2::new(1.0, 0.0))
+            .line_to(Vec2::new(3.0, 0.0))
+            .line_to(Vec2::new(3.0, 2.0));
+        scene
+            .play(MoveAlongPath::new(square, path.clone()).follow_tangent(true))
+            .run_time(1.0)
+            .unwrap();
+
+        let track = &scene.definition().tracks()[0];
+        assert_eq!(track.property, Property::Transform);
+        let TrackValues::Motion {
+            from,
+            to,
+            path: motion,
+        } = &track.values
+        else {
+            panic!("MoveAlongPath lowers to a motion track");
+        };
+        assert_eq!(from.center(), Vec2::new(1.0, 0.0));
+        assert_eq!(to.transform.translation, Vec2::new(3.0, 2.0));
+        assert!((to.transform.rotation - PI * 0.5).abs() < 1.0e-6);
+        assert_eq!(
+            motion,
+            &MotionPath::Path {
+                path,
+                follow_tangent: true
+            }
+        );
+        assert_eq!(scene.snapshot(square).unwrap(), to);
+
+        assert_eq!(
+            scene
+                .play(MoveAlongPath::new(square, VectorPath::new()))
+                .run_time(1.0),
+            Err(AuthoringError::InvalidMotionPath)
+        );
+    }
+
+    #[test]
+    fn only_nonzero_path_arcs_lower_to_motion_tracks() {
+        let mut scene = Scene::new();
+        let circle = scene.add(Circle::new(0.5));
+        scene
+            .play(circle.animate().shift(Vec2::new(2.0, 0.0)).path_arc(PI))
+            .run_time(1.0)
+            .unwrap();
+        scene
+            .play(Transform::new(circle, Circle::new(0.5)).path_arc(0.0))
+            .run_time(1.0)
+            .unwrap();
+
+        let tracks = scene.definition().tracks();
+        assert!(matches!(
+            tracks[0].values,
+            TrackValues::Motion {
+                path: MotionPath::Arc { angle },
+                ..
+            } if angle == PI
+        ));
+        assert!(matches!(tracks[1].values, TrackValues::Object { .. }));
+        assert_eq!(
+            scene.snapshot(circle).unwrap().transform.translation,
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn pivot_rotations_lower_to_pivot_tracks_and_rotating_runs_linearly() {
+        let mut scene = Scene::new();
+        let bob = scene.add(Square::new(0.5).shift(Vec2::new(0.0, -2.0)));
+        let pivot = Vec2::ZERO;
+        scene
+            .play(
+                bob.animate()
+                    .rotate_about(PI * 0.5, pivot)
+                    .scale_about(0.5, pivot),
+            )
+            .run_time(1.0)
+            .unwrap();
+        scene
+            .play(bob.animate().scale_about(2.0, pivot))
+            .run_time(1.0)
+            .unwrap();
+        scene
+            .play(Rotating::new(bob).angle(-PI * 0.5).about_point(pivot))
+            .with_easing(Easing::EaseInOutCubic)
+            .run_time(2.0)
+            .unwrap();
+
+        let tracks = scene.definition().tracks();
+        assert!(matches!(
+            tracks[0].values,
+            TrackValues::Motion {
+                path: MotionPath::Pivot { point, angle },
+                ..
+            } if point == pivot && angle == PI * 0.5
+        ));
+        assert!(matches!(tracks[1].values, TrackValues::Object { .. }));
+        assert!(matches!(
+            tracks[2].values,
+            TrackValues::Motion {
+                path: MotionPath::Pivot { angle, .. },
+                ..
+            } if angle == -PI * 0.5
+        ));
+        assert_eq!(tracks[2].timing.easing, Easing::Linear);
+        let transform = scene.snapshot(bob).unwrap().transform;
+        assert!(
+            (transform.translation - Vec2::new(0.0, -2.0)).length() < 1.0e-5,
+            "{transform:?}"
+        );
+        assert!(transform.rotation.abs() < 1.0e-6);
+        assert_eq!(transform.scale, Vec2::ONE);
+
+        assert_eq!(
+            scene
+                .play(
+                    bob.animate()
+                        .rotate_about(PI, pivot)
+                        .rotate_about(PI, Vec2::ONE)
+                )
+                .run_time(1.0),
+            Err(AuthoringError::MixedPivotPoints)
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! Animated numbers.
+//!
+//! A [`DecimalNumber`] is a glyph run that carries its own number layout, so
+//! the runtime re-typesets it from the Value property on every frame. A
+//! [`ValueTracker`] exists only while authoring: changing it writes ordinary
+//! tracks for every follower, so nothing is evaluated through it at playback.
+
+use noon_core::{Color, FontId, GeometryRef, NumberFormat, ObjectSnapshot, TrackTiming, Vec2};
+
+use crate::{AuthoringError, IntoSnapshot, Mobject, Scene, TextError};
+
+define_shape!(DecimalNumber);
+
+/// Count a [`DecimalNumber`] from its current value to `value`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChangeDecimalToValue {
+    number: Mobject,
+    value: f32,
+}
+
+impl ChangeDecimalToValue {
+    pub const fn new(number: Mobject, value: f32) -> Self {
+        Self { number, value }
+    }
+}
+
+/// Authoring-time scalar that objects can follow; see [`Scene::follow`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValueTracker {
+    index: usize,
+}
+
+impl ValueTracker {
+    /// Animate the tracker to `value`.
+    pub const fn set_value(self, value: f32) -> ChangeValue {
+        ChangeValue {
+            tracker: self,
+            change: ValueChange::To(value),
+        }
+    }
+
+    /// Animate the tracker by `delta` from wherever it is when played.
+    pub const fn increment_value(self, delta: f32) -> ChangeValue {
+        ChangeValue {
+            tracker: self,
+            change: ValueChange::By(delta),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ValueChange {
+    To(f32),
+    By(f32),
+}
+
+/// Tracker change built by [`ValueTracker::set_value`] and
+/// [`ValueTracker::increment_value`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChangeValue {
+    tracker: ValueTracker,
+    change: ValueChange,
+}
+
+/// How a follower's property depends on a tracker value `v`.
+///
+/// Every mapping is affine in `v`, and tracks interpolate linearly under the
+/// play's easing, so followers stay exact between the keyed endpoints.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Follow {
+    /// A [`DecimalNumber`] displays `v`.
+    Number,
+    /// The object's center sits at `origin + per_unit * v`.
+    Position { origin: Vec2, per_unit: Vec2 },
+    /// The object's rotation is `origin + per_unit * v` radians.
+    Rotation { origin: f32, per_unit: f32 },
+    /// The object's uniform scale is `origin + per_unit * v`.
+    Scale { origin: f32, per_unit: f32 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TrackerState {
+    value: f32,
+    followers: Vec<(Mobject, Follow)>,
+}
+
+impl Scene {
+    /// Shapes a counter showing `value` with a registered font, centered on
+    /// its ink, `size` world units per em.
+    pub fn decimal_number(
+        &self,
+        font: FontId,
+        value: f32,
+        format: NumberFormat,
+        size: f32,
+    ) -> Result<DecimalNumber, TextError> {
+        let face = self.font_face(font)?;
+        Ok(DecimalNumber(ObjectSnapshot::new(GeometryRef::glyph_run(
+            face.number_run(font, format, value, size),
+        ))))
+    }
+
+    /// Current value of a counter created with [`Scene::decimal_number`].
+    pub fn number_value(&self, number: Mobject) -> Result<f32, AuthoringError> {
+        number_value(self.snapshot(number)?).ok_or(AuthoringError::NotANumber(number.id))
+    }
+
+    pub fn value_tracker(&mut self, value: f32) -> ValueTracker {
+        self.trackers.push(TrackerState {
+            value,
+            followers: Vec::new(),
+        });
+        ValueTracker {
+            index: self.trackers.len() - 1,
+        }
+    }
+
+    pub fn tracker_value(&self, tracker: ValueTracker) -> Result<f32, AuthoringError> {
+        Ok(self.tracker(tracker)?.value)
+    }
+
+    /// Makes `object` follow `tracker` from now on. The object is set to the
+    /// tracker's current value immediately, which is a static edit and is
+    /// rejected once the object has authored animation.
+    pub fn follow(
+        &mut self,
+        tracker: ValueTracker,
+        object: Mobject,
+        follow: Follow,
+    ) -> Result<(), AuthoringError> {
+        let value = self.tracker(tracker)?.value;
+        let current = self.snapshot(object)?.clone();
+        if follow == Follow::Number && number_value(&current).is_none() {
+            return Err(AuthoringError::NotANumber(object.id));
+        }
+        let snapshot = apply_follow(current, follow, value);
+        self.edit(object)?;
+        self.apply_static(object, snapshot)?;
+        self.trackers[tracker.index]
+            .followers
+            .push((object, follow));
+        Ok(())
+    }
+
+    fn tracker(&self, tracker: ValueTracker) -> Result<&TrackerState, AuthoringError> {
+        self.trackers
+            .get(tracker.index)
+            .ok_or(AuthoringError::UnknownValueTracker)
+    }
+
+    pub(crate) fn schedule_change_decimal(
+        &mut self,
+        animation: ChangeDecimalToValue,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let from = self.number_value(animation.number)?;
+        self.animate_number(animation.number, from, animation.value, timing)
+    }
+
+    pub(crate) fn schedule_change_value(
+        &mut self,
+        animation: ChangeValue,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let state = self.tracker(animation.tracker)?;
+        let from = state.value;
+        let to = match animation.change {
+            ValueChange::To(value) => value,
+            ValueChange::By(delta) => from + delta,
+        };
+        for (object, follow) in state.followers.clone() {
+            if follow == Follow::Number {
+                self.animate_number(object, from, to, timing)?;
+                continue;
+            }
+            let current = self.snapshot(object)?.clone();
+            let start = apply_follow(current.clone(), follow, from);
+            let end = apply_follow(current, follow, to);
+            self.definition
+                .animate_transform(object.id, start, end.clone(), timing)?;
+            self.authored.insert(object.id, end);
+        }
+        self.trackers[animation.tracker.index].value = to;
+        Ok(())
+    }
+
+    fn animate_number(
+        &mut self,
+        number: Mobject,
+        from: f32,
+        to: f32,
+        timing: TrackTiming,
+    ) -> Result<(), AuthoringError> {
+        let mut snapshot = self.snapshot(number)?.clone();
+        let GeometryRef::GlyphRun(run) = &mut snapshot.geometry else {
+            return Err(AuthoringError::NotANumber(number.id));
+        };
+        run.set_number_value(to);
+        self.definition.animate_value(number.id, from, to, timing)?;
+        self.authored.insert(number.id, snapshot);
+        Ok(())
+    }
+}
+
+fn number_value(snapshot: &ObjectSnapshot) -> Option<f32> {
+    match &snapshot.geometry {
+        GeometryRef::GlyphRun(run) => run.number.as_ref().map(|number| number.value),
+        _ => None,
+    }
+}
+
+fn apply_follow(mut snapshot: ObjectSnapshot, follow: Follow, value: f32) -> ObjectSnapshot {
+    match follow {
+        Follow::Number => {
+            if let GeometryRef::GlyphRun(run) = &mut snapshot.geometry {
+                run.set_number_value(value);
+            }
+            snapshot
+        }
+        Follow::Position { origin, per_unit } => snapshot.move_to(origin + per_unit * value),
+        Follow::Rotation { origin, per_unit } => {
+            snapshot.transform.rotation = origin + per_unit * value;
+            snapshot
+        }
+        Follow::Scale { origin, per_unit } => {
+            let scale = origin + per_unit * value;
+            snapshot.transform.scale = Vec2::new(scale, scale);
+            snapshot
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noon_core::{Property, TrackValues, LEFT, RIGHT};
+
+    use crate::Circle;
+
+    use super::*;
+
+    const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+    fn run(scene: &Scene, object: Mobject) -> &noon_core::GlyphRun {
+        let GeometryRef::GlyphRun(run) = &scene.snapshot(object).unwrap().geometry else {
+            panic!("counter must be a glyph run");
+        };
+        run
+    }
+
+    #[test]
+    fn counters_lower_to_value_tracks_and_keep_their_left_edge() {
+        let mut scene = Scene::new();
+        let font = scene.load_font(TEST_FONT).unwrap();
+        let format = NumberFormat::new().decimal_places(0).group_separator(',');
+        let number = scene.add(scene.decimal_number(font, 0.0, format, 1.0).unwrap());
+        let first = run(&scene, number).glyphs[0].origin;
+        assert_eq!(run(&scene, number).text, "0");
+        // The test font lacks digits, so every character is a 0.5 em .notdef
+        // box whose ink spans 0.05..0.45 em.
+        assert_eq!(first, Vec2::new(-0.25, -0.35));
+
+        scene
+            .play(ChangeDecimalToValue::new(number, 1250.0))
+            .run_time(2.0)
+            .unwrap();
+        let track = &scene.definition().tracks()[0];
+        assert_eq!(track.property, Property::Value);
+        assert_eq!(
+            track.values,
+            TrackValues::Scalar {
+                from: 0.0,
+                to: 1250.0
+            }
+        );
+        assert_eq!(scene.number_value(number), Ok(1250.0));
+        let counted = run(&scene, number);
+        assert_eq!(counted.text, "1,250");
+        assert_eq!(counted.glyphs[0].origin, first);
+        assert_eq!(counted.glyphs[4].origin, first + Vec2::new(2.0, 0.0));
+
+        let circle = scene.add(Circle::new(0.5));
+        assert_eq!(
+            scene
+                .play(ChangeDecimalToValue::new(circle, 1.0))
+                .run_time(1.0),
+            Err(AuthoringError::NotANumber(circle.id()))
+        );
+    }
+
+    #[test]
+    fn tracker_changes_key_every_follower() {
+        let mut scene = Scene::new();
+        let font = scene.load_font(TEST_FONT).unwrap();
+        let number = scene.add(
+            scene
+                .decimal_number(font, 0.0, NumberFormat::new(), 0.5)
+                .unwrap(),
+        );
+        let dot = scene.add(Circle::new(0.1));
+        let tracker = scene.value_tracker(1.0);
+        scene.follow(tracker, number, Follow::Number).unwrap();
+        scene
+            .follow(
+                tracker,
+                dot,
+                Follow::Position {
+                    origin: LEFT * 2.0,
+                    per_unit: RIGHT,
+                },
+            )
+            .unwrap();
+        assert_eq!(scene.number_value(number), Ok(1.0));
+        assert_eq!(scene.snapshot(dot).unwrap().center(), LEFT);
+
+        scene.play(tracker.set_value(4.0)).run_time(1.0).unwrap();
+        scene
+            .play(tracker.increment_value(-1.0))
+            .run_time(1.0)
+            .unwrap();
+
+        let tracks: Vec<_> = scene
+            .definition()
+            .tracks()
+            .iter()
+            .map(|track| (track.object, track.property, track.timing.start_time))
+            .collect();
+        assert_eq!(
+            tracks,
+            vec![
+                (number.id(), Property::Value, 0.0),
+                (dot.id(), Property::Transform, 0.0),
+                (number.id(), Property::Value, 1.0),
+                (dot.id(), Property::Transform, 1.0),
+            ]
+        );
+        assert_eq!(scene.tracker_value(tracker), Ok(3.0));
+        assert_eq!(run(&scene, number).text, "3.00");
+        assert_eq!(scene.snapshot(dot).unwrap().center(), RIGHT);
+
+        assert_eq!(
+            scene.follow(tracker, dot, Follow::Number),
+            Err(AuthoringError::NotANumber(dot.id()))
+        );
+        assert_eq!(
+            scene.follow(tracker, number, Follow::Number),
+            Err(AuthoringError::StaticMutationAfterAnimation(number.id()))
+        );
+        assert_eq!(
+            Scene::new().tracker_value(tracker),
+            Err(AuthoringError::UnknownValueTracker)
+        );
+    }
+}
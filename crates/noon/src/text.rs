@@ -0,0 +1,108 @@
+use noon_core::{Color, FontData, FontId, GeometryRef, GlyphRun, ObjectSnapshot, Vec2};
+use noon_geometry::{FontError, FontFace, MathError};
+
+use crate::{IntoSnapshot, Scene};
+
+/// World units per em given to text created without an explicit size.
+pub const DEFAULT_FONT_SIZE: f32 = 0.5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TextError {
+    UnknownFont(FontId),
+    Font(FontError),
+    Math(MathError),
+}
+
+impl std::fmt::Display for TextError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFont(id) => write!(formatter, "font {} is not registered", id.get()),
+            Self::Font(error) => error.fmt(formatter),
+            Self::Math(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl std::error::Error for TextError {}
+
+impl From<FontError> for TextError {
+    fn from(value: FontError) -> Self {
+        Self::Font(value)
+    }
+}
+
+impl From<MathError> for TextError {
+    fn from(value: MathError) -> Self {
+        Self::Math(value)
+    }
+}
+
+define_shape!(Text);
+
+impl Text {
+    /// A pre-shaped glyph run; see [`Scene::text`] for shaping a string.
+    pub fn new(run: GlyphRun) -> Self {
+        Self(ObjectSnapshot::new(GeometryRef::glyph_run(run)))
+    }
+}
+
+impl Scene {
+    /// Validates and registers TrueType/OpenType bytes for later text objects.
+    pub fn load_font(&mut self, bytes: impl Into<Vec<u8>>) -> Result<FontId, FontError> {
+        let bytes = bytes.into();
+        FontFace::parse(&bytes)?;
+        Ok(self.definition.register_font(FontData::new(bytes)))
+    }
+
+    /// Shapes `text` with a registered font into a run centered on its ink,
+    /// `size` world units per em.
+    pub fn text(&self, font: FontId, text: &str, size: f32) -> Result<Text, TextError> {
+        let face = self.font_face(font)?;
+        Ok(Text::new(face.glyph_run(font, text, size)))
+    }
+
+    pub(crate) fn font_face(&self, font: FontId) -> Result<FontFace<'_>, TextError> {
+        let data = self
+            .definition
+            .font(font)
+            .ok_or(TextError::UnknownFont(font))?;
+        Ok(FontFace::parse(data.bytes())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+    #[test]
+    fn loaded_fonts_shape_text_into_glyph_runs() {
+        let mut scene = Scene::new();
+        assert!(matches!(
+            scene.load_font(b"not a font".to_vec()),
+            Err(FontError::Malformed(_))
+        ));
+        let font = scene.load_font(TEST_FONT).expect("test font must load");
+        assert_eq!(font, FontId::new(0));
+
+        let text = scene
+            .text(font, "LV", DEFAULT_FONT_SIZE)
+            .expect("text must shape")
+            .color(Color::RED)
+            .shift(Vec2::new(1.0, 0.0));
+        let object = scene.add(text);
+        let snapshot = scene.snapshot(object).expect("text object exists");
+        let GeometryRef::GlyphRun(run) = &snapshot.geometry else {
+            panic!("text must be a glyph run");
+        };
+        assert_eq!(run.text, "LV");
+        assert_eq!(run.glyphs.len(), 2);
+        assert_eq!(snapshot.style.fill, Some(Color::RED));
+
+        assert_eq!(
+            scene.text(FontId::new(9), "L", 1.0),
+            Err(TextError::UnknownFont(FontId::new(9)))
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn created_objects_are_hidden_until_drawn_and_gone_after_uncreate() {
+    let mut scene = Scene::new();
+    let square = scene.add(Square::new(1.0).color(BLUE));
+    let circle = scene.add(Circle::new(0.5).color(RED));
+    scene.wait(1.0).unwrap();
+    scene
+        .play(DrawBorderThenFill(square))
+        .run_time(2.0)
+        .unwrap();
+    scene.play(Create(circle)).run_time(1.0).unwrap();
+    scene.play(Uncreate(square)).run_time(1.0).unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+
+    let frame = instance.seek(0.5).expect("valid time");
+    assert!(!frame.is_present(0) && !frame.is_present(1));
+
+    let frame = instance.seek(1.5).expect("valid time");
+    assert!(frame.is_present(0));
+    assert_eq!(frame.reveal(0), 0.5);
+    assert_eq!(frame.objects[0].style.fill, None);
+    assert_eq!(frame.objects[0].style.stroke, Some(BLUE));
+
+    let frame = instance.seek(2.9).expect("valid time");
+    assert_eq!(frame.reveal(0), 1.0);
+    assert!(!frame.is_present(1));
+    let frame = instance.seek(3.0).expect("valid time");
+    assert_eq!(frame.objects[0].style.fill, Some(BLUE));
+
+    let frame = instance.seek(3.5).expect("valid time");
+    assert!(frame.is_present(1));
+    assert_eq!(frame.reveal(1), 0.5);
+
+    let frame = instance.seek(4.5).expect("valid time");
+    assert_eq!(frame.reveal(0), 0.5);
+    let frame = instance.seek(5.0).expect("valid time");
+    assert!(!frame.is_present(0));
+    assert!(frame.is_present(1));
+}
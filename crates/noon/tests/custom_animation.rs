@@ -0,0 +1,60 @@
+//! A house-style animation written the way a third-party crate would.
+
+use noon::noon_core::{Property, TrackTiming, TrackValues};
+use noon::prelude::*;
+use noon::{CustomAnimation, LoweringContext};
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+/// Hops an object up and back down, then flashes it yellow.
+#[derive(Debug)]
+struct Hop {
+    object: Mobject,
+    height: f32,
+}
+
+impl CustomAnimation for Hop {
+    fn lower(&self, context: &mut LoweringContext<'_>) -> Result<(), AuthoringError> {
+        let timing = context.timing();
+        let half = timing.duration * 0.5;
+        let up = TrackTiming::new(timing.start_time, half, timing.easing);
+        let down = TrackTiming::new(timing.start_time + half, half, timing.easing);
+        let rest = context.snapshot(self.object)?.clone();
+        context.play(self.object.animate().shift(UP * self.height), up)?;
+        context.transform_to(self.object, rest, down)?;
+        context.add_track(
+            self.object,
+            Property::Opacity,
+            TrackValues::Scalar { from: 0.5, to: 1.0 },
+            down,
+        )?;
+        Ok(())
+    }
+}
+
+#[test]
+fn third_party_animations_compose_with_builtins_and_play_back() {
+    let mut scene = Scene::new();
+    let square = scene.add(Square::new(1.0));
+    let circle = scene.add(Circle::new(0.5).shift(RIGHT * 2.0));
+    scene
+        .play(LaggedStart::new((
+            Hop {
+                object: square,
+                height: 1.0,
+            },
+            circle.animate().shift(LEFT),
+        )))
+        .run_time(2.0)
+        .unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+    let window = 2.0 / 1.05;
+    let frame = instance.seek(window * 0.5).unwrap();
+    assert!((frame.objects[0].transform.translation - UP).length() < 1.0e-5);
+    let frame = instance.seek(2.0).unwrap();
+    assert_eq!(frame.objects[0].transform.translation, ORIGIN);
+    assert_eq!(frame.objects[0].style.opacity, 1.0);
+    assert_eq!(frame.objects[1].transform.translation, RIGHT);
+}
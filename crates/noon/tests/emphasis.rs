@@ -0,0 +1,49 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn emphasized_objects_return_and_helpers_are_present_only_while_playing() {
+    let mut scene = Scene::new();
+    let square = scene.add(Square::new(1.0).color(BLUE).shift(RIGHT));
+    let rest = scene.snapshot(square).unwrap().clone();
+    scene.play(Indicate::new(square)).run_time(1.0).unwrap();
+    scene
+        .play((Wiggle::new(square), Circumscribe::new(square)))
+        .run_time(2.0)
+        .unwrap();
+    scene
+        .play((Flash::new(square).num_lines(4), FocusOn::new(ORIGIN)))
+        .run_time(1.0)
+        .unwrap();
+    scene.wait(1.0).unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+
+    let frame = instance.seek(0.5).expect("valid time");
+    assert_eq!(frame.objects[0].style.fill, Some(YELLOW));
+    assert!((frame.objects[0].transform.scale.x - 1.2).abs() < 1.0e-5);
+    assert_eq!(
+        frame.objects[0].transform.translation,
+        rest.transform.translation
+    );
+
+    // The outline is the first helper; it is drawn during the first half.
+    let frame = instance.seek(1.5).expect("valid time");
+    assert!(frame.is_present(1));
+    assert_eq!(frame.reveal(1), 0.5);
+    assert_eq!(frame.objects[1].style.stroke, Some(YELLOW));
+
+    let frame = instance.seek(3.5).expect("valid time");
+    assert!(!frame.is_present(1));
+    assert!((2..7).all(|id| frame.is_present(id)));
+    let spotlight = &frame.objects[6];
+    assert_eq!(spotlight.style.fill, Some(GRAY));
+    assert!((spotlight.style.opacity - 0.1).abs() < 1.0e-5);
+
+    let frame = instance.seek(4.5).expect("valid time");
+    assert!((1..7).all(|id| !frame.is_present(id)));
+    assert_eq!(frame.objects[0].transform, rest.transform);
+    assert_eq!(frame.objects[0].style, rest.style);
+}
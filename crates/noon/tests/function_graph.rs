@@ -0,0 +1,60 @@
+use noon::prelude::*;
+use noon::{Property, TrackTiming};
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn sampled_graph_is_an_ordinary_revealable_path() {
+    let graph = FunctionGraph::new(|x| 0.5 * x * x, (-2.0, 2.0))
+        .color(BLUE)
+        .build()
+        .expect("parabola samples");
+    let GeometryRef::VectorPath(path) = &graph.snapshot().geometry else {
+        panic!("function graphs must lower to vector paths");
+    };
+    let tessellated = noon_geometry::tessellate_styled_with_fill(
+        path,
+        0.04,
+        noon_core::StrokeJoin::Round,
+        noon_core::StrokeCap::Round,
+        false,
+    )
+    .expect("sampled path tessellates");
+    // Arc length of y = x^2 / 2 over [-2, 2] is 2 * sqrt(5) + asinh(2).
+    let expected = 2.0 * 5.0f32.sqrt() + 2.0f32.asinh();
+    assert!((tessellated.stroke_length - expected).abs() < 0.01);
+
+    let mut scene = Scene::new();
+    let plot = scene.add(graph);
+    let mut definition = scene.into_definition();
+    definition
+        .animate_reveal(
+            plot.id(),
+            0.0,
+            1.0,
+            TrackTiming::new(0.0, 2.0, Easing::Linear),
+        )
+        .expect("plots accept reveal tracks");
+    assert_eq!(definition.tracks()[0].property, Property::Reveal);
+
+    let compiled = CompiledScene::compile(&definition).expect("plot scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+    assert_eq!(instance.seek(0.0).expect("valid time").reveal(0), 0.0);
+    assert_eq!(instance.seek(1.0).expect("valid time").reveal(0), 0.5);
+    assert_eq!(instance.seek(2.0).expect("valid time").reveal(0), 1.0);
+}
+
+#[test]
+fn expression_graph_matches_closure_graph() {
+    let from_expression = FunctionGraph::from_expression("sin(2x) / (1 + x^2)", (-4.0, 4.0))
+        .expect("expression parses")
+        .to_vector_path()
+        .expect("expression samples");
+    let from_closure = FunctionGraph::new(|x| (2.0 * x).sin() / (1.0 + x * x), (-4.0, 4.0))
+        .to_vector_path()
+        .expect("closure samples");
+    assert_eq!(
+        from_expression.commands().len(),
+        from_closure.commands().len()
+    );
+}
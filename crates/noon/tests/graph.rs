@@ -0,0 +1,144 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn edges_stay_attached_while_vertices_move() {
+    let mut scene = Scene::new();
+    let graph = scene
+        .add_graph(
+            Graph::new(3, [(0, 1), (1, 2)])
+                .layout(GraphLayout::circular(1.0))
+                .edge_color(BLUE),
+        )
+        .expect("valid graph");
+    assert_eq!(graph.vertices().len(), 3);
+    assert_eq!(graph.edges().len(), 2);
+
+    let animations = graph
+        .move_vertex(&scene, 1, Vec2::new(0.0, 3.0))
+        .expect("known vertex");
+    assert_eq!(animations.len(), 3, "the vertex and both incident edges");
+    scene
+        .play(animations)
+        .with_easing(Easing::EaseInOutCubic)
+        .run_time(2.0)
+        .unwrap();
+
+    let edge = graph.edges()[0].object;
+    let compiled = CompiledScene::compile(scene.definition()).expect("graph must compile");
+    let mut instance = SceneInstance::new(compiled);
+    for time in [0.5, 1.0, 1.7, 2.0] {
+        let frame = instance.seek(time).expect("valid time");
+        let vertex = frame
+            .objects
+            .iter()
+            .find(|object| object.id == graph.vertex(1).expect("vertex").id())
+            .expect("vertex state");
+        let line = frame
+            .objects
+            .iter()
+            .find(|object| object.id == edge.id())
+            .expect("edge state");
+        let GeometryRef::Line { end, .. } = line.geometry else {
+            panic!("edges stay lines");
+        };
+        let attached = line.transform.transform_point(end);
+        assert!(
+            (attached - vertex.transform.translation).length() < 1.0e-5,
+            "edge detached at {time}"
+        );
+    }
+}
+
+#[test]
+fn moving_a_vertex_around_the_graph_api_is_rejected() {
+    let mut scene = Scene::new();
+    let graph = scene
+        .add_graph(Graph::new(3, [(0, 1), (1, 2)]).layout(GraphLayout::grid(1.0)))
+        .expect("valid graph");
+    let vertex = graph.vertex(1).expect("vertex");
+
+    assert_eq!(
+        scene.play(vertex.animate().shift(UP)).run_time(1.0),
+        Err(AuthoringError::DetachedGraphEdge(
+            graph.edges()[0].object.id()
+        ))
+    );
+    assert!(
+        scene.definition().tracks().is_empty(),
+        "the play rolled back"
+    );
+    assert_eq!(scene.time(), 0.0);
+
+    scene
+        .play(vertex.animate().set_color(RED))
+        .run_time(1.0)
+        .expect("recoloring keeps edges attached");
+    let whole: Group = graph
+        .vertices()
+        .iter()
+        .copied()
+        .chain(graph.edges().iter().map(|edge| edge.object))
+        .collect();
+    scene
+        .play(whole.animate().rotate(PI * 0.5))
+        .run_time(1.0)
+        .expect("moving the whole graph keeps edges attached");
+}
+
+#[test]
+fn vertices_swinging_on_an_arc_detach_straight_edges_mid_play() {
+    let mut scene = Scene::new();
+    let graph = scene
+        .add_graph(Graph::new(2, [(0, 1)]).layout(GraphLayout::grid(2.0)))
+        .expect("valid graph");
+    let vertex = graph.vertex(0).expect("vertex");
+    let edge = graph.edges()[0].object;
+    let start = scene.snapshot(vertex).unwrap().center();
+    let other = scene.snapshot(graph.vertex(1).unwrap()).unwrap().center();
+    let mut line = scene.snapshot(edge).unwrap().clone();
+    line.geometry = GeometryRef::line(-start, other);
+
+    // Both plays end attached, but the vertex swings around the origin while
+    // the edge endpoint slides straight through it.
+    assert_eq!(
+        scene
+            .play(vec![
+                vertex.animate().rotate_about(PI, ORIGIN).into(),
+                Transform::new(edge, line).into(),
+            ])
+            .run_time(2.0),
+        Err(AuthoringError::DetachedGraphEdge(edge.id()))
+    );
+    assert!(scene.definition().tracks().is_empty());
+}
+
+#[test]
+fn moving_a_vertex_after_shifting_the_whole_graph_keeps_edges_attached() {
+    let mut scene = Scene::new();
+    let graph = scene
+        .add_graph(Graph::new(3, [(0, 1), (1, 2)]).layout(GraphLayout::circular(1.0)))
+        .expect("valid graph");
+    let whole: Group = graph
+        .vertices()
+        .iter()
+        .copied()
+        .chain(graph.edges().iter().map(|edge| edge.object))
+        .collect();
+    scene
+        .play(whole.animate().shift(RIGHT * 2.0 + UP))
+        .run_time(1.0)
+        .expect("shifting the whole graph keeps edges attached");
+
+    let target = Vec2::new(-1.0, -2.0);
+    scene
+        .play(graph.move_vertex(&scene, 0, target).expect("known vertex"))
+        .run_time(1.0)
+        .expect("edges follow the moved vertex in their shifted frame");
+    let edge = scene.snapshot(graph.edges()[0].object).unwrap();
+    let GeometryRef::Line { start, .. } = edge.geometry else {
+        panic!("edges stay lines");
+    };
+    assert!((edge.transform.transform_point(start) - target).length() < 1.0e-5);
+}
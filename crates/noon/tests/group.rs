@@ -0,0 +1,32 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn group_rotation_keeps_members_on_a_circle_about_the_group_center() {
+    let mut scene = Scene::new();
+    let left = scene.add(Circle::new(0.5).shift(LEFT * 2.0));
+    let right = scene.add(Circle::new(0.5).shift(RIGHT * 2.0 + UP * 2.0));
+    let group = Group::new([left, right]);
+    scene
+        .play(group.animate().rotate(PI * 0.5))
+        .run_time(1.0)
+        .unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+    let center = Vec2::new(0.0, 1.0);
+    let radius = Vec2::new(2.0, 1.0).length();
+    for time in [0.25, 0.5, 0.75] {
+        let frame = instance.seek(time).expect("valid time");
+        for object in &frame.objects {
+            let distance = (object.transform.translation - center).length();
+            assert!(
+                (distance - radius).abs() < 1.0e-4,
+                "distance {distance} at {time}s"
+            );
+        }
+    }
+    let frame = instance.seek(1.0).expect("valid time");
+    assert!((frame.objects[0].transform.translation - Vec2::new(1.0, -1.0)).length() < 1.0e-4);
+}
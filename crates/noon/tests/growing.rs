@@ -0,0 +1,45 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn grown_objects_keep_their_origin_fixed_while_playing() {
+    let mut scene = Scene::new();
+    let square = scene.add(Square::new(2.0).color(BLUE));
+    let spinner = scene.add(Square::new(1.0).shift(RIGHT * 3.0));
+    scene
+        .play((FadeOut::new(square), FadeOut::new(spinner)))
+        .run_time(0.25)
+        .unwrap();
+    scene.wait(0.75).unwrap();
+    scene
+        .play((
+            GrowFromEdge::new(square, LEFT),
+            SpinInFromNothing::new(spinner),
+        ))
+        .run_time(2.0)
+        .unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+
+    let frame = instance.seek(0.5).expect("valid time");
+    assert!(!frame.is_present(0));
+    assert!(!frame.is_present(1));
+
+    let frame = instance.seek(2.0).expect("valid time");
+    assert!(frame.is_present(0));
+    let half = frame.objects[0].transform;
+    assert_eq!(half.scale, Vec2::new(0.5, 0.5));
+    assert_eq!(half.translation.x - half.scale.x, -1.0);
+    let spin = frame.objects[1].transform;
+    assert!(
+        (spin.translation - RIGHT * 3.0).length() < 1.0e-5,
+        "{spin:?}"
+    );
+    assert!((spin.rotation + PI * 0.25).abs() < 1.0e-6);
+
+    let frame = instance.seek(3.0).expect("valid time");
+    assert_eq!(frame.objects[0].transform.scale, Vec2::ONE);
+    assert!(frame.objects[1].transform.rotation.abs() < 1.0e-6);
+}
@@ -0,0 +1,40 @@
+use noon::prelude::*;
+use noon::{ImageData, ImageSampling};
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+#[test]
+fn image_objects_take_part_in_fade_and_transform_tracks() {
+    let mut scene = Scene::new();
+    let pixels = ImageData::new(2, 1, vec![255; 8]).expect("valid image");
+    let image = scene.image(pixels).sampling(ImageSampling::Nearest);
+    let target = image.clone().scale(2.0).set_opacity(0.5).shift(RIGHT);
+    let object = scene.add(image);
+
+    scene
+        .play(Transform::new(object, target))
+        .run_time(2.0)
+        .unwrap();
+    scene.play(FadeOut::new(object)).run_time(1.0).unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("image scene must compile");
+    assert_eq!(compiled.images().len(), 1);
+    let mut instance = SceneInstance::new(compiled);
+
+    let frame = instance.seek(1.0).expect("valid time");
+    let state = &frame.objects[0];
+    assert_eq!(state.appearance, 1.0);
+    assert_eq!(state.style.opacity, 0.75);
+    assert_eq!(state.transform.translation, Vec2::new(0.5, 0.0));
+    assert!(matches!(
+        state.geometry,
+        GeometryRef::Image {
+            sampling: ImageSampling::Nearest,
+            ..
+        }
+    ));
+
+    let frame = instance.seek(2.5).expect("valid time");
+    assert_eq!(frame.objects[0].appearance, 0.5);
+    assert_eq!(frame.objects[0].style.opacity, 0.5);
+}
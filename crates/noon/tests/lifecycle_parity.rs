@@ -0,0 +1,78 @@
+//! The Rust facade must lower lifecycle animations to the same canonical
+//! tracks as the Python authoring layer. Fixtures are regenerated with
+//! `web/python/export_rust_parity.py`.
+
+use noon::prelude::*;
+use noon::SceneDefinition;
+
+const LIFECYCLE_HANDOFFS: &str = include_str!("fixtures/lifecycle_handoffs.json");
+const MATCHING_SHAPES: &str = include_str!("fixtures/matching_shapes.json");
+
+/// Re-adds the first `count` Python objects so both sides share snapshots
+/// and the names Python derives from explicit keys.
+fn scene_from_fixture(json: &str, count: u64) -> (SceneDefinition, Scene, Vec<Mobject>) {
+    let expected = noon_ir::decode_scene(json).expect("fixture must decode");
+    let mut scene = Scene::new();
+    let objects = (0..count)
+        .map(|id| {
+            let id = ObjectId::new(id);
+            let snapshot = expected.snapshot(id).expect("fixture object exists");
+            let object = scene.add(snapshot);
+            let labels = expected
+                .object(id)
+                .expect("fixture object exists")
+                .labels
+                .clone();
+            scene.label(object, labels).expect("object was just added");
+            object
+        })
+        .collect();
+    (expected, scene, objects)
+}
+
+fn assert_same_lowering(actual: &Scene, expected: &SceneDefinition) {
+    assert_eq!(actual.definition().objects(), expected.objects());
+    assert_eq!(actual.definition().tracks(), expected.tracks());
+}
+
+#[test]
+fn lifecycle_handoffs_match_python_scene_document() {
+    let (expected, mut scene, objects) = scene_from_fixture(LIFECYCLE_HANDOFFS, 5);
+    let [first, middle, last, copy_source, copy_target] = objects[..] else {
+        unreachable!("five fixture objects");
+    };
+
+    scene
+        .play(ReplacementTransform::new(first, middle))
+        .with_easing(Easing::EaseInOutCubic)
+        .run_time(1.0)
+        .unwrap();
+    scene
+        .play(ReplacementTransform::new(middle, last))
+        .with_easing(Easing::EaseInOutCubic)
+        .run_time(1.0)
+        .unwrap();
+    scene
+        .play(TransformFromCopy::new(copy_source, copy_target))
+        .with_easing(Easing::EaseInOutCubic)
+        .run_time(1.4)
+        .unwrap();
+
+    assert_same_lowering(&scene, &expected);
+}
+
+#[test]
+fn matching_shapes_match_python_scene_document() {
+    let (expected, mut scene, objects) = scene_from_fixture(MATCHING_SHAPES, 6);
+
+    scene
+        .play(TransformMatchingShapes::new(
+            objects[..3].iter().copied(),
+            objects[3..].iter().copied(),
+        ))
+        .with_easing(Easing::EaseInOutCubic)
+        .run_time(2.8)
+        .unwrap();
+
+    assert_same_lowering(&scene, &expected);
+}
@@ -0,0 +1,41 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+fn text(frame: &noon_runtime::FrameState, index: usize) -> &str {
+    let GeometryRef::GlyphRun(run) = &frame.objects[index].geometry else {
+        panic!("counter must stay a glyph run");
+    };
+    &run.text
+}
+
+#[test]
+fn counters_are_retypeset_every_frame_even_while_moving() {
+    let mut scene = Scene::new();
+    let font = scene.load_font(TEST_FONT).unwrap();
+    let format = NumberFormat::new()
+        .decimal_places(0)
+        .prefix("$")
+        .group_separator(',');
+    let number = scene.add(scene.decimal_number(font, 0.0, format, 0.5).unwrap());
+    scene
+        .play((
+            ChangeDecimalToValue::new(number, 1250.0),
+            number.animate().shift(RIGHT),
+        ))
+        .run_time(2.0)
+        .unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("scene compiles");
+    let mut instance = SceneInstance::new(compiled);
+    assert_eq!(text(instance.seek(0.0).unwrap(), 0), "$0");
+
+    let frame = instance.advance_to(1.0).unwrap();
+    assert_eq!(text(frame, 0), "$625");
+    assert_eq!(frame.objects[0].transform.translation, RIGHT * 0.5);
+    let frame = instance.advance_to(2.0).unwrap();
+    assert_eq!(text(frame, 0), "$1,250");
+    assert_eq!(text(instance.seek(1.0).unwrap(), 0), "$625");
+}
@@ -0,0 +1,36 @@
+use noon::prelude::*;
+use noon_compile::CompiledScene;
+use noon_runtime::SceneInstance;
+
+const TEST_FONT: &[u8] = include_bytes!("../../../assets/fonts/noon-test.ttf");
+
+#[test]
+fn text_objects_move_and_fade_without_reshaping() {
+    let mut scene = Scene::new();
+    let font = scene.load_font(TEST_FONT).expect("test font must load");
+    let label = scene.text(font, "LOVI", 1.0).expect("text must shape");
+    let target = label.clone().shift(RIGHT * 2.0).color(RED);
+    let object = scene.add(label);
+
+    scene
+        .play(Transform::new(object, target))
+        .run_time(2.0)
+        .unwrap();
+    scene.play(FadeOut::new(object)).run_time(1.0).unwrap();
+
+    let compiled = CompiledScene::compile(scene.definition()).expect("text scene must compile");
+    assert_eq!(compiled.fonts().len(), 1);
+    let mut instance = SceneInstance::new(compiled);
+
+    let frame = instance.seek(1.0).expect("valid time");
+    let shaped = frame.objects[0].geometry.clone();
+    assert_eq!(frame.objects[0].transform.translation, Vec2::new(1.0, 0.0));
+    let GeometryRef::GlyphRun(run) = &shaped else {
+        panic!("text must stay a glyph run");
+    };
+    assert_eq!(run.text, "LOVI");
+
+    let frame = instance.seek(2.5).expect("valid time");
+    assert_eq!(frame.objects[0].appearance, 0.5);
+    assert_eq!(frame.objects[0].geometry, shaped);
+}
@@ -1,8 +1,10 @@
-import init, { NoonCanvasPlayer, demoSceneJson } from "./pkg/noon_web.js";
+import init, { NoonCanvasPlayer, bundleDuration, demoSceneJson } from "./pkg/noon_web.js";
 import { PythonAuthoringClient } from "./authoring-client.js";
 import { SceneIdentityMap } from "./scene-identity.js";
 import { SampleWindow } from "./frame-metrics.js";
 
+const DEMO_LOOP_SECONDS = 4.0;
+
 const canvas = document.querySelector("#scene");
 const status = document.querySelector("#status");
 const statusText = document.querySelector("#status-text");
@@ -307,6 +309,21 @@ function formatBytes(bytes) {
   return `${(bytes / (1024 * 1024)).toFixed(1)} MiB`;
 }
 
+async function loadSceneBundle(path) {
+  const response = await fetch(path);
+  if (!response.ok) {
+    throw new Error(`Unable to load scene bundle: HTTP ${response.status}`);
+  }
+  return new Uint8Array(await response.arrayBuffer());
+}
+
+// Bundles loop over their whole timeline. Looping needs a positive period,
+// so still scenes fall back to the demo loop.
+function bundleLoopSeconds(bytes) {
+  const duration = bundleDuration(bytes);
+  return duration > 0 ? duration : DEMO_LOOP_SECONDS;
+}
+
 async function loadDemoAuthoringSource(path) {
   const response = await fetch(path);
   if (!response.ok) {
@@ -321,7 +338,11 @@ try {
   }
 
   await init();
-  const player = await NoonCanvasPlayer.create(canvas, demoSceneJson(), 4.0);
+  const bundlePath = new URLSearchParams(window.location.search).get("bundle");
+  const bundle = bundlePath ? await loadSceneBundle(bundlePath) : null;
+  const player = bundle
+    ? await NoonCanvasPlayer.createFromBundle(canvas, bundle, bundleLoopSeconds(bundle))
+    : await NoonCanvasPlayer.create(canvas, demoSceneJson(), DEMO_LOOP_SECONDS);
   const gpuProfilingSupported = player.gpuProfilingSupported();
   player.setGpuProfilingEnabled(gpuProfilingSupported);
 
@@ -10,7 +10,7 @@ import copy
 import json
 import math
 from dataclasses import dataclass
-from typing import Any
+from typing import Any, Iterable
 
 FORMAT_VERSION = 1
 
@@ -842,7 +842,8 @@ class Scene:
             if animation.key is not None
             else f"@copy:{source_key}->{target_key}"
         )
-        copy_object = self._append_snapshot(source_snapshot, copy_key)
+        # The copy is a lowering detail, so its derived key names nothing.
+        copy_object = self._append_snapshot(source_snapshot, copy_key, named=False)
         transform_key = (
             animation.key if animation.key is not None else f"{copy_key}.transform"
         )
@@ -1026,6 +1027,35 @@ class Scene:
             key,
         )
 
+    def label(
+        self,
+        obj: Object,
+        *,
+        name: str | None = None,
+        tags: Iterable[str] = (),
+        metadata: dict[str, str] | None = None,
+    ) -> Scene:
+        """Attach authoring labels. Explicit keys already name their object."""
+        if not isinstance(obj, Object) or obj._owner is not self._owner:
+            raise ValueError("labelled object must belong to this Scene")
+        stored = self._objects[obj.id]
+        labels = copy.deepcopy(stored.get("labels", {}))
+        if name is not None:
+            labels["name"] = _authoring_key("name", name, "")
+        for tag in tags:
+            if not isinstance(tag, str):
+                raise TypeError("tags must be strings")
+            labels.setdefault("tags", [])
+            if tag not in labels["tags"]:
+                labels["tags"].append(tag)
+        for entry, value in (metadata or {}).items():
+            if not isinstance(entry, str) or not isinstance(value, str):
+                raise TypeError("metadata keys and values must be strings")
+            labels.setdefault("metadata", {})[entry] = value
+        if labels:
+            stored["labels"] = labels
+        return self
+
     def animate_position(
         self,
         obj: Object,
@@ -1238,7 +1268,7 @@ class Scene:
             )
 
     def _append_snapshot(
-        self, snapshot: dict[str, Any], key: str | None
+        self, snapshot: dict[str, Any], key: str | None, *, named: bool = True
     ) -> Object:
         object_id = len(self._objects)
         authoring_key = _authoring_key("key", key, f"@object:{object_id}")
@@ -1247,6 +1277,8 @@ class Scene:
         self._object_keys[object_id] = authoring_key
         stored = copy.deepcopy(snapshot)
         stored["id"] = object_id
+        if named and key is not None:
+            stored["labels"] = {"name": authoring_key}
         self._objects.append(stored)
         return Object(object_id, self._owner)
 
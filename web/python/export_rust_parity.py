@@ -0,0 +1,30 @@
+"""Regenerate the SceneDocument fixtures used by the Rust facade parity tests.
+
+Run from this directory: ``python3 export_rust_parity.py``.
+"""
+
+import runpy
+from pathlib import Path
+
+from noon import Scene
+
+
+EXAMPLES_DIR = Path(__file__).with_name("examples")
+FIXTURES_DIR = Path(__file__).parents[2] / "crates" / "noon" / "tests" / "fixtures"
+PARITY_EXAMPLES = (
+    "lifecycle_handoffs",
+    "matching_shapes",
+)
+
+
+def main() -> None:
+    FIXTURES_DIR.mkdir(parents=True, exist_ok=True)
+    for name in PARITY_EXAMPLES:
+        result = runpy.run_path(EXAMPLES_DIR / f"{name}.py")["result"]
+        if not isinstance(result, Scene):
+            raise TypeError(f"{name} must produce a Scene")
+        (FIXTURES_DIR / f"{name}.json").write_text(result.to_json() + "\n", encoding="utf-8")
+
+
+if __name__ == "__main__":
+    main()
@@ -755,6 +755,17 @@ class Scene(_ir.Scene):
             mobject._bind(self, raw_object)
         return flattened[0] if len(flattened) == 1 else self
 
+    def label(  # type: ignore[override]
+        self,
+        mobject: Mobject | _ir.Object,
+        *,
+        name: str | None = None,
+        tags: Iterable[str] = (),
+        metadata: dict[str, str] | None = None,
+    ) -> Scene:
+        super().label(self._raw_object(mobject), name=name, tags=tags, metadata=metadata)
+        return self
+
     def circle(self, radius: float, *, key: str | None = None, **kwargs: Any) -> Mobject:
         return self.add(Circle(radius, **kwargs), key=key)  # type: ignore[return-value]
 
@@ -48,7 +48,7 @@ class ReplacementTransformTests(unittest.TestCase):
         target_snapshot = {
             key: value
             for key, value in document["objects"][target.id].items()
-            if key != "id"
+            if key not in {"id", "labels"}
         }
         self.assertEqual(transform["values"]["object"]["to"], target_snapshot)
 
@@ -183,13 +183,19 @@ class TransformFromCopyTests(unittest.TestCase):
         )
 
         source_snapshot = {
-            key: value for key, value in document["objects"][0].items() if key != "id"
+            key: value
+            for key, value in document["objects"][0].items()
+            if key not in {"id", "labels"}
         }
         target_snapshot = {
-            key: value for key, value in document["objects"][1].items() if key != "id"
+            key: value
+            for key, value in document["objects"][1].items()
+            if key not in {"id", "labels"}
         }
         copy_snapshot = {
-            key: value for key, value in document["objects"][2].items() if key != "id"
+            key: value
+            for key, value in document["objects"][2].items()
+            if key not in {"id", "labels"}
         }
         self.assertEqual(copy_snapshot, source_snapshot)
 
@@ -331,7 +337,9 @@ class TransformFromCopyTests(unittest.TestCase):
 
         document = scene.to_document()
         copy_snapshot = {
-            key: value for key, value in document["objects"][2].items() if key != "id"
+            key: value
+            for key, value in document["objects"][2].items()
+            if key not in {"id", "labels"}
         }
         self.assertEqual(
             copy_snapshot["transform"]["translation"], {"x": 1.0, "y": 0.0}
@@ -343,6 +343,23 @@ class SceneTests(unittest.TestCase):
         with self.assertRaises(ValueError):
             scene.rectangle(1.0, 1.0, key="hero")
 
+    def test_explicit_keys_and_labels_serialize_as_object_labels(self) -> None:
+        scene = Scene()
+        hero = scene.circle(1.0, key="hero")
+        extra = scene.rectangle(1.0, 1.0)
+        scene.label(hero, tags=["cast", "cast"], metadata={"role": "lead"})
+        scene.label(extra, name="backdrop")
+
+        objects = scene.to_document()["objects"]
+        self.assertEqual(
+            objects[0]["labels"],
+            {"name": "hero", "tags": ["cast"], "metadata": {"role": "lead"}},
+        )
+        self.assertEqual(objects[1]["labels"], {"name": "backdrop"})
+        self.assertNotIn("labels", Scene().circle(1.0).to_ir())
+        with self.assertRaises(TypeError):
+            scene.label(hero, tags=[3])  # type: ignore[list-item]
+
 
 if __name__ == "__main__":
     unittest.main()